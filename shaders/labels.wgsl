@@ -0,0 +1,61 @@
+// Text label overlay.
+// Each glyph is an instanced quad positioned in pixels, and its coverage is read from the baked bitmap font atlas.
+
+// The size of one glyph cell in the font atlas, in texels. (5x7 glyph with 1 texel of spacing)
+const GLYPH_SIZE: vec2f = vec2f(6.0, 8.0);
+
+struct LabelUniformLayout {
+    viewport: vec2f,
+    _padding: vec2f,
+}
+
+struct GlyphInput {
+    @location(0) position: vec2f,
+    @location(1) scale: f32,
+    @location(2) glyph: u32,
+    @location(3) color: vec4f,
+}
+
+struct GlyphOutput {
+    @builtin(position) clip_position: vec4f,
+    @location(0) texel: vec2f,
+    @location(1) @interpolate(flat) glyph: u32,
+    @location(2) color: vec4f,
+}
+
+@group(0) @binding(0)
+var<uniform> label_data: LabelUniformLayout;
+@group(0) @binding(1)
+var font_atlas: texture_2d<f32>;
+
+
+
+@vertex
+fn vs_glyph(@builtin(vertex_index) index: u32, in: GlyphInput) -> GlyphOutput {
+    let corner = vec2f(f32(index & 1u), f32(index >> 1u));
+    let pixel = in.position + corner * GLYPH_SIZE * in.scale;
+
+    var out: GlyphOutput;
+    out.clip_position = vec4f(
+        pixel.x / label_data.viewport.x * 2.0 - 1.0,
+        1.0 - pixel.y / label_data.viewport.y * 2.0,
+        0.0,
+        1.0
+    );
+    out.texel = corner * GLYPH_SIZE;
+    out.glyph = in.glyph;
+    out.color = in.color;
+    return out;
+}
+
+@fragment
+fn fs_glyph(in: GlyphOutput) -> @location(0) vec4f {
+    let texel = vec2i(clamp(floor(in.texel), vec2f(0.0), GLYPH_SIZE - 1.0));
+    let origin = vec2i(i32(in.glyph) * i32(GLYPH_SIZE.x), 0);
+    let coverage = textureLoad(font_atlas, origin + texel, 0).r;
+    if (coverage < 0.5) {
+        discard;
+    }
+
+    return in.color;
+}
@@ -0,0 +1,25 @@
+// Push constant entry points. 
+// This file is appended to `shader.wgsl` only when the device supports the `PUSH_CONSTANTS` feature, 
+// because a `var<push_constant>` global fails shader validation without it. 
+
+// The object data pushed per draw. Same layout as the object uniform, so `ColoredObject::uniform_data` is pushed as is.
+var<push_constant> push_object_data: ObjectUniformLayout;
+
+
+
+@vertex
+fn vs_push_constant_main(in: VertexInput) -> VertexOutput {
+    let local_position = wobble_position(in.position, in.normal, push_object_data.wobble);
+    let color = highlight(push_object_data.color, push_object_data.flags);
+    return project_vertex(camera_data, push_object_data.world, local_position, in.normal, color, push_object_data.depth_bias);
+}
+
+@fragment
+fn fs_opaque_push_constant_main(in: VertexOutput) -> @location(0) vec4f {
+    return shade_opaque(in, push_object_data.roughness, push_object_data.metallic, push_object_data.emissive, 1.0);
+}
+
+@fragment
+fn fs_transparent_push_constant_pass(in: VertexOutput) -> TransparentPassOutput {
+    return shade_transparent(in, push_object_data.roughness, push_object_data.metallic);
+}
@@ -1,18 +1,162 @@
 const EPSILON: f32 = 1.192092896e-07f;
+const PI: f32 = 3.141592654;
+const TAU: f32 = 6.283185307;
+
+// Bits of `ObjectUniformLayout::flags`. Must match the constants in `objects.rs`.
+const OBJECT_FLAG_HIGHLIGHTED: u32 = 1u;
+const OBJECT_FLAG_CAST_SHADOW: u32 = 2u;
+const OBJECT_FLAG_RECEIVE_SHADOW: u32 = 4u;
+
+// If the depths of the four low resolution texels around a pixel differ from the scene depth by more than this, 
+// the pixel is treated as a depth edge and the texel with the nearest depth is used instead of bilinear filtering.
+const OIT_UPSAMPLE_DEPTH_THRESHOLD: f32 = 1e-3f;
+
+// The ground grid is pulled toward the camera by this fraction of its view distance, so it wins over a ground plane at y = 0.
+const GRID_DEPTH_BIAS: f32 = 1e-3f;
 
 struct VertexOutput {
-    @builtin(position) clip_position: vec4f, 
+    // Invariant so that the depth pre-pass and the opaque pass produce bit-identical depth for the `Equal` comparison.
+    @invariant @builtin(position) clip_position: vec4f, 
     @location(0) color: vec4f, 
+    @location(1) view_distance: f32, 
+    @location(2) view_position: vec3f, 
+    @location(3) view_normal: vec3f, 
+    @location(4) light_direction: vec3f, 
+    @location(5) world_normal: vec3f, 
+    @location(6) uv: vec2f, 
+}
+
+// Per-vertex attributes of a mesh. Must match `Vertex` in `mesh.rs`.
+// The normal is in local space. The quad has the local Z axis at every vertex.
+struct VertexInput {
+    @location(0) position: vec3f, 
+    @location(1) normal: vec3f, 
+}
+
+struct InstanceInput {
+    @location(5) world_0: vec4f, 
+    @location(6) world_1: vec4f, 
+    @location(7) world_2: vec4f, 
+    @location(8) world_3: vec4f, 
+    @location(9) color: vec4f, 
+}
+
+// Per-vertex attributes of a skinned mesh. Must match `SkinnedVertex` in `skinning.rs`.
+// Each vertex is influenced by up to four bones, and its weights sum to one.
+struct SkinnedVertexInput {
+    @location(0) position: vec3f, 
+    @location(1) normal: vec3f, 
+    @location(2) joints: vec4u, 
+    @location(3) weights: vec4f, 
 }
 
 struct CameraUniformLayout {
     camera: mat4x4f, 
     projection: mat4x4f, 
+    // inverse(projection * camera), unprojects clip space into world space.
+    inverse_view_projection: mat4x4f, 
+    // inverse(camera), the world transform of the camera. The last column is the camera position.
+    inverse_view: mat4x4f, 
 }
 
 struct ObjectUniformLayout {
     world: mat4x4f, 
     color: vec4f, 
+    wobble: vec4f, 
+    // rgb: emissive color, a: intensity. Added to the lit color in the opaque pass.
+    emissive: vec4f, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+    // Fraction of the view depth by which the depth of the vertex is pulled toward the camera. (see apply_depth_bias)
+    depth_bias: f32, 
+}
+
+// Uniform data of a textured object. Must match `TexturedObjectUniformLayout` in `objects.rs`.
+struct TexturedObjectUniformLayout {
+    world: mat4x4f, 
+    // xy: uv scale, zw: uv offset
+    uv_transform: vec4f, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+}
+
+struct GlobalUniformLayout {
+    hsv_adjust: vec3f, 
+    time: f32, 
+    // rgb: fog color
+    fog_color: vec4f, 
+    // x: density, y: falloff (0 = exp, 1 = exp2), z: enabled (0 or 1)
+    fog_params: vec4f, 
+    // rgb: highlight color, a: blend strength
+    highlight_color: vec4f, 
+    // xyz: direction the light travels (world space), w: enabled (0 or 1)
+    light_direction: vec4f, 
+    // rgb: light color * intensity, a: ambient
+    light_color: vec4f, 
+    // rgb: hemispheric ambient color toward up, a: enabled (0 or 1)
+    ambient_sky: vec4f, 
+    // rgb: hemispheric ambient color toward down
+    ambient_ground: vec4f, 
+    // x: bright pass threshold, y: bloom intensity
+    bloom_params: vec4f, 
+}
+
+// Weight function of the transparent pass. (McGuire and Bavoil, 2013)
+// w = clamp((min(1, a * alpha.x) + alpha.y)^alpha.z * depth.x * (1 - z * depth.y)^depth.z, clamp.x, clamp.y)
+struct WboitUniformLayout {
+    // x: alpha scale, y: alpha bias, z: alpha exponent
+    alpha_params: vec4f, 
+    // x: depth scale, y: depth range, z: depth exponent
+    depth_params: vec4f, 
+    // x: minimum weight, y: maximum weight of a transparent fragment, z: maximum accumulated weight (composite pass)
+    clamp_params: vec4f, 
+}
+
+// Uniform data of the ground grid. Must match `GridUniformLayout` in `grid.rs`.
+struct GridUniformLayout {
+    // rgba: line color
+    color: vec4f, 
+    // x: cell spacing, y: line width in pixels, z: fade distance, w: major line every N cells (0 = none)
+    params: vec4f, 
+}
+
+// Uniform data of the tone mapping pass. Must match `ToneMapUniformLayout` in `tonemap.rs`.
+struct ToneMapUniformLayout {
+    // x: exposure, y: operator (0 = Reinhard, 1 = ACES), z: encode sRGB in the shader (0 or 1)
+    params: vec4f, 
+}
+
+// Uniform data of the sky background. Must match `SkyUniformLayout` in `sky.rs`.
+struct SkyUniformLayout {
+    top_color: vec4f, 
+    bottom_color: vec4f, 
+}
+
+// Uniform data of the shadow map. Must match `ShadowUniformLayout` in `shadow.rs`.
+struct ShadowUniformLayout {
+    // World space to the clip space of the directional light. (orthographic, depth in [0, 1])
+    light_view_projection: mat4x4f, 
+    // x: constant bias, y: slope bias
+    params: vec4f, 
+}
+
+// Uniform data of the object ID pass. Must match `PickingUniformLayout` in `picking.rs`.
+struct PickingUniformLayout {
+    // Index of the object in the opaque draw list + 1. 0 is left for pixels without an object.
+    object_id: u32, 
+}
+
+// Output of a full-screen triangle. Shared by the ground grid, the sky and the bloom passes.
+struct FullScreenVertexOutput {
+    @builtin(position) clip_position: vec4f, 
+    @location(0) ndc: vec2f, 
+}
+
+struct GridFragmentOutput {
+    @location(0) color: vec4f, 
+    @builtin(frag_depth) depth: f32, 
 }
 
 struct TransparentPassOutput {
@@ -24,38 +168,203 @@ struct TransparentPassOutput {
 var<uniform> camera_data: CameraUniformLayout;
 @group(1) @binding(0)
 var<uniform> object_data: ObjectUniformLayout;
+@group(2) @binding(0)
+var<uniform> global_data: GlobalUniformLayout;
 @group(0) @binding(0)
 var accum: texture_2d<f32>;
 @group(0) @binding(1)
 var reveal: texture_2d<f32>;
+@group(0) @binding(2)
+var oit_sampler: sampler;
+@group(1) @binding(0)
+var scene_depth: texture_depth_2d;
+@group(2) @binding(0)
+var oit_depth: texture_depth_2d;
+// Bone matrices of the skeleton, in the local space of the object. (bone world * inverse bind matrix)
+@group(3) @binding(0)
+var<storage, read> bone_matrices: array<mat4x4f>;
+// Bind group 3 of the transparent pass and the upsampling composite pass.
+@group(3) @binding(0)
+var<uniform> wboit_data: WboitUniformLayout;
+// Bind group 1 of the composite pass. The same uniform as wboit_data.
+@group(1) @binding(0)
+var<uniform> composite_wboit_data: WboitUniformLayout;
+// Bind group 1 of textured objects. Only used by the textured entry points.
+@group(1) @binding(0)
+var<uniform> textured_object_data: TexturedObjectUniformLayout;
+@group(1) @binding(1)
+var albedo_texture: texture_2d<f32>;
+@group(1) @binding(2)
+var albedo_sampler: sampler;
+// Bind group 3 of the depth peel pass. Only used by the depth peel entry points.
+@group(3) @binding(0)
+var peel_scene_depth: texture_depth_2d;
+@group(3) @binding(1)
+var peel_previous_depth: texture_depth_2d;
+// Bind group 0 of the depth peel blend and composite passes.
+@group(0) @binding(0)
+var peel_color: texture_2d<f32>;
+// Bind group 1 of the ground grid. Only used by the grid entry points.
+@group(1) @binding(0)
+var<uniform> grid_data: GridUniformLayout;
+// Bind group 1 of the sky. Only used by the sky entry points.
+@group(1) @binding(0)
+var<uniform> sky_data: SkyUniformLayout;
+// Bind group 2 of the cubemap sky. Only used by fs_sky_cubemap.
+@group(2) @binding(0)
+var sky_texture: texture_cube<f32>;
+@group(2) @binding(1)
+var sky_sampler: sampler;
+// Bind group 0 of the post-process passes: the texture being read. (HDR scene or a blur target)
+@group(0) @binding(0)
+var post_process_source: texture_2d<f32>;
+@group(0) @binding(1)
+var post_process_sampler: sampler;
+// Bind group 1 of the bloom bright and composite passes. The same buffer as `global_data`, bound at slot 1.
+@group(1) @binding(0)
+var<uniform> bloom_global_data: GlobalUniformLayout;
+// Bind group 1 of the tone mapping pass.
+@group(1) @binding(0)
+var<uniform> tonemap_data: ToneMapUniformLayout;
+// Bind group 0 of the shadow map pass. Only used by vs_shadow.
+@group(0) @binding(0)
+var<uniform> shadow_light_data: ShadowUniformLayout;
+// Bind group 3 of the opaque pass with shadows. Only used by fs_opaque_shadowed_main.
+@group(3) @binding(0)
+var<uniform> shadow_data: ShadowUniformLayout;
+@group(3) @binding(1)
+var shadow_map: texture_depth_2d;
+@group(3) @binding(2)
+var shadow_sampler: sampler_comparison;
+// Bind group 2 of the object ID pass, bound with a dynamic offset per object. Only used by fs_picking.
+@group(2) @binding(0)
+var<uniform> picking_data: PickingUniformLayout;
 
 
 
 @vertex
-fn vs_main(@location(0) pos: vec3f) -> VertexOutput {
-    var out: VertexOutput;
-    out.clip_position = camera_data.projection * camera_data.camera * object_data.world * vec4f(pos, 1.0);
-    out.color = object_data.color;
-    return out;
+fn vs_main(in: VertexInput) -> VertexOutput {
+    return transform_vertex(camera_data, object_data.world, in.position, in.normal, object_data.color);
+}
+
+@vertex
+fn vs_instanced_main(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let instance_world = mat4x4f(instance.world_0, instance.world_1, instance.world_2, instance.world_3);
+    return transform_vertex(camera_data, object_data.world * instance_world, in.position, in.normal, object_data.color * instance.color);
+}
+
+// Depth pre-pass. Computes only the clip space position, with the same expression as transform_vertex.
+@vertex
+fn vs_depth_prepass(in: VertexInput) -> @invariant @builtin(position) vec4f {
+    return project_position(camera_data, object_data.world, wobble_position(in.position, in.normal, object_data.wobble), object_data.depth_bias);
+}
+
+@vertex
+fn vs_depth_prepass_instanced(in: VertexInput, instance: InstanceInput) -> @invariant @builtin(position) vec4f {
+    let instance_world = mat4x4f(instance.world_0, instance.world_1, instance.world_2, instance.world_3);
+    return project_position(camera_data, object_data.world * instance_world, wobble_position(in.position, in.normal, object_data.wobble), object_data.depth_bias);
+}
+
+// Shadow map pass. Draws the depth of shadow casters from the light's point of view.
+@vertex
+fn vs_shadow(in: VertexInput) -> @builtin(position) vec4f {
+    let local_position = wobble_position(in.position, in.normal, object_data.wobble);
+    return shadow_light_data.light_view_projection * object_data.world * vec4f(local_position, 1.0);
+}
+
+@vertex
+fn vs_skinned_main(in: SkinnedVertexInput) -> VertexOutput {
+    let skin = skin_matrix(in.joints, in.weights);
+    let position = (skin * vec4f(in.position, 1.0)).xyz;
+    let normal = (skin * vec4f(in.normal, 0.0)).xyz;
+    return transform_vertex(camera_data, object_data.world, position, normal, object_data.color);
+}
+
+@vertex
+fn vs_textured_main(in: VertexInput) -> VertexOutput {
+    return transform_textured_vertex(camera_data, in.position, in.normal);
 }
 
 @fragment
 fn fs_opaque_main(in: VertexOutput) -> @location(0) vec4f {
-    return in.color;
+    return shade_opaque(in, object_data.roughness, object_data.metallic, object_data.emissive, 1.0);
+}
+
+// Opaque pass with shadows. The world position is recovered from the view position with the inverse view matrix.
+@fragment
+fn fs_opaque_shadowed_main(in: VertexOutput) -> @location(0) vec4f {
+    var shadow = 1.0;
+    if ((object_data.flags & OBJECT_FLAG_RECEIVE_SHADOW) != 0u) {
+        let world_position = (camera_data.inverse_view * vec4f(in.view_position, 1.0)).xyz;
+        shadow = shadow_factor(world_position, in.world_normal);
+    }
+    return shade_opaque(in, object_data.roughness, object_data.metallic, object_data.emissive, shadow);
+}
+
+// Object ID pass. The vertex stage is vs_depth_prepass, and the depth test keeps the nearest object per pixel.
+@fragment
+fn fs_picking() -> @location(0) u32 {
+    return picking_data.object_id;
+}
+
+// Textured quads are drawn in the opaque pass, so texels with alpha below one half are cut out.
+@fragment
+fn fs_textured_main(in: VertexOutput) -> @location(0) vec4f {
+    let albedo = textureSample(albedo_texture, albedo_sampler, in.uv);
+    if (albedo.a < 0.5) {
+        discard;
+    }
+
+    var color = adjust_hsv(highlight(vec4f(albedo.rgb, 1.0), textured_object_data.flags));
+    color = vec4f(shade_surface(color.rgb, in, textured_object_data.roughness, textured_object_data.metallic), color.a);
+    return vec4f(apply_fog(color.rgb, in.view_distance), color.a);
+}
+
+@fragment
+fn fs_wireframe_main(in: VertexOutput) -> @location(0) vec4f {
+    // Picks black or white edges depending on the luminance of the filled face.
+    let luminance = dot(in.color.rgb, vec3f(0.2126, 0.7152, 0.0722));
+    return select(vec4f(1.0, 1.0, 1.0, 1.0), vec4f(0.0, 0.0, 0.0, 1.0), luminance > 0.5);
 }
 
 @fragment
 fn fs_transparent_pass(in: VertexOutput) -> TransparentPassOutput {
-    let depth = in.clip_position.z;
-    let color = in.color;
+    return shade_transparent(in, object_data.roughness, object_data.metallic);
+}
+
+// Sorted alpha blending path. Outputs a premultiplied alpha color, and the blend state of the pipeline selects the blend mode.
+@fragment
+fn fs_sorted_transparent_main(in: VertexOutput) -> @location(0) vec4f {
+    var color = adjust_hsv(in.color);
+    color = vec4f(apply_fog(apply_lighting(color.rgb, in), in.view_distance), color.a);
+    return vec4f(color.rgb * color.a, color.a);
+}
 
-    let weight: f32 = clamp(pow(min(1.0, color.a * 10.0) + 0.01, 3.0) * 1e8f * pow(1.0 - depth * 0.9, 3.0), 1e-2f, 3e3f);
+// Depth peeling. Peels the nearest transparent surface behind the previous layer and outputs it with premultiplied alpha.
+@fragment
+fn fs_depth_peel(in: VertexOutput) -> @location(0) vec4f {
+    if (is_peeled_away(in.clip_position, false)) {
+        discard;
+    }
+    return peel_layer_color(in);
+}
 
-    var out: TransparentPassOutput;
-    out.accum = vec4f(color.rgb * color.a, color.a) * weight;
-    out.reveal = color.a;
+@fragment
+fn fs_depth_peel_reverse_z(in: VertexOutput) -> @location(0) vec4f {
+    if (is_peeled_away(in.clip_position, true)) {
+        discard;
+    }
+    return peel_layer_color(in);
+}
 
-    return out;
+// Shared by the blend pass (under operator) and the composite pass (over operator) of depth peeling. The pipeline blend state selects the operator.
+@fragment
+fn fs_depth_peel_resolve_pass(@builtin(position) clip_position: vec4f) -> @location(0) vec4f {
+    let color = textureLoad(peel_color, vec2i(clip_position.xy), 0);
+    if (color.a <= 0.0) {
+        discard;
+    }
+    return color;
 }
 
 @vertex
@@ -92,17 +401,346 @@ fn fs_composite_pass(@builtin(position) clip_position: vec4f) -> @location(0) ve
         discard;
     }
 
-    var accumulation: vec4f = textureLoad(accum, coords, 0);
+    return resolve_oit(textureLoad(accum, coords, 0), revealage, composite_wboit_data.clamp_params.z);
+}
+
+@fragment
+fn fs_downsample_depth(@builtin(position) clip_position: vec4f) -> @builtin(frag_depth) f32 {
+    return downsample_depth(clip_position.xy, false);
+}
+
+@fragment
+fn fs_downsample_depth_reverse_z(@builtin(position) clip_position: vec4f) -> @builtin(frag_depth) f32 {
+    return downsample_depth(clip_position.xy, true);
+}
+
+@fragment
+fn fs_upsampled_composite_pass(@builtin(position) clip_position: vec4f) -> @location(0) vec4f {
+    let scene_size = vec2f(textureDimensions(scene_depth));
+    let oit_size = vec2i(textureDimensions(oit_depth));
+    let uv = clip_position.xy / scene_size;
+    let depth = textureLoad(scene_depth, vec2i(clip_position.xy), 0);
+
+    // Finds the low resolution texel whose depth is nearest to the scene depth among the four bilinear taps.
+    let base = vec2i(floor(uv * vec2f(oit_size) - 0.5));
+    var nearest = clamp(base, vec2i(0), oit_size - 1);
+    var nearest_difference = 3.402823e38f;
+    var max_difference = 0.0;
+    for (var i = 0u; i < 4u; i++) {
+        let coords = clamp(base + vec2i(i32(i & 1u), i32(i >> 1u)), vec2i(0), oit_size - 1);
+        let difference = abs(textureLoad(oit_depth, coords, 0) - depth);
+        if (difference < nearest_difference) {
+            nearest_difference = difference;
+            nearest = coords;
+        }
+        max_difference = max(max_difference, difference);
+    }
+
+    // Filters bilinearly on flat regions, and picks the nearest-depth texel on depth edges so transparency does not bleed across them.
+    var accum_value: vec4f;
+    var revealage: f32;
+    if (max_difference < OIT_UPSAMPLE_DEPTH_THRESHOLD) {
+        accum_value = textureSampleLevel(accum, oit_sampler, uv, 0.0);
+        revealage = textureSampleLevel(reveal, oit_sampler, uv, 0.0).r;
+    } else {
+        accum_value = textureLoad(accum, nearest, 0);
+        revealage = textureLoad(reveal, nearest, 0).r;
+    }
+
+    if (is_approximately_equal(revealage, 1.0)) {
+        discard;
+    }
+
+    return resolve_oit(accum_value, revealage, wboit_data.clamp_params.z);
+}
+
+@vertex
+fn vs_grid(@builtin(vertex_index) index: u32) -> FullScreenVertexOutput {
+    return full_screen_triangle(index, 0.0);
+}
+
+@fragment
+fn fs_grid(in: FullScreenVertexOutput) -> GridFragmentOutput {
+    // Unprojects two points of the pixel ray and intersects the ray with the y = 0 plane.
+    // Depth 0.5 and 1.0 are finite points for both standard and reverse-Z (infinite) projections.
+    let p0 = unproject_ndc(vec3f(in.ndc, 0.5));
+    let p1 = unproject_ndc(vec3f(in.ndc, 1.0));
+    let direction = p1 - p0;
+    let parallel = abs(direction.y) <= EPSILON;
+    let t = -p0.y / select(direction.y, 1.0, parallel);
+    let world_position = p0 + direction * t;
+
+    let view_position = camera_data.camera * vec4f(world_position, 1.0);
+    let clip_position = camera_data.projection * vec4f(view_position.xyz * (1.0 - GRID_DEPTH_BIAS), 1.0);
+    let depth = clip_position.z / clip_position.w;
+    let visible = !parallel && clip_position.w > 0.0 && depth >= 0.0 && depth <= 1.0;
+
+    // Derivatives must be taken before any discard. Line distances are measured in pixels, so lines keep their width at any distance.
+    let spacing = grid_data.params.x;
+    let line_width = grid_data.params.y;
+    let major_every = grid_data.params.w;
+    let minor = grid_line_coverage(world_position.xz / spacing, line_width);
+    let major = grid_line_coverage(world_position.xz / (spacing * max(major_every, 1.0)), line_width * 1.5);
+    let coverage = max(0.5 * minor, select(0.0, major, major_every >= 1.0));
+
+    let fade = 1.0 - saturate(length(view_position.xyz) / grid_data.params.z);
+    let alpha = grid_data.color.a * coverage * fade;
+    if (!visible || alpha <= 0.0) {
+        discard;
+    }
+
+    var out: GridFragmentOutput;
+    out.color = vec4f(grid_data.color.rgb, alpha);
+    out.depth = depth;
+    return out;
+}
+
+// The sky is placed on the far plane, so it only passes the `LessEqual` (`GreaterEqual` with reverse-Z) test where nothing was drawn yet.
+@vertex
+fn vs_sky(@builtin(vertex_index) index: u32) -> FullScreenVertexOutput {
+    return full_screen_triangle(index, 1.0);
+}
+
+@vertex
+fn vs_sky_reverse_z(@builtin(vertex_index) index: u32) -> FullScreenVertexOutput {
+    return full_screen_triangle(index, 0.0);
+}
+
+@fragment
+fn fs_sky_gradient(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    let direction = sky_view_direction(in.ndc);
+    return vec4f(mix(sky_data.bottom_color.rgb, sky_data.top_color.rgb, direction.y * 0.5 + 0.5), 1.0);
+}
+
+@fragment
+fn fs_sky_cubemap(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    let direction = sky_view_direction(in.ndc);
+    return vec4f(textureSample(sky_texture, sky_sampler, direction).rgb, 1.0);
+}
+
+@vertex
+fn vs_post_process(@builtin(vertex_index) index: u32) -> FullScreenVertexOutput {
+    return full_screen_triangle(index, 0.0);
+}
+
+// Keeps only the part of the HDR color above the threshold. The target has half resolution, so the linear sampler averages 2x2 scene texels.
+@fragment
+fn fs_bloom_bright(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    let color = textureSample(post_process_source, post_process_sampler, ndc_to_uv(in.ndc)).rgb;
+    let brightness = max(color.r, max(color.g, color.b));
+    let contribution = max(brightness - bloom_global_data.bloom_params.x, 0.0) / max(brightness, EPSILON);
+    return vec4f(color * contribution, 1.0);
+}
+
+@fragment
+fn fs_bloom_blur_horizontal(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    return vec4f(gaussian_blur(ndc_to_uv(in.ndc), vec2f(1.0, 0.0)), 1.0);
+}
+
+@fragment
+fn fs_bloom_blur_vertical(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    return vec4f(gaussian_blur(ndc_to_uv(in.ndc), vec2f(0.0, 1.0)), 1.0);
+}
+
+// Upsamples the blurred bright parts. They are added to the HDR scene with additive blending, and alpha 0 keeps the scene alpha.
+@fragment
+fn fs_bloom_composite(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    let bloom = textureSample(post_process_source, post_process_sampler, ndc_to_uv(in.ndc)).rgb;
+    return vec4f(bloom * bloom_global_data.bloom_params.y, 0.0);
+}
+
+// Compresses the exposed HDR scene into [0, 1] with the selected operator. Gamma is encoded here only if the surface is not an sRGB format.
+@fragment
+fn fs_tonemap(in: FullScreenVertexOutput) -> @location(0) vec4f {
+    let hdr = textureSample(post_process_source, post_process_sampler, ndc_to_uv(in.ndc)).rgb * tonemap_data.params.x;
+    var color = select(tonemap_reinhard(hdr), tonemap_aces(hdr), tonemap_data.params.y >= 1.0);
+    if (tonemap_data.params.z >= 1.0) {
+        color = linear_to_srgb(color);
+    }
+    return vec4f(color, 1.0);
+}
+
+fn downsample_depth(position: vec2f, reverse_z: bool) -> f32 {
+    // The size of the accumulation texture is the size of the low resolution render target.
+    let scene_size = vec2i(textureDimensions(scene_depth));
+    let scale = vec2f(scene_size) / vec2f(textureDimensions(accum));
+    let pixel = floor(position);
+    let min_coords = vec2i(floor(pixel * scale));
+    let max_coords = min(vec2i(ceil((pixel + 1.0) * scale)), scene_size);
+
+    // Keeps the farthest depth in the covered area.
+    var farthest = select(0.0, 1.0, reverse_z);
+    for (var y = min_coords.y; y < max_coords.y; y++) {
+        for (var x = min_coords.x; x < max_coords.x; x++) {
+            let depth = textureLoad(scene_depth, vec2i(x, y), 0);
+            farthest = select(max(farthest, depth), min(farthest, depth), reverse_z);
+        }
+    }
+    return farthest;
+}
+
+// Whether the fragment is occluded by opaque objects, or is not behind the previously peeled layer.
+fn is_peeled_away(clip_position: vec4f, reverse_z: bool) -> bool {
+    let coords = vec2i(clip_position.xy);
+    let depth = clip_position.z;
+    let scene = textureLoad(peel_scene_depth, coords, 0);
+    let previous = textureLoad(peel_previous_depth, coords, 0);
+    let occluded = select(depth >= scene, depth <= scene, reverse_z);
+    let in_front = select(depth <= previous, depth >= previous, reverse_z);
+    return occluded || in_front;
+}
+
+// Shades a peeled layer the same way as the transparent pass, so non-overlapping surfaces match weighted blended OIT.
+fn peel_layer_color(in: VertexOutput) -> vec4f {
+    var color = adjust_hsv(in.color);
+    color = vec4f(apply_fog(apply_lighting(color.rgb, in), in.view_distance), color.a);
+    return vec4f(color.rgb * color.a, color.a);
+}
+
+// Caps the accumulated weight at max_weight, trading physical correctness for a bound on how much many overlapping layers can add up.
+fn resolve_oit(accum_value: vec4f, revealage: f32, max_weight: f32) -> vec4f {
+    var accumulation: vec4f = accum_value;
 
     if (is_infinite(max(max(abs(accumulation.x), abs(accumulation.y)), abs(accumulation.z)))) {
         accumulation = vec4f(accumulation.a, accumulation.a, accumulation.a, accumulation.a);
     }
 
-    let average_color = accumulation.rgb / max(accumulation.a, EPSILON);
+    let average_color = accumulation.rgb / clamp(accumulation.a, EPSILON, max_weight);
 
     return vec4f(average_color, 1.0 - revealage);
 }
 
+// Computes the weight of a transparent fragment from its alpha and depth. (see WboitUniformLayout)
+fn wboit_weight(alpha: f32, depth: f32) -> f32 {
+    let alpha_term = pow(min(1.0, alpha * wboit_data.alpha_params.x) + wboit_data.alpha_params.y, wboit_data.alpha_params.z);
+    let depth_term = wboit_data.depth_params.x * pow(1.0 - depth * wboit_data.depth_params.y, wboit_data.depth_params.z);
+    return clamp(alpha_term * depth_term, wboit_data.clamp_params.x, wboit_data.clamp_params.y);
+}
+
+// Blends the bone matrices by the vertex weights. (linear blend skinning)
+// Non-uniformly scaled bones would need the inverse transpose for normals, which is ignored here.
+fn skin_matrix(joints: vec4u, weights: vec4f) -> mat4x4f {
+    return bone_matrices[joints.x] * weights.x
+        + bone_matrices[joints.y] * weights.y
+        + bone_matrices[joints.z] * weights.z
+        + bone_matrices[joints.w] * weights.w;
+}
+
+fn transform_vertex(camera: CameraUniformLayout, world: mat4x4f, pos: vec3f, normal: vec3f, color: vec4f) -> VertexOutput {
+    return project_vertex(camera, world, wobble_position(pos, normal, object_data.wobble), normal, apply_highlight(color), object_data.depth_bias);
+}
+
+// Displaces a local vertex along its normal by the wobble of the object. (x: amplitude, y: frequency)
+fn wobble_position(pos: vec3f, normal: vec3f, wobble: vec4f) -> vec3f {
+    var local_position = pos;
+    if (wobble.x != 0.0) {
+        local_position += normal * wobble_offset(pos, wobble.x, wobble.y);
+    }
+    return local_position;
+}
+
+// Transforms a local position into clip space. Shared by project_vertex and the depth pre-pass.
+fn project_position(camera: CameraUniformLayout, world: mat4x4f, local_position: vec3f, depth_bias: f32) -> vec4f {
+    let view_position = camera.camera * world * vec4f(local_position, 1.0);
+    return apply_depth_bias(camera, view_position, camera.projection * view_position, depth_bias);
+}
+
+// Replaces the depth of a clip space position with the depth of the vertex pulled toward the camera by `view depth * depth_bias`.
+// Only the view space z is scaled and x, y and w are kept, so the screen position does not change,
+// and the biased depth is nearer in every projection, handedness and depth direction (reverse-Z).
+fn apply_depth_bias(camera: CameraUniformLayout, view_position: vec4f, clip_position: vec4f, depth_bias: f32) -> vec4f {
+    if (depth_bias == 0.0) {
+        return clip_position;
+    }
+    let biased = camera.projection * vec4f(view_position.xy, view_position.z * (1.0 - depth_bias), 1.0);
+    return vec4f(clip_position.xy, biased.z / biased.w * clip_position.w, clip_position.w);
+}
+
+// Transforms a normalized device coordinate back into world space with the inverse view-projection matrix.
+fn unproject_ndc(ndc: vec3f) -> vec3f {
+    let position = camera_data.inverse_view_projection * vec4f(ndc, 1.0);
+    return position.xyz / position.w;
+}
+
+// Draws a triangle that covers the whole screen at the given depth. Unlike the composite quad, it needs no strip index format.
+fn full_screen_triangle(index: u32, depth: f32) -> FullScreenVertexOutput {
+    let ndc = vec2f(f32((index << 1u) & 2u), f32(index & 2u)) * 2.0 - 1.0;
+    var out: FullScreenVertexOutput;
+    out.clip_position = vec4f(ndc, depth, 1.0);
+    out.ndc = ndc;
+    return out;
+}
+
+// Texture coordinates of a normalized device coordinate. (y points down in texture space)
+fn ndc_to_uv(ndc: vec2f) -> vec2f {
+    return ndc * vec2f(0.5, -0.5) + 0.5;
+}
+
+// Separable 9-tap Gaussian blur of the bloom source along the given direction, one texel apart.
+fn gaussian_blur(uv: vec2f, direction: vec2f) -> vec3f {
+    var weights = array<f32, 5>(0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+    let texel = direction / vec2f(textureDimensions(post_process_source));
+    var color = textureSample(post_process_source, post_process_sampler, uv).rgb * weights[0];
+    for (var i = 1; i < 5; i++) {
+        let offset = texel * f32(i);
+        color += textureSample(post_process_source, post_process_sampler, uv + offset).rgb * weights[i];
+        color += textureSample(post_process_source, post_process_sampler, uv - offset).rgb * weights[i];
+    }
+    return color;
+}
+
+// Reinhard tonemapping curve.
+fn tonemap_reinhard(color: vec3f) -> vec3f {
+    return color / (1.0 + color);
+}
+
+// Fitted ACES filmic tonemapping curve. (Narkowicz, 2015)
+fn tonemap_aces(color: vec3f) -> vec3f {
+    return saturate((color * (2.51 * color + 0.03)) / (color * (2.43 * color + 0.59) + 0.14));
+}
+
+// Encodes a linear color with the sRGB transfer function.
+fn linear_to_srgb(color: vec3f) -> vec3f {
+    let c = max(color, vec3f(0.0));
+    return select(1.055 * pow(c, vec3f(1.0 / 2.4)) - 0.055, c * 12.92, c <= vec3f(0.0031308));
+}
+
+// World space view direction of a pixel, from the camera position (the last column of the inverse view matrix) to the unprojected pixel.
+fn sky_view_direction(ndc: vec2f) -> vec3f {
+    return normalize(unproject_ndc(vec3f(ndc, 0.5)) - camera_data.inverse_view[3].xyz);
+}
+
+// Anti-aliased coverage of the grid lines at the given cell coordinates, with the line width in pixels.
+fn grid_line_coverage(coords: vec2f, line_width: f32) -> f32 {
+    let derivative = max(fwidth(coords), vec2f(EPSILON));
+    let line_distance = abs(fract(coords - 0.5) - 0.5) / derivative;
+    return saturate(0.5 * line_width + 0.5 - min(line_distance.x, line_distance.y));
+}
+
+// Maps the [-1, 1] quad to [0, 1] texture coordinates with v pointing down, then applies the uv scale and offset.
+fn transform_textured_vertex(camera: CameraUniformLayout, pos: vec3f, normal: vec3f) -> VertexOutput {
+    var out = project_vertex(camera, textured_object_data.world, pos, normal, vec4f(1.0), 0.0);
+    let uv = vec2f(pos.x * 0.5 + 0.5, 0.5 - pos.y * 0.5);
+    out.uv = uv * textured_object_data.uv_transform.xy + textured_object_data.uv_transform.zw;
+    return out;
+}
+
+// Transforms a local vertex into clip space without touching any object uniform data.
+fn project_vertex(camera: CameraUniformLayout, world: mat4x4f, local_position: vec3f, normal: vec3f, color: vec4f, depth_bias: f32) -> VertexOutput {
+    let view_position = camera.camera * world * vec4f(local_position, 1.0);
+
+    var out: VertexOutput;
+    out.clip_position = project_position(camera, world, local_position, depth_bias);
+    out.color = color;
+    out.view_distance = length(view_position.xyz);
+    out.view_position = view_position.xyz;
+    out.view_normal = (camera.camera * world * vec4f(normal, 0.0)).xyz;
+    out.light_direction = (camera.camera * vec4f(global_data.light_direction.xyz, 0.0)).xyz;
+    out.world_normal = (world * vec4f(normal, 0.0)).xyz;
+    out.uv = vec2f(0.0);
+    return out;
+}
+
 fn is_infinite(v: f32) -> bool {
     return v != 0.0 && v * 2.0 == v;
 }
@@ -110,3 +748,168 @@ fn is_infinite(v: f32) -> bool {
 fn is_approximately_equal(a: f32, b: f32) -> bool {
     return abs(a - b) <= min(abs(a), abs(b)) * EPSILON;
 }
+
+fn rgb_to_hsv(c: vec3f) -> vec3f {
+    let k = vec4f(0.0, -1.0 / 3.0, 2.0 / 3.0, -1.0);
+    let p = mix(vec4f(c.bg, k.wz), vec4f(c.gb, k.xy), step(c.b, c.g));
+    let q = mix(vec4f(p.xyw, c.r), vec4f(c.r, p.yzx), step(p.x, c.r));
+    let d = q.x - min(q.w, q.y);
+    return vec3f(abs(q.z + (q.w - q.y) / (6.0 * d + EPSILON)), d / (q.x + EPSILON), q.x);
+}
+
+fn hsv_to_rgb(c: vec3f) -> vec3f {
+    let k = vec4f(1.0, 2.0 / 3.0, 1.0 / 3.0, 3.0);
+    let p = abs(fract(c.xxx + k.xyz) * 6.0 - k.www);
+    return c.z * mix(k.xxx, clamp(p - k.xxx, vec3f(0.0), vec3f(1.0)), c.y);
+}
+
+fn adjust_hsv(color: vec4f) -> vec4f {
+    var hsv = rgb_to_hsv(color.rgb);
+    hsv.x = fract(hsv.x + global_data.hsv_adjust.x);
+    hsv.y = clamp(hsv.y * global_data.hsv_adjust.y, 0.0, 1.0);
+    hsv.z = hsv.z * global_data.hsv_adjust.z;
+    return vec4f(hsv_to_rgb(hsv), color.a);
+}
+
+fn wobble_offset(pos: vec3f, amplitude: f32, frequency: f32) -> f32 {
+    let phase = (pos.x + pos.y) * PI;
+    return amplitude * sin(TAU * frequency * global_data.time + phase);
+}
+
+fn apply_fog(color: vec3f, distance: f32) -> vec3f {
+    if (global_data.fog_params.z == 0.0) {
+        return color;
+    }
+
+    let density_distance = global_data.fog_params.x * distance;
+    let factor = select(
+        exp(-density_distance), 
+        exp(-density_distance * density_distance), 
+        global_data.fog_params.y > 0.5
+    );
+    return mix(global_data.fog_color.rgb, color, clamp(factor, 0.0, 1.0));
+}
+
+// Shades an opaque fragment. Shared by the uniform and push constant paths, which differ only in where the object data comes from.
+// `shadow` is the fraction of the directional light reaching the fragment. (1 = fully lit)
+fn shade_opaque(in: VertexOutput, roughness: f32, metallic: f32, emissive: vec4f, shadow: f32) -> vec4f {
+    var color = adjust_hsv(in.color);
+    // Emission is not lit, but it is fogged like the rest of the surface.
+    color = vec4f(shade_surface_shadowed(color.rgb, in, roughness, metallic, shadow) + emissive.rgb * emissive.a, color.a);
+    return vec4f(apply_fog(color.rgb, in.view_distance), color.a);
+}
+
+// Shades and weights a transparent fragment. Shared by the uniform and push constant paths.
+fn shade_transparent(in: VertexOutput, roughness: f32, metallic: f32) -> TransparentPassOutput {
+    let depth = in.clip_position.z;
+    // Fog is applied to the color before it is premultiplied and weighted, so the composite resolves fogged layers.
+    var color = adjust_hsv(in.color);
+    color = vec4f(apply_fog(shade_surface(color.rgb, in, roughness, metallic), in.view_distance), color.a);
+
+    let weight: f32 = wboit_weight(color.a, depth);
+
+    var out: TransparentPassOutput;
+    out.accum = vec4f(color.rgb * color.a, color.a) * weight;
+    out.reveal = color.a;
+
+    return out;
+}
+
+fn apply_lighting(albedo: vec3f, in: VertexOutput) -> vec3f {
+    return shade_surface(albedo, in, object_data.roughness, object_data.metallic);
+}
+
+fn shade_surface(albedo: vec3f, in: VertexOutput, surface_roughness: f32, surface_metallic: f32) -> vec3f {
+    return shade_surface_shadowed(albedo, in, surface_roughness, surface_metallic, 1.0);
+}
+
+// Shades the albedo with the directional light using a Cook-Torrance BRDF. (GGX distribution, Smith geometry, Schlick Fresnel)
+// Lighting is computed in view space. Quads are double sided, so the normal is flipped toward the viewer.
+// The shadow scales only the direct light, so shadowed areas keep the ambient term.
+fn shade_surface_shadowed(albedo: vec3f, in: VertexOutput, surface_roughness: f32, surface_metallic: f32, shadow: f32) -> vec3f {
+    if (global_data.light_direction.w == 0.0) {
+        return albedo;
+    }
+
+    let v = normalize(-in.view_position);
+    var n = normalize(in.view_normal);
+    let facing = select(1.0, -1.0, dot(n, v) < 0.0);
+    n *= facing;
+    let l = -normalize(in.light_direction);
+    let h = normalize(v + l);
+
+    let n_dot_l = max(dot(n, l), 0.0);
+    let n_dot_v = max(dot(n, v), EPSILON);
+    let n_dot_h = max(dot(n, h), 0.0);
+    let v_dot_h = max(dot(v, h), 0.0);
+
+    let roughness = clamp(surface_roughness, 0.04, 1.0);
+    let metallic = clamp(surface_metallic, 0.0, 1.0);
+
+    // GGX normal distribution function.
+    let alpha = roughness * roughness;
+    let alpha2 = alpha * alpha;
+    let d_denom = n_dot_h * n_dot_h * (alpha2 - 1.0) + 1.0;
+    let d = alpha2 / (PI * d_denom * d_denom);
+
+    // Smith geometry term with the Schlick-GGX approximation for direct lighting.
+    let k = (roughness + 1.0) * (roughness + 1.0) / 8.0;
+    let g = (n_dot_l / (n_dot_l * (1.0 - k) + k)) * (n_dot_v / (n_dot_v * (1.0 - k) + k));
+
+    // Schlick Fresnel. Dielectrics reflect about 4% at normal incidence.
+    let f0 = mix(vec3f(0.04), albedo, metallic);
+    let f = f0 + (1.0 - f0) * pow(1.0 - v_dot_h, 5.0);
+
+    let specular = d * g * f / max(4.0 * n_dot_l * n_dot_v, EPSILON);
+    let diffuse = (1.0 - f) * (1.0 - metallic) * albedo / PI;
+    let ambient = ambient_light(normalize(in.world_normal) * facing) * albedo;
+    return ambient + (diffuse + specular) * global_data.light_color.rgb * n_dot_l * shadow;
+}
+
+// Fraction of the directional light reaching a world space position, from the shadow map with 3x3 percentage-closer filtering.
+// Positions outside the orthographic bounds of the light are treated as lit.
+// The slope-scaled bias grows as the surface turns away from the light, where one shadow map texel covers a larger depth range. (shadow acne)
+fn shadow_factor(world_position: vec3f, world_normal: vec3f) -> f32 {
+    let light_clip = shadow_data.light_view_projection * vec4f(world_position, 1.0);
+    let ndc = light_clip.xyz / light_clip.w;
+    let uv = ndc_to_uv(ndc.xy);
+    if (any(uv < vec2f(0.0)) || any(uv > vec2f(1.0)) || ndc.z > 1.0) {
+        return 1.0;
+    }
+
+    // Quads are double sided, so the absolute cosine is used.
+    let n_dot_l = abs(dot(normalize(world_normal), normalize(global_data.light_direction.xyz)));
+    let bias = max(shadow_data.params.y * (1.0 - n_dot_l), shadow_data.params.x);
+    let texel = 1.0 / vec2f(textureDimensions(shadow_map));
+    var lit = 0.0;
+    for (var y = -1; y <= 1; y++) {
+        for (var x = -1; x <= 1; x++) {
+            lit += textureSampleCompareLevel(shadow_map, shadow_sampler, uv + vec2f(f32(x), f32(y)) * texel, ndc.z - bias);
+        }
+    }
+    return lit / 9.0;
+}
+
+// Hemispheric ambient: blends the ground color toward the sky color as the world space normal turns up.
+// Falls back to the uniform ambient of the directional light if disabled.
+fn ambient_light(world_normal: vec3f) -> vec3f {
+    if (global_data.ambient_sky.a == 0.0) {
+        return vec3f(global_data.light_color.a);
+    }
+
+    let t = clamp(world_normal.y * 0.5 + 0.5, 0.0, 1.0);
+    return mix(global_data.ambient_ground.rgb, global_data.ambient_sky.rgb, t);
+}
+
+fn apply_highlight(color: vec4f) -> vec4f {
+    return highlight(color, object_data.flags);
+}
+
+fn highlight(color: vec4f, flags: u32) -> vec4f {
+    if ((flags & OBJECT_FLAG_HIGHLIGHTED) == 0u) {
+        return color;
+    }
+
+    let strength = clamp(global_data.highlight_color.a, 0.0, 1.0);
+    return vec4f(mix(color.rgb, global_data.highlight_color.rgb, strength), color.a);
+}
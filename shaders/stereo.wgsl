@@ -0,0 +1,53 @@
+// Stereo (multiview) entry points. 
+// This file is appended to `shader.wgsl` only when the device supports the `MULTIVIEW` feature, 
+// because `@builtin(view_index)` fails shader validation without it.
+
+// Stores the camera matrices of both eyes, indexed by `@builtin(view_index)`.
+struct StereoCameraUniformLayout {
+    views: array<CameraUniformLayout, 2>, 
+}
+
+@group(0) @binding(0)
+var<uniform> stereo_camera_data: StereoCameraUniformLayout;
+@group(0) @binding(0)
+var stereo_accum: texture_2d_array<f32>;
+@group(0) @binding(1)
+var stereo_reveal: texture_2d_array<f32>;
+
+
+
+@vertex
+fn vs_stereo_main(@builtin(view_index) view_index: i32, in: VertexInput) -> VertexOutput {
+    return transform_vertex(stereo_camera_data.views[view_index], object_data.world, in.position, in.normal, object_data.color);
+}
+
+@vertex
+fn vs_stereo_instanced_main(@builtin(view_index) view_index: i32, in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let instance_world = mat4x4f(instance.world_0, instance.world_1, instance.world_2, instance.world_3);
+    return transform_vertex(stereo_camera_data.views[view_index], object_data.world * instance_world, in.position, in.normal, object_data.color * instance.color);
+}
+
+@vertex
+fn vs_stereo_skinned_main(@builtin(view_index) view_index: i32, in: SkinnedVertexInput) -> VertexOutput {
+    let skin = skin_matrix(in.joints, in.weights);
+    let position = (skin * vec4f(in.position, 1.0)).xyz;
+    let normal = (skin * vec4f(in.normal, 0.0)).xyz;
+    return transform_vertex(stereo_camera_data.views[view_index], object_data.world, position, normal, object_data.color);
+}
+
+@vertex
+fn vs_stereo_textured_main(@builtin(view_index) view_index: i32, in: VertexInput) -> VertexOutput {
+    return transform_textured_vertex(stereo_camera_data.views[view_index], in.position, in.normal);
+}
+
+@fragment
+fn fs_stereo_composite_pass(@builtin(position) clip_position: vec4f, @builtin(view_index) view_index: i32) -> @location(0) vec4f {
+    let coords: vec2i = vec2i(clip_position.xy);
+    
+    let revealage: f32 = textureLoad(stereo_reveal, coords, view_index, 0).r;
+    if (is_approximately_equal(revealage, 1.0)) {
+        discard;
+    }
+
+    return resolve_oit(textureLoad(stereo_accum, coords, view_index, 0), revealage, composite_wboit_data.clamp_params.z);
+}
@@ -0,0 +1,195 @@
+use std::fmt;
+use std::f32::consts::TAU;
+use crate::interfaces::GameObject;
+use crate::objects::ColoredObject;
+
+
+
+/// #### 한국어 </br>
+/// 매 프레임 게임 시간에 따라 색상 오브젝트를 움직이거나 색상을 바꾸는 애니메이터 입니다. </br>
+/// 
+/// 애니메이터는 오브젝트의 현재 상태에 변화량만 더하므로, 여러 애니메이터를 목록(`Vec<Box<dyn Animator>>`)으로 묶어 </br>
+/// 하나의 오브젝트에 함께 적용할 수 있습니다. 오브젝트의 유니폼 데이터는 이후 [`Scene::update_all`](crate::scene::Scene::update_all)에서 갱신됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An animator that moves a colored object or changes its color over game time every frame. </br>
+/// 
+/// Animators only add a change to the current state of the object, so several animators can be grouped in a list (`Vec<Box<dyn Animator>>`) </br>
+/// and applied to one object together. The uniform data of the object is updated afterwards in [`Scene::update_all`](crate::scene::Scene::update_all). </br>
+/// 
+pub trait Animator : fmt::Debug {
+    /// #### 한국어 </br>
+    /// 경과한 게임 시간(`elapsed_time_sec`, 초 단위)만큼 오브젝트를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the object by the elapsed game time (`elapsed_time_sec`, in seconds). </br>
+    /// 
+    fn update(&mut self, object: &mut ColoredObject, elapsed_time_sec: f32);
+}
+
+impl Animator for Vec<Box<dyn Animator>> {
+    fn update(&mut self, object: &mut ColoredObject, elapsed_time_sec: f32) {
+        for animator in self.iter_mut() {
+            animator.update(object, elapsed_time_sec);
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 오브젝트의 위치를 중심으로 월드 좌표계의 축을 따라 오브젝트를 회전시킵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Rotates the object about a world axis through the object's position. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spin {
+    pub axis: glam::Vec3, 
+    pub radians_per_sec: f32, 
+}
+
+#[allow(dead_code)]
+impl Spin {
+    #[inline]
+    pub fn new(axis: glam::Vec3, radians_per_sec: f32) -> Self {
+        Self { axis: axis.normalize_or_zero(), radians_per_sec }
+    }
+
+    /// #### 한국어 </br>
+    /// 경과한 시간(`elapsed_time_sec`, 초 단위)만큼 게임 오브젝트를 회전시킵니다. 회전 축이 없는 경우 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Rotates the game object by the elapsed time (`elapsed_time_sec`, in seconds). Does nothing if there is no rotation axis. </br>
+    /// 
+    pub fn rotate<T: GameObject + ?Sized>(&self, object: &mut T, elapsed_time_sec: f32) {
+        if self.axis == glam::Vec3::ZERO {
+            return;
+        }
+        let rotation = glam::Quat::from_axis_angle(self.axis, self.radians_per_sec * elapsed_time_sec);
+        object.rotate_around_point(object.get_position(), rotation);
+    }
+}
+
+impl Animator for Spin {
+    #[inline]
+    fn update(&mut self, object: &mut ColoredObject, elapsed_time_sec: f32) {
+        self.rotate(object, elapsed_time_sec);
+    }
+}
+
+/// #### 한국어 </br>
+/// 주기(`period_sec`)에 따라 오브젝트의 색상을 `from`과 `to` 사이에서 부드럽게 오가게 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Smoothly moves the color of the object back and forth between `from` and `to` with the period (`period_sec`). </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorPulse {
+    pub from: glam::Vec4, 
+    pub to: glam::Vec4, 
+    pub period_sec: f32, 
+    time_sec: f32, 
+}
+
+#[allow(dead_code)]
+impl ColorPulse {
+    #[inline]
+    pub fn new(from: glam::Vec4, to: glam::Vec4, period_sec: f32) -> Self {
+        Self { from, to, period_sec, time_sec: 0.0 }
+    }
+}
+
+impl Animator for ColorPulse {
+    fn update(&mut self, object: &mut ColoredObject, elapsed_time_sec: f32) {
+        if self.period_sec <= 0.0 {
+            return;
+        }
+        self.time_sec = (self.time_sec + elapsed_time_sec) % self.period_sec;
+        let t = 0.5 - 0.5 * (TAU * self.time_sec / self.period_sec).cos();
+        object.set_color(self.from.lerp(self.to, t));
+    }
+}
+
+/// #### 한국어 </br>
+/// 주기(`period_sec`)에 따라 오브젝트를 월드 좌표계의 `offset` 방향으로 위아래로 흔듭니다. </br>
+/// 이전 프레임과의 변위 차이만 이동하므로, 다른 애니메이터나 게임 로직이 오브젝트를 옮겨도 함께 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Bobs the object up and down along `offset` in world coordinates with the period (`period_sec`). </br>
+/// Only the difference in displacement from the previous frame is moved, so it combines with other animators or game logic moving the object. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bob {
+    pub offset: glam::Vec3, 
+    pub period_sec: f32, 
+    time_sec: f32, 
+}
+
+#[allow(dead_code)]
+impl Bob {
+    #[inline]
+    pub fn new(offset: glam::Vec3, period_sec: f32) -> Self {
+        Self { offset, period_sec, time_sec: 0.0 }
+    }
+
+    #[inline]
+    fn displacement(&self) -> glam::Vec3 {
+        self.offset * (TAU * self.time_sec / self.period_sec).sin()
+    }
+}
+
+impl Animator for Bob {
+    fn update(&mut self, object: &mut ColoredObject, elapsed_time_sec: f32) {
+        if self.period_sec <= 0.0 {
+            return;
+        }
+        let previous = self.displacement();
+        self.time_sec = (self.time_sec + elapsed_time_sec) % self.period_sec;
+        object.translate_world(self.displacement() - previous);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestObject {
+        transform: glam::Mat4, 
+    }
+
+    impl GameObject for TestObject {
+        fn ref_world_transform(&self) -> &glam::Mat4 {
+            &self.transform
+        }
+
+        fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+            &mut self.transform
+        }
+    }
+
+    #[test]
+    fn spin_rotates_by_known_dt() {
+        let position = glam::Vec3::new(1.0, 2.0, 3.0);
+        let mut object = TestObject { transform: glam::Mat4::from_translation(position) };
+        let spin = Spin::new(glam::Vec3::Y * 2.0, 90.0f32.to_radians());
+
+        // (한국어) 초당 90°로 0.5초 동안 회전하면 45° 회전하며, 위치는 그대로 입니다.
+        // (English Translation) Rotating at 90° per second for 0.5 seconds turns 45°, and the position stays the same.
+        spin.rotate(&mut object, 0.5);
+        let (_, rotation, translation) = object.transform.to_scale_rotation_translation();
+        assert!(rotation.abs_diff_eq(glam::Quat::from_rotation_y(45.0f32.to_radians()), 1e-5));
+        assert!(translation.abs_diff_eq(position, 1e-5));
+
+        spin.rotate(&mut object, 0.5);
+        let (_, rotation, _) = object.transform.to_scale_rotation_translation();
+        assert!(rotation.abs_diff_eq(glam::Quat::from_rotation_y(90.0f32.to_radians()), 1e-5));
+    }
+
+    #[test]
+    fn spin_without_axis_does_nothing() {
+        let transform = glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+        let mut object = TestObject { transform };
+        Spin::new(glam::Vec3::ZERO, 1.0).rotate(&mut object, 1.0);
+        assert_eq!(object.transform, transform);
+    }
+}
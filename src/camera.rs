@@ -1,6 +1,16 @@
 use std::mem;
+use std::cell::Cell;
+use std::collections::HashSet;
+use glam::Vec4Swizzles;
+use winit::{
+    keyboard::{KeyCode, PhysicalKey}, 
+    event::{WindowEvent, MouseButton}, 
+};
+use crate::input::{InputSettings, MouseLook};
 use crate::interfaces::{
+    Handedness, 
     GameObject, 
+    GameCamera, 
     GameCameraObject, 
     ShaderResource, 
 };
@@ -10,8 +20,11 @@ use crate::interfaces::{
 /// #### 한국어 </br>
 /// 쉐이더에 전달되는 카메라 유니폼 데이터 레이아웃 입니다. </br>
 /// 
+/// 전체 화면 패스(바닥 격자, 하늘 등)가 화면 좌표를 월드 공간으로 되돌릴 수 있도록 뷰-투영 행렬과 뷰 행렬의 역행렬도 함께 전달합니다. </br>
+/// 
 /// #### English (Translation) </br>
 /// This is the camera uniform data layout passed to the shader. </br>
+/// The inverses of the view-projection and view matrices are also passed so that full-screen passes (ground grid, sky, etc.) can unproject screen coordinates into world space. </br>
 /// 
 #[repr(C, align(16))]
 #[derive(bytemuck::Pod, bytemuck::Zeroable)]
@@ -19,6 +32,77 @@ use crate::interfaces::{
 pub struct CameraUniformLayout {
     pub camera_matrix: glam::Mat4, 
     pub projection_matrix: glam::Mat4, 
+    pub inverse_view_projection: glam::Mat4, 
+    pub inverse_view: glam::Mat4, 
+}
+
+impl CameraUniformLayout {
+    #[inline]
+    pub fn new(camera_matrix: glam::Mat4, projection_matrix: glam::Mat4) -> Self {
+        Self {
+            camera_matrix, 
+            projection_matrix, 
+            inverse_view_projection: (projection_matrix * camera_matrix).inverse(), 
+            inverse_view: camera_matrix.inverse(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 유니폼 버퍼의 크기를 계산합니다. 스테레오 렌더링인 경우 두 눈의 데이터를 저장합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the size of a camera uniform buffer. For stereo rendering, it stores the data of both eyes. </br>
+    /// 
+    #[inline]
+    pub fn buffer_size(eye_separation: Option<f32>) -> wgpu::BufferAddress {
+        (mem::size_of::<Self>() * eye_separation.map_or(1, |_| 2)) as wgpu::BufferAddress
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 유니폼 데이터를 버퍼에 씁니다. </br>
+    /// 눈 사이 거리가 주어진 경우 왼쪽 눈과 오른쪽 눈의 데이터를 차례대로 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the camera uniform data into the buffer. </br>
+    /// If an eye separation is given, writes the data of the left and right eyes in order. </br>
+    /// 
+    pub fn write(queue: &wgpu::Queue, buffer: &wgpu::Buffer, camera_matrix: glam::Mat4, projection_matrix: glam::Mat4, eye_separation: Option<f32>) {
+        match eye_separation {
+            Some(eye_separation) => {
+                // (한국어) 각 눈은 뷰 공간의 x축을 따라 눈 사이 거리의 절반만큼 이동합니다.
+                // (English Translation) Each eye is moved by half the eye separation along the x-axis of view space.
+                let half = 0.5 * eye_separation;
+                let data = [half, -half].map(|offset| Self::new(
+                    glam::Mat4::from_translation(glam::vec3(offset, 0.0, 0.0)) * camera_matrix, 
+                    projection_matrix
+                ));
+                queue.write_buffer(buffer, 0, bytemuck::cast_slice(&data));
+            },
+            None => {
+                let data = Self::new(camera_matrix, projection_matrix);
+                queue.write_buffer(buffer, 0, bytemuck::bytes_of(&data));
+            }
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 변환 행렬의 자세를 목표 자세로 `t`만큼 보간한 변환 행렬을 계산합니다. `t`는 `[0, 1]` 범위로 고정되며, `NaN`은 0으로 취급합니다. </br>
+/// 위치는 선형 보간하고, 회전은 구면 선형 보간(`glam::Quat::slerp`)하며, 결과에는 크기가 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the transform matrix interpolated from the pose of `transform` toward the target pose by `t`. `t` is clamped to `[0, 1]`, and `NaN` is treated as 0. </br>
+/// The position is linearly interpolated, the rotation is spherically interpolated (`glam::Quat::slerp`), and the result has no scale. </br>
+/// 
+pub fn lerp_transform(transform: &glam::Mat4, target_position: glam::Vec3, target_rotation: glam::Quat, t: f32) -> glam::Mat4 {
+    let t = match t.is_nan() {
+        true => 0.0, 
+        false => t.clamp(0.0, 1.0), 
+    };
+    let (_, rotation, position) = transform.to_scale_rotation_translation();
+    let position = position.lerp(target_position, t);
+    let rotation = rotation.normalize().slerp(target_rotation.normalize(), t).normalize();
+    glam::Mat4::from_rotation_translation(rotation, position)
 }
 
 /// #### 한국어 </br>
@@ -27,14 +111,18 @@ pub struct CameraUniformLayout {
 /// #### English (Translation) </br>
 /// A builder that creates a perspective projection camera. </br>
 /// 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PerspectiveCameraBuilder {
+    pub name: Option<String>, 
     pub translation: glam::Vec3, 
     pub rotation: glam::Quat,  
     pub fov_y_radians: f32, 
     pub aspect_ratio: f32, 
     pub z_near: f32, 
     pub z_far: f32, 
+    pub eye_separation: Option<f32>, 
+    pub handedness: Handedness, 
+    pub reverse_z: bool, 
 }
 
 #[allow(dead_code)]
@@ -42,15 +130,87 @@ impl PerspectiveCameraBuilder {
     #[inline]
     pub fn new(fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
         Self { 
+            name: None, 
             translation: glam::Vec3::ZERO, 
             rotation: glam::Quat::IDENTITY, 
             fov_y_radians, 
             aspect_ratio, 
             z_near, 
-            z_far 
+            z_far, 
+            eye_separation: None, 
+            handedness: Handedness::RightHanded, 
+            reverse_z: false, 
         }
     }
 
+    /// #### 한국어 </br>
+    /// 카메라의 이름을 설정합니다. </br>
+    /// 이름은 GPU 디버깅 도구에서 리소스를 구분할 수 있도록 리소스 레이블에 포함됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the name of the camera. </br>
+    /// The name is included in the resource labels so that resources can be distinguished in GPU debugging tools. </br>
+    /// 
+    #[inline]
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 스테레오 렌더링을 위한 두 눈 사이의 거리를 설정합니다. </br>
+    /// 설정된 경우 유니폼 버퍼에 왼쪽 눈과 오른쪽 눈의 카메라 행렬을 차례대로 저장합니다. </br>
+    /// 일반 렌더링 쉐이더는 첫 번째(왼쪽 눈) 행렬을 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance between the two eyes for stereo rendering. </br>
+    /// If set, the camera matrices of the left and right eyes are stored in order in the uniform buffer. </br>
+    /// Mono rendering shaders read the first (left eye) matrices. </br>
+    /// 
+    #[inline]
+    pub fn set_stereo(mut self, eye_separation: f32) -> Self {
+        self.eye_separation = Some(eye_separation);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라가 사용하는 좌표계의 방향을 설정합니다. (기본값: `Handedness::RightHanded`) </br>
+    /// 투영 행렬과 `look_at_point`의 카메라 기저가 함께 바뀝니다. (참고: [Handedness]) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the handedness of the coordinate system used by the camera. (default: `Handedness::RightHanded`) </br>
+    /// The projection matrix and the camera basis of `look_at_point` change together. (see also: [Handedness]) </br>
+    /// 
+    #[inline]
+    pub fn set_handedness(mut self, handedness: Handedness) -> Self {
+        self.handedness = handedness;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 역방향 깊이(reverse-Z)의 사용 여부를 설정합니다. (기본값: `false`) </br>
+    /// 사용하는 경우 투영 행렬이 가까운 평면을 깊이 1.0, 먼 평면을 깊이 0.0으로 옮깁니다. </br>
+    /// 
+    /// 부동 소수점 깊이 버퍼(`Depth32Float`)는 0 근처에서 정밀도가 높으므로, 원근 나눗셈으로 먼 곳에 몰리는 깊이 값과 </br>
+    /// 서로 상쇄되어 `z_far`가 큰 경우에도 깊이 정밀도가 거의 균일해지고 Z-파이팅이 줄어듭니다. </br>
+    /// 대신 파이프라인의 깊이 비교 함수(`Greater`)와 깊이 버퍼를 지우는 값(`0.0`)이 카메라와 반드시 일치해야 하며, </br>
+    /// 정수 깊이 형식(`Depth24Plus` 등)에서는 이점이 거의 없습니다. (참고: `PipelineSetBuilder::set_reverse_z`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use reversed depth (reverse-Z). (default: `false`) </br>
+    /// If used, the projection matrix maps the near plane to depth 1.0 and the far plane to depth 0.0. </br>
+    /// 
+    /// A floating point depth buffer (`Depth32Float`) is most precise near 0, which cancels out the depth values bunching up in the distance </br>
+    /// due to the perspective divide, so depth precision becomes nearly uniform and Z-fighting is reduced even with a large `z_far`. </br>
+    /// In exchange, the pipeline's depth comparison function (`Greater`) and depth clear value (`0.0`) must match the camera, </br>
+    /// and integer depth formats (`Depth24Plus`, etc.) gain little. (see also: `PipelineSetBuilder::set_reverse_z`) </br>
+    /// 
+    #[inline]
+    pub fn set_reverse_z(mut self, reverse_z: bool) -> Self {
+        self.reverse_z = reverse_z;
+        self
+    }
+
     #[inline]
     pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
         self.translation = translation;
@@ -80,7 +240,7 @@ impl PerspectiveCameraBuilder {
     pub fn look_at_point(mut self, point: glam::Vec3) -> Self {
         let mat = glam::Mat3::from_quat(self.rotation.normalize());
         let up = mat.y_axis.normalize_or_zero();
-        let look = (self.translation - point).normalize_or_zero();
+        let look = self.handedness.look_axis(self.translation, point);
         let right = up.cross(look);
         let up = look.cross(right);
         self.rotation = glam::Quat::from_mat3(&glam::Mat3::from_cols(right, up, look)).normalize();
@@ -94,18 +254,23 @@ impl PerspectiveCameraBuilder {
     }
 
     pub fn build(self, device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> PerspectiveCamera {
+        let label = |kind: &str| match self.name.as_deref() {
+            Some(name) => format!("{}(PerspectiveCamera:{})", kind, name), 
+            None => format!("{}(PerspectiveCamera)", kind), 
+        };
+
         let buffer = device.create_buffer(
             &wgpu::BufferDescriptor {
-                label: Some("UniformBuffer(PerspectiveCamera)"), 
+                label: Some(&label("UniformBuffer")), 
                 mapped_at_creation: false, 
-                size: mem::size_of::<CameraUniformLayout>() as wgpu::BufferAddress, 
+                size: CameraUniformLayout::buffer_size(self.eye_separation), 
                 usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
             },
         );
 
         let bind_group = device.create_bind_group(
             &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(PerspectiveCamera)"), 
+                label: Some(&label("BindGroup")), 
                 layout: &bind_group_layout, 
                 entries: &[
                     wgpu::BindGroupEntry {
@@ -120,6 +285,9 @@ impl PerspectiveCameraBuilder {
 
         PerspectiveCamera {
             fov_y_radians: self.fov_y_radians, 
+            eye_separation: self.eye_separation, 
+            handedness: self.handedness, 
+            reverse_z: self.reverse_z, 
             aspect_ratio: self.aspect_ratio, 
             z_near: self.z_near, 
             z_far: self.z_far, 
@@ -127,6 +295,8 @@ impl PerspectiveCameraBuilder {
                 self.rotation.normalize(), 
                 self.translation
             ), 
+            jitter: glam::Vec2::ZERO, 
+            dirty: Cell::new(true), 
             buffer, 
             bind_group, 
         }
@@ -136,16 +306,27 @@ impl PerspectiveCameraBuilder {
 /// #### 한국어 </br>
 /// 게임 월드 좌표계에 존재하는 카메라 입니다. </br>
 /// 
+/// 카메라가 바뀌지 않은 경우 유니폼 버퍼를 다시 쓰지 않도록 변경 여부(`dirty`)를 추적합니다. </br>
+/// `mut_world_transform`을 빌리거나 투영 설정을 바꾸면 변경된 것으로 표시되고, `update_shader_resource`에서 지워집니다. </br>
+/// 
 /// #### English (Translation) </br>
 /// This is a camera that exists in the game world coordinate system. </br>
 /// 
+/// Tracks whether it changed (`dirty`) so that the uniform buffer is not rewritten when the camera did not change. </br>
+/// Borrowing `mut_world_transform` or changing the projection settings marks it as changed, and `update_shader_resource` clears it. </br>
+/// 
 #[derive(Debug)]
 pub struct PerspectiveCamera {
     fov_y_radians: f32, 
+    eye_separation: Option<f32>, 
+    handedness: Handedness, 
+    reverse_z: bool, 
     aspect_ratio: f32, 
     z_near: f32, 
     z_far: f32, 
     transform: glam::Mat4, 
+    jitter: glam::Vec2, 
+    dirty: Cell<bool>, 
     buffer: wgpu::Buffer, 
     bind_group: wgpu::BindGroup, 
 }
@@ -156,26 +337,569 @@ impl GameObject for PerspectiveCamera {
         &self.transform
     }
 
+    /// #### 한국어 </br>
+    /// 변환 행렬을 수정할 수 있으므로 카메라를 변경된 것으로 표시합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Marks the camera as changed since the transform matrix may be modified. </br>
+    /// 
     #[inline]
     fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+        self.mark_dirty();
         &mut self.transform
     }
+
+    #[inline]
+    fn handedness(&self) -> Handedness {
+        self.handedness
+    }
+}
+
+#[allow(dead_code)]
+impl PerspectiveCamera {
+    /// #### 한국어 </br>
+    /// 수직 시야각의 최소값 입니다. (10°) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The minimum vertical field of view. (10°) </br>
+    /// 
+    pub const MIN_FOV_Y: f32 = 10.0 * std::f32::consts::PI / 180.0;
+
+    /// #### 한국어 </br>
+    /// 수직 시야각의 최대값 입니다. (120°) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum vertical field of view. (120°) </br>
+    /// 
+    pub const MAX_FOV_Y: f32 = 120.0 * std::f32::consts::PI / 180.0;
+
+    /// #### 한국어 </br>
+    /// 마우스 휠 한 줄마다 수직 시야각에 곱해지는 비율 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The ratio the vertical field of view is multiplied by per line of the mouse wheel. </br>
+    /// 
+    pub const ZOOM_RATIO: f32 = 0.9;
+
+    /// #### 한국어 </br>
+    /// 카메라를 변경된 것으로 표시하여 다음 `update_shader_resource` 호출에서 유니폼 버퍼를 다시 쓰도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Marks the camera as changed so that the next `update_shader_resource` call rewrites the uniform buffer. </br>
+    /// 
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+
+    #[inline]
+    pub fn fov_y_radians(&self) -> f32 {
+        self.fov_y_radians
+    }
+
+    /// #### 한국어 </br>
+    /// 수직 시야각(라디안)을 설정합니다. `[MIN_FOV_Y, MAX_FOV_Y]` 범위로 고정되며, 유한한 값이 아닌 경우 무시됩니다. </br>
+    /// 저장된 값만 바뀌며, 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the vertical field of view (in radians). It is clamped to `[MIN_FOV_Y, MAX_FOV_Y]`, and ignored if it is not finite. </br>
+    /// Only the stored value changes, and the change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    #[inline]
+    pub fn set_fov_y(&mut self, radians: f32) {
+        if let Some(radians) = Self::clamp_fov_y(radians) {
+            self.fov_y_radians = radians;
+            self.mark_dirty();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 수직 시야각(라디안)을 `[MIN_FOV_Y, MAX_FOV_Y]` 범위로 고정합니다. 유한한 값이 아닌 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Clamps the vertical field of view (in radians) to `[MIN_FOV_Y, MAX_FOV_Y]`. Returns `None` if it is not finite. </br>
+    /// 
+    #[inline]
+    pub fn clamp_fov_y(radians: f32) -> Option<f32> {
+        radians.is_finite().then(|| radians.clamp(Self::MIN_FOV_Y, Self::MAX_FOV_Y))
+    }
+
+    #[inline]
+    pub fn jitter(&self) -> glam::Vec2 {
+        self.jitter
+    }
+
+    /// #### 한국어 </br>
+    /// 투영 행렬에 더해지는 정규화 장치 좌표계(NDC) 단위의 서브 픽셀 흔들림(jitter)을 설정합니다. </br>
+    /// 시간적 안티 앨리어싱(TAA)에서 프레임마다 다른 값을 설정하며, 사용하지 않는 경우 `glam::Vec2::ZERO` 입니다. </br>
+    /// 유한한 값이 아닌 경우 무시되며, 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the sub-pixel jitter in normalized device coordinates (NDC) added to the projection matrix. </br>
+    /// Temporal anti-aliasing (TAA) sets a different value every frame, and it is `glam::Vec2::ZERO` when not used. </br>
+    /// Ignored if it is not finite, and the change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    #[inline]
+    pub fn set_jitter(&mut self, offset_ndc: glam::Vec2) {
+        if offset_ndc.is_finite() {
+            self.jitter = offset_ndc;
+            self.mark_dirty();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 픽셀 단위의 흔들림을 정규화 장치 좌표계(NDC) 단위로 변환합니다. `viewport`는 픽셀 단위의 화면 크기 입니다. </br>
+    /// 픽셀 좌표계의 y축은 아래쪽, 정규화 장치 좌표계의 y축은 위쪽을 향하므로 y값의 부호가 바뀝니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts a jitter in pixels to normalized device coordinates (NDC). `viewport` is the screen size in pixels. </br>
+    /// The y-axis points down in pixel coordinates and up in NDC, so the sign of y is flipped. </br>
+    /// 
+    #[inline]
+    pub fn pixel_jitter_to_ndc(offset_pixels: glam::Vec2, viewport: glam::Vec2) -> glam::Vec2 {
+        match viewport.x > 0.0 && viewport.y > 0.0 {
+            true => glam::Vec2::new(2.0, -2.0) * offset_pixels / viewport, 
+            false => glam::Vec2::ZERO, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 원근 나눗셈 이후 정규화 장치 좌표계에서 `jitter`만큼 이동하도록 투영 행렬 앞에 이동 행렬을 곱합니다. </br>
+    /// 흔들림이 없는 경우 투영 행렬을 그대로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Multiplies a translation in front of the projection so that it shifts by `jitter` in NDC after the perspective divide. </br>
+    /// Returns the projection unchanged if there is no jitter. </br>
+    /// 
+    #[inline]
+    pub fn apply_jitter(projection: glam::Mat4, jitter: glam::Vec2) -> glam::Mat4 {
+        match jitter == glam::Vec2::ZERO {
+            true => projection, 
+            false => glam::Mat4::from_translation((jitter, 0.0).into()) * projection, 
+        }
+    }
+
+    #[inline]
+    pub fn aspect_ratio(&self) -> f32 {
+        self.aspect_ratio
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 크기(픽셀)에서 화면 비율(너비 / 높이)을 계산합니다. 너비나 높이가 0인 경우(창이 최소화된 경우 등) `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the aspect ratio (width / height) from a screen size in pixels. Returns `None` if the width or height is 0 (e.g. the window is minimized). </br>
+    /// 
+    #[inline]
+    pub fn aspect_ratio_from_size(width: u32, height: u32) -> Option<f32> {
+        (width > 0 && height > 0).then(|| width as f32 / height as f32)
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 비율(너비 / 높이)을 설정합니다. 유한한 양수가 아닌 경우 무시됩니다. </br>
+    /// 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the aspect ratio (width / height). Ignored if it is not a finite positive number. </br>
+    /// The change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    #[inline]
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: f32) {
+        if aspect_ratio.is_finite() && aspect_ratio > 0.0 {
+            self.aspect_ratio = aspect_ratio;
+            self.mark_dirty();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라를 현재 자세에서 목표 자세로 `t`만큼 보간합니다. `t`는 `[0, 1]` 범위로 고정됩니다. </br>
+    /// 위치는 선형 보간하고, 회전은 구면 선형 보간(`glam::Quat::slerp`)합니다. </br>
+    /// 변환 행렬만 다시 계산하며, 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// 매 프레임 같은 시작 자세에서 보간하려면 시작 자세를 저장해 두고 `set_position`, `set_rotation`으로 되돌린 후 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Interpolates the camera from its current pose toward the target pose by `t`. `t` is clamped to `[0, 1]`. </br>
+    /// The position is linearly interpolated, and the rotation is spherically interpolated (`glam::Quat::slerp`). </br>
+    /// Only the transform matrix is recomputed, and the change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    /// To interpolate from the same start pose every frame, store the start pose and restore it with `set_position` and `set_rotation` before calling. </br>
+    /// 
+    pub fn lerp_to(&mut self, target_position: glam::Vec3, target_rotation: glam::Quat, t: f32) {
+        self.transform = lerp_transform(&self.transform, target_position, target_rotation, t);
+        self.mark_dirty();
+    }
 }
 
 impl GameCameraObject for PerspectiveCamera {
+    /// #### 한국어 </br>
+    /// 역방향 깊이를 사용하는 경우 가까운 평면과 먼 평면을 바꾸어 깊이 범위를 뒤집습니다. </br>
+    /// 흔들림이 있는 경우 원근 나눗셈 이후 정규화 장치 좌표계에서 일정한 거리만큼 이동하도록 투영 행렬 앞에 이동 행렬을 곱합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If reverse-Z is used, swaps the near and far planes to flip the depth range. </br>
+    /// If there is a jitter, a translation is multiplied in front of the projection so that it shifts by a constant amount in NDC after the perspective divide. </br>
+    /// 
     #[inline]
     fn get_projection_transform(&self) -> glam::Mat4 {
-        glam::Mat4::perspective_rh(self.fov_y_radians, self.aspect_ratio, self.z_near, self.z_far)
+        let projection = match self.reverse_z {
+            true => self.handedness.perspective(self.fov_y_radians, self.aspect_ratio, self.z_far, self.z_near), 
+            false => self.handedness.perspective(self.fov_y_radians, self.aspect_ratio, self.z_near, self.z_far), 
+        };
+
+        Self::apply_jitter(projection, self.jitter)
+    }
+
+    #[inline]
+    fn reverse_z(&self) -> bool {
+        self.reverse_z
+    }
+
+    #[inline]
+    fn on_resize(&mut self, width: u32, height: u32) {
+        if let Some(aspect_ratio) = Self::aspect_ratio_from_size(width, height) {
+            self.set_aspect_ratio(aspect_ratio);
+        }
+    }
+
+    #[inline]
+    fn on_zoom(&mut self, steps: f32) {
+        self.set_fov_y(self.fov_y_radians * Self::ZOOM_RATIO.powf(steps));
     }
 }
 
 impl ShaderResource for PerspectiveCamera {
     fn update_shader_resource(&self, queue: &wgpu::Queue) {
-        let data = CameraUniformLayout {
-            camera_matrix: self.get_camera_transform(), 
-            projection_matrix: self.get_projection_transform(), 
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        CameraUniformLayout::write(queue, &self.buffer, self.get_camera_transform(), self.get_projection_transform(), self.eye_separation);
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// #### 한국어 </br>
+/// 직교 투영 카메라를 생성하는 빌더입니다. </br>
+/// 원근 투영 카메라와 같은 유니폼 데이터 레이아웃과 바인드 그룹 레이아웃을 사용하므로, 쉐이더를 바꾸지 않고 서로 바꿔 쓸 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates an orthographic projection camera. </br>
+/// It uses the same uniform data layout and bind group layout as the perspective camera, so the two are interchangeable without shader changes. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthographicCameraBuilder {
+    pub name: Option<String>, 
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub left: f32, 
+    pub right: f32, 
+    pub bottom: f32, 
+    pub top: f32, 
+    pub z_near: f32, 
+    pub z_far: f32, 
+    pub eye_separation: Option<f32>, 
+    pub handedness: Handedness, 
+    pub reverse_z: bool, 
+}
+
+#[allow(dead_code)]
+impl OrthographicCameraBuilder {
+    #[inline]
+    pub fn new(left: f32, right: f32, bottom: f32, top: f32, z_near: f32, z_far: f32) -> Self {
+        Self { 
+            name: None, 
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            left, 
+            right, 
+            bottom, 
+            top, 
+            z_near, 
+            z_far, 
+            eye_separation: None, 
+            handedness: Handedness::RightHanded, 
+            reverse_z: false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 이름을 설정합니다. </br>
+    /// 이름은 GPU 디버깅 도구에서 리소스를 구분할 수 있도록 리소스 레이블에 포함됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the name of the camera. </br>
+    /// The name is included in the resource labels so that resources can be distinguished in GPU debugging tools. </br>
+    /// 
+    #[inline]
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 중심을 기준으로 대칭인 투영 영역을 크기로 설정합니다. </br>
+    /// 화면 크기(픽셀)를 주면 한 단위가 한 픽셀이 되므로 2D 화면이나 UI 오버레이에 편리합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the projection bounds symmetric around the camera center from a size. </br>
+    /// Given the screen size in pixels, one unit becomes one pixel, which is convenient for 2D views or UI overlays. </br>
+    /// 
+    #[inline]
+    pub fn set_dimensions(mut self, width: f32, height: f32) -> Self {
+        let half_width = 0.5 * width;
+        let half_height = 0.5 * height;
+        self.left = -half_width;
+        self.right = half_width;
+        self.bottom = -half_height;
+        self.top = half_height;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라가 사용하는 좌표계의 방향을 설정합니다. (기본값: `Handedness::RightHanded`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the handedness of the coordinate system used by the camera. (default: `Handedness::RightHanded`) </br>
+    /// 
+    #[inline]
+    pub fn set_handedness(mut self, handedness: Handedness) -> Self {
+        self.handedness = handedness;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 스테레오 렌더링을 위한 두 눈 사이의 거리를 설정합니다. (참고: `PerspectiveCameraBuilder::set_stereo`) </br>
+    /// 스테레오 쉐이더는 두 눈의 유니폼 데이터를 읽으므로, 스테레오 파이프라인과 함께 사용하는 경우 반드시 설정해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance between the two eyes for stereo rendering. (see also: `PerspectiveCameraBuilder::set_stereo`) </br>
+    /// Stereo shaders read the uniform data of both eyes, so this must be set when used with stereo pipelines. </br>
+    /// 
+    #[inline]
+    pub fn set_stereo(mut self, eye_separation: f32) -> Self {
+        self.eye_separation = Some(eye_separation);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 역방향 깊이(reverse-Z)를 사용할지 설정합니다. (참고: `PerspectiveCameraBuilder::set_reverse_z`) </br>
+    /// 파이프라인의 깊이 비교 함수와 일치해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use reversed depth (reverse-Z). (see also: `PerspectiveCameraBuilder::set_reverse_z`) </br>
+    /// It must match the depth comparison function of the pipelines. </br>
+    /// 
+    #[inline]
+    pub fn set_reverse_z(mut self, reverse_z: bool) -> Self {
+        self.reverse_z = reverse_z;
+        self
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    pub fn translate_local(self, distance: glam::Vec3) -> Self {
+        let mat = glam::Mat3::from_quat(self.rotation.normalize());
+        let right = mat.x_axis.normalize_or_zero() * distance.x;
+        let up = mat.y_axis.normalize_or_zero() * distance.y;
+        let look = mat.z_axis.normalize_or_zero() * distance.z;
+        self.translate_world(right + up + look)
+    }
+
+    #[inline]
+    pub fn translate_world(mut self, distance: glam::Vec3) -> Self {
+        self.translation += distance;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation.normalize();
+        self
+    }
+
+    pub fn look_at_point(mut self, point: glam::Vec3) -> Self {
+        let mat = glam::Mat3::from_quat(self.rotation.normalize());
+        let up = mat.y_axis.normalize_or_zero();
+        let look = self.handedness.look_axis(self.translation, point);
+        let right = up.cross(look);
+        let up = look.cross(right);
+        self.rotation = glam::Quat::from_mat3(&glam::Mat3::from_cols(right, up, look)).normalize();
+        self
+    }
+
+    #[inline]
+    pub fn rotate(mut self, rotation: glam::Quat) -> Self {
+        self.rotation *= rotation.normalize();
+        self
+    }
+
+    pub fn build(self, device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> OrthographicCamera {
+        let label = |kind: &str| match self.name.as_deref() {
+            Some(name) => format!("{}(OrthographicCamera:{})", kind, name), 
+            None => format!("{}(OrthographicCamera)", kind), 
         };
-        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&label("UniformBuffer")), 
+                mapped_at_creation: false, 
+                size: CameraUniformLayout::buffer_size(self.eye_separation), 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            },
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&label("BindGroup")), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ),
+                    },
+                ],
+            },
+        );
+
+        OrthographicCamera {
+            left: self.left, 
+            right: self.right, 
+            bottom: self.bottom, 
+            top: self.top, 
+            z_near: self.z_near, 
+            z_far: self.z_far, 
+            eye_separation: self.eye_separation, 
+            handedness: self.handedness, 
+            reverse_z: self.reverse_z, 
+            transform: glam::Mat4::from_rotation_translation(
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            dirty: Cell::new(true), 
+            buffer, 
+            bind_group, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 게임 월드 좌표계에 존재하는 직교 투영 카메라 입니다. </br>
+/// 
+/// 원근 투영 카메라와 같이 변경 여부(`dirty`)를 추적하여, 바뀌지 않은 경우 유니폼 버퍼를 다시 쓰지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an orthographic projection camera that exists in the game world coordinate system. </br>
+/// 
+/// Like the perspective camera, it tracks whether it changed (`dirty`) and does not rewrite the uniform buffer when it did not change. </br>
+/// 
+#[derive(Debug)]
+pub struct OrthographicCamera {
+    left: f32, 
+    right: f32, 
+    bottom: f32, 
+    top: f32, 
+    z_near: f32, 
+    z_far: f32, 
+    eye_separation: Option<f32>, 
+    handedness: Handedness, 
+    reverse_z: bool, 
+    transform: glam::Mat4, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+impl GameObject for OrthographicCamera {
+    #[inline]
+    fn ref_world_transform(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬을 수정할 수 있으므로 카메라를 변경된 것으로 표시합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Marks the camera as changed since the transform matrix may be modified. </br>
+    /// 
+    #[inline]
+    fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+        self.mark_dirty();
+        &mut self.transform
+    }
+
+    #[inline]
+    fn handedness(&self) -> Handedness {
+        self.handedness
+    }
+}
+
+#[allow(dead_code)]
+impl OrthographicCamera {
+    /// #### 한국어 </br>
+    /// 카메라를 변경된 것으로 표시하여 다음 `update_shader_resource` 호출에서 유니폼 버퍼를 다시 쓰도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Marks the camera as changed so that the next `update_shader_resource` call rewrites the uniform buffer. </br>
+    /// 
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+}
+
+impl GameCameraObject for OrthographicCamera {
+    /// #### 한국어 </br>
+    /// 역방향 깊이를 사용하는 경우 가까운 평면과 먼 평면을 바꾸어 깊이 범위를 뒤집습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If reverse-Z is used, swaps the near and far planes to flip the depth range. </br>
+    /// 
+    #[inline]
+    fn get_projection_transform(&self) -> glam::Mat4 {
+        match self.reverse_z {
+            true => self.handedness.orthographic(self.left, self.right, self.bottom, self.top, self.z_far, self.z_near), 
+            false => self.handedness.orthographic(self.left, self.right, self.bottom, self.top, self.z_near, self.z_far), 
+        }
+    }
+
+    #[inline]
+    fn reverse_z(&self) -> bool {
+        self.reverse_z
+    }
+}
+
+impl ShaderResource for OrthographicCamera {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        CameraUniformLayout::write(queue, &self.buffer, self.get_camera_transform(), self.get_projection_transform(), self.eye_separation);
     }
 
     #[inline]
@@ -183,3 +907,620 @@ impl ShaderResource for PerspectiveCamera {
         &self.bind_group
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 뷰-투영 행렬에서 추출한 여섯 개의 평면으로 이루어진 절두체 입니다. </br>
+/// 각 평면은 `(법선, 거리)`로 저장되며, 법선은 절두체의 안쪽을 향합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A frustum made of six planes extracted from a view-projection matrix. </br>
+/// Each plane is stored as `(normal, distance)`, and the normal points to the inside of the frustum. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    planes: [glam::Vec4; 6], 
+}
+
+#[allow(dead_code)]
+impl Frustum {
+    /// #### 한국어 </br>
+    /// 뷰-투영 행렬에서 절두체를 추출합니다. (깊이 범위: 0 ~ 1) </br>
+    /// 각 평면은 법선의 길이가 1이 되도록 정규화되므로, `법선 · 점 + 거리`는 점에서 평면까지의 실제 거리(월드 단위) 입니다. </br>
+    /// 따라서 경계 구의 반지름이나 상자의 반 크기와 바로 비교할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Extracts a frustum from a view-projection matrix. (depth range: 0 to 1) </br>
+    /// Each plane is normalized so that its normal has unit length, so `normal · point + distance` is the actual distance from the point to the plane (in world units). </br>
+    /// It can therefore be compared directly with the radius of a bounding sphere or the half extents of a box. </br>
+    /// 
+    pub fn from_view_projection(view_projection: &glam::Mat4) -> Self {
+        let row0 = view_projection.row(0);
+        let row1 = view_projection.row(1);
+        let row2 = view_projection.row(2);
+        let row3 = view_projection.row(3);
+
+        let planes = [
+            row3 + row0, // left
+            row3 - row0, // right
+            row3 + row1, // bottom
+            row3 - row1, // top
+            row2,        // near
+            row3 - row2, // far
+        ]
+        .map(|plane| plane / plane.xyz().length().max(f32::EPSILON));
+
+        Self { planes }
+    }
+
+    #[inline]
+    pub fn planes(&self) -> &[glam::Vec4; 6] {
+        &self.planes
+    }
+
+    /// #### 한국어 </br>
+    /// 경계 구가 절두체와 겹치는지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether a bounding sphere overlaps the frustum. </br>
+    /// 
+    pub fn intersects_sphere(&self, center: glam::Vec3, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.xyz().dot(center) + plane.w >= -radius)
+    }
+
+    /// #### 한국어 </br>
+    /// 축 정렬 경계 상자(AABB)가 절두체와 겹치는지 확인합니다. </br>
+    /// 각 평면에 대해 법선 방향으로 가장 멀리 있는 꼭짓점이 평면 바깥에 있으면 상자는 절두체 밖에 있습니다. </br>
+    /// 평면에 걸친 상자는 겹치는 것으로 판단하며, 절두체의 모서리 근처에서는 보수적으로 `true`를 반환할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether an axis-aligned bounding box (AABB) overlaps the frustum. </br>
+    /// For each plane, if the corner farthest along the normal is outside the plane, the box is outside the frustum. </br>
+    /// A box straddling a plane is treated as overlapping, and near the edges of the frustum this may conservatively return `true`. </br>
+    /// 
+    pub fn intersects_aabb(&self, min: glam::Vec3, max: glam::Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| {
+                let normal = plane.xyz();
+                let corner = glam::Vec3::select(normal.cmpge(glam::Vec3::ZERO), max, min);
+                normal.dot(corner) + plane.w >= 0.0
+            })
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 키보드와 마우스로 카메라를 자유롭게 움직이는 1인칭 비행 카메라 컨트롤러 입니다. </br>
+/// 
+/// - `W`/`S`: 앞/뒤로 이동. </br>
+/// - `A`/`D`: 왼쪽/오른쪽으로 이동. </br>
+/// - `Q`/`E`: 아래/위로 이동. </br>
+/// - 마우스 오른쪽 버튼을 누른 채로 움직이기: 바라보는 방향 회전. (`MouseLook`) </br>
+/// 
+/// 눌린 키를 집합으로 추적하므로 여러 키를 동시에 누르면 이동 방향이 합쳐집니다. </br>
+/// 상하 회전은 `MAX_PITCH`로 제한되어 카메라가 뒤집히지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A first-person fly camera controller that moves the camera freely with the keyboard and mouse. </br>
+/// 
+/// - `W`/`S`: move forward/backward. </br>
+/// - `A`/`D`: move left/right. </br>
+/// - `Q`/`E`: move down/up. </br>
+/// - Moving the mouse while holding the right button: rotates the view direction. (`MouseLook`) </br>
+/// 
+/// Pressed keys are tracked as a set, so holding several keys at once combines their movement directions. </br>
+/// Pitch is limited to `MAX_PITCH` so that the camera does not flip over. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct FlyCameraController {
+    move_speed: f32, 
+    pressed_keys: HashSet<KeyCode>, 
+    mouse_look: MouseLook, 
+}
+
+impl Default for FlyCameraController {
+    #[inline]
+    fn default() -> Self {
+        Self::new(5.0, InputSettings::default())
+    }
+}
+
+#[allow(dead_code)]
+impl FlyCameraController {
+    /// #### 한국어 </br>
+    /// 상하 회전 각도의 최대값 입니다. (라디안) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum pitch angle. (in radians) </br>
+    /// 
+    pub const MAX_PITCH: f32 = 89.0 * std::f32::consts::PI / 180.0;
+
+    /// #### 한국어 </br>
+    /// 이동 속도(초당 월드 단위)와 마우스 입력 설정으로 새 컨트롤러를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new controller with the move speed (world units per second) and the mouse input settings. </br>
+    /// 
+    #[inline]
+    pub fn new(move_speed: f32, settings: InputSettings) -> Self {
+        Self {
+            move_speed: move_speed.max(0.0), 
+            pressed_keys: HashSet::new(), 
+            mouse_look: MouseLook::new(settings), 
+        }
+    }
+
+    #[inline]
+    pub fn move_speed(&self) -> f32 {
+        self.move_speed
+    }
+
+    #[inline]
+    pub fn set_move_speed(&mut self, move_speed: f32) {
+        self.move_speed = move_speed.max(0.0);
+    }
+
+    #[inline]
+    pub fn ref_mouse_look(&self) -> &MouseLook {
+        &self.mouse_look
+    }
+
+    #[inline]
+    pub fn mut_mouse_look(&mut self) -> &mut MouseLook {
+        &mut self.mouse_look
+    }
+
+    /// #### 한국어 </br>
+    /// 컨트롤러가 사용하는 키인지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the key is used by the controller. </br>
+    /// 
+    #[inline]
+    pub fn is_movement_key(code: KeyCode) -> bool {
+        matches!(
+            code, 
+            KeyCode::KeyW | KeyCode::KeyA | KeyCode::KeyS | KeyCode::KeyD | KeyCode::KeyQ | KeyCode::KeyE
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 창 이벤트를 처리합니다. 컨트롤러가 이벤트를 사용한 경우 `true`를 반환합니다. </br>
+    /// 키보드 입력은 눌린 키 집합을 갱신하고, 마우스 오른쪽 버튼은 마우스 보기를 켜거나 끕니다. </br>
+    /// 창이 초점을 잃으면 눌린 키가 남지 않도록 모두 놓은 것으로 처리합니다. </br>
+    /// 
+    /// 커서 이동량은 이전 커서 위치가 필요하므로 `accumulate_mouse_delta`로 따로 전달해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Handles a window event. Returns `true` if the controller used the event. </br>
+    /// Keyboard input updates the set of pressed keys, and the right mouse button turns mouse-look on or off. </br>
+    /// When the window loses focus, all keys are treated as released so that no key stays pressed. </br>
+    /// 
+    /// The cursor delta needs the previous cursor position, so it must be passed separately with `accumulate_mouse_delta`. </br>
+    /// 
+    pub fn process_event(&mut self, event: &WindowEvent) -> bool {
+        match event {
+            WindowEvent::KeyboardInput { event, .. } => match event.physical_key {
+                PhysicalKey::Code(code) if Self::is_movement_key(code) => {
+                    match event.state.is_pressed() {
+                        true => self.pressed_keys.insert(code), 
+                        false => self.pressed_keys.remove(&code), 
+                    };
+                    true
+                }, 
+                _ => false, 
+            }, 
+            WindowEvent::MouseInput { state, button: MouseButton::Right, .. } => {
+                self.mouse_look.set_active(state.is_pressed());
+                true
+            }, 
+            WindowEvent::Focused(false) => {
+                self.pressed_keys.clear();
+                self.mouse_look.set_active(false);
+                false
+            }, 
+            _ => false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 이동량(픽셀)을 다음 갱신까지 누적합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Accumulates a mouse delta (in pixels) until the next update. </br>
+    /// 
+    #[inline]
+    pub fn accumulate_mouse_delta(&mut self, delta: glam::Vec2) {
+        self.mouse_look.accumulate(delta);
+    }
+
+    /// #### 한국어 </br>
+    /// 눌린 키로부터 카메라 로컬 좌표계의 이동 방향을 계산합니다. 길이는 0 또는 1 입니다. </br>
+    /// 반대 방향의 키를 동시에 누르면 서로 상쇄됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the move direction in the camera's local space from the pressed keys. The length is either 0 or 1. </br>
+    /// Pressing keys of opposite directions at the same time cancels them out. </br>
+    /// 
+    pub fn move_direction(&self, handedness: Handedness) -> glam::Vec3 {
+        let axis = |positive: KeyCode, negative: KeyCode| -> f32 {
+            self.pressed_keys.contains(&positive) as i32 as f32 - self.pressed_keys.contains(&negative) as i32 as f32
+        };
+
+        // (한국어) 오른손 좌표계의 카메라는 `-z`를 바라보고, 왼손 좌표계의 카메라는 `+z`를 바라봅니다.
+        // (English Translation) A right-handed camera looks down `-z`, and a left-handed camera looks down `+z`.
+        let forward = match handedness {
+            Handedness::RightHanded => -axis(KeyCode::KeyW, KeyCode::KeyS), 
+            Handedness::LeftHanded => axis(KeyCode::KeyW, KeyCode::KeyS), 
+        };
+        glam::Vec3::new(
+            axis(KeyCode::KeyD, KeyCode::KeyA), 
+            axis(KeyCode::KeyE, KeyCode::KeyQ), 
+            forward
+        ).normalize_or_zero()
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임 동안의 입력을 카메라에 적용합니다. `dt`는 지난 프레임부터 흐른 시간(초) 입니다. (`GameTimer::elapsed_time_sec`) </br>
+    /// 마우스 이동량으로 카메라를 회전시킨 후 상하 회전을 제한하고, 눌린 키 방향으로 `translate_local`을 사용해 이동시킵니다. </br>
+    /// 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies one frame of input to the camera. `dt` is the time elapsed since the last frame in seconds. (`GameTimer::elapsed_time_sec`) </br>
+    /// Rotates the camera by the mouse delta, limits the pitch, then moves it toward the pressed keys with `translate_local`. </br>
+    /// The change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    pub fn apply(&mut self, camera: &mut dyn GameCamera, dt: f32) {
+        self.mouse_look.update(camera);
+        Self::clamp_pitch(camera);
+
+        let dt = if dt.is_finite() { dt.max(0.0) } else { 0.0 };
+        let direction = self.move_direction(camera.handedness());
+        if direction != glam::Vec3::ZERO && dt > 0.0 {
+            camera.translate_local(direction * self.move_speed * dt);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 상하 회전이 `MAX_PITCH`를 넘는 경우 좌우 회전은 유지한 채 상하 회전을 제한합니다. </br>
+    /// 제한되는 경우 카메라의 기울기(roll)는 사라집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the camera's pitch exceeds `MAX_PITCH`, limits the pitch while keeping the yaw. </br>
+    /// When limited, the camera's roll is discarded. </br>
+    /// 
+    fn clamp_pitch(camera: &mut dyn GameCamera) {
+        // (한국어) 오일러 각의 상하 회전은 두 좌표계 모두 `[-90°, 90°]` 범위이며, 제한은 양쪽이 대칭이므로 좌표계에 따라 나누지 않습니다.
+        // (English Translation) The Euler pitch is in `[-90°, 90°]` in both coordinate systems, and the limit is symmetric, so it is not split by handedness.
+        let euler = camera.get_euler();
+        if euler.x.abs() <= Self::MAX_PITCH {
+            return;
+        }
+
+        let pitch = euler.x.clamp(-Self::MAX_PITCH, Self::MAX_PITCH);
+        camera.set_euler(glam::Vec3::new(pitch, euler.y, 0.0));
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 초점(`target`)을 중심으로 카메라를 공전시키는 궤도 카메라 컨트롤러 입니다. </br>
+/// 카메라의 위치는 `target + 구면 좌표 오프셋(radius, azimuth, elevation)` 이며, 항상 초점을 바라봅니다. </br>
+/// 
+/// - `azimuth`: 월드 좌표계의 y축을 중심으로 한 회전각. (`+z` 방향이 0) </br>
+/// - `elevation`: 수평면으로부터의 높이각. 극점에서 카메라가 뒤집히지 않도록 `MAX_ELEVATION`으로 제한됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An orbit camera controller that orbits the camera around a focus point (`target`). </br>
+/// The camera position is `target + spherical offset (radius, azimuth, elevation)`, and it always looks at the focus point. </br>
+/// 
+/// - `azimuth`: the rotation angle around the world y-axis. (0 toward `+z`) </br>
+/// - `elevation`: the angle above the horizontal plane. Limited to `MAX_ELEVATION` so that the camera does not flip at the poles. </br>
+/// 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCameraController {
+    target: glam::Vec3, 
+    radius: f32, 
+    azimuth: f32, 
+    elevation: f32, 
+    settings: InputSettings, 
+}
+
+#[allow(dead_code)]
+impl OrbitCameraController {
+    /// #### 한국어 </br>
+    /// 높이각의 최대값 입니다. (라디안) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum elevation angle. (in radians) </br>
+    /// 
+    pub const MAX_ELEVATION: f32 = 89.0 * std::f32::consts::PI / 180.0;
+
+    /// #### 한국어 </br>
+    /// 궤도 반지름의 최소값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The minimum orbit radius. </br>
+    /// 
+    pub const MIN_RADIUS: f32 = 0.01;
+
+    /// #### 한국어 </br>
+    /// 마우스 휠 한 줄마다 궤도 반지름에 곱해지는 비율 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The ratio the orbit radius is multiplied by per line of the mouse wheel. </br>
+    /// 
+    pub const ZOOM_RATIO: f32 = 0.9;
+
+    #[inline]
+    pub fn new(target: glam::Vec3, radius: f32, settings: InputSettings) -> Self {
+        Self {
+            target, 
+            radius: radius.max(Self::MIN_RADIUS), 
+            azimuth: 0.0, 
+            elevation: 0.0, 
+            settings, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 현재 위치에서 초점까지의 거리와 방향으로 컨트롤러를 생성합니다. </br>
+    /// 처음 `update`를 호출할 때 카메라가 갑자기 움직이지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a controller from the distance and direction from the camera's current position to the focus point. </br>
+    /// The camera does not jump when `update` is called for the first time. </br>
+    /// 
+    pub fn from_camera(camera: &dyn GameCamera, target: glam::Vec3, settings: InputSettings) -> Self {
+        let offset = camera.get_position() - target;
+        let radius = offset.length().max(Self::MIN_RADIUS);
+        let (azimuth, elevation) = match offset.length_squared() > f32::EPSILON {
+            true => (offset.x.atan2(offset.z), (offset.y / offset.length()).clamp(-1.0, 1.0).asin()), 
+            false => (0.0, 0.0), 
+        };
+
+        Self {
+            target, 
+            radius, 
+            azimuth, 
+            elevation: elevation.clamp(-Self::MAX_ELEVATION, Self::MAX_ELEVATION), 
+            settings, 
+        }
+    }
+
+    #[inline]
+    pub fn target(&self) -> glam::Vec3 {
+        self.target
+    }
+
+    #[inline]
+    pub fn set_target(&mut self, target: glam::Vec3) {
+        self.target = target;
+    }
+
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    /// #### 한국어 </br>
+    /// 궤도 반지름을 설정합니다. `MIN_RADIUS`보다 작아지지 않으며, 유한한 값이 아닌 경우 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the orbit radius. It does not go below `MIN_RADIUS`, and is ignored if it is not finite. </br>
+    /// 
+    #[inline]
+    pub fn set_radius(&mut self, radius: f32) {
+        if radius.is_finite() {
+            self.radius = radius.max(Self::MIN_RADIUS);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 휠을 굴린 줄 수만큼 궤도 반지름을 바꿉니다. 양수는 초점에 가까워집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the orbit radius by the number of lines the mouse wheel scrolled. Positive moves closer to the focus point. </br>
+    /// 
+    #[inline]
+    pub fn zoom(&mut self, steps: f32) {
+        self.set_radius(self.radius * Self::ZOOM_RATIO.powf(steps));
+    }
+
+    #[inline]
+    pub fn azimuth(&self) -> f32 {
+        self.azimuth
+    }
+
+    #[inline]
+    pub fn elevation(&self) -> f32 {
+        self.elevation
+    }
+
+    /// #### 한국어 </br>
+    /// 초점으로부터의 구면 좌표 오프셋을 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the spherical offset from the focus point. </br>
+    /// 
+    #[inline]
+    pub fn offset(&self) -> glam::Vec3 {
+        let (sin_azimuth, cos_azimuth) = self.azimuth.sin_cos();
+        let (sin_elevation, cos_elevation) = self.elevation.sin_cos();
+        glam::Vec3::new(cos_elevation * sin_azimuth, sin_elevation, cos_elevation * cos_azimuth) * self.radius
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 누적된 마우스 드래그 이동량(픽셀)으로 방위각과 높이각을 바꾸고 카메라를 다시 배치합니다. </br>
+    /// 오른쪽으로 드래그하면 장면이 오른쪽으로 돌고, 위로 드래그하면 카메라가 초점 아래쪽으로 내려갑니다. </br>
+    /// 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the azimuth and elevation by the mouse drag delta (in pixels) accumulated this frame, and repositions the camera. </br>
+    /// Dragging right turns the scene to the right, and dragging up moves the camera below the focus point. </br>
+    /// The change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    pub fn update(&mut self, camera: &mut dyn GameCamera, drag: glam::Vec2) {
+        let angles = self.settings.to_angles(drag);
+        self.azimuth = (self.azimuth - angles.x).rem_euclid(std::f32::consts::TAU);
+        self.elevation = (self.elevation - angles.y).clamp(-Self::MAX_ELEVATION, Self::MAX_ELEVATION);
+
+        // (한국어) 회전을 초기화하여 카메라의 위쪽 방향이 월드 좌표계의 y축을 따르도록 합니다.
+        // (English Translation) Resets the rotation so that the camera's up direction follows the world y-axis.
+        camera.set_position(self.target + self.offset());
+        camera.set_rotation(glam::Quat::IDENTITY);
+        camera.look_at_point(self.target);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{} != {}", actual, expected);
+    }
+
+    /// #### 한국어 </br>
+    /// 원점에서 `-z` 방향을 바라보는 수직 시야각 90°, 화면 비율 1의 절두체 입니다. (가까운 평면: 1, 먼 평면: 100) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A frustum looking down `-z` from the origin with a 90° vertical field of view and an aspect ratio of 1. (near: 1, far: 100) </br>
+    /// 
+    fn test_frustum() -> Frustum {
+        let projection = glam::Mat4::perspective_rh(90.0f32.to_radians(), 1.0, 1.0, 100.0);
+        Frustum::from_view_projection(&projection)
+    }
+
+    #[test]
+    fn frustum_planes_are_normalized() {
+        let frustum = test_frustum();
+        for plane in frustum.planes() {
+            assert_approx(plane.xyz().length(), 1.0);
+        }
+
+        // (한국어) 가까운 평면(z = -1)에서 점(0, 0, -5)까지의 거리는 4 입니다.
+        // (English Translation) The distance from the near plane (z = -1) to the point (0, 0, -5) is 4.
+        let near = frustum.planes()[4];
+        assert_approx(near.xyz().dot(glam::Vec3::new(0.0, 0.0, -5.0)) + near.w, 4.0);
+    }
+
+    #[test]
+    fn frustum_aabb_inside_outside_and_straddling() {
+        let frustum = test_frustum();
+        assert!(frustum.intersects_aabb(glam::Vec3::new(-1.0, -1.0, -6.0), glam::Vec3::new(1.0, 1.0, -4.0)));
+        assert!(!frustum.intersects_aabb(glam::Vec3::new(-1.0, -1.0, 4.0), glam::Vec3::new(1.0, 1.0, 6.0)));
+        assert!(!frustum.intersects_aabb(glam::Vec3::new(-20.0, -1.0, -6.0), glam::Vec3::new(-18.0, 1.0, -4.0)));
+        assert!(!frustum.intersects_aabb(glam::Vec3::new(-1.0, -1.0, -120.0), glam::Vec3::new(1.0, 1.0, -110.0)));
+
+        // (한국어) 왼쪽 평면(z = -5에서 x = -5)에 걸친 상자와 가까운 평면에 걸친 상자 입니다.
+        // (English Translation) A box straddling the left plane (x = -5 at z = -5), and a box straddling the near plane.
+        assert!(frustum.intersects_aabb(glam::Vec3::new(-6.0, -1.0, -6.0), glam::Vec3::new(-4.0, 1.0, -4.0)));
+        assert!(frustum.intersects_aabb(glam::Vec3::new(-0.5, -0.5, -2.0), glam::Vec3::new(0.5, 0.5, 0.5)));
+    }
+
+    #[test]
+    fn frustum_sphere_inside_outside_and_straddling() {
+        let frustum = test_frustum();
+        assert!(frustum.intersects_sphere(glam::Vec3::new(0.0, 0.0, -10.0), 1.0));
+        assert!(!frustum.intersects_sphere(glam::Vec3::new(0.0, 0.0, 10.0), 1.0));
+        assert!(frustum.intersects_sphere(glam::Vec3::new(-10.5, 0.0, -10.0), 1.0));
+        assert!(!frustum.intersects_sphere(glam::Vec3::new(-12.0, 0.0, -10.0), 1.0));
+    }
+
+    #[test]
+    fn aspect_ratio_from_size_guards_zero() {
+        assert_approx(PerspectiveCamera::aspect_ratio_from_size(1920, 1080).unwrap(), 16.0 / 9.0);
+        assert_eq!(PerspectiveCamera::aspect_ratio_from_size(0, 1080), None);
+        assert_eq!(PerspectiveCamera::aspect_ratio_from_size(1920, 0), None);
+    }
+
+    #[test]
+    fn projection_follows_aspect_ratio() {
+        // (한국어) 화면 비율이 바뀌어도 수평 시야의 가장자리에 있는 점은 항상 x = 1에 투영되어야 합니다.
+        // (English Translation) When the aspect ratio changes, a point on the edge of the horizontal view must still project to x = 1.
+        let fov_y = 60.0f32.to_radians();
+        for aspect_ratio in [1.0, 16.0 / 9.0, 0.5] {
+            let projection = Handedness::RightHanded.perspective(fov_y, aspect_ratio, 0.1, 100.0);
+            let edge = glam::Vec3::new(aspect_ratio * (0.5 * fov_y).tan() * 10.0, 0.0, -10.0);
+            assert_approx(projection.project_point3(edge).x, 1.0);
+        }
+    }
+
+    #[test]
+    fn zero_jitter_leaves_projection_unchanged() {
+        let projection = Handedness::RightHanded.perspective(60.0f32.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+        assert_eq!(PerspectiveCamera::apply_jitter(projection, glam::Vec2::ZERO), projection);
+        let jitter = PerspectiveCamera::pixel_jitter_to_ndc(glam::Vec2::ZERO, glam::Vec2::new(1920.0, 1080.0));
+        assert_eq!(PerspectiveCamera::apply_jitter(projection, jitter), projection);
+    }
+
+    #[test]
+    fn pixel_jitter_shifts_ndc_by_constant_offset() {
+        // (한국어) 1920x1080 화면에서 (0.5, 0.5) 픽셀은 NDC에서 (1/1920, -1/1080) 입니다.
+        // (English Translation) On a 1920x1080 screen, (0.5, 0.5) pixels is (1/1920, -1/1080) in NDC.
+        let jitter = PerspectiveCamera::pixel_jitter_to_ndc(glam::Vec2::splat(0.5), glam::Vec2::new(1920.0, 1080.0));
+        assert_approx(jitter.x, 1.0 / 1920.0);
+        assert_approx(jitter.y, -1.0 / 1080.0);
+        assert_eq!(PerspectiveCamera::pixel_jitter_to_ndc(glam::Vec2::ONE, glam::Vec2::ZERO), glam::Vec2::ZERO);
+
+        // (한국어) 흔들림은 깊이와 관계없이 같은 NDC 거리만큼 점을 옮기며, 깊이 값은 바꾸지 않습니다.
+        // (English Translation) The jitter moves points by the same NDC distance regardless of depth, and does not change the depth value.
+        let projection = Handedness::RightHanded.perspective(60.0f32.to_radians(), 16.0 / 9.0, 0.1, 100.0);
+        let jittered = PerspectiveCamera::apply_jitter(projection, glam::Vec2::new(0.01, -0.02));
+        for point in [glam::Vec3::new(0.3, -0.2, -1.0), glam::Vec3::new(-4.0, 2.0, -50.0)] {
+            let expected = projection.project_point3(point);
+            let actual = jittered.project_point3(point);
+            assert_approx(actual.x - expected.x, 0.01);
+            assert_approx(actual.y - expected.y, -0.02);
+            assert_approx(actual.z, expected.z);
+        }
+    }
+
+    #[test]
+    fn lerp_transform_hits_endpoints_and_stays_normalized() {
+        let start = glam::Mat4::from_rotation_translation(glam::Quat::from_rotation_y(0.3), glam::Vec3::new(1.0, 2.0, 3.0));
+        let target_position = glam::Vec3::new(-4.0, 0.0, 8.0);
+        let target_rotation = glam::Quat::from_rotation_x(1.2) * glam::Quat::from_rotation_y(-0.7);
+
+        let begin = lerp_transform(&start, target_position, target_rotation, 0.0);
+        assert!(begin.abs_diff_eq(start, 1e-5));
+        let end = lerp_transform(&start, target_position, target_rotation, 1.0);
+        assert!(end.abs_diff_eq(glam::Mat4::from_rotation_translation(target_rotation, target_position), 1e-5));
+
+        let middle = lerp_transform(&start, target_position, target_rotation, 0.5);
+        let (scale, rotation, position) = middle.to_scale_rotation_translation();
+        assert!(scale.abs_diff_eq(glam::Vec3::ONE, 1e-5));
+        assert!(rotation.is_normalized());
+        assert!(position.abs_diff_eq(glam::Vec3::new(-1.5, 1.0, 5.5), 1e-5));
+
+        // (한국어) 범위를 벗어난 `t`는 고정되고, `NaN`은 시작 자세를 유지합니다.
+        // (English Translation) Out-of-range `t` is clamped, and `NaN` keeps the start pose.
+        assert!(lerp_transform(&start, target_position, target_rotation, 2.0).abs_diff_eq(end, 1e-5));
+        assert!(lerp_transform(&start, target_position, target_rotation, -1.0).abs_diff_eq(start, 1e-5));
+        assert!(lerp_transform(&start, target_position, target_rotation, f32::NAN).abs_diff_eq(start, 1e-5));
+    }
+
+    #[test]
+    fn fov_y_is_clamped_at_both_extremes() {
+        assert_approx(PerspectiveCamera::clamp_fov_y(1.0f32.to_radians()).unwrap(), PerspectiveCamera::MIN_FOV_Y);
+        assert_approx(PerspectiveCamera::clamp_fov_y(170.0f32.to_radians()).unwrap(), PerspectiveCamera::MAX_FOV_Y);
+        assert_approx(PerspectiveCamera::clamp_fov_y(1.0).unwrap(), 1.0);
+        assert_eq!(PerspectiveCamera::clamp_fov_y(f32::NAN), None);
+        assert_eq!(PerspectiveCamera::clamp_fov_y(f32::INFINITY), None);
+
+        // (한국어) 휠을 여러 번 굴려도 범위를 벗어나지 않습니다.
+        // (English Translation) Scrolling the wheel many times never leaves the range.
+        let zoomed_in = 60.0f32.to_radians() * PerspectiveCamera::ZOOM_RATIO.powf(100.0);
+        let zoomed_out = 60.0f32.to_radians() * PerspectiveCamera::ZOOM_RATIO.powf(-100.0);
+        assert_approx(PerspectiveCamera::clamp_fov_y(zoomed_in).unwrap(), PerspectiveCamera::MIN_FOV_Y);
+        assert_approx(PerspectiveCamera::clamp_fov_y(zoomed_out).unwrap(), PerspectiveCamera::MAX_FOV_Y);
+    }
+}
@@ -0,0 +1,317 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::mpsc;
+use crate::camera::PerspectiveCameraBuilder;
+use crate::interfaces::{
+    GameObject, 
+    ShaderResource, 
+};
+use crate::renderer::{self, DrawContext, FrameTargets, HdrTargets};
+use crate::resources::{self, ResourceError};
+
+
+
+/// #### 한국어 </br>
+/// 렌더링 결과를 이미지 파일로 저장할 때 발생하는 오류 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An error that occurs when saving the rendered output to image files. </br>
+/// 
+#[derive(Debug)]
+pub enum CaptureError {
+    Resource(ResourceError), 
+    UnsupportedFormat(wgpu::TextureFormat), 
+    Readback(wgpu::BufferAsyncError), 
+    Io(io::Error), 
+    Image(image::ImageError), 
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resource(e) => write!(f, "{}", e), 
+            Self::UnsupportedFormat(format) => write!(f, "texture format {:?} cannot be saved as an image", format), 
+            Self::Readback(e) => write!(f, "failed to read back the texture: {}", e), 
+            Self::Io(e) => write!(f, "{}", e), 
+            Self::Image(e) => write!(f, "failed to save the image: {}", e), 
+        }
+    }
+}
+
+impl std::error::Error for CaptureError { }
+
+impl From<ResourceError> for CaptureError {
+    #[inline]
+    fn from(e: ResourceError) -> Self {
+        Self::Resource(e)
+    }
+}
+
+impl From<io::Error> for CaptureError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<image::ImageError> for CaptureError {
+    #[inline]
+    fn from(e: image::ImageError) -> Self {
+        Self::Image(e)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// `target`을 중심으로 도는 카메라의 `index`번째 프레임의 변환 행렬을 계산합니다. </br>
+/// `frames`개의 프레임 동안 y축을 중심으로 정확히 한 바퀴(360°)를 돌며, 마지막 프레임은 시작 위치와 겹치지 않습니다. </br>
+/// `start`는 첫 번째 프레임의 카메라 위치이며, 반지름과 높이는 `start`에서 정해집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the transformation matrix of the `index`-th frame of a camera orbiting around `target`. </br>
+/// It makes exactly one full turn (360°) around the y-axis over `frames` frames, and the last frame does not overlap the start position. </br>
+/// `start` is the camera position of the first frame, and the radius and height are taken from `start`. </br>
+/// 
+pub fn orbit_transform(target: glam::Vec3, start: glam::Vec3, index: u32, frames: u32) -> glam::Mat4 {
+    let angle = std::f32::consts::TAU * index as f32 / frames.max(1) as f32;
+    let position = target + glam::Quat::from_rotation_y(angle) * (start - target);
+
+    // (한국어) 카메라의 z축은 바라보는 방향의 반대 방향입니다.
+    // (English Translation) The camera's z-axis is opposite to the viewing direction.
+    let look = (position - target).normalize_or_zero();
+    let right = glam::Vec3::Y.cross(look).normalize_or_zero();
+    let up = look.cross(right);
+    glam::Mat4::from_cols(
+        (right, 0.0).into(), 
+        (up, 0.0).into(), 
+        (look, 0.0).into(), 
+        (position, 1.0).into()
+    )
+}
+
+/// #### 한국어 </br>
+/// 턴테이블 캡처의 설정 입니다. </br>
+/// 카메라는 `start`에서 출발하여 `target`을 중심으로 한 바퀴 돌며, `width` x `height` 크기의 프레임을 `frames`장 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The settings of a turntable capture. </br>
+/// The camera starts at `start` and makes one full turn around `target`, drawing `frames` frames of size `width` x `height`. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TurntableSettings {
+    pub target: glam::Vec3, 
+    pub start: glam::Vec3, 
+    pub frames: u32, 
+    pub width: u32, 
+    pub height: u32, 
+}
+
+/// #### 한국어 </br>
+/// 카메라가 `target`을 중심으로 한 바퀴 도는 동안 장면을 `frames`장 그려 </br>
+/// `output_dir`에 `frame_0000.png`, `frame_0001.png`, ... 로 저장합니다. 저장한 프레임의 수를 반환합니다. </br>
+/// 각 프레임은 화면 밖 텍스처에 그린 후 버퍼로 읽어오므로 창의 크기나 스왑체인과 관계가 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Draws the scene `frames` times while the camera makes one full turn around `target`, </br>
+/// and saves them into `output_dir` as `frame_0000.png`, `frame_0001.png`, .... Returns the number of saved frames. </br>
+/// Each frame is drawn into an offscreen texture and read back into a buffer, so it does not depend on the window size or swapchain. </br>
+/// 
+pub fn turntable_capture(
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    context: &DrawContext, 
+    camera_bind_group_layout: &wgpu::BindGroupLayout, 
+    oit_bind_group_layout: &wgpu::BindGroupLayout, 
+    turntable: &TurntableSettings, 
+    output_dir: &Path
+) -> Result<u32, CaptureError> {
+    let TurntableSettings { target, start, frames, width, height } = *turntable;
+    resources::validate_texture_size(&device.limits(), width, height)?;
+    let format = context.pipelines.settings.color_format;
+    let swizzle = is_bgra8(format)?;
+    fs::create_dir_all(output_dir)?;
+
+    let texture = device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some("Texture(Turntable)"), 
+            size: wgpu::Extent3d {
+                width, 
+                height, 
+                depth_or_array_layers: 1, 
+            }, 
+            format, 
+            dimension: wgpu::TextureDimension::D2, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC, 
+            view_formats: &[], 
+        }, 
+    );
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let mut targets = FrameTargets::new(
+        device, 
+        oit_bind_group_layout, 
+        context.pipelines.settings.reveal_format, 
+        context.pipelines.settings.depth_format, 
+        width, 
+        height, 
+        1
+    );
+    targets.hdr = context.pipelines.hdr.as_ref().map(|hdr| HdrTargets::new(device, hdr, width, height));
+
+    // (한국어) 카메라가 장면을 돌며 바라보는 방향이 바뀌므로 주 카메라의 컬링 결과를 사용하지 않습니다.
+    // (English Translation) The camera's viewing direction changes as it orbits, so the main camera's culling result is not used.
+    // (한국어) 정렬된 순서도 주 카메라 기준이므로 사용하지 않습니다.
+    // (English Translation) The sorted order is also relative to the main camera, so it is not used either.
+    let context = &DrawContext { culling: None, timestamps: None, sorted_transparent: None, ..*context };
+    let mut camera = PerspectiveCameraBuilder::new(60.0f32.to_radians(), width as f32 / height.max(1) as f32, 0.001, 1000.0)
+        .set_name("Turntable")
+        .set_reverse_z(context.pipelines.settings.reverse_z)
+        .build(device, camera_bind_group_layout);
+
+    for index in 0..frames {
+        *camera.mut_world_transform() = orbit_transform(target, start, index, frames);
+        camera.update_shader_resource(queue);
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(Turntable)"), 
+        });
+        renderer::record_scene_passes(&mut encoder, context, camera.ref_bind_group(), &targets, &view);
+        queue.submit(Some(encoder.finish()));
+
+        let mut pixels = read_texture_rgba8(device, queue, &texture)?;
+        if swizzle {
+            pixels.chunks_exact_mut(4).for_each(|texel| texel.swap(0, 2));
+        }
+        write_png(&output_dir.join(format!("frame_{:04}.png", index)), width, height, &pixels)?;
+    }
+
+    log::info!("Saved {} turntable frames to {}", frames, output_dir.display());
+    Ok(frames)
+}
+
+/// #### 한국어 </br>
+/// 화면(스왑체인) 텍스처 또는 화면 밖 렌더 타겟을 읽어 RGBA8 PNG 파일로 저장합니다. </br>
+/// `Bgra8` 형식인 경우 저장하기 전에 빨간색과 파란색 채널을 바꿉니다. </br>
+/// 텍스처는 `COPY_SRC` 용도로 생성되어야 하며, 스왑체인 텍스처인 경우 표시(present)하기 전에 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads a surface (swapchain) texture or an offscreen render target and saves it as an RGBA8 PNG file. </br>
+/// For `Bgra8` formats, the red and blue channels are swapped before saving. </br>
+/// The texture must be created with the `COPY_SRC` usage, and for a swapchain texture this must be called before presenting. </br>
+/// 
+pub fn save_screenshot(
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    texture: &wgpu::Texture, 
+    path: &Path
+) -> Result<(), CaptureError> {
+    let swizzle = is_bgra8(texture.format())?;
+    let mut pixels = read_texture_rgba8(device, queue, texture)?;
+    if swizzle {
+        pixels.chunks_exact_mut(4).for_each(|texel| texel.swap(0, 2));
+    }
+    write_png(path, texture.width(), texture.height(), &pixels)?;
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 텍스처 형식이 `Bgra8` 형식인지 확인합니다. 4 바이트 RGBA/BGRA 형식이 아닌 경우 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the texture format is a `Bgra8` format. Returns an error if it is not a 4 byte RGBA/BGRA format. </br>
+/// 
+fn is_bgra8(format: wgpu::TextureFormat) -> Result<bool, CaptureError> {
+    match format {
+        wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => Ok(false), 
+        wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => Ok(true), 
+        _ => Err(CaptureError::UnsupportedFormat(format)), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 4 바이트 텍셀 형식의 2D 텍스처를 CPU 메모리로 읽어옵니다. (행 사이의 여백 없음) </br>
+/// 텍스처는 `COPY_SRC` 용도로 생성되어야 하며, 이 함수는 GPU 작업이 끝날 때까지 기다립니다. </br>
+/// 
+/// 텍스처를 버퍼로 복사할 때 `bytes_per_row`는 `COPY_BYTES_PER_ROW_ALIGNMENT` (256 바이트)의 배수여야 합니다. </br>
+/// 따라서 버퍼의 각 행은 `너비 * 4` 바이트를 256의 배수로 올림한 크기를 가지며, 남는 바이트는 여백입니다. </br>
+/// 예를 들어 너비가 100인 경우 한 행은 400 바이트이지만 버퍼에서는 512 바이트를 차지합니다. </br>
+/// 읽어온 후 각 행에서 여백을 잘라내므로, 창의 크기와 관계없이 결과는 빈틈없이 채워진 `너비 * 높이 * 4` 바이트 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads a 2D texture with a 4 byte texel format back into CPU memory. (no padding between rows) </br>
+/// The texture must be created with the `COPY_SRC` usage, and this function waits until the GPU work is finished. </br>
+/// 
+/// When copying a texture into a buffer, `bytes_per_row` must be a multiple of `COPY_BYTES_PER_ROW_ALIGNMENT` (256 bytes). </br>
+/// So each row in the buffer takes `width * 4` bytes rounded up to a multiple of 256, and the remaining bytes are padding. </br>
+/// For example, with a width of 100 a row is 400 bytes, but it takes 512 bytes in the buffer. </br>
+/// The padding is cut off each row after reading, so the result is tightly packed `width * height * 4` bytes regardless of the window size. </br>
+/// 
+pub fn read_texture_rgba8(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) -> Result<Vec<u8>, CaptureError> {
+    let size = texture.size();
+    let row_bytes = size.width * 4;
+    let padded_row_bytes = row_bytes.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+    let buffer = device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some("ReadbackBuffer"), 
+            mapped_at_creation: false, 
+            size: (padded_row_bytes * size.height) as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    );
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("CommandEncoder(Readback)"), 
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(), 
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer, 
+            layout: wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(padded_row_bytes), 
+                rows_per_image: Some(size.height), 
+            }, 
+        }, 
+        wgpu::Extent3d {
+            width: size.width, 
+            height: size.height, 
+            depth_or_array_layers: 1, 
+        }
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let (sender, receiver) = mpsc::channel();
+    let slice = buffer.slice(..);
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    receiver.recv()
+        .expect("The map callback must be called after waiting for the device.")
+        .map_err(CaptureError::Readback)?;
+
+    let mut pixels = Vec::with_capacity((row_bytes * size.height) as usize);
+    {
+        let mapped = slice.get_mapped_range();
+        for row in mapped.chunks_exact(padded_row_bytes as usize) {
+            pixels.extend_from_slice(&row[..row_bytes as usize]);
+        }
+    }
+    buffer.unmap();
+    Ok(pixels)
+}
+
+/// #### 한국어 </br>
+/// 행 사이의 여백이 없는 RGBA8 픽셀 데이터를 PNG 파일로 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Saves tightly packed RGBA8 pixel data (no padding between rows) as a PNG file. </br>
+/// 
+pub fn write_png(path: &Path, width: u32, height: u32, pixels: &[u8]) -> Result<(), CaptureError> {
+    image::save_buffer_with_format(path, pixels, width, height, image::ColorType::Rgba8, image::ImageFormat::Png)?;
+    Ok(())
+}
@@ -0,0 +1,163 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering as MemOrdering};
+use std::collections::VecDeque;
+use winit::event::{Event, WindowEvent};
+
+
+
+/// #### 한국어 </br>
+/// 윈도우 메시지 루프에서 렌더링 루프로 창 이벤트를 전달하는 대기열 입니다. </br>
+/// 
+/// 커서 이동, 창 크기 변경과 같이 자주 발생하는 이벤트는 가장 최근 값만 의미가 있으므로, </br>
+/// 같은 종류의 이벤트가 대기 중인 경우 이전 이벤트를 제거하고 새 이벤트를 뒤에 추가합니다. (병합) </br>
+/// 용량을 설정한 경우 대기열이 가득 차면 가장 오래된 이벤트를 버립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A queue that forwards window events from the window message loop to the rendering loop. </br>
+/// 
+/// For high-frequency events such as cursor moves and window resizes only the latest value matters, </br>
+/// so if an event of the same kind is pending, the older event is removed and the new event is appended. (coalescing) </br>
+/// If a capacity is set, the oldest event is dropped when the queue is full. </br>
+/// 
+#[derive(Debug)]
+pub struct EventQueue {
+    queue: Mutex<VecDeque<Event<()>>>, 
+    capacity: AtomicUsize, 
+    dropped: AtomicUsize, 
+}
+
+#[allow(dead_code)]
+impl EventQueue {
+    /// #### 한국어 </br>
+    /// 용량 제한이 없음을 나타내는 값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The value indicating that the capacity is unbounded. </br>
+    /// 
+    const UNBOUNDED: usize = 0;
+
+    pub const fn new() -> Self {
+        Self {
+            queue: Mutex::new(VecDeque::new()), 
+            capacity: AtomicUsize::new(Self::UNBOUNDED), 
+            dropped: AtomicUsize::new(0), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 대기열의 용량을 가져옵니다. 용량 제한이 없는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the capacity of the queue. Returns `None` if the capacity is unbounded. </br>
+    /// 
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        match self.capacity.load(MemOrdering::Acquire) {
+            Self::UNBOUNDED => None, 
+            capacity => Some(capacity), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 대기열의 용량을 설정합니다. `None`인 경우 용량 제한이 없습니다. (기본값: `None`) </br>
+    /// 대기 중인 이벤트가 새 용량보다 많은 경우 가장 오래된 이벤트부터 버립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the capacity of the queue. If `None`, the capacity is unbounded. (default: `None`) </br>
+    /// If more events are pending than the new capacity, the oldest events are dropped first. </br>
+    /// 
+    pub fn set_capacity(&self, capacity: Option<usize>) {
+        let capacity = capacity.map_or(Self::UNBOUNDED, |capacity| capacity.max(1));
+        self.capacity.store(capacity, MemOrdering::Release);
+
+        let mut queue = self.queue.lock().unwrap();
+        self.drop_oldest(&mut queue, capacity);
+    }
+
+    /// #### 한국어 </br>
+    /// 용량 초과로 버려진 이벤트의 총 개수를 가져옵니다. (병합된 이벤트는 포함하지 않습니다.) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the total number of events dropped due to the capacity. (coalesced events are not included.) </br>
+    /// 
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped.load(MemOrdering::Acquire)
+    }
+
+    /// #### 한국어 </br>
+    /// 대기 중인 이벤트의 개수를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the number of pending events. </br>
+    /// 
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// #### 한국어 </br>
+    /// 이벤트를 대기열에 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds an event to the queue. </br>
+    /// 
+    pub fn push(&self, event: Event<()>) {
+        let mut queue = self.queue.lock().unwrap();
+        if let Some(kind) = CoalescedKind::of(&event) {
+            queue.retain(|pending| CoalescedKind::of(pending) != Some(kind));
+        }
+
+        queue.push_back(event);
+        self.drop_oldest(&mut queue, self.capacity.load(MemOrdering::Acquire));
+    }
+
+    /// #### 한국어 </br>
+    /// 대기열에서 가장 오래된 이벤트를 꺼냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Takes the oldest event out of the queue. </br>
+    /// 
+    #[inline]
+    pub fn pop(&self) -> Option<Event<()>> {
+        self.queue.lock().unwrap().pop_front()
+    }
+
+    fn drop_oldest(&self, queue: &mut VecDeque<Event<()>>, capacity: usize) {
+        if capacity == Self::UNBOUNDED || queue.len() <= capacity {
+            return;
+        }
+
+        let overflow = queue.len() - capacity;
+        queue.drain(..overflow);
+        let dropped = self.dropped.fetch_add(overflow, MemOrdering::AcqRel) + overflow;
+        log::warn!("Event queue is full. Dropped {} oldest event(s). (total dropped: {})", overflow, dropped);
+    }
+}
+
+/// #### 한국어 </br>
+/// 가장 최근 값만 의미가 있어 병합되는 이벤트의 종류 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The kinds of events that are coalesced because only the latest value matters. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CoalescedKind {
+    CursorMoved, 
+    Resized, 
+}
+
+impl CoalescedKind {
+    fn of(event: &Event<()>) -> Option<Self> {
+        match event {
+            Event::WindowEvent { event: WindowEvent::CursorMoved { .. }, .. } => Some(Self::CursorMoved), 
+            Event::WindowEvent { event: WindowEvent::Resized(_), .. } => Some(Self::Resized), 
+            _ => None, 
+        }
+    }
+}
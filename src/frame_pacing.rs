@@ -0,0 +1,107 @@
+use crate::timer::FrameTimeHistory;
+
+
+
+/// #### 한국어 </br>
+/// 수직 동기화를 사용하는 표시 모드와 사용하지 않는 표시 모드의 프레임 시간을 비교합니다. </br>
+/// 하나의 표면에 두 표시 모드를 동시에 사용할 수 없으므로, 일정 시간마다 표시 모드를 번갈아 전환하며 </br>
+/// 각 모드의 프레임 시간을 따로 기록하고, 두 모드를 한 번씩 측정할 때마다 비교 결과를 로그로 출력합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Compares the frame times of a present mode with vertical sync and one without. </br>
+/// Since one surface cannot use two present modes at once, the present mode is alternated at a fixed interval, </br>
+/// the frame times of each mode are recorded separately, and the comparison is logged every time both modes have been measured once. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct PresentModeComparison {
+    modes: [wgpu::PresentMode; 2], 
+    histories: [FrameTimeHistory; 2], 
+    active: usize, 
+    interval_sec: f32, 
+    phase_elapsed_sec: f32, 
+    warmup_frames: u32, 
+}
+
+#[allow(dead_code)]
+impl PresentModeComparison {
+    /// #### 한국어 </br>
+    /// 표시 모드를 전환한 직후 측정에서 제외하는 프레임 수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of frames excluded from measurement right after switching the present mode. </br>
+    /// 
+    pub const WARMUP_FRAMES: u32 = 10;
+
+    pub fn new(interval_sec: f32) -> Self {
+        Self {
+            modes: [wgpu::PresentMode::AutoVsync, wgpu::PresentMode::AutoNoVsync], 
+            histories: [FrameTimeHistory::new(); 2], 
+            active: 0, 
+            interval_sec, 
+            phase_elapsed_sec: 0.0, 
+            warmup_frames: Self::WARMUP_FRAMES, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 측정 중인 표시 모드를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the present mode currently being measured. </br>
+    /// 
+    #[inline]
+    pub fn active_mode(&self) -> wgpu::PresentMode {
+        self.modes[self.active]
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 표시 모드의 프레임 시간을 기록합니다. </br>
+    /// 표시 모드를 전환해야 하는 경우 새 표시 모드를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the frame time of the current present mode. </br>
+    /// Returns the new present mode if the present mode should be switched. </br>
+    /// 
+    pub fn update(&mut self, frame_time_sec: f32) -> Option<wgpu::PresentMode> {
+        self.phase_elapsed_sec += frame_time_sec;
+        if self.warmup_frames > 0 {
+            self.warmup_frames -= 1;
+        } else {
+            self.histories[self.active].push(frame_time_sec);
+        }
+
+        if self.phase_elapsed_sec < self.interval_sec {
+            return None;
+        }
+
+        if self.active == self.modes.len() - 1 {
+            self.log_comparison();
+        }
+
+        self.active = (self.active + 1) % self.modes.len();
+        self.histories[self.active].clear();
+        self.phase_elapsed_sec = 0.0;
+        self.warmup_frames = Self::WARMUP_FRAMES;
+        return Some(self.active_mode());
+    }
+
+    /// #### 한국어 </br>
+    /// 두 표시 모드의 프레임 시간 통계를 나란히 로그로 출력합니다. (밀리초 단위) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Logs the frame time statistics of both present modes side by side. (in milliseconds) </br>
+    /// 
+    pub fn log_comparison(&self) {
+        log::info!("{:>12} | {:>8} | {:>8} | {:>8} | {:>8}", "Present mode", "avg(ms)", "min(ms)", "max(ms)", "p99(ms)");
+        for (mode, history) in self.modes.iter().zip(self.histories.iter()) {
+            log::info!(
+                "{:>12} | {:>8.3} | {:>8.3} | {:>8.3} | {:>8.3}", 
+                format!("{:?}", mode), 
+                history.average() * 1000.0, 
+                history.min() * 1000.0, 
+                history.max() * 1000.0, 
+                history.percentile(0.99) * 1000.0
+            );
+        }
+    }
+}
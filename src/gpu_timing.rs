@@ -0,0 +1,197 @@
+/// #### 한국어 </br>
+/// GPU 시간을 측정하는 렌더 패스 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A render pass whose GPU time is measured. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimedPass {
+    Opaque = 0, 
+    Transparent = 1, 
+    Composite = 2, 
+}
+
+impl TimedPass {
+    /// #### 한국어 </br>
+    /// 측정하는 렌더 패스의 수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of measured render passes. </br>
+    /// 
+    pub const COUNT: u32 = 3;
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더 패스별 GPU 시간 입니다. (밀리초) </br>
+/// 
+/// #### English (Translation) </br>
+/// GPU time per render pass. (milliseconds) </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PassTimings {
+    pub opaque_ms: f32, 
+    pub transparent_ms: f32, 
+    pub composite_ms: f32, 
+}
+
+#[allow(dead_code)]
+impl PassTimings {
+    /// #### 한국어 </br>
+    /// 세 렌더 패스의 GPU 시간의 합을 가져옵니다. (밀리초) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the sum of the GPU times of the three render passes. (milliseconds) </br>
+    /// 
+    #[inline]
+    pub fn total_ms(&self) -> f32 {
+        self.opaque_ms + self.transparent_ms + self.composite_ms
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 불투명 패스, 투명 패스, 합성 패스의 시작과 끝에 기록되는 GPU 타임스탬프 쿼리 입니다. </br>
+/// 각 패스는 쿼리 집합에서 `2 * 패스` (시작)와 `2 * 패스 + 1` (끝) 인덱스를 사용합니다. </br>
+/// 장치가 `TIMESTAMP_QUERY` 기능을 지원하는 경우에만 생성됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// GPU timestamp queries written at the beginning and end of the opaque, transparent and composite passes. </br>
+/// Each pass uses indices `2 * pass` (beginning) and `2 * pass + 1` (end) in the query set. </br>
+/// Only created if the device supports the `TIMESTAMP_QUERY` feature. </br>
+/// 
+#[derive(Debug)]
+pub struct PassTimestamps {
+    query_set: wgpu::QuerySet, 
+    resolve_buffer: wgpu::Buffer, 
+    readback_buffer: wgpu::Buffer, 
+    period_ns: f32, 
+}
+
+#[allow(dead_code)]
+impl PassTimestamps {
+    /// #### 한국어 </br>
+    /// 쿼리 집합의 쿼리 수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of queries in the query set. </br>
+    /// 
+    pub const QUERY_COUNT: u32 = TimedPass::COUNT * 2;
+
+    const BUFFER_SIZE: wgpu::BufferAddress = Self::QUERY_COUNT as wgpu::BufferAddress * wgpu::QUERY_SIZE as wgpu::BufferAddress;
+
+    /// #### 한국어 </br>
+    /// 타임스탬프 쿼리를 생성합니다. </br>
+    /// 장치가 `TIMESTAMP_QUERY` 기능을 지원하지 않는 경우 로그를 한 번 출력하고 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the timestamp queries. </br>
+    /// If the device does not support the `TIMESTAMP_QUERY` feature, logs once and returns `None`. </br>
+    /// 
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            log::info!("GPU pass timing requires the TIMESTAMP_QUERY feature, which is not supported. GPU pass timing is disabled.");
+            return None;
+        }
+
+        let query_set = device.create_query_set(
+            &wgpu::QuerySetDescriptor {
+                label: Some("QuerySet(PassTimestamps)"), 
+                ty: wgpu::QueryType::Timestamp, 
+                count: Self::QUERY_COUNT, 
+            }
+        );
+
+        let resolve_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("ResolveBuffer(PassTimestamps)"), 
+                mapped_at_creation: false, 
+                size: Self::BUFFER_SIZE, 
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC, 
+            }
+        );
+
+        let readback_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("ReadbackBuffer(PassTimestamps)"), 
+                mapped_at_creation: false, 
+                size: Self::BUFFER_SIZE, 
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+            }
+        );
+
+        Some(Self {
+            query_set, 
+            resolve_buffer, 
+            readback_buffer, 
+            period_ns: queue.get_timestamp_period(), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스의 시작과 끝에 기록할 타임스탬프 쓰기를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the timestamp writes to record at the beginning and end of a render pass. </br>
+    /// 
+    #[inline]
+    pub fn pass_writes(&self, pass: TimedPass) -> wgpu::RenderPassTimestampWrites<'_> {
+        let index = pass as u32 * 2;
+        wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set, 
+            beginning_of_pass_write_index: Some(index), 
+            end_of_pass_write_index: Some(index + 1), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 쿼리 결과를 읽기 버퍼로 복사하는 명령을 기록합니다. 세 렌더 패스를 기록한 후 제출하기 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the commands that copy the query results into the readback buffer. </br>
+    /// Must be called after the three render passes are recorded and before submitting. </br>
+    /// 
+    pub fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.resolve_query_set(&self.query_set, 0..Self::QUERY_COUNT, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(&self.resolve_buffer, 0, &self.readback_buffer, 0, Self::BUFFER_SIZE);
+    }
+
+    /// #### 한국어 </br>
+    /// 제출한 명령이 끝날 때 까지 기다린 후 렌더 패스별 GPU 시간을 읽어옵니다. </br>
+    /// 타임스탬프 값(틱)은 `get_timestamp_period` (틱당 나노초)를 곱해 밀리초로 변환됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Waits until the submitted commands finish and reads the GPU time per render pass. </br>
+    /// Timestamp values (ticks) are converted to milliseconds by multiplying with `get_timestamp_period` (nanoseconds per tick). </br>
+    /// 
+    pub fn read_timings(&self, device: &wgpu::Device) -> Option<PassTimings> {
+        let slice = self.readback_buffer.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        if let Err(e) = receiver.recv().ok()? {
+            log::warn!("Failed to read GPU pass timestamps: {}", e);
+            return None;
+        }
+
+        let timings = {
+            let mapped = slice.get_mapped_range();
+            let ticks: &[u64] = bytemuck::cast_slice(&mapped);
+            let elapsed_ms = |pass: TimedPass| {
+                let index = pass as usize * 2;
+                ticks[index + 1].wrapping_sub(ticks[index]) as f64 * self.period_ns as f64 / 1_000_000.0
+            };
+            PassTimings {
+                opaque_ms: elapsed_ms(TimedPass::Opaque) as f32, 
+                transparent_ms: elapsed_ms(TimedPass::Transparent) as f32, 
+                composite_ms: elapsed_ms(TimedPass::Composite) as f32, 
+            }
+        };
+        self.readback_buffer.unmap();
+        Some(timings)
+    }
+}
@@ -0,0 +1,238 @@
+use std::mem;
+use std::cell::Cell;
+use crate::interfaces::ShaderResource;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 바닥 격자의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the ground grid passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GridUniformLayout {
+    pub color: glam::Vec4, 
+    pub params: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// XZ 평면(y = 0) 위에 그려지는 참조용 바닥 격자의 설정 입니다. </br>
+/// 
+/// 격자는 메쉬 없이 전체 화면 삼각형(`vs_grid`, `fs_grid`)으로 그려집니다. </br>
+/// 조각 쉐이더는 카메라 유니폼의 뷰-투영 역행렬로 각 픽셀의 광선을 만들어 y = 0 평면과의 교차점을 구하며, </br>
+/// 교차점의 깊이 값을 출력하므로 불투명한 오브젝트에 올바르게 가려집니다. 카메라에서 멀어질수록 흐려져 끝이 없는 것처럼 보입니다. </br>
+/// 
+/// 카메라 유니폼과 같이 값이 바뀐 경우에만 (`dirty`) `update_shader_resource`에서 유니폼 버퍼를 다시 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings of the reference ground grid drawn on the XZ plane (y = 0). </br>
+/// 
+/// The grid is drawn without a mesh as a full-screen triangle (`vs_grid`, `fs_grid`). </br>
+/// The fragment shader builds a ray for each pixel with the inverse view-projection matrix of the camera uniform and intersects it with the y = 0 plane, </br>
+/// and outputs the depth of the intersection so that it is correctly occluded by opaque objects. It fades out away from the camera so that it looks infinite. </br>
+/// 
+/// Like the camera uniform, `update_shader_resource` rewrites the uniform buffer only if the values changed (`dirty`). </br>
+/// 
+#[derive(Debug)]
+pub struct GridSettings {
+    enabled: bool, 
+    spacing: f32, 
+    major_every: u32, 
+    line_width: f32, 
+    fade_distance: f32, 
+    color: glam::Vec4, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl GridSettings {
+    pub const DEFAULT_SPACING: f32 = 1.0;
+    pub const DEFAULT_MAJOR_EVERY: u32 = 10;
+    pub const DEFAULT_LINE_WIDTH: f32 = 1.0;
+    pub const DEFAULT_FADE_DISTANCE: f32 = 60.0;
+    pub const DEFAULT_COLOR: glam::Vec4 = glam::Vec4::new(0.8, 0.8, 0.8, 0.5);
+
+    pub fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Grid)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<GridUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Grid)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            enabled: true, 
+            spacing: Self::DEFAULT_SPACING, 
+            major_every: Self::DEFAULT_MAJOR_EVERY, 
+            line_width: Self::DEFAULT_LINE_WIDTH, 
+            fade_distance: Self::DEFAULT_FADE_DISTANCE, 
+            color: Self::DEFAULT_COLOR, 
+            dirty: Cell::new(true), 
+            buffer, 
+            bind_group, 
+        }
+    }
+
+    #[inline]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// #### 한국어 </br>
+    /// 격자를 그리는지 여부를 설정합니다. (기본값: `true`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to draw the grid. (default: `true`) </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn spacing(&self) -> f32 {
+        self.spacing
+    }
+
+    /// #### 한국어 </br>
+    /// 격자 한 칸의 월드 공간 크기를 설정합니다. 0 이하의 값은 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the world space size of one grid cell. Values of 0 or less are ignored. </br>
+    /// 
+    #[inline]
+    pub fn set_spacing(&mut self, spacing: f32) {
+        if spacing > 0.0 {
+            self.spacing = spacing;
+            self.mark_dirty();
+        }
+    }
+
+    #[inline]
+    pub fn major_every(&self) -> u32 {
+        self.major_every
+    }
+
+    /// #### 한국어 </br>
+    /// 몇 칸마다 굵은 주 격자선을 그릴지 설정합니다. 0인 경우 주 격자선을 그리지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets every how many cells a thicker major line is drawn. If 0, no major lines are drawn. </br>
+    /// 
+    #[inline]
+    pub fn set_major_every(&mut self, major_every: u32) {
+        self.major_every = major_every;
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn line_width(&self) -> f32 {
+        self.line_width
+    }
+
+    /// #### 한국어 </br>
+    /// 격자선의 두께를 픽셀 단위로 설정합니다. 거리와 관계없이 같은 두께로 보입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the thickness of the grid lines in pixels. They look equally thick regardless of distance. </br>
+    /// 
+    #[inline]
+    pub fn set_line_width(&mut self, line_width: f32) {
+        self.line_width = line_width.max(0.0);
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn fade_distance(&self) -> f32 {
+        self.fade_distance
+    }
+
+    /// #### 한국어 </br>
+    /// 격자가 완전히 사라지는 카메라로부터의 거리를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the distance from the camera at which the grid fully fades out. </br>
+    /// 
+    #[inline]
+    pub fn set_fade_distance(&mut self, fade_distance: f32) {
+        self.fade_distance = fade_distance.max(f32::EPSILON);
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn color(&self) -> glam::Vec4 {
+        self.color
+    }
+
+    /// #### 한국어 </br>
+    /// 격자선의 색상을 설정합니다. (rgb: 색상, a: 불투명도) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the color of the grid lines. (rgb: color, a: opacity) </br>
+    /// 
+    #[inline]
+    pub fn set_color(&mut self, color: glam::Vec4) {
+        self.color = color;
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+}
+
+impl ShaderResource for GridSettings {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        let data = GridUniformLayout {
+            color: self.color, 
+            params: glam::Vec4::new(self.spacing, self.line_width, self.fade_distance, self.major_every as f32), 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// #### 한국어 </br>
+/// 바닥 격자의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 1) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the ground grid. (bind group slot 1) </br>
+/// 
+#[inline]
+pub fn create_grid_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    utils::create_uniform_bind_group_layout(device, "BindGroupLayout(Grid)", wgpu::ShaderStages::FRAGMENT)
+}
@@ -0,0 +1,227 @@
+use std::path::Path;
+use crate::camera::PerspectiveCameraBuilder;
+use crate::capture::{self, CaptureError};
+use crate::interfaces::{
+    GameCameraObject, 
+    ShaderResource, 
+};
+use crate::mesh::GpuMesh;
+use crate::objects::ColordObjectBuilder;
+use crate::pipeline::PipelineSetBuilder;
+use crate::renderer::{self, DrawContext, FrameResources, PassLabels};
+use crate::resources;
+use crate::settings::RenderSettings;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 창 없이 그릴 때 사용하는 화면 밖 색상 렌더 타겟 입니다. </br>
+/// 스왑체인 텍스처 대신 합성 패스의 출력이 되며, `COPY_SRC` 용도로 생성되므로 결과를 CPU 메모리로 읽어올 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An offscreen color render target used when drawing without a window. </br>
+/// It becomes the output of the composite pass instead of the swapchain texture, and since it is created with the `COPY_SRC` usage, the result can be read back into CPU memory. </br>
+/// 
+#[derive(Debug)]
+pub struct OffscreenTarget {
+    texture: wgpu::Texture, 
+    view: wgpu::TextureView, 
+}
+
+#[allow(dead_code)]
+impl OffscreenTarget {
+    /// #### 한국어 </br>
+    /// 화면 밖 렌더 타겟의 색상 형식 입니다. 일반적인 화면 형식과 같게 하여 창이 있을 때와 같은 결과를 얻습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The color format of the offscreen render target. It matches the usual surface format to get the same output as with a window. </br>
+    /// 
+    pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8UnormSrgb;
+
+    pub fn new(device: &wgpu::Device, width: u32, height: u32) -> Result<Self, CaptureError> {
+        resources::validate_texture_size(&device.limits(), width, height)?;
+        let texture = device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(Offscreen)"), 
+                size: wgpu::Extent3d {
+                    width, 
+                    height, 
+                    depth_or_array_layers: 1, 
+                }, 
+                format: Self::FORMAT, 
+                dimension: wgpu::TextureDimension::D2, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC, 
+                view_formats: &[], 
+            }, 
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        Ok(Self { texture, view })
+    }
+
+    #[inline]
+    pub fn ref_texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    #[inline]
+    pub fn ref_view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟을 RGBA8 순서의 빈틈없는 픽셀 배열로 읽어옵니다. GPU 작업이 끝날 때까지 기다립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the render target back as a tightly packed pixel array in RGBA8 order. Waits until the GPU work is finished. </br>
+    /// 
+    pub fn read_pixels(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Vec<u8>, CaptureError> {
+        let mut pixels = capture::read_texture_rgba8(device, queue, &self.texture)?;
+        pixels.chunks_exact_mut(4).for_each(|texel| texel.swap(0, 2));
+        Ok(pixels)
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟을 PNG 파일로 저장합니다. (참고: `capture::save_screenshot`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Saves the render target as a PNG file. (see also: `capture::save_screenshot`) </br>
+    /// 
+    #[inline]
+    pub fn save_png(&self, device: &wgpu::Device, queue: &wgpu::Queue, path: &Path) -> Result<(), CaptureError> {
+        capture::save_screenshot(device, queue, &self.texture, path)
+    }
+}
+
+/// #### 한국어 </br>
+/// 창 없이 고정된 테스트 장면을 한 번 그려 `path`에 PNG 파일로 저장합니다. </br>
+/// 창이 있을 때와 같은 파이프라인으로 불투명 패스, 투명 패스, 합성 패스를 그리며, 합성 패스의 출력만 화면 밖 렌더 타겟으로 바뀝니다. </br>
+/// 표면(surface) 설정, 스왑체인 텍스처 획득, 표시(present)와 창 이벤트 처리는 모두 생략됩니다. </br>
+/// 
+/// 장면, 카메라와 렌더링 설정이 고정되어 있고 타이머를 사용하지 않으므로 (흔들림 등의 애니메이션이 시작 상태), </br>
+/// 같은 장치에서는 항상 같은 이미지를 얻습니다. 자동화된 이미지 비교 테스트에 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Draws a fixed test scene once without a window and saves it as a PNG file at `path`. </br>
+/// It draws the opaque, transparent and composite passes with the same pipelines as with a window, only the output of the composite pass becomes an offscreen render target. </br>
+/// Surface configuration, swapchain texture acquisition, presenting and window event handling are all skipped. </br>
+/// 
+/// The scene, camera and rendering settings are fixed and no timer is used (animations such as wobbling stay at their initial state), </br>
+/// so the same device always produces the same image. Used for automated image comparison tests. </br>
+/// 
+pub fn render_headless(
+    adapter: &wgpu::Adapter, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    width: u32, 
+    height: u32, 
+    path: &Path
+) -> Result<(), CaptureError> {
+    let target = OffscreenTarget::new(device, width, height)?;
+
+    let camera_bind_group_layout = utils::create_uniform_bind_group_layout(device, "BindGroupLayout(Camera)", wgpu::ShaderStages::VERTEX_FRAGMENT);
+    let object_bind_group_layout = utils::create_uniform_bind_group_layout(device, "BindGroupLayout(ColoredObject)", wgpu::ShaderStages::VERTEX_FRAGMENT);
+    let global_bind_group_layout = utils::create_uniform_bind_group_layout(device, "BindGroupLayout(Global)", wgpu::ShaderStages::VERTEX_FRAGMENT);
+    let wboit_bind_group_layout = utils::create_uniform_bind_group_layout(device, "BindGroupLayout(Wboit)", wgpu::ShaderStages::FRAGMENT);
+    let oit_bind_group_layout = renderer::create_oit_bind_group_layout(device, wgpu::TextureViewDimension::D2);
+
+    let camera = PerspectiveCameraBuilder::new(60.0f32.to_radians(), width as f32 / height.max(1) as f32, 0.001, 1000.0)
+        .set_name("Headless")
+        .set_translation((0.0, 3.0, 15.0).into())
+        .look_at_point((0.0, 0.0, 0.0).into())
+        .build(device, &camera_bind_group_layout);
+    camera.update_shader_resource(queue);
+
+    // (한국어) 창이 있을 때의 장면에서 데칼과 인스턴스 묶음을 뺀 불투명한 오브젝트와 투명한 오브젝트들을 사용합니다.
+    // (English Translation) Uses the opaque and transparent objects of the windowed scene, without the decals and instance batches.
+    let opaque_objects = vec![
+        ColordObjectBuilder::new()
+            .set_color((0.5, 0.5, 0.5, 1.0).into())
+            .set_scale((8.0, 8.0, 1.0).into())
+            .look_at_point((0.0, 1.0, 0.0).into())
+            .build(device, &object_bind_group_layout), 
+        ColordObjectBuilder::new()
+            .set_color((0.7, 0.7, 0.7, 1.0).into())
+            .set_translation((3.0, 1.0, 0.0).into())
+            .build(device, &object_bind_group_layout), 
+        ColordObjectBuilder::new()
+            .set_color((0.7, 0.7, 0.7, 1.0).into())
+            .set_translation((-0.5, 1.0, -2.5).into())
+            .build(device, &object_bind_group_layout), 
+    ];
+    let transparent_objects = vec![
+        ColordObjectBuilder::new()
+            .set_color((1.0, 0.0, 0.0, 0.3).into())
+            .set_translation((0.0, 1.0, 0.0).into())
+            .build(device, &object_bind_group_layout), 
+        ColordObjectBuilder::new()
+            .set_color((0.0, 1.0, 0.0, 0.3).into())
+            .set_translation((1.0, 1.0, 3.0).into())
+            .build(device, &object_bind_group_layout), 
+        ColordObjectBuilder::new()
+            .set_color((0.0, 0.0, 1.0, 0.3).into())
+            .set_translation((-2.0, 1.0, -5.0).into())
+            .build(device, &object_bind_group_layout), 
+    ];
+    for object in opaque_objects.iter().chain(transparent_objects.iter()) {
+        object.update_shader_resource(queue);
+    }
+
+    let quad_mesh = GpuMesh::quad(device, queue);
+
+    let settings = RenderSettings::new(device, &global_bind_group_layout, &wboit_bind_group_layout);
+    settings.update_shader_resource(queue);
+
+    let module = device.create_shader_module(
+        wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shader.wgsl"))
+    );
+    let pipeline_builder = PipelineSetBuilder::new(
+        device, 
+        &module, 
+        &camera_bind_group_layout, 
+        &object_bind_group_layout, 
+        &global_bind_group_layout, 
+        &wboit_bind_group_layout, 
+        &oit_bind_group_layout
+    )
+    .set_reverse_z(camera.reverse_z())
+    .set_reveal_format(utils::select_reveal_format(adapter, device, wgpu::TextureFormat::R16Float))
+    .set_color_format(OffscreenTarget::FORMAT);
+    let resources = FrameResources::new(pipeline_builder, 1.0, width, height);
+
+    let pass_labels = PassLabels::default();
+    let context = DrawContext {
+        pipelines: &resources.pipelines, 
+        settings: &settings, 
+        quad_mesh: &quad_mesh, 
+        opaque_objects: &opaque_objects, 
+        decal_objects: &[], 
+        transparent_objects: &transparent_objects, 
+        opaque_batches: &[], 
+        transparent_batches: &[], 
+        mesh_objects: &[], 
+        textured_objects: &[], 
+        skinned_objects: &[], 
+        hidden_line: false, 
+        culling: None, 
+        pass_labels: &pass_labels, 
+        timestamps: None, 
+        sorted_transparent: None, 
+        grid: None, 
+        sky: None, 
+        tonemap: None, 
+        shadow: None, 
+    };
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("CommandEncoder(Headless)"), 
+    });
+    renderer::record_scene_passes(&mut encoder, &context, camera.ref_bind_group(), &resources.targets, target.ref_view());
+    queue.submit(Some(encoder.finish()));
+
+    target.save_png(device, queue, path)?;
+    log::info!("Saved the headless frame ({}x{}) to {}", width, height, path.display());
+    Ok(())
+}
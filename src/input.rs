@@ -0,0 +1,169 @@
+use crate::interfaces::{Handedness, GameCamera};
+
+
+
+/// #### 한국어 </br>
+/// 마우스 입력 설정 입니다. </br>
+/// 
+/// - `mouse_sensitivity`: 마우스가 한 픽셀 움직일 때 카메라가 회전하는 각도. (라디안) </br>
+/// - `invert_y`: `true`인 경우 마우스를 위로 움직이면 카메라가 아래를 바라봅니다. </br>
+/// - `smoothing`: 이전 프레임의 움직임을 유지하는 비율. (`[0, 1)`, `0`인 경우 평활화하지 않음) </br>
+/// 
+/// #### English (Translation) </br>
+/// Mouse input settings. </br>
+/// 
+/// - `mouse_sensitivity`: the angle the camera rotates when the mouse moves by one pixel. (in radians) </br>
+/// - `invert_y`: if `true`, moving the mouse up makes the camera look down. </br>
+/// - `smoothing`: the fraction of the previous frame's motion that is kept. (`[0, 1)`, no smoothing if `0`) </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputSettings {
+    pub mouse_sensitivity: f32, 
+    pub invert_y: bool, 
+    pub smoothing: f32, 
+}
+
+impl Default for InputSettings {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 0.003, 
+            invert_y: false, 
+            smoothing: 0.5, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl InputSettings {
+    /// #### 한국어 </br>
+    /// 평활화 비율의 최대값 입니다. `1`에 가까우면 카메라가 마우스를 따라가지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum smoothing fraction. Close to `1`, the camera would stop following the mouse. </br>
+    /// 
+    pub const MAX_SMOOTHING: f32 = 0.95;
+
+    /// #### 한국어 </br>
+    /// 이전 프레임의 움직임(`previous`)에 새 마우스 이동량(`raw`)을 지수 필터로 섞습니다. </br>
+    /// `previous * smoothing + raw * (1 - smoothing)` </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Blends the new mouse delta (`raw`) into the previous frame's motion (`previous`) with an exponential filter. </br>
+    /// `previous * smoothing + raw * (1 - smoothing)` </br>
+    /// 
+    #[inline]
+    pub fn smooth(&self, previous: glam::Vec2, raw: glam::Vec2) -> glam::Vec2 {
+        let smoothing = self.smoothing.clamp(0.0, Self::MAX_SMOOTHING);
+        previous * smoothing + raw * (1.0 - smoothing)
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 이동량(픽셀)을 카메라의 (좌우 회전, 상하 회전) 각도로 변환합니다. (라디안) </br>
+    /// 양수 좌우 회전은 오른쪽, 양수 상하 회전은 위쪽 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts a mouse delta (in pixels) to the camera's (yaw, pitch) angles. (in radians) </br>
+    /// A positive yaw turns right, and a positive pitch looks up. </br>
+    /// 
+    #[inline]
+    pub fn to_angles(self, delta: glam::Vec2) -> glam::Vec2 {
+        let pitch = if self.invert_y { delta.y } else { -delta.y };
+        glam::Vec2::new(delta.x, pitch) * self.mouse_sensitivity
+    }
+}
+
+/// #### 한국어 </br>
+/// 마우스로 카메라가 바라보는 방향을 조정하는 컨트롤러 입니다. </br>
+/// 카메라의 위치는 바뀌지 않으며, 좌우 회전은 월드 좌표계의 y축, 상하 회전은 카메라의 x축을 기준으로 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A controller that adjusts the direction the camera looks with the mouse. </br>
+/// The camera position does not change; yaw is around the world y-axis and pitch is around the camera's x-axis. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MouseLook {
+    settings: InputSettings, 
+    pending: glam::Vec2, 
+    smoothed: glam::Vec2, 
+    active: bool, 
+}
+
+#[allow(dead_code)]
+impl MouseLook {
+    #[inline]
+    pub fn new(settings: InputSettings) -> Self {
+        Self { settings, ..Default::default() }
+    }
+
+    #[inline]
+    pub fn settings(&self) -> &InputSettings {
+        &self.settings
+    }
+
+    #[inline]
+    pub fn set_settings(&mut self, settings: InputSettings) {
+        self.settings = settings;
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 보기를 켜거나 끕니다. 끄는 경우 쌓인 움직임을 모두 버립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns mouse-look on or off. When turned off, all accumulated motion is discarded. </br>
+    /// 
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        if !active {
+            self.pending = glam::Vec2::ZERO;
+            self.smoothed = glam::Vec2::ZERO;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 이동량(픽셀)을 다음 갱신까지 누적합니다. 비활성 상태에서는 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Accumulates a mouse delta (in pixels) until the next update. Ignored while inactive. </br>
+    /// 
+    #[inline]
+    pub fn accumulate(&mut self, delta: glam::Vec2) {
+        if self.active {
+            self.pending += delta;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 한 프레임 동안 누적된 마우스 이동량을 평활화하여 카메라를 회전시킵니다. 프레임마다 한 번 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Smooths the mouse delta accumulated over one frame and rotates the camera. Must be called once per frame. </br>
+    /// 
+    pub fn update(&mut self, camera: &mut dyn GameCamera) {
+        self.smoothed = self.settings.smooth(self.smoothed, self.pending);
+        self.pending = glam::Vec2::ZERO;
+        if self.smoothed.length_squared() <= f32::EPSILON {
+            return;
+        }
+
+        // (한국어) 오른손 좌표계의 카메라는 `-z`를 바라보므로 양수 y축 회전이 왼쪽 회전이 됩니다.
+        // (English Translation) A right-handed camera looks down `-z`, so a positive rotation about the y-axis turns left.
+        let angles = self.settings.to_angles(self.smoothed);
+        let yaw = match camera.handedness() {
+            Handedness::RightHanded => -angles.x, 
+            Handedness::LeftHanded => angles.x, 
+        };
+        let pitch = match camera.handedness() {
+            Handedness::RightHanded => angles.y, 
+            Handedness::LeftHanded => -angles.y, 
+        };
+
+        let rotation = glam::Quat::from_rotation_y(yaw) * camera.get_rotation() * glam::Quat::from_rotation_x(pitch);
+        camera.set_rotation(rotation);
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt;
 use glam::Vec4Swizzles;
+use crate::camera::Frustum;
 
 
 
@@ -81,22 +82,166 @@ pub trait GameObject : fmt::Debug {
         self.mut_world_transform().z_axis = mat.z_axis;
     }
 
+    /// #### 한국어 </br>
+    /// 오브젝트의 회전을 오일러 각(라디안)으로 가져옵니다. (x: 상하 회전(pitch), y: 좌우 회전(yaw), z: 기울기(roll)) </br>
+    /// 회전 순서는 `glam::EulerRot::YXZ` 이며, `from_rotation_y(y) * from_rotation_x(x) * from_rotation_z(z)`와 같습니다. </br>
+    /// 
+    /// 상하 회전이 ±90°에 가까우면 좌우 회전과 기울기가 같은 축을 돌게 되어(짐벌 락) 두 값을 구분할 수 없으므로, </br>
+    /// 반환되는 각도는 설정한 값과 다를 수 있지만 같은 회전을 나타냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the rotation of an object as Euler angles in radians. (x: pitch, y: yaw, z: roll) </br>
+    /// The rotation order is `glam::EulerRot::YXZ`, which equals `from_rotation_y(y) * from_rotation_x(x) * from_rotation_z(z)`. </br>
+    /// 
+    /// When the pitch is close to ±90°, yaw and roll turn about the same axis (gimbal lock) and cannot be told apart, </br>
+    /// so the returned angles may differ from the ones that were set, but they represent the same rotation. </br>
+    /// 
+    fn get_euler(&self) -> glam::Vec3 {
+        // (한국어) 크기가 회전에 섞이지 않도록 정규화된 축으로 회전을 만듭니다.
+        // (English Translation) Builds the rotation from normalized axes so that the scale does not leak into it.
+        let transform = self.ref_world_transform();
+        let rotation = glam::Quat::from_mat3(&glam::Mat3::from_cols(
+            transform.x_axis.xyz().normalize_or_zero(), 
+            transform.y_axis.xyz().normalize_or_zero(), 
+            transform.z_axis.xyz().normalize_or_zero()
+        ));
+        let (yaw, pitch, roll) = rotation.normalize().to_euler(glam::EulerRot::YXZ);
+        glam::Vec3::new(pitch, yaw, roll)
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 회전을 오일러 각(라디안)으로 설정합니다. (x: 상하 회전(pitch), y: 좌우 회전(yaw), z: 기울기(roll)) </br>
+    /// 회전 순서는 `get_euler`와 같으며, 오브젝트의 크기는 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the rotation of an object with Euler angles in radians. (x: pitch, y: yaw, z: roll) </br>
+    /// The rotation order is the same as `get_euler`, and the object's scale is preserved. </br>
+    /// 
+    fn set_euler(&mut self, angles: glam::Vec3) {
+        let scale = self.get_scale();
+        self.set_rotation(glam::Quat::from_euler(glam::EulerRot::YXZ, angles.y, angles.x, angles.z));
+        self.set_scale(scale);
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 크기를 가져옵니다. 각 값은 변환 행렬의 x, y, z축의 길이 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the scale of an object. Each value is the length of the x, y, and z axes of the transform matrix. </br>
+    /// 
+    #[inline]
+    fn get_scale(&self) -> glam::Vec3 {
+        let transform = self.ref_world_transform();
+        glam::Vec3::new(
+            transform.x_axis.xyz().length(), 
+            transform.y_axis.xyz().length(), 
+            transform.z_axis.xyz().length()
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 크기를 설정합니다. 각 축을 정규화한 후 새 크기를 곱하며, 회전과 위치는 유지됩니다. </br>
+    /// 길이가 0인 축은 방향을 알 수 없으므로, 다른 두 축의 외적으로 복원하거나 복원할 수 없는 경우 단위 축을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the scale of an object. Each axis is normalized and then multiplied by the new scale, and the rotation and position are kept. </br>
+    /// The direction of a zero-length axis is unknown, so it is restored from the cross product of the other two axes, or the unit axis is used if it cannot be restored. </br>
+    /// 
+    fn set_scale(&mut self, scale: glam::Vec3) {
+        let transform = self.ref_world_transform();
+        let x_axis = transform.x_axis.xyz().normalize_or_zero();
+        let y_axis = transform.y_axis.xyz().normalize_or_zero();
+        let z_axis = transform.z_axis.xyz().normalize_or_zero();
+
+        let restore = |axis: glam::Vec3, a: glam::Vec3, b: glam::Vec3, unit: glam::Vec3| -> glam::Vec3 {
+            if axis != glam::Vec3::ZERO {
+                return axis;
+            }
+            match a.cross(b).try_normalize() {
+                Some(axis) => axis, 
+                None => unit, 
+            }
+        };
+        let x_axis = restore(x_axis, y_axis, z_axis, glam::Vec3::X);
+        let y_axis = restore(y_axis, z_axis, x_axis, glam::Vec3::Y);
+        let z_axis = restore(z_axis, x_axis, y_axis, glam::Vec3::Z);
+
+        self.mut_world_transform().x_axis = (x_axis * scale.x, 0.0).into();
+        self.mut_world_transform().y_axis = (y_axis * scale.y, 0.0).into();
+        self.mut_world_transform().z_axis = (z_axis * scale.z, 0.0).into();
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 오른쪽 방향(로컬 좌표계의 x축)을 가져옵니다. 두 좌표계 모두 같습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the right direction (the local x-axis) of an object. It is the same in both coordinate systems. </br>
+    /// 
+    #[inline]
+    fn get_right(&self) -> glam::Vec3 {
+        self.ref_world_transform().x_axis.xyz().normalize_or_zero()
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 위쪽 방향(로컬 좌표계의 y축)을 가져옵니다. 두 좌표계 모두 같습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the up direction (the local y-axis) of an object. It is the same in both coordinate systems. </br>
+    /// 
+    #[inline]
+    fn get_up(&self) -> glam::Vec3 {
+        self.ref_world_transform().y_axis.xyz().normalize_or_zero()
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 바라보는 앞쪽 방향을 가져옵니다. </br>
+    /// 로컬 좌표계의 z축은 `look_at_point`가 설정하는 바라보는 축(`Handedness::look_axis`) 이므로, </br>
+    /// 오른손 좌표계에서는 `-z`축, 왼손 좌표계에서는 `+z`축이 앞쪽 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the forward direction an object faces. </br>
+    /// The local z-axis is the look axis set by `look_at_point` (`Handedness::look_axis`), </br>
+    /// so forward is the `-z` axis in a right-handed coordinate system, and the `+z` axis in a left-handed one. </br>
+    /// 
+    #[inline]
+    fn get_forward(&self) -> glam::Vec3 {
+        let z_axis = self.ref_world_transform().z_axis.xyz().normalize_or_zero();
+        match self.handedness() {
+            Handedness::RightHanded => -z_axis, 
+            Handedness::LeftHanded => z_axis, 
+        }
+    }
+
     /// #### 한국어 </br>
     /// 점을 바라보도록 오브젝트의 회전을 설정합니다. </br>
+    /// 각 축의 길이를 오브젝트의 크기로 보고, 방향을 바꾼 후 다시 적용하므로 오브젝트의 크기는 유지됩니다. </br>
     /// 
     /// #### English (Translation) </br>
     /// Sets the object's rotation to look at a point. </br>
+    /// The length of each axis is taken as the object's scale and re-applied after orienting, so the object's size is preserved. </br>
     /// 
     fn look_at_point(&mut self, point: glam::Vec3) {
+        let scale = self.get_scale();
         let position = self.ref_world_transform().w_axis.xyz();
         let up = self.ref_world_transform().y_axis.xyz();
-        let look = (position - point).normalize_or_zero();
+        let look = self.handedness().look_axis(position, point);
         let right = up.cross(look).normalize_or_zero();
         let up = look.cross(right).normalize_or_zero();
 
-        self.mut_world_transform().x_axis = (right, 0.0).into();
-        self.mut_world_transform().y_axis = (up, 0.0).into();
-        self.mut_world_transform().z_axis = (look, 0.0).into();
+        self.mut_world_transform().x_axis = (right * scale.x, 0.0).into();
+        self.mut_world_transform().y_axis = (up * scale.y, 0.0).into();
+        self.mut_world_transform().z_axis = (look * scale.z, 0.0).into();
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 사용하는 좌표계의 방향 입니다. (기본값: 오른손 좌표계) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The handedness of the coordinate system used by the object. (default: right-handed) </br>
+    /// 
+    #[inline]
+    fn handedness(&self) -> Handedness {
+        Handedness::RightHanded
     }
 
     /// #### 한국어 </br>
@@ -111,6 +256,21 @@ pub trait GameObject : fmt::Debug {
         *self.mut_world_transform() = mat.mul_mat4(self.ref_world_transform());
     }
 
+    /// #### 한국어 </br>
+    /// 월드 좌표계의 점(`pivot`)을 중심으로 오브젝트를 회전시킵니다. </br>
+    /// 오브젝트의 방향과 `pivot`에서 오브젝트까지의 오프셋이 함께 회전하므로, 오브젝트는 `pivot`을 중심으로 공전합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Rotates the object around a point (`pivot`) in world coordinates. </br>
+    /// Both the object's orientation and the offset from `pivot` to the object are rotated, so the object orbits around `pivot`. </br>
+    /// 
+    fn rotate_around_point(&mut self, pivot: glam::Vec3, rotation: glam::Quat) {
+        let rotation = rotation.normalize();
+        let offset = self.get_position() - pivot;
+        self.rotate(rotation);
+        self.set_position(pivot + rotation * offset);
+    }
+
     /// #### 한국어 </br>
     /// 오브젝트의 월드 변환 행렬을 빌려옵니다. (reference ver) </br>
     /// 
@@ -137,8 +297,8 @@ pub trait GameCameraObject : GameObject {
     /// 
     #[inline]
     fn get_camera_transform(&self) -> glam::Mat4 {
-        let right = self.ref_world_transform().x_axis.xyz().normalize_or_zero();
-        let up = self.ref_world_transform().y_axis.xyz().normalize_or_zero();
+        let right = self.get_right();
+        let up = self.get_up();
         let look = self.ref_world_transform().z_axis.xyz().normalize_or_zero();
         let position = self.ref_world_transform().w_axis.xyz();
         return glam::mat4(
@@ -156,6 +316,370 @@ pub trait GameCameraObject : GameObject {
     /// Gets the projection transformation matrix of a camera object. </br>
     /// 
     fn get_projection_transform(&self) -> glam::Mat4;
+
+    /// #### 한국어 </br>
+    /// 카메라 오브젝트의 뷰-투영 변환 행렬을 가져옵니다. (`투영 * 카메라`) </br>
+    /// 월드 좌표계의 점을 클립 공간으로 변환하며, 컬링이나 피킹에 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the view-projection transformation matrix of a camera object. (`projection * camera`) </br>
+    /// Transforms points in world coordinates into clip space, and is used for culling or picking. </br>
+    /// 
+    #[inline]
+    fn get_view_projection(&self) -> glam::Mat4 {
+        self.get_projection_transform() * self.get_camera_transform()
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 뷰-투영 행렬에서 추출한 절두체를 가져옵니다. 오브젝트를 그리기 전에 컬링하는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the frustum extracted from the camera's view-projection matrix. Used to cull objects before drawing. </br>
+    /// 
+    #[inline]
+    fn frustum(&self) -> Frustum {
+        Frustum::from_view_projection(&self.get_view_projection())
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 투영 행렬이 역방향 깊이(reverse-Z)를 사용하는지 확인합니다. </br>
+    /// 파이프라인의 깊이 비교 함수와 깊이 버퍼를 지우는 값은 이 값과 일치해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the camera's projection matrix uses reversed depth (reverse-Z). </br>
+    /// The pipeline's depth comparison function and depth clear value must match this value. </br>
+    /// 
+    #[inline]
+    fn reverse_z(&self) -> bool {
+        false
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라가 그려지는 화면의 크기(픽셀)가 바뀌었을 때 호출됩니다. 기본 구현은 아무것도 하지 않습니다. </br>
+    /// 원근 투영 카메라는 화면 비율을 갱신하여 이미지가 늘어나지 않도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when the size (in pixels) of the screen the camera is drawn to changes. The default implementation does nothing. </br>
+    /// Perspective cameras update their aspect ratio so that the image does not stretch. </br>
+    /// 
+    #[inline]
+    fn on_resize(&mut self, _width: u32, _height: u32) {
+        /*--- empty ---*/
+    }
+
+    /// #### 한국어 </br>
+    /// 마우스 휠을 굴렸을 때 호출됩니다. `steps`는 굴린 줄 수이며, 양수는 확대(휠을 위로) 입니다. </br>
+    /// 기본 구현은 아무것도 하지 않습니다. 원근 투영 카메라는 수직 시야각을 바꾸어 확대/축소 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when the mouse wheel is scrolled. `steps` is the number of lines scrolled, and positive means zoom in (wheel up). </br>
+    /// The default implementation does nothing. Perspective cameras zoom by changing the vertical field of view. </br>
+    /// 
+    #[inline]
+    fn on_zoom(&mut self, _steps: f32) {
+        /*--- empty ---*/
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 좌표계의 경계 구(중심, 반지름)가 화면에서 차지하는 픽셀 사각형을 계산합니다. </br>
+    /// 반환되는 사각형은 뷰포트(`viewport`, 픽셀 단위 크기) 안으로 잘리며, 원점은 화면의 왼쪽 위 입니다. </br>
+    /// 
+    /// 경계 구가 완전히 카메라 뒤에 있거나 뷰포트와 겹치지 않는 경우 `None`을 반환합니다. </br>
+    /// 경계 구의 일부가 카메라 뒤에 있는 경우 투영 결과를 신뢰할 수 없으므로 뷰포트 전체를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the pixel rectangle on screen occupied by a bounding sphere (center, radius) in world coordinates. </br>
+    /// The returned rectangle is clamped to the viewport (`viewport`, size in pixels), and the origin is the top-left of the screen. </br>
+    /// 
+    /// Returns `None` if the bounding sphere is entirely behind the camera or does not overlap the viewport. </br>
+    /// If part of the bounding sphere is behind the camera, the projection is unreliable, so the whole viewport is returned. </br>
+    /// 
+    #[cfg(feature = "labels")]
+    fn project_bounds(&self, center: glam::Vec3, radius: f32, viewport: glam::Vec2) -> Option<Rect> {
+        let view = self.get_camera_transform();
+        let projection = self.get_projection_transform();
+        let center = view.transform_point3(center);
+        let radius = radius.abs();
+
+        // (한국어) 뷰 공간에서 경계 구를 감싸는 상자의 꼭짓점들을 클립 공간으로 변환합니다.
+        // (English Translation) Transforms the corners of the box enclosing the bounding sphere in view space into clip space.
+        let mut corners = [glam::Vec4::ZERO; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let offset = glam::Vec3::new(
+                if i & 1 == 0 { -radius } else { radius }, 
+                if i & 2 == 0 { -radius } else { radius }, 
+                if i & 4 == 0 { -radius } else { radius }, 
+            );
+            *corner = projection * (center + offset).extend(1.0);
+        }
+
+        let behind = corners.iter().filter(|corner| corner.w <= f32::EPSILON).count();
+        if behind == corners.len() {
+            return None;
+        } else if behind > 0 {
+            return Some(Rect::new(glam::Vec2::ZERO, viewport));
+        }
+
+        let mut ndc_min = glam::Vec2::splat(f32::MAX);
+        let mut ndc_max = glam::Vec2::splat(f32::MIN);
+        for corner in corners.iter() {
+            let ndc = corner.xy() / corner.w;
+            ndc_min = ndc_min.min(ndc);
+            ndc_max = ndc_max.max(ndc);
+        }
+
+        // (한국어) 정규화된 장치 좌표를 픽셀 좌표로 변환합니다. (y축 반전)
+        // (English Translation) Converts normalized device coordinates to pixel coordinates. (y-axis flipped)
+        let min = glam::Vec2::new(ndc_min.x * 0.5 + 0.5, 0.5 - ndc_max.y * 0.5) * viewport;
+        let max = glam::Vec2::new(ndc_max.x * 0.5 + 0.5, 0.5 - ndc_min.y * 0.5) * viewport;
+        let rect = Rect::new(min.max(glam::Vec2::ZERO), max.min(viewport));
+        return (rect.width() > 0.0 && rect.height() > 0.0).then_some(rect);
+    }
+
+    /// #### 한국어 </br>
+    /// 화면의 일부 영역(`viewport`)에 그려지는 경우, 창의 픽셀 좌표를 지나는 월드 좌표계의 광선을 계산합니다. </br>
+    /// 화면 분할처럼 카메라가 창의 일부에만 그려지는 경우에 사용합니다. </br>
+    /// 픽셀 좌표와 영역 모두 창의 왼쪽 위를 원점으로 하는 픽셀 단위 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the world space ray that passes through a window pixel coordinate when the camera is drawn into a sub-rectangle (`viewport`) of the window. </br>
+    /// Used when the camera is drawn into only part of the window, such as split-screen. </br>
+    /// Both the pixel coordinate and the rectangle are in pixels with the origin at the top-left of the window. </br>
+    /// 
+    fn viewport_ray(&self, pixel: glam::Vec2, viewport: Rect) -> Ray {
+        // (한국어) 영역 안의 픽셀 좌표를 정규화된 장치 좌표로 변환합니다. (y축 반전)
+        // (English Translation) Converts the pixel coordinates within the rectangle to normalized device coordinates. (y-axis flipped)
+        let local = pixel - viewport.min;
+        let ndc = glam::Vec2::new(
+            local.x / viewport.width().max(1.0) * 2.0 - 1.0, 
+            1.0 - local.y / viewport.height().max(1.0) * 2.0
+        );
+
+        let (origin, direction) = self.screen_point_to_ray(ndc);
+        Ray::new(origin, direction)
+    }
+
+    /// #### 한국어 </br>
+    /// 정규화된 장치 좌표(`[-1, 1]`, y축 위쪽)를 지나는 월드 좌표계의 광선을 (원점, 정규화된 방향)으로 계산합니다. </br>
+    /// 뷰-투영 행렬의 역행렬로 가까운 평면과 먼 평면 위의 점을 역투영하며, 방향은 항상 카메라가 바라보는 쪽을 향합니다. </br>
+    /// 원근 투영 카메라의 원점은 카메라의 위치이고, 직교 투영 카메라의 원점은 가까운 평면 위의 점 입니다. </br>
+    /// 
+    /// 역행렬을 구할 수 없는 경우 (카메라 위치, 카메라가 바라보는 방향)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the world space ray through a normalized device coordinate (`[-1, 1]`, y-axis up) as (origin, normalized direction). </br>
+    /// Points on the near and far planes are unprojected with the inverse of the view-projection matrix, and the direction always faces where the camera looks. </br>
+    /// The origin is the camera position for perspective cameras, and a point on the near plane for orthographic cameras. </br>
+    /// 
+    /// If the matrix is not invertible, returns (camera position, camera forward direction). </br>
+    /// 
+    fn screen_point_to_ray(&self, ndc: glam::Vec2) -> (glam::Vec3, glam::Vec3) {
+        let position = self.get_position();
+        let forward = self.get_forward();
+
+        // (한국어) 역행렬을 구할 수 없는 행렬에서 `inverse`는 (`debug-glam-assert`에서) 패닉하므로 행렬식을 먼저 확인합니다.
+        // (English Translation) `inverse` panics on a non-invertible matrix (with `debug-glam-assert`), so the determinant is checked first.
+        let view_projection = self.get_view_projection();
+        if view_projection.determinant() == 0.0 {
+            return (position, forward);
+        }
+        let inverse = view_projection.inverse();
+        if !inverse.is_finite() {
+            return (position, forward);
+        }
+
+        // (한국어) 
+        // 무한 원근 투영에서는 평면 하나가 무한히 멀리 있으므로 깊이 범위의 가운데 점을 대신 사용합니다.
+        // 역 깊이(reverse-Z)에서는 가까운 평면과 먼 평면이 바뀌므로 방향을 카메라가 바라보는 쪽으로 맞춥니다.
+        // 
+        // (English Translation) 
+        // With an infinite perspective projection one plane is infinitely far away, so the middle point of the depth range is used instead.
+        // With reverse-Z the near and far planes are swapped, so the direction is aligned with where the camera looks.
+        // 
+        let middle = inverse.project_point3(ndc.extend(0.5));
+        let unproject = |depth: f32| Some(inverse.project_point3(ndc.extend(depth)))
+            .filter(|point| point.is_finite())
+            .unwrap_or(middle);
+        let (near, far) = (unproject(0.0), unproject(1.0));
+        let (near, far) = match (far - near).dot(forward) < 0.0 {
+            true => (far, near), 
+            false => (near, far), 
+        };
+
+        let direction = (far - near).normalize_or_zero();
+        if direction == glam::Vec3::ZERO {
+            return (position, forward);
+        }
+
+        // (한국어) 원근 투영 행렬의 마지막 열은 (0, 0, -1, 0) 또는 (0, 0, 1, 0) 이며, 모든 광선이 카메라 위치를 지납니다.
+        // (English Translation) The last column of a perspective projection matrix is (0, 0, -1, 0) or (0, 0, 1, 0), and every ray passes through the camera position.
+        let origin = match self.get_projection_transform().w_axis.w == 0.0 {
+            true => position, 
+            false => near, 
+        };
+        (origin, direction)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 장면에서 교체하여 사용할 수 있는 카메라의 인터페이스 입니다. </br>
+/// 모든 카메라는 같은 카메라 유니폼 레이아웃과 바인드 그룹 레이아웃을 사용해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an interface for cameras that can be swapped in a scene. </br>
+/// All cameras must use the same camera uniform layout and bind group layout. </br>
+/// 
+pub trait GameCamera : GameCameraObject + ShaderResource { }
+
+impl<T: GameCameraObject + ShaderResource> GameCamera for T { }
+/// #### 한국어 </br>
+/// 좌표계의 방향 입니다. 두 좌표계 모두 x축은 오른쪽, y축은 위쪽을 향하며, z축의 방향만 다릅니다. </br>
+/// 
+/// - 오른손 좌표계: 카메라는 뷰 공간의 `-z` 방향을 바라보고, 오브젝트의 z축은 바라보는 점의 반대 방향을 향합니다. (`perspective_rh`) </br>
+/// - 왼손 좌표계: 카메라는 뷰 공간의 `+z` 방향을 바라보고, 오브젝트의 z축은 바라보는 점을 향합니다. (`perspective_lh`) </br>
+/// 
+/// 정규화된 장치 좌표계(NDC)와 깊이 범위는 같으므로 쉐이더와 파이프라인은 바뀌지 않습니다. </br>
+/// 단, 왼손 좌표계 도구에서 가져온 메시는 보통 시계 방향 감김 순서를 앞면으로 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The handedness of a coordinate system. In both the x-axis points right and the y-axis points up; only the direction of the z-axis differs. </br>
+/// 
+/// - Right-handed: the camera looks down `-z` in view space, and an object's z-axis points away from the point it looks at. (`perspective_rh`) </br>
+/// - Left-handed: the camera looks down `+z` in view space, and an object's z-axis points toward the point it looks at. (`perspective_lh`) </br>
+/// 
+/// The normalized device coordinates (NDC) and depth range are the same, so shaders and pipelines do not change. </br>
+/// However, meshes imported from left-handed tools usually use clockwise winding for front faces. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Handedness {
+    #[default]
+    RightHanded, 
+    LeftHanded, 
+}
+
+impl Handedness {
+    /// #### 한국어 </br>
+    /// `position`에서 `point`를 바라볼 때 오브젝트의 z축 방향을 계산합니다. (정규화 됨) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the direction of the object's z-axis when looking at `point` from `position`. (normalized) </br>
+    /// 
+    #[inline]
+    pub fn look_axis(self, position: glam::Vec3, point: glam::Vec3) -> glam::Vec3 {
+        match self {
+            Self::RightHanded => (position - point).normalize_or_zero(), 
+            Self::LeftHanded => (point - position).normalize_or_zero(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 좌표계에 맞는 원근 투영 행렬을 생성합니다. (깊이 범위: `[0, 1]`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the perspective projection matrix for the coordinate system. (depth range: `[0, 1]`) </br>
+    /// 
+    #[inline]
+    pub fn perspective(self, fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> glam::Mat4 {
+        match self {
+            Self::RightHanded => glam::Mat4::perspective_rh(fov_y_radians, aspect_ratio, z_near, z_far), 
+            Self::LeftHanded => glam::Mat4::perspective_lh(fov_y_radians, aspect_ratio, z_near, z_far), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 좌표계에 맞는 직교 투영 행렬을 생성합니다. (깊이 범위: `[0, 1]`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the orthographic projection matrix for the coordinate system. (depth range: `[0, 1]`) </br>
+    /// 
+    #[inline]
+    pub fn orthographic(self, left: f32, right: f32, bottom: f32, top: f32, z_near: f32, z_far: f32) -> glam::Mat4 {
+        match self {
+            Self::RightHanded => glam::Mat4::orthographic_rh(left, right, bottom, top, z_near, z_far), 
+            Self::LeftHanded => glam::Mat4::orthographic_lh(left, right, bottom, top, z_near, z_far), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 뷰 공간의 점이 카메라 앞쪽으로 떨어진 거리를 가져옵니다. 카메라 뒤에 있는 경우 음수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets how far a view space point is in front of the camera. Negative if it is behind the camera. </br>
+    /// 
+    #[inline]
+    pub fn view_depth(self, view_position: glam::Vec3) -> f32 {
+        match self {
+            Self::RightHanded => -view_position.z, 
+            Self::LeftHanded => view_position.z, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 화면 공간의 축 정렬 사각형 입니다. (픽셀 단위) </br>
+/// 
+/// #### English (Translation) </br>
+/// An axis-aligned rectangle in screen space. (in pixels) </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub min: glam::Vec2, 
+    pub max: glam::Vec2, 
+}
+
+#[allow(dead_code)]
+impl Rect {
+    #[inline]
+    pub fn new(min: glam::Vec2, max: glam::Vec2) -> Self {
+        Self { min, max }
+    }
+
+    #[inline]
+    pub fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    #[inline]
+    pub fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    #[inline]
+    pub fn center(&self) -> glam::Vec2 {
+        (self.min + self.max) * 0.5
+    }
+}
+
+/// #### 한국어 </br>
+/// 월드 좌표계의 광선 입니다. 방향은 정규화 되어 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A ray in world space. The direction is normalized. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: glam::Vec3, 
+    pub direction: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl Ray {
+    #[inline]
+    pub fn new(origin: glam::Vec3, direction: glam::Vec3) -> Self {
+        Self { origin, direction: direction.normalize_or_zero() }
+    }
+
+    /// #### 한국어 </br>
+    /// 광선 위의 점 `origin + direction * t`를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the point `origin + direction * t` on the ray. </br>
+    /// 
+    #[inline]
+    pub fn at(&self, t: f32) -> glam::Vec3 {
+        self.origin + self.direction * t
+    }
 }
 
 
@@ -184,3 +708,247 @@ pub trait ShaderResource : fmt::Debug {
     /// 
     fn ref_bind_group(&self) -> &wgpu::BindGroup;
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestObject {
+        transform: glam::Mat4, 
+    }
+
+    impl TestObject {
+        fn new(transform: glam::Mat4) -> Self {
+            Self { transform }
+        }
+    }
+
+    impl GameObject for TestObject {
+        fn ref_world_transform(&self) -> &glam::Mat4 {
+            &self.transform
+        }
+
+        fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+            &mut self.transform
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestCamera {
+        transform: glam::Mat4, 
+        projection: glam::Mat4, 
+        handedness: Handedness, 
+    }
+
+    impl TestCamera {
+        /// #### 한국어 </br>
+        /// `position`에서 `point`를 바라보는 수직 시야각 90°, 화면 비율 1의 원근 투영 카메라를 생성합니다. </br>
+        /// 
+        /// #### English (Translation) </br>
+        /// Creates a perspective camera at `position` looking at `point` with a 90° vertical field of view and an aspect ratio of 1. </br>
+        /// 
+        fn looking_at(position: glam::Vec3, point: glam::Vec3) -> Self {
+            Self::with_handedness(Handedness::RightHanded, position, point)
+        }
+
+        fn with_handedness(handedness: Handedness, position: glam::Vec3, point: glam::Vec3) -> Self {
+            let mut camera = Self {
+                transform: glam::Mat4::from_translation(position), 
+                projection: handedness.perspective(90.0f32.to_radians(), 1.0, 0.1, 100.0), 
+                handedness, 
+            };
+            camera.look_at_point(point);
+            camera
+        }
+    }
+
+    impl GameObject for TestCamera {
+        fn ref_world_transform(&self) -> &glam::Mat4 {
+            &self.transform
+        }
+
+        fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+            &mut self.transform
+        }
+
+        fn handedness(&self) -> Handedness {
+            self.handedness
+        }
+    }
+
+    impl GameCameraObject for TestCamera {
+        fn get_projection_transform(&self) -> glam::Mat4 {
+            self.projection
+        }
+    }
+
+    fn assert_vec3_approx(actual: glam::Vec3, expected: glam::Vec3) {
+        assert!(actual.abs_diff_eq(expected, 1e-4), "{} != {}", actual, expected);
+    }
+
+    fn assert_same_rotation(actual: glam::Quat, expected: glam::Quat) {
+        // (한국어) q와 -q는 같은 회전을 나타냅니다.
+        // (English Translation) q and -q represent the same rotation.
+        assert!(actual.dot(expected).abs() > 1.0 - 1e-4, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn known_points_project_under_each_handedness() {
+        // (한국어) 
+        // 두 카메라 모두 (0, 0, 5)에서 원점을 바라봅니다. 수직 시야각이 90°이므로 거리 5에서 한 단위는 NDC 0.2 입니다.
+        // 왼손 좌표계에서 -z 방향을 바라보면 월드의 +x축은 왼쪽에 있으므로, x값의 부호만 바뀌고 y값과 깊이는 같습니다.
+        // 
+        // (English Translation) 
+        // Both cameras look at the origin from (0, 0, 5). With a 90° vertical field of view, one unit at distance 5 is 0.2 in NDC.
+        // Looking down -z in a left-handed system, the world +x axis is on the left, so only the sign of x flips and y and depth stay the same.
+        // 
+        let position = glam::Vec3::new(0.0, 0.0, 5.0);
+        let right_handed = TestCamera::with_handedness(Handedness::RightHanded, position, glam::Vec3::ZERO);
+        let left_handed = TestCamera::with_handedness(Handedness::LeftHanded, position, glam::Vec3::ZERO);
+
+        let point = glam::Vec3::new(1.0, 1.0, 0.0);
+        let rh = (right_handed.get_projection_transform() * right_handed.get_camera_transform()).project_point3(point);
+        let lh = (left_handed.get_projection_transform() * left_handed.get_camera_transform()).project_point3(point);
+        assert_vec3_approx(rh * glam::Vec3::new(1.0, 1.0, 0.0), glam::Vec3::new(0.2, 0.2, 0.0));
+        assert_vec3_approx(lh * glam::Vec3::new(1.0, 1.0, 0.0), glam::Vec3::new(-0.2, 0.2, 0.0));
+        assert!((rh.z - lh.z).abs() < 1e-5 && (0.0..=1.0).contains(&rh.z));
+
+        // (한국어) 두 좌표계 모두 뷰 공간의 앞쪽 거리는 5 입니다.
+        // (English Translation) In both systems the forward distance in view space is 5.
+        for camera in [&right_handed, &left_handed] {
+            let view_position = camera.get_camera_transform().transform_point3(glam::Vec3::ZERO);
+            assert!((camera.handedness().view_depth(view_position) - 5.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn view_projection_maps_focus_to_clip_center() {
+        let camera = TestCamera::looking_at(glam::Vec3::new(3.0, 2.0, 5.0), glam::Vec3::new(1.0, 0.0, -1.0));
+        let clip = camera.get_view_projection() * glam::Vec4::new(1.0, 0.0, -1.0, 1.0);
+        assert!(clip.w > 0.0);
+        assert_vec3_approx((clip.xyz() / clip.w) * glam::Vec3::new(1.0, 1.0, 0.0), glam::Vec3::ZERO);
+        assert!((0.0..=1.0).contains(&(clip.z / clip.w)));
+    }
+
+    #[test]
+    fn screen_center_ray_follows_look_axis() {
+        let position = glam::Vec3::new(3.0, 2.0, 5.0);
+        let camera = TestCamera::looking_at(position, glam::Vec3::ZERO);
+        let (origin, direction) = camera.screen_point_to_ray(glam::Vec2::ZERO);
+        assert_vec3_approx(origin, position);
+        assert_vec3_approx(direction, -position.normalize());
+    }
+
+    #[test]
+    fn screen_ray_falls_back_when_not_invertible() {
+        let mut camera = TestCamera::looking_at(glam::Vec3::new(0.0, 0.0, 5.0), glam::Vec3::ZERO);
+        camera.projection = glam::Mat4::ZERO;
+        let (origin, direction) = camera.screen_point_to_ray(glam::Vec2::new(0.5, -0.5));
+        assert_vec3_approx(origin, glam::Vec3::new(0.0, 0.0, 5.0));
+        assert_vec3_approx(direction, -glam::Vec3::Z);
+    }
+
+    #[test]
+    fn basis_vectors_are_orthonormal() {
+        let rotation = glam::Quat::from_euler(glam::EulerRot::YXZ, 0.8, -0.4, 0.3);
+        let object = TestObject::new(glam::Mat4::from_scale_rotation_translation(glam::Vec3::new(2.0, 3.0, 4.0), rotation, glam::Vec3::ONE));
+        let (right, up, forward) = (object.get_right(), object.get_up(), object.get_forward());
+        for axis in [right, up, forward] {
+            assert!((axis.length() - 1.0).abs() < 1e-5);
+        }
+        assert!(right.dot(up).abs() < 1e-5 && up.dot(forward).abs() < 1e-5 && forward.dot(right).abs() < 1e-5);
+
+        // (한국어) 오른손 좌표계에서 앞쪽은 `-z`축이므로 `right × up = -forward` 입니다.
+        // (English Translation) Forward is the `-z` axis in a right-handed system, so `right × up = -forward`.
+        assert_vec3_approx(right.cross(up), -forward);
+        assert_vec3_approx(forward, rotation * -glam::Vec3::Z);
+    }
+
+    #[test]
+    fn look_at_point_preserves_scale() {
+        let scale = glam::Vec3::new(2.0, 3.0, 4.0);
+        let mut object = TestObject::new(glam::Mat4::from_scale_rotation_translation(scale, glam::Quat::IDENTITY, glam::Vec3::ZERO));
+        object.look_at_point(glam::Vec3::new(5.0, 1.0, -3.0));
+        let (actual, _, _) = object.ref_world_transform().to_scale_rotation_translation();
+        assert_vec3_approx(actual, scale);
+        assert_vec3_approx(object.get_forward(), glam::Vec3::new(5.0, 1.0, -3.0).normalize());
+    }
+
+    #[test]
+    fn set_scale_round_trips() {
+        let rotation = glam::Quat::from_rotation_y(0.7) * glam::Quat::from_rotation_x(-0.3);
+        let mut object = TestObject::new(glam::Mat4::from_rotation_translation(rotation, glam::Vec3::new(1.0, 2.0, 3.0)));
+        object.set_scale(glam::Vec3::new(0.5, 2.0, 7.0));
+        assert_vec3_approx(object.get_scale(), glam::Vec3::new(0.5, 2.0, 7.0));
+        assert_same_rotation(object.get_rotation(), rotation);
+        assert_vec3_approx(object.get_position(), glam::Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn set_scale_restores_zero_length_axis() {
+        let mut object = TestObject::new(glam::Mat4::from_scale(glam::Vec3::new(1.0, 0.0, 1.0)));
+        object.set_scale(glam::Vec3::ONE);
+        assert_vec3_approx(object.get_scale(), glam::Vec3::ONE);
+        assert_vec3_approx(object.get_up(), glam::Vec3::Y);
+
+        let mut object = TestObject::new(glam::Mat4::from_cols(glam::Vec4::ZERO, glam::Vec4::ZERO, glam::Vec4::ZERO, glam::Vec4::W));
+        object.set_scale(glam::Vec3::splat(2.0));
+        assert_vec3_approx(object.get_scale(), glam::Vec3::splat(2.0));
+        assert_vec3_approx(object.get_right(), glam::Vec3::X);
+    }
+
+    #[test]
+    fn rotate_around_point_orbits_pivot() {
+        let mut object = TestObject::new(glam::Mat4::from_translation(glam::Vec3::X));
+        object.rotate_around_point(glam::Vec3::ZERO, glam::Quat::from_rotation_y(std::f32::consts::PI));
+        assert_vec3_approx(object.get_position(), -glam::Vec3::X);
+        assert_vec3_approx(object.get_right(), -glam::Vec3::X);
+
+        let mut object = TestObject::new(glam::Mat4::from_translation(glam::Vec3::new(2.0, 1.0, 0.0)));
+        object.rotate_around_point(glam::Vec3::new(1.0, 1.0, 0.0), glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        assert_vec3_approx(object.get_position(), glam::Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn euler_near_gimbal_lock_keeps_rotation() {
+        let angles = glam::Vec3::new(std::f32::consts::FRAC_PI_2 - 1e-4, 0.6, 0.4);
+        let expected = glam::Quat::from_euler(glam::EulerRot::YXZ, angles.y, angles.x, angles.z);
+
+        let mut object = TestObject::new(glam::Mat4::IDENTITY);
+        object.set_euler(angles);
+        let euler = object.get_euler();
+        let actual = glam::Quat::from_euler(glam::EulerRot::YXZ, euler.y, euler.x, euler.z);
+        assert_same_rotation(actual, expected);
+    }
+
+    #[test]
+    fn euler_ignores_and_preserves_scale() {
+        let angles = glam::Vec3::new(0.4, -0.9, 0.2);
+        let rotation = glam::Quat::from_euler(glam::EulerRot::YXZ, angles.y, angles.x, angles.z);
+        let scale = glam::Vec3::new(2.0, 3.0, 4.0);
+        let mut object = TestObject::new(glam::Mat4::from_scale_rotation_translation(scale, rotation, glam::Vec3::ONE));
+        assert_vec3_approx(object.get_euler(), angles);
+
+        object.set_euler(glam::Vec3::new(-0.2, 1.1, 0.5));
+        assert_vec3_approx(object.get_scale(), scale);
+        assert_vec3_approx(object.get_euler(), glam::Vec3::new(-0.2, 1.1, 0.5));
+        assert_vec3_approx(object.get_position(), glam::Vec3::ONE);
+    }
+
+    #[test]
+    fn euler_round_trips() {
+        let angles = [
+            glam::Vec3::ZERO, 
+            glam::Vec3::new(0.3, -1.2, 0.7), 
+            glam::Vec3::new(-0.8, 2.5, -0.1), 
+            glam::Vec3::new(1.0, 0.0, 3.0), 
+        ];
+        for angles in angles {
+            let mut object = TestObject::new(glam::Mat4::IDENTITY);
+            object.set_euler(angles);
+            assert_vec3_approx(object.get_euler(), angles);
+        }
+    }
+}
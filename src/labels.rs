@@ -0,0 +1,443 @@
+use std::mem;
+use crate::interfaces::GameCamera;
+
+
+
+/// #### 한국어 </br>
+/// 5x7 비트맵 글꼴 입니다. 인쇄 가능한 ASCII 문자(`' '` ~ `'~'`)마다 다섯 개의 열을 가지며, </br>
+/// 각 열의 최하위 비트가 가장 위쪽 픽셀입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A 5x7 bitmap font. Each printable ASCII character (`' '` to `'~'`) has five columns, </br>
+/// and the least significant bit of each column is the topmost pixel. </br>
+/// 
+const FONT_5X7: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], [0x00, 0x00, 0x5F, 0x00, 0x00], [0x00, 0x07, 0x00, 0x07, 0x00], [0x14, 0x7F, 0x14, 0x7F, 0x14], 
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12], [0x23, 0x13, 0x08, 0x64, 0x62], [0x36, 0x49, 0x55, 0x22, 0x50], [0x00, 0x05, 0x03, 0x00, 0x00], 
+    [0x00, 0x1C, 0x22, 0x41, 0x00], [0x00, 0x41, 0x22, 0x1C, 0x00], [0x08, 0x2A, 0x1C, 0x2A, 0x08], [0x08, 0x08, 0x3E, 0x08, 0x08], 
+    [0x00, 0x50, 0x30, 0x00, 0x00], [0x08, 0x08, 0x08, 0x08, 0x08], [0x00, 0x60, 0x60, 0x00, 0x00], [0x20, 0x10, 0x08, 0x04, 0x02], 
+    [0x3E, 0x51, 0x49, 0x45, 0x3E], [0x00, 0x42, 0x7F, 0x40, 0x00], [0x42, 0x61, 0x51, 0x49, 0x46], [0x21, 0x41, 0x45, 0x4B, 0x31], 
+    [0x18, 0x14, 0x12, 0x7F, 0x10], [0x27, 0x45, 0x45, 0x45, 0x39], [0x3C, 0x4A, 0x49, 0x49, 0x30], [0x01, 0x71, 0x09, 0x05, 0x03], 
+    [0x36, 0x49, 0x49, 0x49, 0x36], [0x06, 0x49, 0x49, 0x29, 0x1E], [0x00, 0x36, 0x36, 0x00, 0x00], [0x00, 0x56, 0x36, 0x00, 0x00], 
+    [0x08, 0x14, 0x22, 0x41, 0x00], [0x14, 0x14, 0x14, 0x14, 0x14], [0x00, 0x41, 0x22, 0x14, 0x08], [0x02, 0x01, 0x51, 0x09, 0x06], 
+    [0x32, 0x49, 0x79, 0x41, 0x3E], [0x7E, 0x11, 0x11, 0x11, 0x7E], [0x7F, 0x49, 0x49, 0x49, 0x36], [0x3E, 0x41, 0x41, 0x41, 0x22], 
+    [0x7F, 0x41, 0x41, 0x22, 0x1C], [0x7F, 0x49, 0x49, 0x49, 0x41], [0x7F, 0x09, 0x09, 0x01, 0x01], [0x3E, 0x41, 0x41, 0x51, 0x32], 
+    [0x7F, 0x08, 0x08, 0x08, 0x7F], [0x00, 0x41, 0x7F, 0x41, 0x00], [0x20, 0x40, 0x41, 0x3F, 0x01], [0x7F, 0x08, 0x14, 0x22, 0x41], 
+    [0x7F, 0x40, 0x40, 0x40, 0x40], [0x7F, 0x02, 0x04, 0x02, 0x7F], [0x7F, 0x04, 0x08, 0x10, 0x7F], [0x3E, 0x41, 0x41, 0x41, 0x3E], 
+    [0x7F, 0x09, 0x09, 0x09, 0x06], [0x3E, 0x41, 0x51, 0x21, 0x5E], [0x7F, 0x09, 0x19, 0x29, 0x46], [0x46, 0x49, 0x49, 0x49, 0x31], 
+    [0x01, 0x01, 0x7F, 0x01, 0x01], [0x3F, 0x40, 0x40, 0x40, 0x3F], [0x1F, 0x20, 0x40, 0x20, 0x1F], [0x7F, 0x20, 0x18, 0x20, 0x7F], 
+    [0x63, 0x14, 0x08, 0x14, 0x63], [0x03, 0x04, 0x78, 0x04, 0x03], [0x61, 0x51, 0x49, 0x45, 0x43], [0x00, 0x7F, 0x41, 0x41, 0x00], 
+    [0x02, 0x04, 0x08, 0x10, 0x20], [0x00, 0x41, 0x41, 0x7F, 0x00], [0x04, 0x02, 0x01, 0x02, 0x04], [0x40, 0x40, 0x40, 0x40, 0x40], 
+    [0x00, 0x01, 0x02, 0x04, 0x00], [0x20, 0x54, 0x54, 0x54, 0x78], [0x7F, 0x48, 0x44, 0x44, 0x38], [0x38, 0x44, 0x44, 0x44, 0x20], 
+    [0x38, 0x44, 0x44, 0x48, 0x7F], [0x38, 0x54, 0x54, 0x54, 0x18], [0x08, 0x7E, 0x09, 0x01, 0x02], [0x08, 0x14, 0x54, 0x54, 0x3C], 
+    [0x7F, 0x08, 0x04, 0x04, 0x78], [0x00, 0x44, 0x7D, 0x40, 0x00], [0x20, 0x40, 0x44, 0x3D, 0x00], [0x00, 0x7F, 0x10, 0x28, 0x44], 
+    [0x00, 0x41, 0x7F, 0x40, 0x00], [0x7C, 0x04, 0x18, 0x04, 0x78], [0x7C, 0x08, 0x04, 0x04, 0x78], [0x38, 0x44, 0x44, 0x44, 0x38], 
+    [0x7C, 0x14, 0x14, 0x14, 0x08], [0x08, 0x14, 0x14, 0x18, 0x7C], [0x7C, 0x08, 0x04, 0x04, 0x08], [0x48, 0x54, 0x54, 0x54, 0x20], 
+    [0x04, 0x3F, 0x44, 0x40, 0x20], [0x3C, 0x40, 0x40, 0x20, 0x7C], [0x1C, 0x20, 0x40, 0x20, 0x1C], [0x3C, 0x40, 0x30, 0x40, 0x3C], 
+    [0x44, 0x28, 0x10, 0x28, 0x44], [0x0C, 0x50, 0x50, 0x50, 0x3C], [0x44, 0x64, 0x54, 0x4C, 0x44], [0x00, 0x08, 0x36, 0x41, 0x00], 
+    [0x00, 0x00, 0x7F, 0x00, 0x00], [0x00, 0x41, 0x36, 0x08, 0x00], [0x08, 0x04, 0x08, 0x10, 0x08], 
+];
+
+/// #### 한국어 </br>
+/// 글꼴 텍스처에서 한 글자가 차지하는 셀의 크기 입니다. (5x7 글자와 1 텍셀의 간격) </br>
+/// 쉐이더의 `GLYPH_SIZE` 상수와 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the cell one glyph occupies in the font texture. (5x7 glyph with 1 texel of spacing) </br>
+/// Must match the `GLYPH_SIZE` constant in the shader. </br>
+/// 
+const GLYPH_WIDTH: u32 = 6;
+const GLYPH_HEIGHT: u32 = 8;
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 레이블의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the labels passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct LabelUniformLayout {
+    viewport: glam::Vec2, 
+    _padding: glam::Vec2, 
+}
+
+/// #### 한국어 </br>
+/// 인스턴스 정점 버퍼에 저장되는 글자별 데이터 레이아웃 입니다. (왼쪽 위 픽셀 위치, 배율, 글자 번호, 색상) </br>
+/// 
+/// #### English (Translation) </br>
+/// The per-glyph data layout stored in the instance vertex buffer. (top-left pixel position, scale, glyph index, color) </br>
+/// 
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct GlyphInstance {
+    position: glam::Vec2, 
+    scale: f32, 
+    glyph: u32, 
+    color: glam::Vec4, 
+}
+
+impl GlyphInstance {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        0 => Float32x2, 
+        1 => Float32, 
+        2 => Uint32, 
+        3 => Float32x4
+    ];
+}
+
+/// #### 한국어 </br>
+/// 레이블을 붙일 오브젝트의 경계 구(중심, 반지름)와 문자열 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The bounding sphere (center, radius) and string of an object to label. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Label<'a> {
+    pub center: glam::Vec3, 
+    pub radius: f32, 
+    pub text: &'a str, 
+}
+
+/// #### 한국어 </br>
+/// 오브젝트 위에 문자열 레이블을 그리는 오버레이 렌더러 입니다. </br>
+/// 내장된 5x7 비트맵 글꼴을 사용하며, 장면을 모두 그린 후 마지막 패스에서 화면 위에 그려집니다. </br>
+/// 레이블은 오브젝트의 경계 구가 화면에 투영된 사각형의 위쪽 가운데에 놓이며, </br>
+/// 카메라 뒤에 있거나 화면 밖에 있는 오브젝트의 레이블은 그리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An overlay renderer that draws string labels above objects. </br>
+/// It uses a built-in 5x7 bitmap font and is drawn over the screen in the final pass after the whole scene is drawn. </br>
+/// A label is placed at the top center of the rectangle that the object's bounding sphere projects to on screen, </br>
+/// and labels of objects behind the camera or off-screen are not drawn. </br>
+/// 
+#[derive(Debug)]
+pub struct LabelRenderer {
+    pipeline: wgpu::RenderPipeline, 
+    uniform_buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+    instance_buffer: wgpu::Buffer, 
+    instance_capacity: usize, 
+    instance_count: u32, 
+    scale: f32, 
+    color: glam::Vec4, 
+    instances: Vec<GlyphInstance>, 
+}
+
+#[allow(dead_code)]
+impl LabelRenderer {
+    /// #### 한국어 </br>
+    /// 한 번에 그릴 수 있는 글자 수의 기본값 입니다. 글자 수가 넘치면 버퍼를 다시 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default number of glyphs that can be drawn at once. The buffer is recreated if the number of glyphs overflows. </br>
+    /// 
+    const DEFAULT_CAPACITY: usize = 256;
+
+    /// #### 한국어 </br>
+    /// 레이블과 오브젝트의 투영된 사각형 사이의 간격(픽셀) 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The gap (in pixels) between a label and the projected rectangle of the object. </br>
+    /// 
+    const MARGIN: f32 = 4.0;
+
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, color_format: wgpu::TextureFormat) -> Self {
+        let module = device.create_shader_module(
+            wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/labels.wgsl"))
+        );
+
+        // (한국어) 비트맵 글꼴을 한 줄로 늘어선 글꼴 텍스처로 굽습니다.
+        // (English Translation) Bakes the bitmap font into a font texture laid out in a single row.
+        let width = GLYPH_WIDTH * FONT_5X7.len() as u32;
+        let mut texels = vec![0u8; (width * GLYPH_HEIGHT) as usize];
+        for (index, glyph) in FONT_5X7.iter().enumerate() {
+            for (column, bits) in glyph.iter().enumerate() {
+                for row in 0..7 {
+                    if bits & (1 << row) != 0 {
+                        let x = index * GLYPH_WIDTH as usize + column;
+                        texels[row * width as usize + x] = u8::MAX;
+                    }
+                }
+            }
+        }
+
+        let size = wgpu::Extent3d {
+            width, 
+            height: GLYPH_HEIGHT, 
+            depth_or_array_layers: 1, 
+        };
+        let font_texture = device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(Font)"), 
+                size, 
+                format: wgpu::TextureFormat::R8Unorm, 
+                dimension: wgpu::TextureDimension::D2, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        );
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &font_texture, 
+                mip_level: 0, 
+                origin: wgpu::Origin3d::ZERO, 
+                aspect: wgpu::TextureAspect::All, 
+            }, 
+            &texels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(width), 
+                rows_per_image: Some(GLYPH_HEIGHT), 
+            }, 
+            size
+        );
+
+        let uniform_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Labels)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<LabelUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group_layout = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(Labels)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let font_texture_view = font_texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Labels)"), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&font_texture_view), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let pipeline_layout = device.create_pipeline_layout(
+            &wgpu::PipelineLayoutDescriptor {
+                label: Some("PipelineLayout(Labels)"), 
+                bind_group_layouts: &[&bind_group_layout], 
+                push_constant_ranges: &[], 
+            }, 
+        );
+
+        let pipeline = device.create_render_pipeline(
+            &wgpu::RenderPipelineDescriptor {
+                label: Some("RenderPipeline(Labels)"), 
+                layout: Some(&pipeline_layout), 
+                vertex: wgpu::VertexState {
+                    module: &module, 
+                    entry_point: "vs_glyph", 
+                    buffers: &[
+                        wgpu::VertexBufferLayout {
+                            step_mode: wgpu::VertexStepMode::Instance, 
+                            array_stride: mem::size_of::<GlyphInstance>() as wgpu::BufferAddress, 
+                            attributes: &GlyphInstance::ATTRIBUTES, 
+                        }, 
+                    ], 
+                }, 
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                    strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                    polygon_mode: wgpu::PolygonMode::Fill, 
+                    ..Default::default()
+                }, 
+                depth_stencil: None, 
+                multisample: wgpu::MultisampleState::default(), 
+                fragment: Some(wgpu::FragmentState {
+                    module: &module, 
+                    entry_point: "fs_glyph", 
+                    targets: &[
+                        Some(wgpu::ColorTargetState {
+                            blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                            format: color_format, 
+                            write_mask: wgpu::ColorWrites::ALL, 
+                        }), 
+                    ], 
+                }), 
+                multiview: None, 
+            }, 
+        );
+
+        let instance_capacity = Self::DEFAULT_CAPACITY;
+        let instance_buffer = Self::create_instance_buffer(device, instance_capacity);
+
+        Self {
+            pipeline, 
+            uniform_buffer, 
+            bind_group, 
+            instance_buffer, 
+            instance_capacity, 
+            instance_count: 0, 
+            scale: 2.0, 
+            color: glam::Vec4::ONE, 
+            instances: Vec::with_capacity(instance_capacity), 
+        }
+    }
+
+    fn create_instance_buffer(device: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("InstanceBuffer(Labels)"), 
+                mapped_at_creation: false, 
+                size: (mem::size_of::<GlyphInstance>() * capacity) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 글자의 배율을 설정합니다. (기본값: `2.0`, 한 글자는 `6 x 8 x 배율` 픽셀) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the glyph scale. (default: `2.0`, one glyph is `6 x 8 x scale` pixels) </br>
+    /// 
+    #[inline]
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.max(1.0);
+    }
+
+    #[inline]
+    pub fn set_color(&mut self, color: glam::Vec4) {
+        self.color = color;
+    }
+
+    /// #### 한국어 </br>
+    /// 레이블들을 화면에 투영하여 글자 인스턴스를 만들고 버퍼를 갱신합니다. </br>
+    /// 인쇄할 수 없는 문자는 `'?'`로 그려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Projects the labels onto the screen, builds the glyph instances and updates the buffers. </br>
+    /// Non-printable characters are drawn as `'?'`. </br>
+    /// 
+    pub fn prepare<'a, I>(
+        &mut self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        camera: &dyn GameCamera, 
+        viewport: glam::Vec2, 
+        labels: I
+    ) where I: IntoIterator<Item = Label<'a>> {
+        let glyph_size = glam::Vec2::new(GLYPH_WIDTH as f32, GLYPH_HEIGHT as f32) * self.scale;
+        let shadow_color = glam::Vec4::new(0.0, 0.0, 0.0, self.color.w);
+        let view = camera.get_camera_transform();
+
+        self.instances.clear();
+        for label in labels {
+            // (한국어) 중심이 카메라 뒤에 있는 오브젝트는 화면 전체로 투영되므로 제외합니다.
+            // (English Translation) Objects whose center is behind the camera project onto the whole screen, so they are skipped.
+            if camera.handedness().view_depth(view.transform_point3(label.center)) <= 0.0 {
+                continue;
+            }
+
+            let Some(rect) = camera.project_bounds(label.center, label.radius, viewport) else {
+                continue;
+            };
+
+            let width = glyph_size.x * label.text.chars().count() as f32;
+            let origin = glam::Vec2::new(
+                rect.center().x - width * 0.5, 
+                (rect.min.y - glyph_size.y - Self::MARGIN).max(0.0)
+            );
+            for (index, ch) in label.text.chars().enumerate() {
+                let glyph = match ch {
+                    ' '..='~' => ch as u32 - ' ' as u32, 
+                    _ => '?' as u32 - ' ' as u32, 
+                };
+                let position = origin + glam::Vec2::new(glyph_size.x * index as f32, 0.0);
+
+                // (한국어) 배경과 관계없이 읽을 수 있도록 한 픽셀 어긋난 그림자를 먼저 그립니다.
+                // (English Translation) Draws a one pixel offset shadow first so the text is readable regardless of the background.
+                self.instances.push(GlyphInstance { position: position + self.scale, scale: self.scale, glyph, color: shadow_color });
+                self.instances.push(GlyphInstance { position, scale: self.scale, glyph, color: self.color });
+            }
+        }
+
+        if self.instances.len() > self.instance_capacity {
+            self.instance_capacity = self.instances.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(device, self.instance_capacity);
+        }
+
+        let uniform = LabelUniformLayout { viewport, ..Default::default() };
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniform));
+        queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(&self.instances));
+        self.instance_count = self.instances.len() as u32;
+    }
+
+    /// #### 한국어 </br>
+    /// 준비된 레이블들을 렌더 타겟 위에 그리는 오버레이 패스를 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the overlay pass that draws the prepared labels over the render target. </br>
+    /// 
+    pub fn draw(&self, encoder: &mut wgpu::CommandEncoder, render_target_view: &wgpu::TextureView) {
+        if self.instance_count == 0 {
+            return;
+        }
+
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("RenderPass(Labels)"), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: render_target_view, 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ], 
+                depth_stencil_attachment: None, 
+                timestamp_writes: None, 
+                occlusion_query_set: None, 
+            }, 
+        );
+
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+        rpass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+        rpass.draw(0..4, 0..self.instance_count);
+    }
+}
@@ -1,25 +1,46 @@
+// (한국어) 이 저장소는 명시적인 `return`과 참조 전달(`&`)을 코드 스타일로 사용하므로, 해당 clippy 경고들은 크레이트 전체에서 허용합니다.
+// (English Translation) This repository uses explicit `return`s and passing references (`&`) as its code style, so those clippy lints are allowed crate-wide.
+#![allow(clippy::needless_return, clippy::needless_borrow, clippy::collapsible_match, clippy::type_complexity)]
+
+mod animation;
 mod camera;
+mod capture;
+mod events;
+mod frame_pacing;
+mod gpu_timing;
+mod grid;
+mod headless;
+mod input;
 mod interfaces;
+#[cfg(feature = "labels")]
+mod labels;
+mod mesh;
 mod objects;
+mod picking;
 mod pipeline;
+mod renderer;
+mod resources;
+mod scene;
+mod settings;
+mod shadow;
+#[cfg(feature = "hot-reload")]
+mod shader_reload;
+mod skinning;
+mod sky;
 mod timer;
+mod tonemap;
 mod utils;
 
-use std::mem;
 use std::thread;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
-use crossbeam_queue::SegQueue;
 use winit::{
     keyboard::{KeyCode, PhysicalKey},
-    event::{Event, WindowEvent}, 
+    event::{Event, ElementState, WindowEvent, MouseButton, MouseScrollDelta}, 
     window::{Window, WindowBuilder},
     event_loop::{EventLoop, ControlFlow},
 };
-use crate::interfaces::{
-    GameObject, 
-    ShaderResource, 
-};
+use crate::interfaces::ShaderResource;
 
 /// #### 한국어 </br>
 /// 현재 애플리케이션이 실행 중인 경우 `true`값을 가집니다. </br>
@@ -31,215 +52,412 @@ static IS_RUNNING: AtomicBool = AtomicBool::new(true);
 
 /// #### 한국어 </br>
 /// 렌더링 루프로 보내는 창 이벤트 대기열 입니다. </br>
+/// 자주 발생하는 이벤트는 병합되며, 용량을 설정한 경우 가장 오래된 이벤트부터 버립니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is the window event queue that is sent to the rendering loop. </br>
+/// High-frequency events are coalesced, and if a capacity is set, the oldest events are dropped first. </br>
+/// 
+static EVENT_QUEUE: events::EventQueue = events::EventQueue::new();
+
+/// #### 한국어 </br>
+/// 창 이벤트 대기열의 기본 용량 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default capacity of the window event queue. </br>
+/// 
+const EVENT_QUEUE_CAPACITY: usize = 256;
+
+/// #### 한국어 </br>
+/// 창이 최소화되었거나 가려진 동안 렌더링 루프가 한 번 반복할 때마다 쉬는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time the rendering loop sleeps per iteration while the window is minimized or occluded. </br>
+/// 
+const PAUSED_SLEEP_DURATION: std::time::Duration = std::time::Duration::from_millis(16);
+
+/// #### 한국어 </br>
+/// `--headless` 인자로 그리는 화면 밖 렌더 타겟의 크기 입니다. </br>
 /// 
-static EVENT_QUEUE: SegQueue<Event<()>> = SegQueue::new();
+/// #### English (Translation) </br>
+/// The size of the offscreen render target drawn with the `--headless` argument. </br>
+/// 
+const HEADLESS_WIDTH: u32 = 1280;
+const HEADLESS_HEIGHT: u32 = 720;
 
 
 
-fn render_loop(
+/// #### 한국어 </br>
+/// 렌더링 루프가 장면을 그릴 때 사용하는 메쉬, 인스턴스 묶음과 패스별 설정들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The meshes, instance batches and per-pass settings that the rendering loop uses to draw the scene. </br>
+/// 
+struct SceneDrawResources {
+    quad_mesh: mesh::GpuMesh, 
+    opaque_batches: Vec<objects::InstanceBatch>, 
+    transparent_batches: Vec<objects::InstanceBatch>, 
+    mesh_objects: Vec<objects::MeshObject>, 
+    textured_objects: Vec<objects::TexturedObject>, 
+    skinned_objects: Vec<skinning::SkinnedObject>, 
+    grid_settings: grid::GridSettings, 
+    sky_settings: sky::SkySettings, 
+    tonemap_settings: tonemap::ToneMapSettings, 
+    shadow_map: shadow::ShadowMap, 
+}
+
+/// #### 한국어 </br>
+/// 장면 전체를 그리는 그리기 문맥을 생성합니다. </br>
+/// 컬링 결과, GPU 타임스탬프, 정렬된 순서는 주 카메라와 이번 프레임에만 해당하므로 설정하지 않습니다. </br>
+/// 캡처는 이 문맥을 그대로 사용하고, 프레임 그리기는 세 값을 채워서 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the draw context that draws the whole scene. </br>
+/// The culling result, GPU timestamps and sorted order only apply to the main camera and the current frame, so they are not set. </br>
+/// Captures use this context as is, and frame drawing fills in those three values. </br>
+/// 
+fn scene_draw_context<'a>(
+    renderer: &'a renderer::Renderer, 
+    scene: &'a scene::Scene, 
+    settings: &'a settings::RenderSettings, 
+    resources: &'a SceneDrawResources, 
+    hidden_line: bool, 
+    pass_labels: &'a renderer::PassLabels
+) -> renderer::DrawContext<'a> {
+    renderer::DrawContext {
+        pipelines: &renderer.resources.pipelines, 
+        settings, 
+        quad_mesh: &resources.quad_mesh, 
+        opaque_objects: &scene.opaque_objects, 
+        decal_objects: &scene.decal_objects, 
+        transparent_objects: &scene.transparent_objects, 
+        opaque_batches: &resources.opaque_batches, 
+        transparent_batches: &resources.transparent_batches, 
+        mesh_objects: &resources.mesh_objects, 
+        textured_objects: &resources.textured_objects, 
+        skinned_objects: &resources.skinned_objects, 
+        hidden_line, 
+        culling: None, 
+        pass_labels, 
+        timestamps: None, 
+        sorted_transparent: None, 
+        grid: Some(&resources.grid_settings), 
+        sky: Some(&resources.sky_settings), 
+        tonemap: Some(&resources.tonemap_settings), 
+        shadow: Some(&resources.shadow_map), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 명령줄 인자로 정하는 렌더링 루프의 옵션들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The rendering loop options given by the command line arguments. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+struct RenderOptions {
+    frame_limit: Option<u64>, 
+    smoke_dump: Option<std::path::PathBuf>, 
+    stereo: bool, 
+    oit_scale: f32, 
+    depth_peel_layers: Option<u32>, 
+    depth_prepass: bool, 
+    handedness: interfaces::Handedness, 
+}
+
+/// #### 한국어 </br>
+/// 렌더링 루프를 실행합니다. </br>
+/// `update`는 매 프레임 창 이벤트를 처리한 후, 장면을 그리기 전에 한 번 호출됩니다. </br>
+/// 렌더링 루프를 수정하지 않고 오브젝트를 움직이거나, 카메라를 옮기거나, 오브젝트를 추가하고 제거할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Runs the rendering loop. </br>
+/// `update` is called once per frame after the window events are processed and before the scene is drawn. </br>
+/// It can animate objects, move the camera, or add and remove objects without modifying the rendering loop. </br>
+/// 
+fn render_loop<F>(
     window: Arc<Window>, 
-    instance: Arc<wgpu::Instance>, 
-    surface: Arc<wgpu::Surface>, 
-    _adapter: Arc<wgpu::Adapter>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    adapter: Arc<wgpu::Adapter>, 
     device: Arc<wgpu::Device>, 
-    queue: Arc<wgpu::Queue>
-) {
+    queue: Arc<wgpu::Queue>, 
+    options: RenderOptions, 
+    mut update: F
+) where F: FnMut(&mut scene::Scene, &timer::GameTimer) {
+    let RenderOptions { frame_limit, mut smoke_dump, stereo, oit_scale, depth_peel_layers, depth_prepass, handedness } = options;
+
+    // (한국어) 스테레오 렌더링은 장치가 `MULTIVIEW` 기능을 지원하는 경우에만 사용합니다.
+    // (English Translation) Stereo rendering is only used if the device supports the `MULTIVIEW` feature.
+    if stereo && !device.features().contains(wgpu::Features::MULTIVIEW) {
+        log::warn!("Stereo rendering requires the MULTIVIEW feature, which is not supported. Fall back to mono rendering.");
+    }
+    let stereo = stereo && device.features().contains(wgpu::Features::MULTIVIEW);
+    let views = if stereo { pipeline::PipelineSettings::STEREO_VIEW_COUNT } else { 1 };
+    const EYE_SEPARATION: f32 = 0.064;
+    const TURNTABLE_FRAMES: u32 = 120;
     // (한국어) 카메라의 쉐이더 레이아웃을 생성합니다. 
     // (English Translation) Create a shader layout for the camera. 
-    let camera_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(Camera)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
-                    ty: wgpu::BindingType::Buffer { 
-                        ty: wgpu::BufferBindingType::Uniform, 
-                        has_dynamic_offset: false, 
-                        min_binding_size: None 
-                    }, 
-                    count: None,
-                },
-            ],
-        },
-    );
+    let camera_bind_group_layout = utils::create_uniform_bind_group_layout(&device, "BindGroupLayout(Camera)", wgpu::ShaderStages::VERTEX_FRAGMENT);
 
     // (한국어) 카메라를 생성합니다. 
     // (English Translation) Create a camera. 
-    let mut camera = camera::PerspectiveCameraBuilder::new(
+    // (한국어) 스테레오 렌더링인 경우 화면의 절반이 한 눈의 시야가 됩니다.
+    // (English Translation) For stereo rendering, half of the screen is the view of one eye.
+    let aspect_ratio = (window.inner_size().width / views) as f32 / window.inner_size().height as f32;
+    let mut camera_builder = camera::PerspectiveCameraBuilder::new(
         60.0f32.to_radians(), 
-        window.inner_size().width as f32 / window.inner_size().height as f32, 
+        aspect_ratio, 
         0.001, 
         1000.0
     )
+    .set_handedness(handedness);
+    if stereo {
+        camera_builder = camera_builder.set_stereo(EYE_SEPARATION);
+    }
+
+    let camera = camera_builder.clone()
+    .set_name("main")
     .set_translation((0.0, 3.0, 15.0).into())
     .look_at_point((0.0, 0.0, 0.0).into())
     .build(&device, &camera_bind_group_layout);
-    camera.update_shader_resource(&queue);
+
+    // (한국어) 위에서 장면을 내려다보는 보조 카메라를 생성합니다. `V` 키로 활성 카메라와 교체합니다.
+    // (English Translation) Create a secondary camera looking down at the scene. Swap with the active camera using the `V` key.
+    let mut spare_camera: Box<dyn interfaces::GameCamera> = Box::new(camera_builder
+    .set_name("overhead")
+    .set_translation((0.0, 12.0, 6.0).into())
+    .look_at_point((0.0, 0.0, 0.0).into())
+    .build(&device, &camera_bind_group_layout));
+
+    // (한국어) 장면을 생성합니다. 색상 오브젝트들의 유니폼 데이터는 하나의 버퍼 풀에 저장됩니다.
+    // (English Translation) Create a scene. The uniform data of colored objects is stored in a single buffer pool.
+    let mut scene = scene::Scene::new(
+        Box::new(camera), 
+        objects::ObjectUniformPool::new(&device, 64)
+    );
 
     // (한국어) 사각형 메쉬를 생성합니다.
     // (English Translation) Creates a quad mesh.
-    const MESH_DATA: [[f32; 3]; 4] = [[-1.0, -1.0, 0.0], [-1.0, 1.0, 0.0], [1.0, -1.0, 0.0], [1.0, 1.0, 0.0]];
-    let quad_mesh_strip = device.create_buffer(
-        &wgpu::BufferDescriptor {
-            label: Some("VertexBuffer(QuadMesh)"), 
-            mapped_at_creation: false, 
-            size: mem::size_of::<[[f32; 3]; 4]>() as wgpu::BufferAddress, 
-            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
-        }
-    );
-    queue.write_buffer(&quad_mesh_strip, 0, bytemuck::cast_slice(&MESH_DATA));
+    let quad_mesh = mesh::GpuMesh::quad(&device, &queue);
 
     // (한국어) 색상 오브젝트의 쉐이더 레이아웃을 생성합니다.
     // (English Translation) Create a shader layout for the colored object. 
-    let object_bind_group_layout = device.create_bind_group_layout(
-        &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(ColoredObject)"), 
-            entries: &[
-                wgpu::BindGroupLayoutEntry {
-                    binding: 0, 
-                    visibility: wgpu::ShaderStages::VERTEX, 
-                    ty: wgpu::BindingType::Buffer { 
-                        ty: wgpu::BufferBindingType::Uniform, 
-                        has_dynamic_offset: false, 
-                        min_binding_size: None 
-                    }, 
-                    count: None,
-                },
-            ],
-        },
-    );
+    let object_bind_group_layout = utils::create_uniform_bind_group_layout(&device, "BindGroupLayout(ColoredObject)", wgpu::ShaderStages::VERTEX_FRAGMENT);
 
     // (한국어) 색상 오브젝트들을 생성합니다. 
     // (English Translation) Create color objects. 
-    let mut opaque_objects = Vec::new();
-    let mut transparent_objects = Vec::new();
     let gray_plain = objects::ColordObjectBuilder::new()
+        .set_name("gray_plain")
         .set_color((0.5, 0.5, 0.5, 1.0).into())
         .set_scale((8.0, 8.0, 1.0).into())
         .set_translation((0.0, 0.0, 0.0).into())
         .look_at_point((0.0, 1.0, 0.0).into())
-        .build(&device, &object_bind_group_layout);
-    gray_plain.update_shader_resource(&queue);
-    opaque_objects.push(gray_plain);
+        .set_cast_shadow(false)
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(gray_plain);
 
     let wall = objects::ColordObjectBuilder::new()
+        .set_name("wall_0")
         .set_color((0.7, 0.7, 0.7, 1.0).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((3.0, 1.0, 0.0).into())
-        .build(&device, &object_bind_group_layout);
-    wall.update_shader_resource(&queue);
-    opaque_objects.push(wall);
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(wall);
+    let id = scene::ObjectId { bucket: scene::ObjectBucket::Opaque, index: scene.opaque_objects.len() - 1 };
+    scene.add_animator(id, Box::new(animation::Spin::new(glam::Vec3::Y, 45.0f32.to_radians())));
 
     let red_glass = objects::ColordObjectBuilder::new()
+        .set_name("red_glass")
         .set_color((1.0, 0.0, 0.0, 0.3).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((0.0, 1.0, 0.0).into())
-        .build(&device, &object_bind_group_layout);
-    red_glass.update_shader_resource(&queue);
-    transparent_objects.push(red_glass);
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.transparent_objects.push(red_glass);
 
     let wall = objects::ColordObjectBuilder::new()
+        .set_name("wall_1")
         .set_color((0.7, 0.7, 0.7, 1.0).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((-2.0, 1.0, 5.0).into())
-        .build(&device, &object_bind_group_layout);
-    wall.update_shader_resource(&queue);
-    opaque_objects.push(wall);
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(wall);
 
     let green_glass = objects::ColordObjectBuilder::new()
+        .set_name("green_glass")
         .set_color((0.0, 1.0, 0.0, 0.3).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((1.0, 1.0, 3.0).into())
-        .build(&device, &object_bind_group_layout);
-    green_glass.update_shader_resource(&queue);
-    transparent_objects.push(green_glass);
+        .set_wobble(0.15, 0.5)
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.transparent_objects.push(green_glass);
+    let id = scene::ObjectId { bucket: scene::ObjectBucket::Transparent, index: scene.transparent_objects.len() - 1 };
+    scene.add_animator(id, Box::new(animation::Bob::new((0.0, 0.25, 0.0).into(), 3.0)));
 
     let blue_glass = objects::ColordObjectBuilder::new()
+        .set_name("blue_glass")
         .set_color((0.0, 0.0, 1.0, 0.3).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((-2.0, 1.0, -5.0).into())
-        .build(&device, &object_bind_group_layout);
-    blue_glass.update_shader_resource(&queue); 
-    transparent_objects.push(blue_glass);
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.transparent_objects.push(blue_glass);
 
     let wall = objects::ColordObjectBuilder::new()
+        .set_name("wall_2")
         .set_color((0.7, 0.7, 0.7, 1.0).into())
         .set_scale((1.0, 1.0, 1.0).into())
         .set_translation((-0.5, 1.0, -2.5).into())
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(wall);
+
+    // (한국어) 
+    // 부모-자식 변환 계층 구조로 연결된 차체와 포탑 오브젝트를 생성합니다. 포탑은 차체를 따라 돌면서 차체 위에서 따로 회전합니다.
+    // 두 오브젝트의 월드 변환 행렬은 매 프레임 노드 계층 구조로부터 계산됩니다.
+    // 
+    // (English Translation) 
+    // Create hull and turret objects linked by a parent-child transform hierarchy. The turret turns with the hull and rotates on its own on top of it.
+    // The world transforms of both objects are computed from the node hierarchy every frame.
+    // 
+    let hierarchy_start = scene.opaque_objects.len();
+    let hull = objects::ColordObjectBuilder::new()
+        .set_name("hull")
+        .set_color((0.3, 0.4, 0.2, 1.0).into())
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(hull);
+    let turret = objects::ColordObjectBuilder::new()
+        .set_name("turret")
+        .set_color((0.4, 0.5, 0.3, 1.0).into())
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.opaque_objects.push(turret);
+    let hull_position = glam::Vec3::new(-4.0, 1.0, 2.0);
+    let turret_offset = glam::Vec3::new(0.0, 1.5, 0.0);
+    let mut hierarchy_nodes = vec![
+        objects::SceneNode::new(glam::Mat4::from_translation(hull_position)), 
+        objects::SceneNode::with_parent(0, glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::splat(0.5), 
+            glam::Quat::IDENTITY, 
+            turret_offset
+        )), 
+    ];
+    objects::apply_world_transforms(&hierarchy_nodes, &mut scene.opaque_objects[hierarchy_start..]);
+
+    // (한국어) 불투명한 표면 위에 겹쳐 그려지는 데칼 오브젝트를 생성합니다.
+    // (English Translation) Create a decal object drawn over opaque surfaces.
+    let marker = objects::ColordObjectBuilder::new()
+        .set_name("marker")
+        .set_color((1.0, 0.8, 0.0, 1.0).into())
+        .set_scale((0.5, 0.5, 1.0).into())
+        .set_translation((2.0, 0.0, 2.0).into())
+        .look_at_point((2.0, 1.0, 2.0).into())
+        .set_cast_shadow(false)
+        .build_pooled(&device, &object_bind_group_layout, &mut scene.object_pool);
+    scene.decal_objects.push(marker);
+    let id = scene::ObjectId { bucket: scene::ObjectBucket::Decal, index: scene.decal_objects.len() - 1 };
+    let pulse: Vec<Box<dyn animation::Animator>> = vec![
+        Box::new(animation::ColorPulse::new((1.0, 0.8, 0.0, 1.0).into(), (1.0, 0.3, 0.0, 1.0).into(), 2.0)), 
+        Box::new(animation::Spin::new(glam::Vec3::Y, 90.0f32.to_radians())), 
+    ];
+    scene.add_animator(id, Box::new(pulse));
+
+    // (한국어) 모든 오브젝트의 유니폼 데이터를 오브젝트 풀에 한 번에 씁니다.
+    // (English Translation) Writes the uniform data of all objects into the object pool at once.
+    scene.update_all(&queue);
+
+    // (한국어) 하나의 그리기 호출로 그려지는 투명한 색상 오브젝트의 인스턴스 묶음을 생성합니다.
+    // (English Translation) Create an instance batch of transparent colored objects drawn with a single draw call.
+    let opaque_batches: Vec<objects::InstanceBatch> = Vec::new();
+    let mut transparent_batches = Vec::new();
+    let glass_row = objects::ColordObjectBuilder::new()
+        .set_name("glass_row")
+        .set_color((1.0, 1.0, 1.0, 1.0).into())
+        .set_scale((1.0, 1.0, 1.0).into())
+        .set_translation((0.0, 3.0, -1.0).into())
         .build(&device, &object_bind_group_layout);
-    wall.update_shader_resource(&queue);
-    opaque_objects.push(wall);
-
-
-    // (한국어) 누적 값을 저장할 텍스처 뷰를 생성합니다.
-    // (English Translation) Create a texture view to store accumulated values.
-    let mut accum_texture_view = device.create_texture(
-        &wgpu::TextureDescriptor {
-            label: Some("Accumulate"), 
-            size: wgpu::Extent3d {
-                width: window.inner_size().width, 
-                height: window.inner_size().height, 
-                depth_or_array_layers: 1, 
-            }, 
-            format: wgpu::TextureFormat::Rgba16Float, 
-            dimension: wgpu::TextureDimension::D2, 
-            mip_level_count: 1, 
-            sample_count: 1, 
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-            view_formats: &[], 
-        },
-    )
-    .create_view(&wgpu::TextureViewDescriptor {
-        ..Default::default()
-    });
+    glass_row.update_shader_resource(&queue);
+    let instances: Vec<_> = (0..6)
+        .map(|i| objects::InstanceLayout {
+            world_matrix: glam::Mat4::from_scale_rotation_translation(
+                glam::Vec3::splat(0.4), 
+                glam::Quat::IDENTITY, 
+                glam::vec3(-2.5 + i as f32, 0.0, 0.0)
+            ), 
+            color: (glam::Vec3::ONE - glam::Vec3::splat(i as f32 / 6.0), 0.4).into(), 
+        })
+        .collect();
+    transparent_batches.push(objects::InstanceBatch::new(&device, &queue, glass_row, &instances));
 
-    // (한국어) 노출 값을 저장할 텍스처 뷰를 생성합니다.
-    // (English Translation) Create a texture view to store revealage values. 
-    let mut reveal_texture_view = device.create_texture(
-        &wgpu::TextureDescriptor {
-            label: Some("Revealage"), 
-            size: wgpu::Extent3d {
-                width: window.inner_size().width, 
-                height: window.inner_size().height, 
-                depth_or_array_layers: 1, 
-            }, 
-            format: wgpu::TextureFormat::R8Unorm, 
-            dimension: wgpu::TextureDimension::D2, 
-            mip_level_count: 1, 
-            sample_count: 1, 
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-            view_formats: &[],
-        },
-    )
-    .create_view(&wgpu::TextureViewDescriptor {
-        ..Default::default()
-    });
+    // (한국어) 실행 파일에 포함된 OBJ 메쉬를 불러와 인덱스 버퍼로 그려지는 메쉬 오브젝트를 생성합니다.
+    // (English Translation) Loads the OBJ mesh embedded in the executable and creates a mesh object drawn with an index buffer.
+    let mut mesh_objects = Vec::new();
+    match mesh::Mesh::parse_obj(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/meshes/pyramid.obj"))) {
+        Ok(pyramid_mesh) => {
+            let pyramid_mesh = Arc::new(mesh::GpuMesh::new(&device, &queue, "pyramid", &pyramid_mesh));
+            let pyramid = objects::ColordObjectBuilder::new()
+                .set_name("pyramid")
+                .set_color((0.9, 0.5, 0.2, 1.0).into())
+                .set_scale((1.0, 1.0, 1.0).into())
+                .set_translation((2.0, 0.0, -2.0).into())
+                .build(&device, &object_bind_group_layout);
+            pyramid.update_shader_resource(&queue);
+            mesh_objects.push(objects::MeshObject::new(pyramid_mesh, pyramid));
+        }, 
+        Err(e) => log::error!("Failed to load the pyramid mesh: {}", e), 
+    }
+
+
+    // (한국어) 전역 유니폼의 쉐이더 레이아웃을 생성합니다.
+    // (English Translation) Create a shader layout for the global uniform.
+    let global_bind_group_layout = utils::create_uniform_bind_group_layout(&device, "BindGroupLayout(Global)", wgpu::ShaderStages::VERTEX_FRAGMENT);
+
+    // (한국어) 가중치 혼합 OIT의 가중치 함수 유니폼의 쉐이더 레이아웃을 생성합니다.
+    // (English Translation) Create a shader layout for the weight function uniform of weighted blended OIT.
+    let wboit_bind_group_layout = utils::create_uniform_bind_group_layout(&device, "BindGroupLayout(Wboit)", wgpu::ShaderStages::FRAGMENT);
+
+    // (한국어) 렌더링 설정을 생성합니다.
+    // (English Translation) Create the rendering settings.
+    let mut settings = settings::RenderSettings::new(&device, &global_bind_group_layout, &wboit_bind_group_layout);
+    settings.update_shader_resource(&queue);
+
+    // (한국어) 바닥 격자의 쉐이더 레이아웃과 설정을 생성합니다. (바인드 그룹 슬롯 1) `J` 키로 전환합니다.
+    // (English Translation) Create the shader layout and settings of the ground grid. (bind group slot 1) Toggle with the `J` key.
+    let grid_bind_group_layout = grid::create_grid_bind_group_layout(&device);
+    let grid_settings = grid::GridSettings::new(&device, &grid_bind_group_layout);
+    grid_settings.update_shader_resource(&queue);
+
+    // (한국어) 
+    // 하늘 배경의 쉐이더 레이아웃과 설정을 생성합니다. 기본값은 그라디언트이며 `Y` 키로 그라디언트, 큐브맵, 배경 색상을 차례로 바꿉니다.
+    // 큐브맵은 `C` 키로 마지막으로 캡처한 큐브맵을 사용합니다.
+    // 
+    // (English Translation) 
+    // Create the shader layouts and settings of the sky background. The default is the gradient, and the `Y` key cycles through the gradient, the cubemap and the clear color.
+    // The cubemap uses the cubemap last captured with the `C` key.
+    // 
+    let sky_bind_group_layout = sky::create_sky_bind_group_layout(&device);
+    let sky_cubemap_bind_group_layout = sky::create_sky_cubemap_bind_group_layout(&device);
+    let sky_settings = sky::SkySettings::new(&device, &sky_bind_group_layout);
+    sky_settings.update_shader_resource(&queue);
+    let mut sky_cubemap: Option<renderer::CubemapCapture> = None;
 
     // (한국어) 누적 값과 노출 값의 바인드 그룹을 생성합니다. 
     // (English Translation) Creates a bind group of accumulated and revealage values. 
-    let oit_bind_group_layout = device.create_bind_group_layout(
+    // (한국어) 스테레오 렌더링인 경우 누적 값과 노출 값은 눈마다 하나의 레이어를 가진 텍스처 배열입니다.
+    // (English Translation) For stereo rendering, the accumulated and revealage values are texture arrays with one layer per eye.
+    let oit_view_dimension = match stereo {
+        true => wgpu::TextureViewDimension::D2Array, 
+        false => wgpu::TextureViewDimension::D2, 
+    };
+    let oit_bind_group_layout = renderer::create_oit_bind_group_layout(&device, oit_view_dimension);
+
+    // (한국어) 투명 패스를 낮은 해상도로 그리는 경우 깊이 텍스처를 읽는 쉐이더 레이아웃을 생성합니다.
+    // (English Translation) Create a shader layout that reads a depth texture if the transparent pass is drawn at a lower resolution.
+    let depth_bind_group_layout = device.create_bind_group_layout(
         &wgpu::BindGroupLayoutDescriptor {
-            label: Some("BindGroupLayout(WeightedBlendedOIT)"), 
+            label: Some("BindGroupLayout(Depth)"), 
             entries: &[
                 wgpu::BindGroupLayoutEntry {
                     binding: 0, 
                     visibility: wgpu::ShaderStages::FRAGMENT, 
                     ty: wgpu::BindingType::Texture { 
-                        sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
-                        view_dimension: wgpu::TextureViewDimension::D2, 
-                        multisampled: false 
-                    },
-                    count: None,
-                },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 1, 
-                    visibility: wgpu::ShaderStages::FRAGMENT, 
-                    ty: wgpu::BindingType::Texture { 
-                        sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                        sample_type: wgpu::TextureSampleType::Depth, 
                         view_dimension: wgpu::TextureViewDimension::D2, 
                         multisampled: false 
                     }, 
@@ -248,190 +466,651 @@ fn render_loop(
             ],
         },
     );
-    let mut oit_bind_group = device.create_bind_group(
-        &wgpu::BindGroupDescriptor {
-            label: Some("BindGroup(WeightedBlendedOIT)"), 
-            layout: &oit_bind_group_layout, 
-            entries: &[
-                wgpu::BindGroupEntry {
-                    binding: 0, 
-                    resource: wgpu::BindingResource::TextureView(&accum_texture_view), 
-                }, 
-                wgpu::BindGroupEntry {
-                    binding: 1, 
-                    resource: wgpu::BindingResource::TextureView(&reveal_texture_view), 
-                },
-            ],
-        },
-    );
+
+    // (한국어) 스키닝 메쉬의 뼈 행렬 저장 버퍼를 읽는 쉐이더 레이아웃을 생성합니다.
+    // (English Translation) Create a shader layout that reads the bone matrix storage buffer of skinned meshes.
+    let skeleton_bind_group_layout = skinning::create_skeleton_bind_group_layout(&device);
+
+    // (한국어) 두 개의 뼈로 휘어지는 기둥 스키닝 오브젝트를 생성합니다. 위쪽 뼈는 매 프레임 게임 시간에 따라 좌우로 흔들립니다.
+    // (English Translation) Create a skinned column object that bends with two bones. The upper bone sways from side to side with game time every frame.
+    let mut skinned_objects = Vec::new();
+    let (column_vertices, column_indices) = skinning::two_bone_column(0.4, 2.0);
+    let column = objects::ColordObjectBuilder::new()
+        .set_name("skinned_column")
+        .set_color((0.3, 0.7, 0.9, 1.0).into())
+        .set_scale((1.0, 1.0, 1.0).into())
+        .set_translation((3.0, 0.0, 1.0).into())
+        .build(&device, &object_bind_group_layout);
+    column.update_shader_resource(&queue);
+    let column_skeleton = skinning::Skeleton::new(&device, &skeleton_bind_group_layout, 2);
+    column_skeleton.update_shader_resource(&queue);
+    skinned_objects.push(skinning::SkinnedObject::new(
+        skinning::SkinnedMesh::new(&device, &queue, &column_vertices, &column_indices), 
+        column, 
+        column_skeleton
+    ));
+
+    // (한국어) 텍스처 오브젝트의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 1)
+    // (English Translation) Create a shader layout for textured objects. (bind group slot 1)
+    let textured_object_bind_group_layout = objects::create_textured_object_bind_group_layout(&device);
+
+    // (한국어) 체크무늬 텍스처를 입힌 텍스처 오브젝트를 생성합니다. (64x64 텍셀, 8 텍셀 크기의 칸)
+    // (English Translation) Create a textured object with a checkerboard texture. (64x64 texels, 8 texel cells)
+    let mut textured_objects = Vec::new();
+    let checker_pixels: Vec<u8> = (0..64 * 64)
+        .flat_map(|i| match (i % 64 / 8 + i / 64 / 8) % 2 {
+            0 => [230, 230, 230, 255], 
+            _ => [40, 40, 40, 255], 
+        })
+        .collect();
+    match objects::TexturedObjectBuilder::new()
+        .set_name("checker_board")
+        .set_scale((1.0, 1.0, 1.0).into())
+        .set_translation((-3.0, 1.0, -2.0).into())
+        .build(&device, &queue, &textured_object_bind_group_layout, 64, 64, &checker_pixels) 
+    {
+        Ok(checker_board) => {
+            checker_board.update_shader_resource(&queue);
+            textured_objects.push(checker_board);
+        }, 
+        Err(e) => log::error!("Failed to create the checker board: {}", e), 
+    }
 
     // (한국어) 색상 오브젝트를 그리는 그래픽스 파이프라인을 생성합니다.
     // (English Translation) Create a graphics pipeline to draw colored object. 
-    let module = device.create_shader_module(
-        wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shader.wgsl"))
-    );
-    let bind_group_layouts = [&camera_bind_group_layout, &object_bind_group_layout];
-    let opaque_pipeline = pipeline::create_opaque_pipeline(&device, &module, &bind_group_layouts);
-    let transparent_pipeline = pipeline::create_transparent_pipeline(&device, &module, &bind_group_layouts);
+    // (한국어) 
+    // 스테레오 렌더링인 경우 스테레오 진입점을, 장치가 `PUSH_CONSTANTS` 기능을 지원하는 경우 푸시 상수 진입점을 덧붙인 쉐이더 모듈을 생성합니다.
+    // `@builtin(view_index)`와 `var<push_constant>`는 각 기능 없이 쉐이더 검증을 통과하지 못하므로 따로 덧붙입니다.
+    // 
+    // (English Translation) 
+    // Creates a shader module with the stereo entry points appended for stereo rendering, 
+    // and the push constant entry points appended if the device supports the `PUSH_CONSTANTS` feature.
+    // `@builtin(view_index)` and `var<push_constant>` fail shader validation without each feature, so they are appended separately.
+    // 
+    let push_constants = device.features().contains(wgpu::Features::PUSH_CONSTANTS);
+    let module = match (stereo, push_constants) {
+        (false, false) => device.create_shader_module(
+            wgpu::include_wgsl!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shader.wgsl"))
+        ), 
+        _ => {
+            let mut source = String::from(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shader.wgsl")));
+            if stereo {
+                source.push('\n');
+                source.push_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/stereo.wgsl")));
+            }
+            if push_constants {
+                source.push('\n');
+                source.push_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/push_constants.wgsl")));
+            }
+            device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some("ShaderModule(Extended)"), 
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Owned(source)), 
+            })
+        }, 
+    };
+    // (한국어) 화면의 색상 형식을 선택합니다. 지원되는 경우 sRGB 형식을 사용하여 최종 출력에 감마 보정을 적용합니다.
+    // (English Translation) Selects the color format of the surface. An sRGB format is used when supported so that the final output is gamma corrected.
+    let surface_format = utils::select_surface_format(&surface, &adapter);
 
-    let bind_group_layouts = [&oit_bind_group_layout];
-    let composite_pipeline = pipeline::create_composite_pipeline(&device, &module, &bind_group_layouts);
-    
+    // (한국어) 
+    // 톤 매핑 패스의 쉐이더 레이아웃과 설정을 생성합니다. 장면은 HDR 렌더 타겟에 그려진 후 톤 매핑되어 화면에 쓰입니다.
+    // `X` 키로 톤 매핑 연산자(Reinhard, ACES)를 바꾸어 비교하며, `U` 키로 블룸을 전환합니다.
+    // 
+    // (English Translation) 
+    // Create the shader layout and settings of the tone mapping pass. The scene is drawn into an HDR render target and then tonemapped to the surface.
+    // The `X` key switches the tone mapping operator (Reinhard, ACES) for comparison, and the `U` key toggles bloom.
+    // 
+    let tonemap_bind_group_layout = tonemap::create_tonemap_bind_group_layout(&device);
+    let tonemap_settings = tonemap::ToneMapSettings::new(&device, &tonemap_bind_group_layout, surface_format);
+    tonemap_settings.update_shader_resource(&queue);
+
+    // (한국어) 
+    // 방향광의 그림자 맵의 쉐이더 레이아웃과 그림자 맵을 생성합니다. 그림자는 방향광이 켜져 있는 경우(`L` 키)에만 그려지며, `Z` 키로 전환합니다.
+    // 그림자 맵의 크기는 창 크기와 관계없으므로 창 크기가 바뀌어도 다시 생성하지 않습니다.
+    // 
+    // (English Translation) 
+    // Create the shader layouts of the shadow map of the directional light and the shadow map. Shadows are only drawn while the directional light is on (`L` key), and are toggled with the `Z` key.
+    // The size of the shadow map is independent of the window size, so it is not recreated when the window is resized.
+    // 
+    let shadow_bind_group_layouts = shadow::ShadowBindGroupLayouts::new(&device);
+    let mut shadow_map = shadow::ShadowMap::new(&device, &shadow_bind_group_layouts);
+    shadow_map.set_light_direction(settings.light().direction);
+    shadow_map.update_shader_resource(&queue);
 
-    // (한국어) 스왑체인 및 프레임 버퍼를 설정합니다.
-    // (English Translation) Sets the swapchain and frame buffer. 
-    let mut config = wgpu::SurfaceConfiguration {
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT, 
-        format: wgpu::TextureFormat::Bgra8Unorm, 
+    // (한국어) 장면을 그릴 때 사용하는 메쉬, 인스턴스 묶음과 패스별 설정들을 모읍니다.
+    // (English Translation) Gathers the meshes, instance batches and per-pass settings used to draw the scene.
+    let mut draw_resources = SceneDrawResources {
+        quad_mesh, 
+        opaque_batches, 
+        transparent_batches, 
+        mesh_objects, 
+        textured_objects, 
+        skinned_objects, 
+        grid_settings, 
+        sky_settings, 
+        tonemap_settings, 
+        shadow_map, 
+    };
+
+    // (한국어) 클릭한 불투명한 오브젝트를 GPU에서 찾는 오브젝트 ID 패스의 쉐이더 레이아웃을 생성합니다.
+    // (English Translation) Create the shader layout of the object ID pass that finds the clicked opaque object on the GPU.
+    let picking_bind_group_layout = picking::create_picking_bind_group_layout(&device);
+
+    // (한국어) 파이프라인 빌더는 렌더링 설정이 바뀌어 리소스를 다시 생성할 때도 사용되므로 유지합니다.
+    // (English Translation) The pipeline builder is kept because it is also used to recreate the resources when the rendering settings change.
+    let pipeline_builder = pipeline::PipelineSetBuilder::new(
+        &device, 
+        &module, 
+        &camera_bind_group_layout, 
+        &object_bind_group_layout, 
+        &global_bind_group_layout, 
+        &wboit_bind_group_layout, 
+        &oit_bind_group_layout
+    )
+    .set_wireframe(true)
+    .set_instanced(true)
+    .set_sorted_transparent(true)
+    .set_stereo(stereo)
+    .set_oit_downsample((oit_scale < 1.0).then_some(&depth_bind_group_layout))
+    .set_depth_peeling(depth_peel_layers)
+    .set_depth_prepass(depth_prepass)
+    .set_push_constants(true)
+    .set_skinning(Some(&skeleton_bind_group_layout))
+    .set_textured(Some(&textured_object_bind_group_layout))
+    .set_grid(Some(&grid_bind_group_layout))
+    .set_sky(Some(&sky_bind_group_layout), Some(&sky_cubemap_bind_group_layout))
+    .set_tonemap(Some(&tonemap_bind_group_layout))
+    .set_shadow(Some(&shadow_bind_group_layouts))
+    .set_picking(Some(&picking_bind_group_layout))
+    .set_reverse_z(scene.ref_camera().reverse_z())
+    .set_reveal_format(utils::select_reveal_format(&adapter, &device, wgpu::TextureFormat::R16Float))
+    .set_color_format(surface_format);
+
+    // (한국어) 
+    // 가중치 혼합 OIT 대신 정렬된 알파 블렌딩으로 투명한 오브젝트를 그리는지 여부 입니다. `M` 키로 전환합니다.
+    // 정렬된 순서는 매 프레임 카메라 공간 깊이에 따라 다시 계산됩니다.
+    // 
+    // (English Translation) 
+    // Whether to draw transparent objects with sorted alpha blending instead of weighted blended OIT. Toggle with the `M` key.
+    // The sorted order is recomputed every frame from the camera space depth.
+    // 
+    let mut sorted_blending = false;
+    let mut transparent_order: Vec<usize> = Vec::new();
+
+    // (한국어) 숨은 선(hidden-line) 모드의 사용 여부 입니다. `H` 키로 전환합니다.
+    // (English Translation) Whether to use hidden-line mode. Toggle with the `H` key.
+    let mut hidden_line = false;
+
+    // (한국어) 장치가 `TIMESTAMP_QUERY` 기능을 지원하는 경우 렌더 패스별 GPU 시간을 측정합니다. `I` 키로 마지막 측정값을 출력합니다.
+    // (English Translation) Measures the GPU time per render pass if the device supports the `TIMESTAMP_QUERY` feature. Log the last measurement with the `I` key.
+    let pass_timestamps = gpu_timing::PassTimestamps::new(&device, &queue);
+    let mut pass_timings = gpu_timing::PassTimings::default();
+
+
+    // (한국어) 
+    // 스왑체인 및 프레임 버퍼를 설정합니다.
+    // `wgpu`는 표시(present) 이후 스왑체인 텍스처의 내용 보존을 요청하는 방법을 제공하지 않으므로,
+    // 색상 렌더 타겟을 지우지 않는 경우 이전 내용은 플랫폼에 따라 달라질 수 있습니다.
+    // (참고: `RenderSettings::set_clear_color_target`)
+    // 
+    // (English Translation) 
+    // Sets the swapchain and frame buffer. 
+    // `wgpu` does not provide a way to request that swapchain texture contents be preserved after presenting,
+    // so when the color render target is not cleared, the previous contents depend on the platform.
+    // (see also: `RenderSettings::set_clear_color_target`)
+    // 
+    // (한국어) 스테레오 렌더링인 경우 두 눈의 장면을 화면 텍스처에 복사합니다.
+    // (English Translation) For stereo rendering, the scenes of both eyes are copied into the surface texture.
+    let surface_usage = match stereo {
+        true => wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_DST, 
+        false => wgpu::TextureUsages::RENDER_ATTACHMENT, 
+    };
+    // (한국어) 화면이 지원하는 경우 스크린샷을 저장할 수 있도록 화면 텍스처를 복사의 원본으로 사용합니다.
+    // (English Translation) If the surface supports it, the surface texture is used as a copy source so that screenshots can be saved.
+    let screenshot_supported = surface.get_capabilities(&adapter).usages.contains(wgpu::TextureUsages::COPY_SRC);
+    let surface_usage = match screenshot_supported {
+        true => surface_usage | wgpu::TextureUsages::COPY_SRC, 
+        false => surface_usage, 
+    };
+    let config = wgpu::SurfaceConfiguration {
+        usage: surface_usage, 
+        format: surface_format, 
         width: window.inner_size().width, 
         height: window.inner_size().height, 
-        present_mode: wgpu::PresentMode::AutoVsync, 
+        present_mode: utils::select_present_mode(&surface, &adapter, wgpu::PresentMode::AutoVsync), 
         desired_maximum_frame_latency: 2, 
         alpha_mode: wgpu::CompositeAlphaMode::Auto, 
         view_formats: vec![], 
     };
-    surface.configure(&device, &config);
-
-    // (한국어) 깊이-스텐실 텍스처 뷰를 생성합니다.
-    // (English Translation) Create the depth-stencil texture view.
-    let mut depth_stencil_view = device.create_texture(
-        &wgpu::TextureDescriptor {
-            label: Some("DepthStencilBuffer"), 
-            size: wgpu::Extent3d {
-                width: window.inner_size().width, 
-                height: window.inner_size().height, 
-                depth_or_array_layers: 1, 
-            },
-            format: wgpu::TextureFormat::Depth32Float, 
-            dimension: wgpu::TextureDimension::D2, 
-            mip_level_count: 1, 
-            sample_count: 1, 
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-            view_formats: &[],
-        },
-    )
-    .create_view(&wgpu::TextureViewDescriptor { 
-        ..Default::default()
-    });
+
+    // (한국어) 화면을 설정하고, 그래픽스 파이프라인과 장면을 그릴 때 사용되는 중간 렌더 타겟들을 생성합니다.
+    // (English Translation) Configures the surface, and creates the graphics pipelines and the intermediate render targets used when drawing the scene.
+    // (한국어) 낮은 해상도의 투명 패스 파이프라인이 있는 경우 누적 값과 노출 값을 `oit_scale` 배율로 생성합니다.
+    // (English Translation) If the low resolution transparent pass pipelines exist, the accumulated and revealage values are created at `oit_scale` times.
+    let mut renderer = renderer::Renderer::new(
+        device.clone(), 
+        queue.clone(), 
+        surface, 
+        config, 
+        pipeline_builder, 
+        oit_scale
+    );
+
+    // (한국어) 
+    // 마우스 왼쪽 버튼으로 클릭한 불투명한 오브젝트를 오브젝트 ID 패스로 찾는 피커를 생성합니다.
+    // 렌더 타겟은 화면 크기와 같으며, 풀을 사용하는 오브젝트의 수 만큼 오브젝트를 구분합니다.
+    // 
+    // (English Translation) 
+    // Create the picker that finds the opaque object clicked with the left mouse button with the object ID pass.
+    // The render target has the size of the surface, and it distinguishes as many objects as the pool holds.
+    // 
+    let mut object_picker = picking::ObjectPicker::new(
+        &device, 
+        &queue, 
+        &picking_bind_group_layout, 
+        scene.object_pool.capacity(), 
+        renderer.config().width, 
+        renderer.config().height
+    );
+
+    // (한국어) `hot-reload` 기능이 켜진 경우 쉐이더 파일이 바뀌면 파이프라인을 다시 생성합니다.
+    // (English Translation) With the `hot-reload` feature, the pipelines are recreated when the shader file changes.
+    #[cfg(feature = "hot-reload")]
+    let mut shader_watcher = shader_reload::ShaderWatcher::new(stereo, push_constants);
+
+    // (한국어) 실제로 선택된 렌더링 구성을 출력합니다.
+    // (English Translation) Logs the rendering configuration actually selected.
+    log::info!("Effective configuration:\n{}", renderer::effective_config(&adapter, &device, renderer.config(), renderer.resources.settings()));
+
+    // (한국어) 이름이 있는 오브젝트 위에 레이블을 그리는 오버레이 렌더러를 생성합니다.
+    // (English Translation) Creates the overlay renderer that draws labels above named objects.
+    #[cfg(feature = "labels")]
+    let mut label_renderer = labels::LabelRenderer::new(&device, &queue, renderer.resources.settings().color_format);
+
+    // (한국어) 표시 모드별 프레임 시간 비교기 입니다. `P` 키로 비교를 시작하거나 끝냅니다.
+    // (English Translation) Frame time comparison per present mode. Start or stop the comparison with the `P` key.
+    let mut present_comparison: Option<frame_pacing::PresentModeComparison> = None;
+
+    // (한국어) 표시 모드 비교 중이 아닐 때 사용하는 표시 모드 입니다. `N` 키로 수직 동기화를 켜거나 끕니다.
+    // (English Translation) The present mode used when not comparing present modes. Toggle vertical sync with the `N` key.
+    let mut present_mode = renderer.config().present_mode;
+
+    // (한국어) 렌더 패스 레이블 입니다. 설정된 경우 매 프레임 프레임 번호가 붙습니다.
+    // (English Translation) Render pass labels. If enabled, the frame number is appended every frame.
+    let mut pass_labels = renderer::PassLabels::default();
+
+    // (한국어) 창 안의 커서 위치 입니다. 커서가 창 밖에 있는 경우 `None` 입니다.
+    // (English Translation) The cursor position in the window. `None` if the cursor is outside the window.
+    let mut cursor_position: Option<glam::Vec2> = None;
+
+    // (한국어) 이번 프레임을 그린 후 스크린샷을 저장할지 여부 입니다. `F12` 키로 요청합니다.
+    // (English Translation) Whether to save a screenshot after drawing this frame. Requested with the `F12` key.
+    let mut screenshot_requested = false;
+
+    // (한국어) 이번 프레임을 그린 후 커서 아래의 오브젝트를 오브젝트 ID 패스로 찾을지 여부 입니다. 마우스 왼쪽 버튼으로 요청합니다.
+    // (English Translation) Whether to find the object under the cursor with the object ID pass after drawing this frame. Requested with the left mouse button.
+    let mut pick_requested = false;
+
+    // (한국어) 
+    // 창이 최소화되었는지(화면 크기가 0인지), 또는 다른 창에 완전히 가려졌는지 여부 입니다.
+    // 둘 중 하나라도 `true`인 경우 창 이벤트만 처리하고 장면을 그리지 않습니다.
+    // 
+    // (English Translation) 
+    // Whether the window is minimized (the surface size is zero), or fully occluded by other windows.
+    // If either is `true`, only window events are handled and the scene is not drawn.
+    // 
+    let mut minimized = renderer.config().width == 0 || renderer.config().height == 0;
+    let mut occluded = false;
+
+    // (한국어) `WASD`, `QE` 키로 카메라를 이동시키고, 마우스 오른쪽 버튼을 누른 채로 움직이면 카메라가 바라보는 방향을 바꿉니다.
+    // (English Translation) Moves the camera with the `WASD` and `QE` keys, and moving the mouse while holding the right button changes the direction the camera looks.
+    let mut fly_camera = camera::FlyCameraController::new(5.0, input::InputSettings::default());
 
     // (한국어) 렌더링 루프를 실행합니다.
     // (English Translation) Run the rendering loop.
     log::info!("Run Rendering loop.");
     let mut timer = timer::GameTimer::<50>::new();
+    // (한국어) 마지막으로 초당 프레임 수를 출력한 이후 흐른 시간 입니다.
+    // (English Translation) The time elapsed since the frames per second were last logged.
+    let mut fps_log_elapsed_sec = 0.0;
     while IS_RUNNING.load(MemOrdering::Acquire) {
         // (한국어) 타이머를 갱신합니다.
         // (English Translation) Updates the timer. 
         timer.tick();
 
+        // (한국어) 1초마다 평균을 낸 초당 프레임 수와 프레임 시간을 출력합니다.
+        // (English Translation) Logs the averaged frames per second and frame time once per second.
+        fps_log_elapsed_sec += timer.frame_time_sec();
+        if fps_log_elapsed_sec >= 1.0 {
+            fps_log_elapsed_sec %= 1.0;
+            log::debug!("{:.1} fps ({:.3} ms)", timer.fps(), timer.frame_time_ms());
+        }
+
+        // (한국어) 표시 모드 비교 중인 경우 프레임 시간을 기록합니다.
+        // (English Translation) Records the frame time while comparing present modes.
+        if let Some(comparison) = present_comparison.as_mut().filter(|_| !minimized && !occluded) {
+            if let Some(present_mode) = comparison.update(timer.frame_time_sec()) {
+                renderer.set_present_mode(present_mode);
+            }
+        }
+
+        pass_labels.set_frame_index(settings.frame_pass_labels().then(|| timer.frame_count()));
+
+        // (한국어) 쉐이더 애니메이션에 사용되는 게임 시간을 갱신합니다. 시간 배율을 따르며 일시 정지된 동안 멈춥니다.
+        // (English Translation) Updates the game time used for shader animations. It follows the time scale and stops while paused.
+        settings.set_time(timer.total_game_time_sec());
+        settings.update_shader_resource(&queue);
+        draw_resources.grid_settings.update_shader_resource(&queue);
+        draw_resources.sky_settings.update_shader_resource(&queue);
+        draw_resources.tonemap_settings.update_shader_resource(&queue);
+        // (한국어) 그림자 맵의 빛의 방향을 렌더링 설정의 방향광과 맞춥니다.
+        // (English Translation) Matches the light direction of the shadow map to the directional light of the rendering settings.
+        draw_resources.shadow_map.set_light_direction(settings.light().direction);
+        draw_resources.shadow_map.update_shader_resource(&queue);
+
         // (한국어) 창 이벤트를 처리합니다.
         // (English Translation) Handles window events. 
         while let Some(event) = EVENT_QUEUE.pop() {
+            if let Event::WindowEvent { event, .. } = &event {
+                if fly_camera.process_event(event) {
+                    continue;
+                }
+            }
+
             match event {
                 Event::WindowEvent { event, .. } => match event {
                     WindowEvent::Resized(size) => {
-                        if size.width > 0 && size.height > 0 {
-                            // (한국어) 모든 작업이 끝날 때 까지 기다립니다.
-                            // (English Translation) Wait until all operations are completed.
-                            instance.poll_all(true);
-
-                            // (한국어) 스왑체인 및 프레임 버퍼를 재설정합니다.
-                            // (English Translation) Reset swapchain and frame buffer. 
-                            config.width = size.width;
-                            config.height = size.height;
-                            surface.configure(&device, &config);
-
-                            // (한국어) 누적 값을 저장할 텍스처 뷰를 재생성합니다.
-                            // (English Translation) Recreate a texture view to store accumulated values.
-                            accum_texture_view = device.create_texture(
-                                &wgpu::TextureDescriptor {
-                                    label: Some("Accumulate"), 
-                                    size: wgpu::Extent3d {
-                                        width: size.width, 
-                                        height: size.height, 
-                                        depth_or_array_layers: 1, 
-                                    }, 
-                                    format: wgpu::TextureFormat::Rgba16Float, 
-                                    dimension: wgpu::TextureDimension::D2, 
-                                    mip_level_count: 1, 
-                                    sample_count: 1, 
-                                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-                                    view_formats: &[], 
-                                },
-                            )
-                            .create_view(&wgpu::TextureViewDescriptor {
-                                ..Default::default()
-                            });
-                        
-                            // (한국어) 노출 값을 저장할 텍스처 뷰를 재생성합니다.
-                            // (English Translation) Recreate a texture view to store revealage values. 
-                            reveal_texture_view = device.create_texture(
-                                &wgpu::TextureDescriptor {
-                                    label: Some("Revealage"), 
-                                    size: wgpu::Extent3d {
-                                        width: size.width, 
-                                        height: size.height, 
-                                        depth_or_array_layers: 1, 
-                                    }, 
-                                    format: wgpu::TextureFormat::R8Unorm, 
-                                    dimension: wgpu::TextureDimension::D2, 
-                                    mip_level_count: 1, 
-                                    sample_count: 1, 
-                                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-                                    view_formats: &[],
-                                },
-                            )
-                            .create_view(&wgpu::TextureViewDescriptor {
-                                ..Default::default()
-                            });
-
-                            oit_bind_group = device.create_bind_group(
-                                &wgpu::BindGroupDescriptor {
-                                    label: Some("BindGroup(WeightedBlendedOIT)"), 
-                                    layout: &oit_bind_group_layout, 
-                                    entries: &[
-                                        wgpu::BindGroupEntry {
-                                            binding: 0, 
-                                            resource: wgpu::BindingResource::TextureView(&accum_texture_view), 
-                                        }, 
-                                        wgpu::BindGroupEntry {
-                                            binding: 1, 
-                                            resource: wgpu::BindingResource::TextureView(&reveal_texture_view), 
-                                        },
-                                    ],
-                                },
-                            );
-
-                            // (한국어) 깊이-스텐실 텍스처 뷰를 재생성합니다.
-                            // (English Translation) Recreate the depth-stencil texture view. 
-                            depth_stencil_view = device.create_texture(
-                                &wgpu::TextureDescriptor {
-                                    label: Some("DepthStencilBuffer"), 
-                                    size: wgpu::Extent3d {
-                                        width: size.width, 
-                                        height: size.height, 
-                                        depth_or_array_layers: 1, 
-                                    },
-                                    format: wgpu::TextureFormat::Depth32Float, 
-                                    dimension: wgpu::TextureDimension::D2, 
-                                    mip_level_count: 1, 
-                                    sample_count: 1, 
-                                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
-                                    view_formats: &[],
-                                },
-                            )
-                            .create_view(&wgpu::TextureViewDescriptor { 
-                                ..Default::default()
-                            });
+                        // (한국어) 모든 작업이 끝날 때 까지 기다린 후, 스왑체인을 재설정하고 중간 렌더 타겟들을 재생성합니다.
+                        // (English Translation) Waits until all operations are completed, then resets the swapchain and recreates the intermediate render targets.
+                        minimized = !renderer.resize(&pipeline_builder, size.width, size.height);
+                        if !minimized {
+                            object_picker.resize(&device, size.width, size.height);
+
+                            // (한국어) 카메라의 화면 비율을 갱신합니다. 스테레오 렌더링인 경우 한 눈의 화면 크기를 사용합니다.
+                            // (English Translation) Updates the aspect ratio of the cameras. For stereo rendering, the screen size of one eye is used.
+                            scene.mut_camera().on_resize(size.width / views, size.height);
+                            spare_camera.on_resize(size.width / views, size.height);
+                        }
+                    },
+                    WindowEvent::Occluded(is_occluded) => {
+                        occluded = is_occluded;
+                    },
+                    WindowEvent::CursorMoved { position, .. } => {
+                        let position = glam::Vec2::new(position.x as f32, position.y as f32);
+                        if let Some(previous) = cursor_position {
+                            fly_camera.accumulate_mouse_delta(position - previous);
                         }
+                        cursor_position = Some(position);
+                    },
+                    WindowEvent::CursorLeft { .. } => {
+                        cursor_position = None;
+                    },
+                    WindowEvent::MouseInput { state: ElementState::Pressed, button: MouseButton::Left, .. } => {
+                        pick_requested = true;
+                    },
+                    WindowEvent::MouseWheel { delta, .. } => {
+                        // (한국어) 픽셀 단위 스크롤(터치패드)은 한 줄을 20 픽셀로 환산합니다.
+                        // (English Translation) Pixel scrolling (touchpads) converts 20 pixels into one line.
+                        let steps = match delta {
+                            MouseScrollDelta::LineDelta(_, y) => y, 
+                            MouseScrollDelta::PixelDelta(position) => position.y as f32 / 20.0, 
+                        };
+                        scene.mut_camera().on_zoom(steps);
                     },
                     WindowEvent::KeyboardInput { event, .. } => {
                         if let PhysicalKey::Code(code) = event.physical_key {
                             if KeyCode::ArrowLeft == code && event.state.is_pressed() {
-                                camera.rotate(glam::Quat::from_rotation_y(-180.0f32.to_radians() * timer.elapsed_time_sec()));
-                                camera.update_shader_resource(&queue);
+                                scene.mut_camera().rotate(glam::Quat::from_rotation_y(-180.0f32.to_radians() * timer.elapsed_time_sec()));
                             } else if KeyCode::ArrowRight == code && event.state.is_pressed() {
-                                camera.rotate(glam::Quat::from_rotation_y(180.0f32.to_radians() * timer.elapsed_time_sec()));
-                                camera.update_shader_resource(&queue);
+                                scene.mut_camera().rotate(glam::Quat::from_rotation_y(180.0f32.to_radians() * timer.elapsed_time_sec()));
+                            } else if KeyCode::KeyP == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 표시 모드를 번갈아 전환하며 프레임 시간을 비교합니다.
+                                // (English Translation) Compares frame times by alternating the present mode.
+                                present_comparison = match present_comparison.take() {
+                                    Some(comparison) => {
+                                        comparison.log_comparison();
+                                        None
+                                    },
+                                    None => Some(frame_pacing::PresentModeComparison::new(2.0)),
+                                };
+                                renderer.set_present_mode(present_comparison
+                                    .map(|comparison| comparison.active_mode())
+                                    .unwrap_or(present_mode));
+                                log::info!("Present mode comparison: {}", present_comparison.is_some());
+                            } else if KeyCode::F12 == code && event.state.is_pressed() && !event.repeat {
+                                match screenshot_supported {
+                                    true => screenshot_requested = true, 
+                                    false => log::warn!("Screenshots require the surface to support the COPY_SRC usage, which is not supported."), 
+                                }
+                            } else if KeyCode::KeyN == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 
+                                // 수직 동기화를 켜거나 끕니다. 화면이 지원하지 않는 표시 모드는 `Fifo`로 대체됩니다.
+                                // 비교할 수 있도록 전환 전의 프레임 속도를 출력합니다.
+                                // 
+                                // (English Translation) 
+                                // Turns vertical sync on or off. A present mode the surface does not support is replaced with `Fifo`.
+                                // Logs the frame rate before switching for comparison.
+                                // 
+                                let requested = match present_mode {
+                                    wgpu::PresentMode::AutoNoVsync | wgpu::PresentMode::Immediate | wgpu::PresentMode::Mailbox => wgpu::PresentMode::AutoVsync, 
+                                    _ => wgpu::PresentMode::AutoNoVsync, 
+                                };
+                                let previous = present_mode;
+                                present_mode = utils::select_present_mode(renderer.surface(), &adapter, requested);
+                                if present_comparison.is_none() {
+                                    renderer.set_present_mode(present_mode);
+                                }
+                                log::info!(
+                                    "Present mode: {:?} -> {:?} (frame rate before switch: {} fps, {:.3} ms)", 
+                                    previous, 
+                                    present_mode, 
+                                    timer.frame_rate(), 
+                                    timer.frame_time_sec() * 1000.0
+                                );
+                            } else if KeyCode::KeyF == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 컬링 절두체를 고정하거나 고정을 해제합니다.
+                                // (English Translation) Freezes or unfreezes the culling frustum.
+                                scene.freeze_culling_frustum(!scene.is_culling_frustum_frozen());
+                                let visible = scene.cull().visible().len();
+                                let total = scene.opaque_objects.len() + scene.decal_objects.len() + scene.transparent_objects.len();
+                                log::info!(
+                                    "Culling frustum frozen: {} (visible objects: {}/{})", 
+                                    scene.is_culling_frustum_frozen(), 
+                                    visible, 
+                                    total
+                                );
+                            } else if KeyCode::KeyL == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 방향광을 켜거나 끕니다.
+                                // (English Translation) Turns the directional light on or off.
+                                settings.set_light_enabled(!settings.light().enabled);
+                                log::info!("Directional light: {}", settings.light().enabled);
+                            } else if KeyCode::KeyZ == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.shadow.is_some() {
+                                    // (한국어) 그림자를 켜거나 끕니다. 그림자는 방향광이 켜져 있는 경우에만 보입니다.
+                                    // (English Translation) Turns shadows on or off. Shadows are only visible while the directional light is on.
+                                    draw_resources.shadow_map.set_enabled(!draw_resources.shadow_map.enabled());
+                                    log::info!("Shadows: {}", draw_resources.shadow_map.enabled());
+                                } else {
+                                    log::warn!("Shadows are not available in stereo rendering.");
+                                }
+                            } else if KeyCode::KeyG == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 거리 안개를 꺼짐, 지수 감소, 지수 제곱 감소 순서로 바꿉니다.
+                                // (English Translation) Switches the distance fog in the order of off, exponential falloff and exponential squared falloff.
+                                match (settings.fog().enabled, settings.fog().falloff) {
+                                    (false, _) => {
+                                        settings.set_fog_enabled(true);
+                                        settings.set_fog_falloff(settings::FogFalloff::Exponential);
+                                    }, 
+                                    (true, settings::FogFalloff::Exponential) => settings.set_fog_falloff(settings::FogFalloff::ExponentialSquared), 
+                                    (true, settings::FogFalloff::ExponentialSquared) => settings.set_fog_enabled(false), 
+                                }
+                                log::info!("Fog: {}", match settings.fog().enabled {
+                                    true => format!("{:?}", settings.fog().falloff), 
+                                    false => "off".to_string(), 
+                                });
+                            } else if KeyCode::KeyI == code && event.state.is_pressed() && !event.repeat {
+                                match pass_timestamps.is_some() {
+                                    true => log::info!(
+                                        "GPU pass timings: opaque {:.3} ms, transparent {:.3} ms, composite {:.3} ms", 
+                                        pass_timings.opaque_ms, 
+                                        pass_timings.transparent_ms, 
+                                        pass_timings.composite_ms
+                                    ), 
+                                    false => log::warn!("GPU pass timing requires the TIMESTAMP_QUERY feature, which is not supported."), 
+                                }
+                            } else if KeyCode::KeyB == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 차단 폴링을 전환하며, 비교할 수 있도록 전환 전의 프레임 속도를 출력합니다.
+                                // (English Translation) Toggles blocking poll and logs the frame rate before switching for comparison.
+                                log::info!(
+                                    "Blocking poll: {} -> {} (frame rate before switch: {} fps, {:.3} ms)", 
+                                    settings.blocking_poll(), 
+                                    !settings.blocking_poll(), 
+                                    timer.frame_rate(), 
+                                    timer.real_elapsed_time_sec() * 1000.0
+                                );
+                                settings.set_blocking_poll(!settings.blocking_poll());
+                            } else if KeyCode::KeyV == code && event.state.is_pressed() && !event.repeat {
+                                spare_camera = scene.set_camera(spare_camera);
+                                log::info!("Swapped active camera.");
+                            } else if KeyCode::KeyT == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 색상 렌더 타겟을 지우지 않고 이전 프레임 위에 그립니다. (잔상 효과)
+                                // (English Translation) Draws over the previous frame without clearing the color render target. (trail effect)
+                                settings.set_clear_color_target(!settings.clear_color_target());
+                                log::info!("Clear color target: {}", settings.clear_color_target());
+                            } else if KeyCode::KeyK == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 배경 색상을 다음 프리셋으로 바꿉니다. 밝은 배경과 어두운 배경에서 투명도를 확인합니다.
+                                // (English Translation) Switches the background color to the next preset. Checks transparency against light and dark backgrounds.
+                                let presets = settings::RenderSettings::CLEAR_COLOR_PRESETS;
+                                let next = presets.iter()
+                                    .position(|&color| color == settings.clear_color())
+                                    .map_or(0, |index| (index + 1) % presets.len());
+                                settings.set_clear_color(presets[next]);
+                                log::info!("Background color: {}", settings.clear_color());
+                            } else if KeyCode::KeyR == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 투명한 조각의 가중치 최대값을 다음 프리셋으로 바꿉니다. 겹친 층이 많은 곳의 결과를 비교합니다.
+                                // (English Translation) Switches the maximum weight of transparent fragments to the next preset. Compares the result where many layers overlap.
+                                let presets = settings::WboitParams::MAX_WEIGHT_PRESETS;
+                                let next = presets.iter()
+                                    .position(|&max_weight| max_weight == settings.wboit().max_weight())
+                                    .map_or(0, |index| (index + 1) % presets.len());
+                                settings.mut_wboit().set_max_weight(presets[next]);
+                                log::info!("Transparent fragment max weight: {}", settings.wboit().max_weight());
+                            } else if KeyCode::KeyY == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.sky.is_some() {
+                                    // (한국어) 그라디언트, 큐브맵(캡처한 큐브맵이 있는 경우), 배경 색상 순서로 바꿉니다.
+                                    // (English Translation) Switches in the order of gradient, cubemap (if a cubemap was captured) and clear color.
+                                    match (draw_resources.sky_settings.enabled(), draw_resources.sky_settings.mode()) {
+                                        (true, sky::SkyMode::Gradient) if sky_cubemap.is_some() => draw_resources.sky_settings.set_mode(sky::SkyMode::Cubemap), 
+                                        (true, _) => draw_resources.sky_settings.set_enabled(false), 
+                                        (false, _) => {
+                                            draw_resources.sky_settings.set_enabled(true);
+                                            draw_resources.sky_settings.set_mode(sky::SkyMode::Gradient);
+                                        }, 
+                                    }
+                                    log::info!("Sky background: {}", match draw_resources.sky_settings.enabled() {
+                                        true => format!("{:?}", draw_resources.sky_settings.mode()), 
+                                        false => "clear color".to_string(), 
+                                    });
+                                } else {
+                                    log::warn!("Sky background is not available in stereo rendering.");
+                                }
+                            } else if KeyCode::KeyX == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.hdr.is_some() {
+                                    draw_resources.tonemap_settings.set_operator(draw_resources.tonemap_settings.operator().next());
+                                    log::info!("Tone mapping operator: {:?}", draw_resources.tonemap_settings.operator());
+                                } else {
+                                    log::warn!("Tone mapping is not available in stereo rendering.");
+                                }
+                            } else if KeyCode::KeyU == code && event.state.is_pressed() && !event.repeat && stereo {
+                                log::warn!("Bloom is not available in stereo rendering.");
+                            } else if KeyCode::KeyU == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 블룸은 장면 파이프라인의 색상 형식을 바꾸므로 파이프라인과 렌더 타겟을 다시 생성합니다.
+                                // (English Translation) Bloom changes the color format of the scene pipelines, so the pipelines and render targets are recreated.
+                                let settings = pipeline::PipelineSettings { bloom: !renderer.resources.settings().bloom, ..*renderer.resources.settings() };
+                                renderer.resources.rebuild(pipeline_builder, settings);
+                                log::info!("Bloom: {}", renderer.resources.settings().bloom);
+                            } else if KeyCode::KeyJ == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.grid.is_some() {
+                                    draw_resources.grid_settings.set_enabled(!draw_resources.grid_settings.enabled());
+                                    log::info!("Ground grid: {}", draw_resources.grid_settings.enabled());
+                                } else {
+                                    log::warn!("Ground grid is not available in stereo rendering.");
+                                }
+                            } else if KeyCode::KeyM == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.sorted_transparent.is_some() {
+                                    sorted_blending = !sorted_blending;
+                                    log::info!("Transparency mode: {}", if sorted_blending { "sorted alpha blending" } else { "weighted blended OIT" });
+                                } else {
+                                    log::warn!("Sorted alpha blending requires the sorted transparent pipelines, which were not created.");
+                                }
+                            } else if KeyCode::KeyH == code && event.state.is_pressed() && !event.repeat {
+                                if renderer.resources.pipelines.wireframe.is_some() {
+                                    hidden_line = !hidden_line;
+                                    log::info!("Hidden-line mode: {}", hidden_line);
+                                } else {
+                                    log::warn!("Hidden-line mode requires the POLYGON_MODE_LINE feature, which is not supported.");
+                                }
+                            } else if KeyCode::KeyC == code && event.state.is_pressed() && !event.repeat && stereo {
+                                log::warn!("Cubemap capture is not available in stereo rendering.");
+                            } else if KeyCode::KeyC == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 카메라 위치에서 장면을 큐브맵으로 캡처합니다.
+                                // (English Translation) Captures the scene as a cubemap at the camera position.
+                                let context = scene_draw_context(
+                                    &renderer, 
+                                    &scene, 
+                                    &settings, 
+                                    &draw_resources, 
+                                    hidden_line, 
+                                    &pass_labels
+                                );
+                                match renderer::render_cubemap(
+                                    &device, 
+                                    &queue, 
+                                    &context, 
+                                    &camera_bind_group_layout, 
+                                    &oit_bind_group_layout, 
+                                    scene.ref_camera().get_position(), 
+                                    512
+                                ) {
+                                    Ok(cubemap) => {
+                                        log::info!("Captured cubemap: {:?}", cubemap.texture.size());
+                                        // (한국어) 캡처한 큐브맵을 하늘의 큐브 텍스처로 사용합니다. 큐브맵 텍스처는 바인드 그룹과 함께 유지합니다.
+                                        // (English Translation) Uses the captured cubemap as the cube texture of the sky. The cubemap texture is kept alive with the bind group.
+                                        draw_resources.sky_settings.set_cubemap(Some(sky::create_sky_cubemap_bind_group(&device, &sky_cubemap_bind_group_layout, &cubemap.cube_view)));
+                                        sky_cubemap = Some(cubemap);
+                                    }, 
+                                    Err(e) => log::error!("Failed to capture cubemap: {}", e), 
+                                }
+                            } else if KeyCode::KeyO == code && event.state.is_pressed() && !event.repeat && stereo {
+                                log::warn!("Turntable capture is not available in stereo rendering.");
+                            } else if KeyCode::KeyO == code && event.state.is_pressed() && !event.repeat {
+                                // (한국어) 카메라가 장면의 원점을 한 바퀴 도는 동안 프레임들을 PNG 파일로 저장합니다.
+                                // (English Translation) Saves frames as PNG files while the camera makes one full turn around the origin of the scene.
+                                let context = scene_draw_context(
+                                    &renderer, 
+                                    &scene, 
+                                    &settings, 
+                                    &draw_resources, 
+                                    hidden_line, 
+                                    &pass_labels
+                                );
+                                if let Err(e) = capture::turntable_capture(
+                                    &device, 
+                                    &queue, 
+                                    &context, 
+                                    &camera_bind_group_layout, 
+                                    &oit_bind_group_layout, 
+                                    &capture::TurntableSettings {
+                                        target: glam::Vec3::ZERO, 
+                                        start: scene.ref_camera().get_position(), 
+                                        frames: TURNTABLE_FRAMES, 
+                                        width: renderer.config().width, 
+                                        height: renderer.config().height, 
+                                    }, 
+                                    std::path::Path::new("turntable")
+                                ) {
+                                    log::error!("Failed to capture turntable: {}", e);
+                                }
                             }
                         }
                     },
@@ -441,17 +1120,133 @@ fn render_loop(
             }
         }
 
+        // (한국어) 창이 최소화되었거나 가려진 경우 화면 텍스처를 가져오지 않고 잠시 쉽니다. 크기가 0이 아닌 크기 변경 이벤트를 받으면 다시 그립니다.
+        // (English Translation) If the window is minimized or occluded, sleeps briefly without acquiring a surface texture. Drawing resumes on a resize to a non-zero size.
+        if minimized || occluded {
+            thread::sleep(PAUSED_SLEEP_DURATION);
+            continue;
+        }
+
+        // (한국어) 
+        // 쉐이더 파일이 바뀐 경우 새 쉐이더 모듈로 모든 파이프라인을 다시 생성하여 교체합니다.
+        // 쉐이더나 파이프라인의 검증에 실패한 경우 오류를 출력하고 마지막으로 성공한 파이프라인을 계속 사용합니다.
+        // 
+        // (English Translation) 
+        // If the shader file changed, recreates all pipelines with the new shader module and swaps them in.
+        // If validation of the shader or the pipelines fails, logs the error and keeps using the last good pipelines.
+        // 
+        #[cfg(feature = "hot-reload")]
+        if shader_watcher.poll_changed() {
+            let reloaded = shader_watcher.load_module(&device).and_then(|module| {
+                let builder = pipeline::PipelineSetBuilder { module: &module, ..pipeline_builder };
+                shader_reload::validate(&device, || renderer.resources.build_pipelines(builder))
+            });
+            match reloaded {
+                Ok(pipelines) => {
+                    renderer.resources.replace_pipelines(pipelines);
+                    log::info!("Reloaded shaders.");
+                },
+                Err(e) => log::error!("Failed to reload shaders, keeping the last good pipelines:\n{}", e), 
+            }
+        }
+
+        // (한국어) 이번 프레임에 누적된 마우스 이동량과 눌린 키로 카메라를 회전시키고 이동시킵니다.
+        // (English Translation) Rotates and moves the camera with the mouse motion accumulated and the keys pressed this frame.
+        // (한국어) 게임 시간이 멈추거나 느려진 동안에도 장면을 둘러볼 수 있도록 실제 시간을 사용합니다.
+        // (English Translation) Real time is used so the scene can be explored while game time is paused or slowed down.
+        fly_camera.apply(scene.mut_camera(), timer.real_elapsed_time_sec());
+
+        // (한국어) 사용자 정의 갱신 함수를 호출합니다. 장면의 쉐이더 리소스는 이후에 갱신됩니다.
+        // (English Translation) Calls the user-defined update function. The shader resources of the scene are updated afterwards.
+        update(&mut scene, &timer);
+
+        // (한국어) 게임 시간으로 오브젝트의 애니메이터들을 갱신합니다. 게임 시간이 멈춘 동안에는 움직이지 않습니다.
+        // (English Translation) Updates the animators of the objects with game time. They do not move while game time is paused.
+        scene.animate(timer.elapsed_time_sec());
+
+        // (한국어) 기둥의 위쪽 뼈를 기둥 가운데 높이를 축으로 게임 시간에 따라 흔듭니다.
+        // (English Translation) Sways the upper bone of the column about the middle height of the column with game time.
+        let sway = glam::Mat4::from_translation(glam::Vec3::Y)
+            * glam::Mat4::from_rotation_z(timer.total_game_time_sec().sin() * 45f32.to_radians())
+            * glam::Mat4::from_translation(glam::Vec3::NEG_Y);
+        for skinned_object in draw_resources.skinned_objects.iter_mut() {
+            skinned_object.mut_skeleton().set_bone_matrix(1, sway);
+            skinned_object.ref_skeleton().update_shader_resource(&queue);
+        }
+
+        // (한국어) 차체를 게임 시간에 따라 돌리고 포탑을 차체 위에서 반대 방향으로 돌린 후, 계층 구조의 월드 변환 행렬을 오브젝트에 설정합니다.
+        // (English Translation) Turns the hull with game time and the turret the other way on top of it, then sets the world transforms of the hierarchy on the objects.
+        let time = timer.total_game_time_sec();
+        hierarchy_nodes[0].local_transform = glam::Mat4::from_rotation_translation(
+            glam::Quat::from_rotation_y(time * 30f32.to_radians()), 
+            hull_position
+        );
+        hierarchy_nodes[1].local_transform = glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::splat(0.5), 
+            glam::Quat::from_rotation_y(-time * 90f32.to_radians()), 
+            turret_offset
+        );
+        objects::apply_world_transforms(&hierarchy_nodes, &mut scene.opaque_objects[hierarchy_start..]);
+
+        // (한국어) 
+        // 커서 아래에 있는 오브젝트를 찾아 강조합니다.
+        // 스테레오 렌더링인 경우 커서가 있는 쪽 눈의 화면을 기준으로 광선을 계산합니다.
+        // 
+        // (English Translation) 
+        // Finds and highlights the object under the cursor.
+        // For stereo rendering, the ray is computed relative to the screen of the eye the cursor is on.
+        // 
+        let hovered = cursor_position
+            .filter(|_| settings.hover_highlight())
+            .and_then(|cursor| {
+                let size = glam::Vec2::new((renderer.config().width / views) as f32, renderer.config().height as f32);
+                let eye = (cursor.x / size.x.max(1.0)).floor().clamp(0.0, (views - 1) as f32);
+                let min = glam::Vec2::new(eye * size.x, 0.0);
+                let viewport = interfaces::Rect::new(min, min + size);
+                scene.pick(&scene.ref_camera().viewport_ray(cursor, viewport))
+            });
+        scene.set_highlighted(hovered);
+
+        // (한국어) 장면의 쉐이더 리소스를 갱신합니다.
+        // (English Translation) Updates the shader resources of the scene.
+        scene.update_all(&queue);
+
+        // (한국어) 오브젝트들을 컬링하고 결과를 저장합니다.
+        // (English Translation) Culls the objects and stores the result.
+        scene.cull();
+
+        // (한국어) 정렬된 알파 블렌딩을 사용하는 경우 투명한 오브젝트들을 뒤에서 앞으로 정렬합니다.
+        // (English Translation) If sorted alpha blending is used, sorts the transparent objects back to front.
+        if sorted_blending {
+            renderer::sort_back_to_front(scene.ref_camera(), &scene.transparent_objects, &mut transparent_order);
+        }
+
         // (한국어) 오브젝트들을 그립니다.
         // (English Translation) Draws the objects.
         window.pre_present_notify();
         
         // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
         // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
+        // (한국어) 차단 폴링을 끈 경우 기다리지 않고 스왑체인이 CPU가 앞서가는 것을 제한합니다.
+        // (English Translation) If blocking poll is off, it does not wait and the swapchain limits how far the CPU runs ahead.
+        device.poll(match settings.blocking_poll() {
+            true => wgpu::Maintain::Wait, 
+            false => wgpu::Maintain::Poll, 
+        });
 
         // (한국어) 다음 프레임을 가져옵니다.
         // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture().unwrap();
+        // (한국어) 
+        // 화면을 잃었거나(Lost) 오래된(Outdated) 경우 저장된 설정으로 화면을 재설정하고 이번 프레임을 건너뜁니다.
+        // 시간 초과(Timeout)인 경우 이번 프레임을 건너뛰며, 메모리가 부족한(OutOfMemory) 경우에만 실패합니다.
+        // 
+        // (English Translation) 
+        // If the surface is lost or outdated, reconfigures it with the stored configuration and skips this frame.
+        // On timeout this frame is skipped, and only running out of memory is a hard failure.
+        // 
+        let Some(frame) = renderer.acquire_frame() else {
+            continue;
+        };
 
         // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
         // (English Translation) Creates a texture view of render target.
@@ -462,164 +1257,104 @@ fn render_loop(
         // (한국어) 커맨드 버퍼를 생성합니다.
         // (English Translation) Creates a command buffer. 
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-        {
-            // <1>
-            // (한국어) 
-            // 불투명한 색상 오브젝트들을 그립니다.
-            // 
-            // 이때, 깊이 버퍼를 이용하여 오브젝트들의 깊이 값을 저장합니다.
-            // 
-            // (English Translation) 
-            // Draws opaque colored objects. 
-            //
-            // At this time, the depth value of the objects is stored using the depth buffer. 
-            // 
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(Opaque)"), 
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &render_target_view, 
-                            resolve_target: None, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), 
-                                store: wgpu::StoreOp::Store, 
-                            }, 
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: &depth_stencil_view, 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }), 
-                    timestamp_writes: None, 
-                    occlusion_query_set: None, 
-                },
-            );
-
-            rpass.set_pipeline(&opaque_pipeline);
-            rpass.set_bind_group(0, camera.ref_bind_group(), &[]);
-            rpass.set_vertex_buffer(0, quad_mesh_strip.slice(..));
-            for object in opaque_objects.iter() {
-                rpass.set_bind_group(1, object.ref_bind_group(), &[]);
-                rpass.draw(0..4, 0..1);
-            }
+        let context = renderer::DrawContext {
+            culling: Some(scene.culling_result()), 
+            timestamps: pass_timestamps.as_ref(), 
+            sorted_transparent: sorted_blending.then_some(transparent_order.as_slice()), 
+            ..scene_draw_context(
+                &renderer, 
+                &scene, 
+                &settings, 
+                &draw_resources, 
+                hidden_line, 
+                &pass_labels
+            )
+        };
+        renderer.render(&mut encoder, &context, scene.ref_camera().ref_bind_group(), &frame, &render_target_view);
+
+        // (한국어) 이름이 있는 오브젝트 위에 레이블을 그립니다. (스테레오 렌더링에서는 지원하지 않습니다.)
+        // (English Translation) Draws labels above named objects. (not supported in stereo rendering.)
+        #[cfg(feature = "labels")]
+        if renderer.resources.stereo_target.is_none() {
+            let labels = scene.opaque_objects.iter()
+                .chain(scene.decal_objects.iter())
+                .chain(scene.transparent_objects.iter())
+                .filter(|object| object.is_visible())
+                .filter_map(|object| object.name().map(|text| {
+                    let (center, radius) = object.bounding_sphere();
+                    labels::Label { center, radius, text }
+                }));
+            let viewport = glam::Vec2::new(renderer.config().width as f32, renderer.config().height as f32);
+            label_renderer.prepare(&device, &queue, scene.ref_camera(), viewport, labels);
+            label_renderer.draw(&mut encoder, &render_target_view);
         }
 
-        {
-            // <2>
-            // (한국어) 
-            // 투명한 색상의 오브젝트들을 그립니다.
-            // 
-            // 누적 값을 저장하는 버퍼는 0으로, 노출 값을 저장하는 버퍼는 1로 초기화 합니다.
-            //
-            // 깊이 버퍼를 읽어서 투명한 오브젝트가 가려지는지 확인하고, 가려지는 투명한 오브젝트는 그리지 않습니다.
-            // 
-            // (English Translation) 
-            // Draws transparent colored objects. 
-            // 
-            // The buffer that stores the accumulate value is initialized to 0, 
-            // and the buffer that stores the revealage value is initialized to 1.
-            // 
-            // Reads the depth buffer to determine whether transparent objects are occluded, 
-            // and does not draw transparent objects that are occluded.
-            // 
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(Transparent)"), 
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &accum_texture_view, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color { 
-                                    r: 0.0, 
-                                    g: 0.0, 
-                                    b: 0.0, 
-                                    a: 0.0, 
-                                }), 
-                                store: wgpu::StoreOp::Store,
-                            },
-                            resolve_target: None,
-                        }),
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &reveal_texture_view, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color {
-                                    r: 1.0, 
-                                    g: 1.0, 
-                                    b: 1.0, 
-                                    a: 1.0,
-                                }), 
-                                store: wgpu::StoreOp::Store, 
-                            },
-                            resolve_target: None,
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Load, 
-                            store: wgpu::StoreOp::Store, 
-                        }),
-                        view: &depth_stencil_view, 
-                        stencil_ops: None,
-                    }), 
-                    timestamp_writes: None,
-                    occlusion_query_set: None, 
-                }
-            );
-
-            rpass.set_pipeline(&transparent_pipeline);
-            rpass.set_bind_group(0, camera.ref_bind_group(), &[]);
-            rpass.set_vertex_buffer(0, quad_mesh_strip.slice(..));
-            for object in transparent_objects.iter() {
-                rpass.set_bind_group(1, object.ref_bind_group(), &[]);
-                rpass.draw(0..4, 0..1);
+        // (한국어) 명령 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
+        // (English Translation) Submit command buffer to the queue and output to the framebuffer. 
+        // (한국어) 렌더 패스의 타임스탬프 쿼리 결과를 읽기 버퍼로 복사합니다.
+        // (English Translation) Copies the timestamp query results of the render passes into the readback buffer.
+        if let Some(timestamps) = pass_timestamps.as_ref() {
+            timestamps.resolve(&mut encoder);
+        }
+
+        queue.submit(Some(encoder.finish()));
+
+        // (한국어) 
+        // 요청된 경우 오브젝트 ID 패스를 그려 커서 아래의 불투명한 오브젝트를 찾습니다.
+        // 광선 선택과 비교할 수 있도록 두 결과를 함께 출력합니다.
+        // 
+        // (English Translation) 
+        // If requested, draws the object ID pass and finds the opaque object under the cursor.
+        // Both results are printed so that it can be compared with ray picking.
+        // 
+        if pick_requested {
+            pick_requested = false;
+            if let Some(cursor) = cursor_position {
+                let picked = object_picker.pick(&device, &queue, &context, scene.ref_camera().ref_bind_group(), cursor);
+                let name = picked
+                    .and_then(|index| scene.opaque_objects.get(index))
+                    .and_then(|object| object.name());
+                log::info!("Picked object: {:?} ({:?}), ray picking: {:?}", picked, name, scene.highlighted());
             }
         }
 
-        {
-            // <3>
-            // (한국어) 불투명한 색상의 오브젝트와 투명한 색상의 오브젝트를 합성합니다. 
-            // (English Translation) Combines opaque colored objects with transparent colored objects. 
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(Composite)"), 
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &render_target_view, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load, 
-                                store: wgpu::StoreOp::Store,
-                            },
-                            resolve_target: None,
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: &depth_stencil_view, 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Load, 
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }), 
-                    timestamp_writes: None,
-                    occlusion_query_set: None, 
-                }
-            );
+        // (한국어) 요청된 경우 표시하기 전에 화면 텍스처를 PNG 파일로 저장합니다.
+        // (English Translation) If requested, saves the surface texture as a PNG file before presenting.
+        if screenshot_requested {
+            screenshot_requested = false;
+            let path = std::path::PathBuf::from(format!("screenshot_{:06}.png", timer.frame_count()));
+            match capture::save_screenshot(&device, &queue, &frame.texture, &path) {
+                Ok(()) => log::info!("Saved screenshot to {}", path.display()), 
+                Err(e) => log::error!("Failed to save screenshot: {}", e), 
+            }
+        }
 
-            rpass.set_pipeline(&composite_pipeline);
-            rpass.set_bind_group(0, &oit_bind_group, &[]);
-            rpass.set_vertex_buffer(0, quad_mesh_strip.slice(..));
-            rpass.draw(0..4, 0..1);
+        // (한국어) `--smoke-dump` 인자가 주어진 경우 처음 그린 프레임을 표시하기 전에 PNG 파일로 저장합니다.
+        // (English Translation) If the `--smoke-dump` argument is given, saves the first drawn frame as a PNG file before presenting.
+        if let Some(path) = smoke_dump.take() {
+            match screenshot_supported {
+                true => match capture::save_screenshot(&device, &queue, &frame.texture, &path) {
+                    Ok(()) => log::info!("Saved smoke test frame to {}", path.display()), 
+                    Err(e) => log::error!("Failed to save smoke test frame: {}", e), 
+                }, 
+                false => log::error!("Failed to save smoke test frame: the surface does not support the COPY_SRC usage."), 
+            }
         }
 
-        // (한국어) 명령 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffer to the queue and output to the framebuffer. 
-        queue.submit(Some(encoder.finish()));
         frame.present();
+
+        // (한국어) 제출한 명령이 끝난 후 렌더 패스별 GPU 시간을 읽어옵니다.
+        // (English Translation) Reads the GPU time per render pass after the submitted commands finish.
+        if let Some(timings) = pass_timestamps.as_ref().and_then(|timestamps| timestamps.read_timings(&device)) {
+            pass_timings = timings;
+        }
+
+        // (한국어) 프레임 제한에 도달한 경우 렌더링 루프를 종료합니다.
+        // (English Translation) Ends the rendering loop when the frame limit is reached.
+        if frame_limit.is_some_and(|limit| timer.frame_count() >= limit) {
+            log::info!("Reached the frame limit. ({} frames)", timer.frame_count());
+            IS_RUNNING.store(false, MemOrdering::Release);
+        }
     }
 
     log::info!("Finish Rendering loop.");
@@ -629,9 +1364,114 @@ fn main() {
     env_logger::init();
     log::info!("❖ Application Launching ❖");
     
+    // (한국어) 
+    // `--smoke` 인자가 주어진 경우 한 프레임만 그린 후 종료합니다. 
+    // 모든 초기화와 렌더링 과정이 동작하는지 빠르게 확인하는 데 사용합니다.
+    // 
+    // (English Translation) 
+    // If the `--smoke` argument is given, exits after drawing only one frame. 
+    // Used to quickly verify that all initialization and rendering steps work.
+    // 
+    // (한국어) `--smoke-dump <경로>` 인자가 주어진 경우 `--smoke`와 같이 동작하며, 그린 프레임을 PNG 파일로 저장합니다.
+    // (English Translation) If the `--smoke-dump <path>` argument is given, behaves like `--smoke` and saves the drawn frame as a PNG file.
+    let smoke_dump = std::env::args()
+        .skip_while(|arg| arg != "--smoke-dump")
+        .nth(1)
+        .map(std::path::PathBuf::from);
+    let smoke = smoke_dump.is_some() || std::env::args().any(|arg| arg == "--smoke");
+    let frame_limit = smoke.then_some(1);
+
+    // (한국어) `--stereo` 인자가 주어진 경우 두 눈의 장면을 한 번의 패스로 그려 화면에 나란히 표시합니다.
+    // (English Translation) If the `--stereo` argument is given, draws the scenes of both eyes in one pass and shows them side by side.
+    let stereo = std::env::args().any(|arg| arg == "--stereo");
+
+    // (한국어) 
+    // `--oit-scale=<배율>` 인자가 주어진 경우 투명 패스를 장면 해상도의 배율로 그린 후 업샘플링 합니다. (예: `--oit-scale=0.5`)
+    // 높은 해상도에서 투명도 비용을 줄이며, 투명한 오브젝트의 경계가 조금 흐려집니다.
+    // 
+    // (English Translation) 
+    // If the `--oit-scale=<scale>` argument is given, draws the transparent pass at that fraction of the scene resolution and upsamples it. (e.g. `--oit-scale=0.5`)
+    // Reduces the cost of transparency at high resolutions, at the price of slightly blurrier edges of transparent objects.
+    // 
+    let oit_scale = std::env::args()
+        .find_map(|arg| arg.strip_prefix("--oit-scale=").and_then(|scale| scale.parse::<f32>().ok()))
+        .unwrap_or(1.0);
+
+    // (한국어) 
+    // `--depth-peeling` 인자가 주어진 경우 가중 블렌딩 OIT 대신 깊이 벗기기로 투명한 오브젝트를 그립니다.
+    // `--depth-peeling=<레이어 수>`로 벗길 레이어의 수를 정할 수 있습니다. (기본값: `DepthPeelPipelines::DEFAULT_LAYERS`)
+    // 겹치는 투명 표면의 색상이 정확한 대신 레이어마다 두 번의 패스가 추가됩니다.
+    // 
+    // (English Translation) 
+    // If the `--depth-peeling` argument is given, draws transparent objects with depth peeling instead of weighted blended OIT.
+    // The number of layers to peel can be set with `--depth-peeling=<layers>`. (default: `DepthPeelPipelines::DEFAULT_LAYERS`)
+    // Overlapping transparent surfaces get accurate colors, at the cost of two extra passes per layer.
+    // 
+    let depth_peel_layers = std::env::args().find_map(|arg| match arg.as_str() {
+        "--depth-peeling" => Some(pipeline::DepthPeelPipelines::DEFAULT_LAYERS), 
+        _ => arg.strip_prefix("--depth-peeling=").and_then(|layers| layers.parse::<u32>().ok()), 
+    });
+
+    // (한국어) 
+    // `--depth-prepass` 인자가 주어진 경우 불투명 패스 전에 깊이 값만 기록하는 깊이 사전 패스를 그립니다.
+    // 불투명 패스는 보이는 조각만 색상을 계산하므로, 조각 쉐이더의 비용이 큰 경우에만 이득이 있습니다.
+    // 
+    // (English Translation) 
+    // If the `--depth-prepass` argument is given, draws a depth pre-pass that writes only depth values before the opaque pass.
+    // The opaque pass then shades only visible fragments, so it only pays off when the fragment shader is expensive.
+    // 
+    let depth_prepass = std::env::args().any(|arg| arg == "--depth-prepass");
+
+    // (한국어) 
+    // `--left-handed` 인자가 주어진 경우 카메라가 왼손 좌표계(`perspective_lh`, `+z` 방향을 바라봄)를 사용합니다.
+    // 장면은 오른손 좌표계로 배치되어 있으므로 좌우가 뒤집혀 보이며, 왼손 좌표계 도구에서 가져온 에셋을 확인할 때 사용합니다.
+    // 
+    // (English Translation) 
+    // If the `--left-handed` argument is given, the cameras use a left-handed coordinate system (`perspective_lh`, looking down `+z`).
+    // The scene is laid out right-handed, so it appears mirrored. Used to check assets imported from left-handed tools.
+    // 
+    let handedness = match std::env::args().any(|arg| arg == "--left-handed") {
+        true => interfaces::Handedness::LeftHanded, 
+        false => interfaces::Handedness::RightHanded, 
+    };
+    let options = RenderOptions { frame_limit, smoke_dump, stereo, oit_scale, depth_peel_layers, depth_prepass, handedness };
+
+    // (한국어) 
+    // `--adapter=<번호|이름>` 인자가 주어진 경우 해당 번호 또는 이름의 일부를 포함하는 렌더링 어뎁터를 사용합니다. (예: `--adapter=1`, `--adapter=nvidia`)
+    // 여러 GPU가 있는 환경(통합 GPU가 기본으로 선택되는 노트북 등)에서 성능을 비교할 때 사용합니다.
+    // 
+    // (English Translation) 
+    // If the `--adapter=<index|name>` argument is given, uses the rendering adapter with that index or whose name contains it. (e.g. `--adapter=1`, `--adapter=nvidia`)
+    // Used when benchmarking on machines with several GPUs (e.g. laptops where the integrated GPU is picked by default).
+    // 
+    let adapter_selector = std::env::args()
+        .find_map(|arg| arg.strip_prefix("--adapter=").map(utils::AdapterSelector::parse));
+
+    // (한국어) 
+    // `--headless=<경로>` 인자가 주어진 경우 창을 만들지 않고 테스트 장면을 한 번 그려 PNG 파일로 저장한 후 종료합니다.
+    // 표면 설정과 표시(present)는 생략되며, 자동화된 이미지 테스트(CI 등)에 사용합니다.
+    // 
+    // (English Translation) 
+    // If the `--headless=<path>` argument is given, draws the test scene once without creating a window, saves it as a PNG file and exits.
+    // Surface configuration and presenting are skipped. Used for automated image tests (CI, etc.).
+    // 
+    if let Some(path) = std::env::args().find_map(|arg| arg.strip_prefix("--headless=").map(std::path::PathBuf::from)) {
+        let (_instance, adapter, device, queue) = utils::setup_headless_rendering_system(adapter_selector.as_ref(), &utils::DeviceRequest::default());
+        if let Err(e) = headless::render_headless(&adapter, &device, &queue, HEADLESS_WIDTH, HEADLESS_HEIGHT, &path) {
+            log::error!("Failed to render headless: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // (한국어) 렌더링 스레드가 멈춘 경우에도 창 이벤트가 무한히 쌓이지 않도록 대기열의 용량을 제한합니다.
+    // (English Translation) Limits the capacity of the queue so that window events do not pile up without bound even if the rendering thread stalls.
+    EVENT_QUEUE.set_capacity(Some(EVENT_QUEUE_CAPACITY));
+
     // (한국어) 창 시스템을 초기화 합니다.
     // (English Translation) Initializes the window system.
     let event_loop = EventLoop::new().unwrap();
+    let event_loop_proxy = event_loop.create_proxy();
     let window = Arc::new(
         WindowBuilder::new()
             .with_visible(true)
@@ -644,32 +1484,47 @@ fn main() {
     // (한국어) 렌더링 시스템을 초기화 합니다.
     // (English Translation) Initialize the rendering system.
     let window_cloned = window.clone();
-    let (instance, surface, adapter, device, queue) = utils::setup_rendering_system(window_cloned);
+    let (instance, surface, adapter, device, queue) = utils::setup_rendering_system(window_cloned, adapter_selector.as_ref(), &utils::DeviceRequest::default());
 
     // (한국어) 새로운 스레드에서 렌더링 루프를 실행합니다.
     // (English Translation) Runs the rendering loop in a new thread.
     let window_cloned = window.clone();
-    let instance_cloned = instance.clone();
-    let mut join = Some(thread::spawn(move || render_loop(
-        window_cloned, 
-        instance_cloned, 
-        surface, 
-        adapter, 
-        device, 
-        queue
-    )));
+    let mut join = Some(thread::spawn(move || {
+        render_loop(
+            window_cloned, 
+            surface, 
+            adapter, 
+            device, 
+            queue, 
+            options, 
+            |_scene, _timer| { /*--- empty ---*/ }
+        );
+
+        // (한국어) 렌더링 루프가 끝났음을 윈도우 메시지 루프에 알립니다.
+        // (English Translation) Notifies the window message loop that the rendering loop has finished.
+        let _ = event_loop_proxy.send_event(());
+    }));
 
     // (한국어) 윈도우 메시지 루프를 실행합니다.
     // (English Translation) Runs the window message loop.
     log::info!("Run Window message loop.");
     event_loop.set_control_flow(ControlFlow::Wait);
     event_loop.run(move |event, elwt| {
-        // (한국어) 현재 렌더링 스레드가 실행 중인지 확인합니다.
-        // (English Translation) Checks if the current rendering thread is running.
-        if join.as_ref().is_some_and(|join| join.is_finished()) {
+        // (한국어) 
+        // 현재 렌더링 스레드가 실행 중인지 확인합니다.
+        // 렌더링 루프가 끝났음을 알리는 사용자 이벤트를 받은 경우에도 종료합니다.
+        // 
+        // (English Translation) 
+        // Checks if the current rendering thread is running.
+        // Also quits when the user event notifying the end of the rendering loop is received.
+        // 
+        let finished = matches!(event, Event::UserEvent(()));
+        if finished || join.as_ref().is_some_and(|join| join.is_finished()) {
             // (한국어) 렌더링 스레드를 join 합니다.
             // (English Translation) Join the rendering thread.
-            join.take().unwrap().join().unwrap();
+            if let Some(join) = join.take() {
+                join.join().unwrap();
+            }
 
             // (한국어) 애플리케이션을 종료합니다.
             // (English Translation) Quit the application.
@@ -0,0 +1,745 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::mem;
+use std::ops::Range;
+use std::path::Path;
+
+
+
+/// #### 한국어 </br>
+/// [`GpuMesh`]의 정점 버퍼에 저장되는 정점 형식 입니다. (위치 0: `Float32x3` 위치, 위치 1: `Float32x3` 로컬 좌표계 법선) </br>
+/// 정점 버퍼와 색상 오브젝트 파이프라인의 정점 레이아웃이 모두 이 형식에서 만들어지므로, 속성을 추가할 때 이 곳만 고치면 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex format stored in the vertex buffer of a [`GpuMesh`]. (location 0: `Float32x3` position, location 1: `Float32x3` local space normal) </br>
+/// Both the vertex buffers and the vertex layout of the colored object pipelines are made from this format, so adding an attribute only needs changes here. </br>
+/// 
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    pub position: [f32; 3], 
+    pub normal: [f32; 3], 
+}
+
+#[allow(dead_code)]
+impl Vertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 2] = wgpu::vertex_attr_array![
+        0 => Float32x3, 
+        1 => Float32x3
+    ];
+
+    #[inline]
+    pub const fn new(position: [f32; 3], normal: [f32; 3]) -> Self {
+        Self { position, normal }
+    }
+
+    /// #### 한국어 </br>
+    /// 정점 단위로 진행하는 정점 버퍼 레이아웃을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the vertex buffer layout that steps per vertex. </br>
+    /// 
+    #[inline]
+    pub fn vertex_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            step_mode: wgpu::VertexStepMode::Vertex, 
+            array_stride: mem::size_of::<Self>() as wgpu::BufferAddress, 
+            attributes: &Self::ATTRIBUTES, 
+        }
+    }
+}
+
+// (한국어) 정점 레이아웃의 간격이 정점 버퍼에 쓰이는 원소의 크기와 다른 경우 컴파일 오류가 발생하도록 합니다.
+// (English Translation) Fails to compile if the stride of the vertex layout differs from the size of the element written into vertex buffers.
+const _: () = assert!(mem::size_of::<Vertex>() == mem::size_of::<[[f32; 3]; 2]>());
+
+/// #### 한국어 </br>
+/// 모든 색상 오브젝트가 공유하는 사각형 메쉬의 정점들 입니다. (로컬 좌표계, 삼각형 띠 순서) </br>
+/// 사각형은 로컬 XY 평면 위에 있으므로, 모든 정점의 법선은 로컬 Z 축 입니다. </br>
+/// 정점 버퍼([`GpuMesh::quad`])와 경계 계산(`ColoredObject::world_aabb`)이 같은 값을 사용해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertices of the quad mesh shared by all colored objects. (local coordinates, triangle strip order) </br>
+/// The quad lies on the local XY plane, so the normal of every vertex is the local Z axis. </br>
+/// The vertex buffer ([`GpuMesh::quad`]) and the bounds computation (`ColoredObject::world_aabb`) must use the same values. </br>
+/// 
+pub const QUAD_VERTICES: [Vertex; 4] = [
+    Vertex::new([-1.0, -1.0, 0.0], [0.0, 0.0, 1.0]), 
+    Vertex::new([-1.0, 1.0, 0.0], [0.0, 0.0, 1.0]), 
+    Vertex::new([1.0, -1.0, 0.0], [0.0, 0.0, 1.0]), 
+    Vertex::new([1.0, 1.0, 0.0], [0.0, 0.0, 1.0]), 
+];
+
+/// #### 한국어 </br>
+/// CPU 메모리에 있는 삼각형 목록 메쉬 입니다. (위치, 정점 법선, 인덱스) </br>
+/// 외부 파일에서 가져온 메쉬를 GPU 버퍼에 올리기 전에 검사하고 고치는 데 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A triangle list mesh in CPU memory. (positions, vertex normals, indices) </br>
+/// Used to check and fix meshes imported from external files before uploading them to GPU buffers. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Mesh {
+    pub positions: Vec<glam::Vec3>, 
+    pub normals: Vec<glam::Vec3>, 
+    pub indices: Vec<u32>, 
+}
+
+/// #### 한국어 </br>
+/// 삼각형 감김 순서 검사 결과 입니다. </br>
+/// 
+/// - `triangles`: 검사한 삼각형의 수. </br>
+/// - `inconsistent`: 감김 순서가 정점 법선과 반대인 삼각형의 수. </br>
+/// - `degenerate`: 넓이가 없어 방향을 알 수 없는 삼각형의 수. (검사에서 제외됨) </br>
+/// - `fixed`: 감김 순서를 뒤집은 삼각형의 수. (`fix_winding`에서만 0이 아님) </br>
+/// 
+/// #### English (Translation) </br>
+/// The result of checking triangle winding. </br>
+/// 
+/// - `triangles`: the number of triangles checked. </br>
+/// - `inconsistent`: the number of triangles whose winding is opposite to the vertex normals. </br>
+/// - `degenerate`: the number of triangles with no area whose orientation is unknown. (excluded from the check) </br>
+/// - `fixed`: the number of triangles whose winding was flipped. (non-zero only in `fix_winding`) </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WindingReport {
+    pub triangles: usize, 
+    pub inconsistent: usize, 
+    pub degenerate: usize, 
+    pub fixed: usize, 
+}
+
+#[allow(dead_code)]
+impl WindingReport {
+    #[inline]
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistent == self.fixed
+    }
+}
+
+impl fmt::Display for WindingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f, 
+            "{} triangles, {} inconsistent, {} degenerate, {} fixed", 
+            self.triangles, 
+            self.inconsistent, 
+            self.degenerate, 
+            self.fixed
+        )
+    }
+}
+
+#[allow(dead_code)]
+impl Mesh {
+    #[inline]
+    pub fn new(positions: Vec<glam::Vec3>, normals: Vec<glam::Vec3>, indices: Vec<u32>) -> Self {
+        Self { positions, normals, indices }
+    }
+
+    #[inline]
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    /// #### 한국어 </br>
+    /// 삼각형의 감김 순서가 정점 법선과 같은 방향을 향하는지 검사합니다. </br>
+    /// 감김 순서로 계산한 면 법선과 세 정점 법선의 합을 비교하며, `front_face`는 앞면의 감김 순서 입니다. </br>
+    /// 정점 법선이 없거나 인덱스가 범위를 벗어난 삼각형은 퇴화된 삼각형으로 셉니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the winding of each triangle faces the same way as its vertex normals. </br>
+    /// The face normal computed from the winding is compared against the sum of the three vertex normals, and `front_face` is the winding of front faces. </br>
+    /// Triangles without vertex normals or with out-of-range indices are counted as degenerate. </br>
+    /// 
+    pub fn validate_winding(&self, front_face: wgpu::FrontFace) -> WindingReport {
+        let mut report = WindingReport { triangles: self.triangle_count(), ..Default::default() };
+        for triangle in self.indices.chunks_exact(3) {
+            match self.is_flipped(triangle, front_face) {
+                Some(true) => report.inconsistent += 1, 
+                Some(false) => { /*--- empty ---*/ }, 
+                None => report.degenerate += 1, 
+            }
+        }
+        report
+    }
+
+    /// #### 한국어 </br>
+    /// 감김 순서가 정점 법선과 반대인 삼각형의 두 정점을 바꾸어 뒤집고, 결과를 반환합니다. </br>
+    /// 후면 컬링을 사용할 때 메쉬의 일부가 보이지 않는 문제를 막습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Flips triangles whose winding is opposite to the vertex normals by swapping two of their vertices, and returns the result. </br>
+    /// Prevents parts of the mesh from disappearing when back-face culling is used. </br>
+    /// 
+    pub fn fix_winding(&mut self, front_face: wgpu::FrontFace) -> WindingReport {
+        let mut report = WindingReport { triangles: self.triangle_count(), ..Default::default() };
+        for i in (0..report.triangles * 3).step_by(3) {
+            match self.is_flipped(&self.indices[i..i + 3], front_face) {
+                Some(true) => {
+                    self.indices.swap(i + 1, i + 2);
+                    report.inconsistent += 1;
+                    report.fixed += 1;
+                }, 
+                Some(false) => { /*--- empty ---*/ }, 
+                None => report.degenerate += 1, 
+            }
+        }
+
+        if report.fixed > 0 {
+            log::warn!("Fixed the winding of mesh triangles: {}", report);
+        }
+        report
+    }
+
+    /// #### 한국어 </br>
+    /// 삼각형의 감김 순서가 정점 법선과 반대인지 확인합니다. 판단할 수 없는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the winding of a triangle is opposite to its vertex normals. Returns `None` if it cannot be determined. </br>
+    /// 
+    fn is_flipped(&self, triangle: &[u32], front_face: wgpu::FrontFace) -> Option<bool> {
+        let mut positions = [glam::Vec3::ZERO; 3];
+        let mut normal = glam::Vec3::ZERO;
+        for (position, &index) in positions.iter_mut().zip(triangle.iter()) {
+            *position = *self.positions.get(index as usize)?;
+            normal += *self.normals.get(index as usize)?;
+        }
+
+        // (한국어) 오른손 좌표계에서 반시계 방향 감김 순서의 면 법선은 외적의 방향과 같습니다.
+        // (English Translation) In a right-handed coordinate system, the face normal of a counter-clockwise winding follows the cross product.
+        let face_normal = (positions[1] - positions[0]).cross(positions[2] - positions[0]);
+        let face_normal = match front_face {
+            wgpu::FrontFace::Ccw => face_normal, 
+            wgpu::FrontFace::Cw => -face_normal, 
+        };
+
+        let dot = face_normal.dot(normal);
+        if face_normal.length_squared() <= f32::EPSILON * f32::EPSILON || dot.abs() <= f32::EPSILON {
+            return None;
+        }
+        Some(dot < 0.0)
+    }
+}
+
+/// #### 한국어 </br>
+/// Wavefront OBJ 파일을 불러올 때 발생하는 오류 입니다. `line`은 1부터 시작하는 줄 번호 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An error that occurs when loading a Wavefront OBJ file. `line` is the 1-based line number. </br>
+/// 
+#[derive(Debug)]
+pub enum ObjError {
+    Io(io::Error), 
+    InvalidNumber { line: usize }, 
+    InvalidFace { line: usize }, 
+    IndexOutOfRange { line: usize, index: i64 }, 
+    Empty, 
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "failed to read the OBJ file: {}", e), 
+            Self::InvalidNumber { line } => write!(f, "invalid number at line {}", line), 
+            Self::InvalidFace { line } => write!(f, "face with fewer than three vertices at line {}", line), 
+            Self::IndexOutOfRange { line, index } => write!(f, "vertex index {} is out of range at line {}", index, line), 
+            Self::Empty => write!(f, "the OBJ file has no faces"), 
+        }
+    }
+}
+
+impl std::error::Error for ObjError { }
+
+impl From<io::Error> for ObjError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+#[allow(dead_code)]
+impl Mesh {
+    /// #### 한국어 </br>
+    /// Wavefront OBJ 텍스트에서 정점 위치(`v`)와 면(`f`)을 읽어 메쉬를 만듭니다. </br>
+    /// 면의 정점은 `v`, `v/vt`, `v//vn`, `v/vt/vn` 형식과 음수(상대) 인덱스를 지원하며, 위치 인덱스만 사용합니다. </br>
+    /// 네 개 이상의 정점을 가진 볼록 다각형 면은 부채꼴로 삼각형 분할합니다. </br>
+    /// 파일의 법선과 텍스처 좌표는 무시하며, 정점 법선은 `compute_normals`로 다시 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds a mesh by reading the vertex positions (`v`) and faces (`f`) from Wavefront OBJ text. </br>
+    /// Face vertices support the `v`, `v/vt`, `v//vn` and `v/vt/vn` forms and negative (relative) indices, and only the position index is used. </br>
+    /// Convex polygon faces with four or more vertices are triangulated as a fan. </br>
+    /// Normals and texture coordinates in the file are ignored, and vertex normals are recomputed with `compute_normals`. </br>
+    /// 
+    pub fn parse_obj(source: &str) -> Result<Self, ObjError> {
+        let mut positions = Vec::new();
+        let mut indices = Vec::new();
+        let mut face = Vec::new();
+        for (number, line) in source.lines().enumerate() {
+            let line_number = number + 1;
+            let line = line.split('#').next().unwrap_or_default();
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let mut position = [0.0f32; 3];
+                    for value in position.iter_mut() {
+                        *value = tokens.next()
+                            .and_then(|token| token.parse().ok())
+                            .ok_or(ObjError::InvalidNumber { line: line_number })?;
+                    }
+                    positions.push(glam::Vec3::from_array(position));
+                }, 
+                Some("f") => {
+                    face.clear();
+                    for token in tokens {
+                        let index: i64 = token.split('/').next()
+                            .and_then(|index| index.parse().ok())
+                            .ok_or(ObjError::InvalidNumber { line: line_number })?;
+
+                        // (한국어) OBJ 인덱스는 1부터 시작하며, 음수는 지금까지 읽은 정점의 끝에서부터 셉니다.
+                        // (English Translation) OBJ indices start at 1, and negative ones count back from the end of the vertices read so far.
+                        let resolved = match index {
+                            i if i > 0 => i - 1, 
+                            i if i < 0 => positions.len() as i64 + i, 
+                            _ => -1, 
+                        };
+                        if resolved < 0 || resolved >= positions.len() as i64 || resolved > u32::MAX as i64 {
+                            return Err(ObjError::IndexOutOfRange { line: line_number, index });
+                        }
+                        face.push(resolved as u32);
+                    }
+
+                    if face.len() < 3 {
+                        return Err(ObjError::InvalidFace { line: line_number });
+                    }
+                    for i in 1..face.len() - 1 {
+                        indices.extend_from_slice(&[face[0], face[i], face[i + 1]]);
+                    }
+                }, 
+                _ => { /*--- empty ---*/ }, 
+            }
+        }
+
+        if indices.is_empty() {
+            return Err(ObjError::Empty);
+        }
+
+        let mut mesh = Self::new(positions, Vec::new(), indices);
+        mesh.compute_normals();
+        Ok(mesh)
+    }
+
+    /// #### 한국어 </br>
+    /// Wavefront OBJ 파일을 읽어 메쉬를 만듭니다. (참고: `parse_obj`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds a mesh by reading a Wavefront OBJ file. (see also: `parse_obj`) </br>
+    /// 
+    #[inline]
+    pub fn load_obj(path: &Path) -> Result<Self, ObjError> {
+        Self::parse_obj(&fs::read_to_string(path)?)
+    }
+
+    /// #### 한국어 </br>
+    /// 반시계 방향 감김 순서를 앞면으로 보고, 삼각형의 면 법선을 넓이에 비례해 더해서 정점 법선을 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes vertex normals by summing the face normals of the triangles weighted by area, treating counter-clockwise winding as the front face. </br>
+    /// 
+    pub fn compute_normals(&mut self) {
+        let mut normals = vec![glam::Vec3::ZERO; self.positions.len()];
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+            if a >= normals.len() || b >= normals.len() || c >= normals.len() {
+                continue;
+            }
+
+            let face_normal = (self.positions[b] - self.positions[a]).cross(self.positions[c] - self.positions[a]);
+            normals[a] += face_normal;
+            normals[b] += face_normal;
+            normals[c] += face_normal;
+        }
+        self.normals = normals.into_iter().map(|normal| normal.normalize_or_zero()).collect();
+    }
+}
+
+/// #### 한국어 </br>
+/// GPU 버퍼에 올라간 메쉬 입니다. 위치 정점 버퍼와 선택적인 인덱스 버퍼, 그리고 기본 도형 토폴로지를 가집니다. </br>
+/// 정점 버퍼는 [`Vertex`] 레이아웃을 가지므로 색상 오브젝트의 파이프라인과 함께 사용할 수 있습니다. </br>
+/// 메쉬의 토폴로지는 그리는 파이프라인의 토폴로지와 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A mesh uploaded to GPU buffers. It has a position vertex buffer, an optional index buffer, and a primitive topology. </br>
+/// The vertex buffer has the [`Vertex`] layout, so it can be used with the colored object pipelines. </br>
+/// The topology of the mesh must match the topology of the pipeline that draws it. </br>
+/// 
+#[derive(Debug)]
+pub struct GpuMesh {
+    vertex_buffer: wgpu::Buffer, 
+    index_buffer: Option<(wgpu::Buffer, wgpu::IndexFormat)>, 
+    vertex_count: u32, 
+    index_count: u32, 
+    topology: wgpu::PrimitiveTopology, 
+}
+
+#[allow(dead_code)]
+impl GpuMesh {
+    /// #### 한국어 </br>
+    /// CPU 메모리의 삼각형 목록 메쉬를 32비트 인덱스로 GPU 버퍼에 올립니다. </br>
+    /// 정점 법선이 없는 정점은 사각형과 같은 로컬 Z 축을 법선으로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads a triangle list mesh in CPU memory to GPU buffers with 32-bit indices. </br>
+    /// Vertices without a vertex normal use the local Z axis as their normal, the same as the quad. </br>
+    /// 
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, label: &str, mesh: &Mesh) -> Self {
+        let vertices: Vec<Vertex> = mesh.positions.iter()
+            .enumerate()
+            .map(|(index, position)| {
+                let normal = mesh.normals.get(index).copied().unwrap_or(glam::Vec3::Z);
+                Vertex::new(position.to_array(), normal.to_array())
+            })
+            .collect();
+        let vertex_buffer = create_vertex_buffer(device, queue, label, bytemuck::cast_slice(&vertices));
+        let index_buffer = create_index_buffer(device, queue, label, bytemuck::cast_slice(&mesh.indices));
+        Self {
+            vertex_buffer, 
+            index_buffer: Some((index_buffer, wgpu::IndexFormat::Uint32)), 
+            vertex_count: mesh.positions.len() as u32, 
+            index_count: mesh.indices.len() as u32, 
+            topology: wgpu::PrimitiveTopology::TriangleList, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스 버퍼 없이 정점 순서대로 그리는 메쉬를 생성합니다. (예: 사각형 메쉬의 삼각형 띠) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a mesh drawn in vertex order without an index buffer. (e.g. the triangle strip of the quad mesh) </br>
+    /// 
+    pub fn from_vertices(
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        label: &str, 
+        vertices: &[Vertex], 
+        topology: wgpu::PrimitiveTopology
+    ) -> Self {
+        Self {
+            vertex_buffer: create_vertex_buffer(device, queue, label, bytemuck::cast_slice(vertices)), 
+            index_buffer: None, 
+            vertex_count: vertices.len() as u32, 
+            index_count: 0, 
+            topology, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 색상 오브젝트가 공유하는 사각형 메쉬(`QUAD_VERTICES`)를 삼각형 띠로 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the quad mesh (`QUAD_VERTICES`) shared by all colored objects as a triangle strip. </br>
+    /// 
+    #[inline]
+    pub fn quad(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        Self::from_vertices(device, queue, "QuadMesh", &QUAD_VERTICES, wgpu::PrimitiveTopology::TriangleStrip)
+    }
+
+    /// #### 한국어 </br>
+    /// 16비트 인덱스로 그리는 삼각형 목록 메쉬를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a triangle list mesh drawn with 16-bit indices. </br>
+    /// 
+    pub fn from_vertices_indices(
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        label: &str, 
+        vertices: &[Vertex], 
+        indices: &[u16]
+    ) -> Self {
+        let padded = pad_u16_indices(indices);
+        Self {
+            vertex_buffer: create_vertex_buffer(device, queue, label, bytemuck::cast_slice(vertices)), 
+            index_buffer: Some((
+                create_index_buffer(device, queue, label, bytemuck::cast_slice(&padded)), 
+                wgpu::IndexFormat::Uint16
+            )), 
+            vertex_count: vertices.len() as u32, 
+            index_count: indices.len() as u32, 
+            topology: wgpu::PrimitiveTopology::TriangleList, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// Wavefront OBJ 파일을 읽어 GPU 버퍼에 올립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads a Wavefront OBJ file and uploads it to GPU buffers. </br>
+    /// 
+    pub fn load_obj(device: &wgpu::Device, queue: &wgpu::Queue, path: &Path) -> Result<Self, ObjError> {
+        let mesh = Mesh::load_obj(path)?;
+        let label = path.file_name().and_then(|name| name.to_str()).unwrap_or("ObjMesh");
+        Ok(Self::new(device, queue, label, &mesh))
+    }
+
+    #[inline]
+    pub fn ref_vertex_buffer(&self) -> &wgpu::Buffer {
+        &self.vertex_buffer
+    }
+
+    #[inline]
+    pub fn ref_index_buffer(&self) -> Option<&wgpu::Buffer> {
+        self.index_buffer.as_ref().map(|(buffer, _)| buffer)
+    }
+
+    #[inline]
+    pub fn is_indexed(&self) -> bool {
+        self.index_buffer.is_some()
+    }
+
+    #[inline]
+    pub fn vertex_count(&self) -> u32 {
+        self.vertex_count
+    }
+
+    #[inline]
+    pub fn index_count(&self) -> u32 {
+        self.index_count
+    }
+
+    #[inline]
+    pub fn topology(&self) -> wgpu::PrimitiveTopology {
+        self.topology
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬의 정점 버퍼(슬롯 0)와 인덱스 버퍼를 바인딩합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the vertex buffer (slot 0) and the index buffer of the mesh. </br>
+    /// 
+    pub fn bind<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>) {
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        if let Some((index_buffer, index_format)) = self.index_buffer.as_ref() {
+            rpass.set_index_buffer(index_buffer.slice(..), *index_format);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 바인딩된 메쉬를 그립니다. 인덱스 버퍼가 있는 경우 `draw_indexed`, 없는 경우 `draw`를 사용합니다. </br>
+    /// 메쉬는 `bind`로 미리 바인딩되어 있어야 하며, 파이프라인과 바인드 그룹도 미리 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the bound mesh. Uses `draw_indexed` if there is an index buffer, and `draw` otherwise. </br>
+    /// The mesh must already be bound with `bind`, and the pipeline and bind groups must already be set. </br>
+    /// 
+    #[inline]
+    pub fn draw_instanced(&self, rpass: &mut wgpu::RenderPass<'_>, instances: Range<u32>) {
+        match self.index_buffer.is_some() {
+            true => rpass.draw_indexed(0..self.index_count, 0, instances), 
+            false => rpass.draw(0..self.vertex_count, instances), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬를 바인딩하고 한 번 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the mesh and draws it once. </br>
+    /// 
+    pub fn draw<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>) {
+        self.bind(rpass);
+        self.draw_instanced(rpass, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 버퍼 쓰기는 4바이트 단위여야 하므로 16비트 인덱스 수가 홀수인 경우 0을 덧붙입니다. </br>
+/// 그리기 호출은 원래 인덱스 수를 사용하므로 덧붙인 인덱스는 그려지지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Buffer writes must be a multiple of 4 bytes, so a zero is appended to an odd number of 16-bit indices. </br>
+/// Draw calls use the original index count, so the padding index is not drawn. </br>
+/// 
+fn pad_u16_indices(indices: &[u16]) -> Vec<u16> {
+    let mut padded = indices.to_vec();
+    if padded.len() % 2 != 0 {
+        padded.push(0);
+    }
+    padded
+}
+
+fn create_vertex_buffer(device: &wgpu::Device, queue: &wgpu::Queue, label: &str, data: &[u8]) -> wgpu::Buffer {
+    let buffer = device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some(&format!("VertexBuffer({})", label)), 
+            mapped_at_creation: false, 
+            size: data.len() as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    );
+    queue.write_buffer(&buffer, 0, data);
+    buffer
+}
+
+fn create_index_buffer(device: &wgpu::Device, queue: &wgpu::Queue, label: &str, data: &[u8]) -> wgpu::Buffer {
+    let buffer = device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some(&format!("IndexBuffer({})", label)), 
+            mapped_at_creation: false, 
+            size: data.len() as wgpu::BufferAddress, 
+            usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
+        }, 
+    );
+    queue.write_buffer(&buffer, 0, data);
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quad_with_flipped_triangle() -> Mesh {
+        // (한국어) +Z를 향하는 사각형이며, 두 번째 삼각형의 감김 순서가 뒤집혀 있습니다.
+        // (English Translation) A quad facing +Z, and the winding of the second triangle is flipped.
+        let positions = vec![
+            glam::vec3(0.0, 0.0, 0.0), 
+            glam::vec3(1.0, 0.0, 0.0), 
+            glam::vec3(1.0, 1.0, 0.0), 
+            glam::vec3(0.0, 1.0, 0.0), 
+        ];
+        Mesh::new(positions, vec![glam::Vec3::Z; 4], vec![0, 1, 2, 0, 3, 2])
+    }
+
+    #[test]
+    fn flipped_triangle_is_detected_and_fixed() {
+        let mut mesh = quad_with_flipped_triangle();
+        let report = mesh.validate_winding(wgpu::FrontFace::Ccw);
+        assert_eq!(report, WindingReport { triangles: 2, inconsistent: 1, degenerate: 0, fixed: 0 });
+        assert!(!report.is_consistent());
+
+        // (한국어) 시계 방향이 앞면이면 첫 번째 삼각형이 뒤집힌 삼각형이 됩니다.
+        // (English Translation) If clockwise is the front face, the first triangle becomes the flipped one.
+        assert_eq!(mesh.validate_winding(wgpu::FrontFace::Cw).inconsistent, 1);
+
+        let report = mesh.fix_winding(wgpu::FrontFace::Ccw);
+        assert_eq!(report, WindingReport { triangles: 2, inconsistent: 1, degenerate: 0, fixed: 1 });
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(mesh.validate_winding(wgpu::FrontFace::Ccw).inconsistent, 0);
+    }
+
+    #[test]
+    fn degenerate_triangle_is_excluded() {
+        let mut mesh = quad_with_flipped_triangle();
+        mesh.indices = vec![0, 1, 1];
+        let report = mesh.validate_winding(wgpu::FrontFace::Ccw);
+        assert_eq!(report, WindingReport { triangles: 1, inconsistent: 0, degenerate: 1, fixed: 0 });
+    }
+
+    const PYRAMID_OBJ: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/meshes/pyramid.obj"));
+
+    const TRIANGLE_POSITIONS: &str = "v 0 0 0\nv 1 0 0\nv 0 1 0\n";
+
+    #[test]
+    fn vertex_layout_stride_matches_element_size() {
+        let layout = Vertex::vertex_buffer_layout();
+        assert_eq!(layout.array_stride, mem::size_of::<Vertex>() as wgpu::BufferAddress);
+        assert_eq!(bytemuck::cast_slice::<Vertex, u8>(&QUAD_VERTICES).len() as wgpu::BufferAddress, layout.array_stride * 4);
+
+        // (한국어) 모든 속성은 정점 하나의 범위 안에 있어야 합니다.
+        // (English Translation) Every attribute must lie within a single vertex.
+        for attribute in layout.attributes {
+            assert!(attribute.offset + attribute.format.size() <= layout.array_stride);
+        }
+        assert_eq!(layout.attributes[1].offset, mem::size_of::<[f32; 3]>() as wgpu::BufferAddress);
+    }
+
+    #[test]
+    fn two_triangle_quad_via_indices() {
+        let indices: [u16; 6] = [0, 1, 2, 2, 1, 3];
+        let padded = pad_u16_indices(&indices);
+        assert_eq!(padded, indices);
+        assert_eq!(std::mem::size_of_val(padded.as_slice()) % wgpu::COPY_BUFFER_ALIGNMENT as usize, 0);
+
+        let positions = vec![
+            glam::vec3(-0.5, -0.5, 0.0), 
+            glam::vec3(0.5, -0.5, 0.0), 
+            glam::vec3(-0.5, 0.5, 0.0), 
+            glam::vec3(0.5, 0.5, 0.0), 
+        ];
+        let mesh = Mesh::new(positions, Vec::new(), indices.iter().map(|&index| index as u32).collect());
+        assert_eq!(mesh.positions.len(), 4);
+        assert_eq!(mesh.indices.len(), 6);
+        assert_eq!(mesh.triangle_count(), 2);
+
+        // (한국어) 삼각형 하나의 인덱스 3개는 4바이트 단위를 맞추기 위해 하나가 덧붙여집니다.
+        // (English Translation) The three indices of a single triangle get one padding index to align to 4 bytes.
+        let padded = pad_u16_indices(&indices[..3]);
+        assert_eq!(padded, [0, 1, 2, 0]);
+        assert_eq!(std::mem::size_of_val(padded.as_slice()) % wgpu::COPY_BUFFER_ALIGNMENT as usize, 0);
+    }
+
+    #[test]
+    fn embedded_pyramid_parses_with_consistent_winding() {
+        let mesh = Mesh::parse_obj(PYRAMID_OBJ).unwrap();
+        assert_eq!(mesh.positions.len(), 5);
+        assert_eq!(mesh.normals.len(), 5);
+        assert_eq!(mesh.triangle_count(), 6);
+        // (한국어) 사각형 밑면은 부채꼴로 분할되고, 옆면의 음수 인덱스는 마지막 정점부터 셉니다.
+        // (English Translation) The quad base is split as a fan, and the negative indices of the sides count back from the last vertex.
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3, 3, 2, 4, 2, 1, 4, 1, 0, 4, 0, 3, 4]);
+        assert!(mesh.validate_winding(wgpu::FrontFace::Ccw).is_consistent());
+    }
+
+    #[test]
+    fn position_only_face_computes_normals() {
+        let mesh = Mesh::parse_obj(&format!("{}f 1 2 3\n", TRIANGLE_POSITIONS)).unwrap();
+        assert_eq!(mesh.indices, vec![0, 1, 2]);
+        for normal in mesh.normals.iter() {
+            assert!((*normal - glam::Vec3::Z).length() < 1e-6, "{:?}", normal);
+        }
+    }
+
+    #[test]
+    fn quad_face_is_triangulated_as_fan() {
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nf 1/1 2/2 3/3 4/4\n";
+        let mesh = Mesh::parse_obj(source).unwrap();
+        assert_eq!(mesh.triangle_count(), 2);
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+    }
+
+    #[test]
+    fn negative_indices_count_back_from_last_vertex() {
+        let source = format!("{}f -3 -2 -1\nv 1 1 0\nf -3 -2 -1\n", TRIANGLE_POSITIONS);
+        let mesh = Mesh::parse_obj(&source).unwrap();
+        assert_eq!(mesh.indices, vec![0, 1, 2, 1, 2, 3]);
+    }
+
+    #[test]
+    fn invalid_indices_are_reported_with_line() {
+        let cases = [("f 1 2 4", 4), ("f 0 1 2", 0), ("f -4 -2 -1", -4)];
+        for (face, expected) in cases {
+            match Mesh::parse_obj(&format!("{}{}\n", TRIANGLE_POSITIONS, face)) {
+                Err(ObjError::IndexOutOfRange { line: 4, index }) => assert_eq!(index, expected), 
+                result => panic!("unexpected result for {:?}: {:?}", face, result), 
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_sources_are_rejected() {
+        assert!(matches!(
+            Mesh::parse_obj(&format!("{}f 1 2\n", TRIANGLE_POSITIONS)), 
+            Err(ObjError::InvalidFace { line: 4 })
+        ));
+        assert!(matches!(
+            Mesh::parse_obj(&format!("{}f 1 x 3\n", TRIANGLE_POSITIONS)), 
+            Err(ObjError::InvalidNumber { line: 4 })
+        ));
+        assert!(matches!(Mesh::parse_obj("v 0 0\n"), Err(ObjError::InvalidNumber { line: 1 })));
+        assert!(matches!(Mesh::parse_obj(TRIANGLE_POSITIONS), Err(ObjError::Empty)));
+    }
+}
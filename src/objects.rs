@@ -1,14 +1,111 @@
 use std::mem;
+use std::sync::Arc;
 use crate::interfaces::{
     GameObject, 
+    Ray, 
     ShaderResource, 
 };
+use crate::mesh::{GpuMesh, QUAD_VERTICES};
+use crate::resources::{self, ResourceError};
+
+/// #### 한국어 </br>
+/// 오브젝트가 강조되었음을 나타내는 플래그 비트 입니다. 쉐이더의 같은 이름의 상수와 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The flag bit indicating that the object is highlighted. Must match the constant of the same name in the shader. </br>
+/// 
+pub const OBJECT_FLAG_HIGHLIGHTED: u32 = 1 << 0;
+
+/// #### 한국어 </br>
+/// 오브젝트가 그림자를 드리우는지 나타내는 플래그 비트 입니다. 쉐이더의 같은 이름의 상수와 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The flag bit indicating that the object casts shadows. Must match the constant of the same name in the shader. </br>
+/// 
+pub const OBJECT_FLAG_CAST_SHADOW: u32 = 1 << 1;
+
+/// #### 한국어 </br>
+/// 오브젝트가 그림자를 받는지 나타내는 플래그 비트 입니다. 쉐이더의 같은 이름의 상수와 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The flag bit indicating that the object receives shadows. Must match the constant of the same name in the shader. </br>
+/// 
+pub const OBJECT_FLAG_RECEIVE_SHADOW: u32 = 1 << 2;
+
+/// #### 한국어 </br>
+/// 투명 오브젝트를 색상 렌더 타겟에 섞는 방법 입니다. </br>
+/// 정렬된 알파 블렌딩 경로(`Pipelines::sorted_transparent`)에서만 파이프라인을 선택하는 데 사용됩니다. </br>
+/// 
+/// 가중 혼합 순서 독립적 투명도(OIT)의 누적은 설계상 더하기 연산이므로, 순수 OIT 모드에서는 이 값이 무시되며 </br>
+/// 더하기와 호환되는 기여(`AlphaBlend`에 가까운 근사와 `Additive`)만 물리적으로 의미가 있습니다. </br>
+/// `Multiply`는 순서에 의존하는 연산이므로 OIT로 표현할 수 없습니다. </br>
+/// 
+/// - `AlphaBlend`: 일반적인 알파 블렌딩. (유리 등) </br>
+/// - `Additive`: 뒤의 색상에 더합니다. (빛, 불꽃 등) </br>
+/// - `Multiply`: 뒤의 색상에 곱합니다. 알파가 낮을수록 흰색(변화 없음)에 가까워집니다. (색조 필터 등) </br>
+/// 
+/// #### English (Translation) </br>
+/// How a transparent object is blended into the color render target. </br>
+/// It is only used to select the pipeline in the sorted alpha blending path (`Pipelines::sorted_transparent`). </br>
+/// 
+/// The accumulation of weighted blended order-independent transparency (OIT) is additive by design, so this value is ignored in pure OIT mode, </br>
+/// and only additive-compatible contributions (the approximation of `AlphaBlend` and `Additive`) are physically meaningful there. </br>
+/// `Multiply` is an order-dependent operation and cannot be expressed with OIT. </br>
+/// 
+/// - `AlphaBlend`: standard alpha blending. (glass, etc.) </br>
+/// - `Additive`: adds onto the color behind. (glows, fire, etc.) </br>
+/// - `Multiply`: multiplies the color behind. The lower the alpha, the closer to white (no change). (tint filters, etc.) </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    #[default]
+    AlphaBlend, 
+    Additive, 
+    Multiply, 
+}
+
+#[allow(dead_code)]
+impl BlendMode {
+    /// #### 한국어 </br>
+    /// 블렌딩 상태를 가져옵니다. 쉐이더는 미리 곱해진 알파(premultiplied alpha) 색상을 출력해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the blend state. The shader must output premultiplied alpha colors. </br>
+    /// 
+    pub fn blend_state(&self) -> wgpu::BlendState {
+        let color = match self {
+            BlendMode::AlphaBlend => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One, 
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha, 
+                operation: wgpu::BlendOperation::Add, 
+            }, 
+            BlendMode::Additive => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One, 
+                dst_factor: wgpu::BlendFactor::One, 
+                operation: wgpu::BlendOperation::Add, 
+            }, 
+            // (한국어) `색상 * 알파 * 뒤 + 뒤 * (1 - 알파) = 뒤 * mix(1, 색상, 알파)`
+            // (English Translation) `color * alpha * dst + dst * (1 - alpha) = dst * mix(1, color, alpha)`
+            BlendMode::Multiply => wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Dst, 
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha, 
+                operation: wgpu::BlendOperation::Add, 
+            }, 
+        };
+        wgpu::BlendState { color, alpha: wgpu::BlendComponent::OVER }
+    }
+}
 
 /// #### 한국어 </br>
 /// 쉐이더에 전달되는 색상된 오브젝트의 유니폼 데이터 레이아웃 입니다. </br>
 /// 
+/// 모든 필드가 16 바이트 단위로 채워지므로 암묵적인 패딩이 없으며 (`bytemuck::Pod`), </br>
+/// 전체 크기(128 바이트)는 푸시 상수 경로에서 요청하는 푸시 상수의 크기(`utils::DeviceRequest`)와 같습니다. </br>
+/// 
 /// #### English (Translation) </br>
 /// This is the uniform data layout of the colored object passed to the shader. </br>
+/// All fields fill whole 16-byte units so there is no implicit padding (`bytemuck::Pod`), </br>
+/// and the total size (128 bytes) equals the push constant size requested for the push constant path (`utils::DeviceRequest`). </br>
 /// 
 #[repr(C, align(16))]
 #[derive(bytemuck::Pod, bytemuck::Zeroable)]
@@ -16,20 +113,40 @@ use crate::interfaces::{
 pub struct ColoredObjectUniformLayout {
     world_matrix: glam::Mat4, 
     color: glam::Vec4, 
+    wobble: glam::Vec4, 
+    emissive: glam::Vec4, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+    depth_bias: f32, 
 }
 
+// (한국어) 필드를 추가하여 푸시 상수의 크기를 넘는 경우 컴파일 오류가 발생하도록 합니다.
+// (English Translation) Fails to compile if an added field makes the layout exceed the push constant size.
+const _: () = assert!(mem::size_of::<ColoredObjectUniformLayout>() == 128);
+
 /// #### 한국어 </br>
 /// 색상 오브젝트를 생성하는 빌더 입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// A builder that creates a colored object. </br>
 /// 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct ColordObjectBuilder {
+    pub name: Option<String>, 
     pub translation: glam::Vec3, 
     pub rotation: glam::Quat, 
     pub scale: glam::Vec3, 
     pub color: glam::Vec4, 
+    pub wobble: glam::Vec2, 
+    pub emissive: glam::Vec4, 
+    pub cast_shadow: Option<bool>, 
+    pub receive_shadow: Option<bool>, 
+    pub roughness: Option<f32>, 
+    pub metallic: Option<f32>, 
+    pub depth_bias: f32, 
+    pub blend_mode: BlendMode, 
+    pub visible: Option<bool>, 
 }
 
 #[allow(dead_code)]
@@ -39,12 +156,167 @@ impl ColordObjectBuilder {
         Self::default()
     }
 
+    /// #### 한국어 </br>
+    /// 오브젝트의 이름을 설정합니다. </br>
+    /// 이름은 GPU 디버깅 도구에서 리소스를 구분할 수 있도록 리소스 레이블에 포함됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the name of the object. </br>
+    /// The name is included in the resource labels so that resources can be distinguished in GPU debugging tools. </br>
+    /// 
+    #[inline]
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
     #[inline]
     pub fn set_color(mut self, color: glam::Vec4) -> Self {
         self.color = color;
         self
     }
 
+    /// #### 한국어 </br>
+    /// 정점 변위(wobble) 효과의 진폭과 진동수(Hz)를 설정합니다. </br>
+    /// 진폭이 0인 경우 효과가 비활성화 됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the amplitude and frequency (Hz) of the vertex displacement (wobble) effect. </br>
+    /// The effect is disabled when the amplitude is 0. </br>
+    /// 
+    #[inline]
+    pub fn set_wobble(mut self, amplitude: f32, frequency: f32) -> Self {
+        self.wobble = glam::vec2(amplitude, frequency);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 조명과 관계없이 더해지는 방출 색상을 설정합니다. (rgb: 색상, a: 세기, 기본값: `0`) </br>
+    /// 불투명 패스에서 `rgb * a`가 조명된 색상에 더해지며 1보다 클 수 있으므로, </br>
+    /// 표면 형식이 부동소수점(HDR)인 경우에만 1을 넘는 밝기가 유지되고 `Bgra8Unorm` 형식에서는 잘립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the emissive color added regardless of lighting. (rgb: color, a: intensity, default: `0`) </br>
+    /// In the opaque pass, `rgb * a` is added to the lit color and may exceed 1, </br>
+    /// so brightness above 1 is only kept when the surface format is floating point (HDR), and is clamped with `Bgra8Unorm`. </br>
+    /// 
+    #[inline]
+    pub fn set_emissive(mut self, emissive: glam::Vec4) -> Self {
+        self.emissive = emissive;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 그림자를 드리우는지 설정합니다. </br>
+    /// 설정하지 않은 경우 불투명한 색상(알파 값 1.0)의 오브젝트만 그림자를 드리웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether the object casts shadows. </br>
+    /// If not set, only objects with an opaque color (alpha of 1.0) cast shadows. </br>
+    /// 
+    #[inline]
+    pub fn set_cast_shadow(mut self, cast_shadow: bool) -> Self {
+        self.cast_shadow = Some(cast_shadow);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 그림자를 받는지 설정합니다. 설정하지 않은 경우 그림자를 받습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether the object receives shadows. If not set, the object receives shadows. </br>
+    /// 
+    #[inline]
+    pub fn set_receive_shadow(mut self, receive_shadow: bool) -> Self {
+        self.receive_shadow = Some(receive_shadow);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 표면의 거칠기를 설정합니다. (`[0.04, 1]`, 기본값: `ColoredObject::DEFAULT_ROUGHNESS`) </br>
+    /// 값이 작을수록 반사광이 작고 선명해집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the roughness of the surface. (`[0.04, 1]`, default: `ColoredObject::DEFAULT_ROUGHNESS`) </br>
+    /// The smaller the value, the smaller and sharper the specular highlight. </br>
+    /// 
+    #[inline]
+    pub fn set_roughness(mut self, roughness: f32) -> Self {
+        self.roughness = Some(roughness);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 표면의 금속성을 설정합니다. (`[0, 1]`, 기본값: `0.0`) </br>
+    /// 유리와 같은 유전체는 `0.0`, 금속은 `1.0`을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the metalness of the surface. (`[0, 1]`, default: `0.0`) </br>
+    /// Use `0.0` for dielectrics such as glass and `1.0` for metals. </br>
+    /// 
+    #[inline]
+    pub fn set_metallic(mut self, metallic: f32) -> Self {
+        self.metallic = Some(metallic);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 깊이 바이어스를 설정합니다. (기본값: `0.0`) </br>
+    /// 같은 평면에 놓인 오브젝트들이 깊이 충돌(z-fighting)하는 경우, 앞에 보여야 하는 오브젝트에 작은 양수(예: `1.0e-4`)를 설정합니다. </br>
+    /// 
+    /// 바이어스는 정점 쉐이더에서 정점을 카메라 쪽으로 `뷰 깊이 * depth_bias` 만큼 당긴 깊이 값으로 적용되며, 화면상의 위치는 바뀌지 않습니다. </br>
+    /// 파이프라인의 `wgpu::DepthBiasState`를 오브젝트마다 다르게 사용하려면 바이어스 값마다 파이프라인이 필요하고, </br>
+    /// 오브젝트 사이에서 파이프라인을 전환해야 하므로 푸시 상수 경로와 인스턴스 묶음의 그리기 순서를 깨뜨립니다. </br>
+    /// 대신 유니폼 데이터의 남는 4 바이트에 저장하므로 유니폼 크기(128 바이트)와 모든 파이프라인이 그대로 유지됩니다. </br>
+    /// 뷰 깊이에 비례하므로 원근 투영에서 깊이 정밀도가 떨어지는 먼 거리에서도 효과가 유지되고, 역방향 깊이(reverse-Z)와 좌표계에 관계없이 동작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the depth bias of the object. (default: `0.0`) </br>
+    /// If objects lying on the same plane z-fight, set a small positive value (e.g. `1.0e-4`) on the object that should appear in front. </br>
+    /// 
+    /// The bias is applied in the vertex shader as the depth of the vertex pulled toward the camera by `view depth * depth_bias`, and its screen position does not change. </br>
+    /// Using a different `wgpu::DepthBiasState` per object would need a pipeline per bias value </br>
+    /// and pipeline switches between objects, which breaks the draw order of the push constant path and instance batches. </br>
+    /// Instead it is stored in the spare 4 bytes of the uniform data, so the uniform size (128 bytes) and all pipelines stay the same. </br>
+    /// Since it is proportional to the view depth, it keeps working at far distances where perspective depth precision drops, regardless of reverse-Z and handedness. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 투명 오브젝트의 블렌딩 방법을 설정합니다. (기본값: `BlendMode::AlphaBlend`) </br>
+    /// 정렬된 알파 블렌딩 경로에서만 적용됩니다. (참고: [`BlendMode`]) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the blend mode of a transparent object. (default: `BlendMode::AlphaBlend`) </br>
+    /// Only applied in the sorted alpha blending path. (see also: [`BlendMode`]) </br>
+    /// 
+    #[inline]
+    pub fn set_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 생성할 오브젝트를 보이거나 숨깁니다. (기본값: `true`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shows or hides the object to create. (default: `true`) </br>
+    /// 
+    #[inline]
+    pub fn set_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.visible.unwrap_or(true)
+    }
+
     #[inline]
     pub fn set_scale(mut self, scale: glam::Vec3) -> Self {
         self.scale = scale;
@@ -96,41 +368,223 @@ impl ColordObjectBuilder {
     pub fn build(self, device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> ColoredObject {
         let buffer = device.create_buffer(
             &wgpu::BufferDescriptor {
-                label: Some("UniformBuffer(ColoredObject)"), 
+                label: Some(&self.label("UniformBuffer")), 
                 mapped_at_creation: false, 
                 size: mem::size_of::<ColoredObjectUniformLayout>() as wgpu::BufferAddress, 
                 usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             },
         );
 
+        self.build_with_buffer(device, bind_group_layout, Arc::new(buffer), 0)
+    }
+
+    /// #### 한국어 </br>
+    /// 유니폼 버퍼 풀의 슬롯을 사용하는 색상 오브젝트를 생성합니다. </br>
+    /// 풀에 남은 슬롯이 없는 경우 경고를 출력하고 전용 유니폼 버퍼를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a colored object that uses a slot in the uniform buffer pool. </br>
+    /// If there are no slots left in the pool, a warning is printed and a dedicated uniform buffer is used. </br>
+    /// 
+    pub fn build_pooled(
+        self, 
+        device: &wgpu::Device, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        pool: &mut ObjectUniformPool
+    ) -> ColoredObject {
+        match pool.allocate() {
+            Some(offset) => {
+                let buffer = pool.buffer.clone();
+                self.build_with_buffer(device, bind_group_layout, buffer, offset)
+            },
+            None => {
+                log::warn!("Object uniform pool is full. (capacity: {})", pool.capacity());
+                self.build(device, bind_group_layout)
+            }
+        }
+    }
+
+    fn label(&self, kind: &str) -> String {
+        match self.name.as_deref() {
+            Some(name) => format!("{}(ColoredObject:{})", kind, name), 
+            None => format!("{}(ColoredObject)", kind), 
+        }
+    }
+
+    fn build_with_buffer(
+        self, 
+        device: &wgpu::Device, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        buffer: Arc<wgpu::Buffer>, 
+        offset: wgpu::BufferAddress
+    ) -> ColoredObject {
         let bind_group = device.create_bind_group(
             &wgpu::BindGroupDescriptor {
-                label: Some("BindGroup(ColoredObject)"), 
+                label: Some(&self.label("BindGroup")), 
                 layout: &bind_group_layout, 
                 entries: &[
                     wgpu::BindGroupEntry {
                         binding: 0, 
                         resource: wgpu::BindingResource::Buffer(
-                            buffer.as_entire_buffer_binding()
+                            wgpu::BufferBinding {
+                                buffer: &buffer, 
+                                offset, 
+                                size: wgpu::BufferSize::new(mem::size_of::<ColoredObjectUniformLayout>() as u64), 
+                            }
                         ),
                     },
                 ],
             },
         );
 
+        let mut flags = 0;
+        if self.cast_shadow.unwrap_or(self.color.w >= 1.0) {
+            flags |= OBJECT_FLAG_CAST_SHADOW;
+        }
+        if self.receive_shadow.unwrap_or(true) {
+            flags |= OBJECT_FLAG_RECEIVE_SHADOW;
+        }
+
         ColoredObject { 
+            name: self.name, 
             color: self.color, 
+            wobble: self.wobble, 
+            emissive: self.emissive, 
             transform: glam::Mat4::from_scale_rotation_translation(
                 self.scale, 
                 self.rotation.normalize(), 
                 self.translation
             ), 
+            flags, 
+            roughness: self.roughness.unwrap_or(ColoredObject::DEFAULT_ROUGHNESS).clamp(ColoredObject::MIN_ROUGHNESS, 1.0), 
+            metallic: self.metallic.unwrap_or(0.0).clamp(0.0, 1.0), 
+            depth_bias: self.depth_bias, 
+            blend_mode: self.blend_mode, 
+            visible: self.visible.unwrap_or(true), 
             buffer, 
+            offset, 
             bind_group, 
         }
     }
 }
 
+/// #### 한국어 </br>
+/// 여러 색상 오브젝트의 유니폼 데이터를 하나의 버퍼에 저장하는 풀 입니다. </br>
+/// 각 오브젝트는 버퍼의 서로 다른 범위를 바인딩하므로, 모든 오브젝트의 데이터를 한 번의 쓰기로 갱신할 수 있습니다. </br>
+/// 슬롯 간격은 장치의 `min_uniform_buffer_offset_alignment` 제한에 맞춰 정렬됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A pool that stores the uniform data of several colored objects in a single buffer. </br>
+/// Each object binds a different range of the buffer, so the data of all objects can be updated with a single write. </br>
+/// The slot stride is aligned to the device's `min_uniform_buffer_offset_alignment` limit. </br>
+/// 
+#[derive(Debug)]
+pub struct ObjectUniformPool {
+    buffer: Arc<wgpu::Buffer>, 
+    stride: wgpu::BufferAddress, 
+    capacity: usize, 
+    len: usize, 
+}
+
+#[allow(dead_code)]
+impl ObjectUniformPool {
+    pub fn new(device: &wgpu::Device, capacity: usize) -> Self {
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let size = mem::size_of::<ColoredObjectUniformLayout>() as wgpu::BufferAddress;
+        let stride = size.div_ceil(alignment) * alignment;
+
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(ObjectPool)"), 
+                mapped_at_creation: false, 
+                size: stride * capacity.max(1) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            },
+        );
+
+        Self { 
+            buffer: Arc::new(buffer), 
+            stride, 
+            capacity, 
+            len: 0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 슬롯의 오프셋을 할당합니다. 풀이 가득 찬 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Allocates the offset of the next slot. Returns `None` if the pool is full. </br>
+    /// 
+    fn allocate(&mut self) -> Option<wgpu::BufferAddress> {
+        if self.len < self.capacity {
+            let offset = self.stride * self.len as wgpu::BufferAddress;
+            self.len += 1;
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 이 풀의 슬롯을 사용하는지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object uses a slot in this pool. </br>
+    /// 
+    #[inline]
+    pub fn contains(&self, object: &ColoredObject) -> bool {
+        Arc::ptr_eq(&self.buffer, &object.buffer)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 오브젝트들의 유니폼 데이터를 하나의 연속된 배열로 모은 후 한 번의 쓰기로 갱신합니다. </br>
+    /// 이 풀을 사용하지 않는 오브젝트는 개별적으로 갱신합니다. 실행한 버퍼 쓰기 횟수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gathers the uniform data of the given objects into one contiguous array and updates it with a single write. </br>
+    /// Objects that do not use this pool are updated individually. Returns the number of buffer writes issued. </br>
+    /// 
+    pub fn write_all<'a, I>(&self, queue: &wgpu::Queue, objects: I) -> usize
+    where I: IntoIterator<Item = &'a ColoredObject> {
+        let mut writes = 0;
+        let mut staging = vec![0u8; (self.stride * self.len as wgpu::BufferAddress) as usize];
+        for object in objects {
+            if self.contains(object) {
+                let data = object.uniform_data();
+                let bytes = bytemuck::bytes_of(&data);
+                let offset = object.offset as usize;
+                staging[offset..offset + bytes.len()].copy_from_slice(bytes);
+            } else {
+                object.update_shader_resource(queue);
+                writes += 1;
+            }
+        }
+
+        if !staging.is_empty() {
+            queue.write_buffer(&self.buffer, 0, &staging);
+            writes += 1;
+        }
+
+        return writes;
+    }
+}
+
 /// #### 한국어 </br>
 /// 게임 월드 좌표계에 존재하는 색상 오브젝트 입니다. </br>
 /// 
@@ -139,12 +593,302 @@ impl ColordObjectBuilder {
 /// 
 #[derive(Debug)]
 pub struct ColoredObject {
+    name: Option<String>, 
     color: glam::Vec4, 
+    wobble: glam::Vec2, 
+    emissive: glam::Vec4, 
     transform: glam::Mat4, 
-    buffer: wgpu::Buffer, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+    depth_bias: f32, 
+    blend_mode: BlendMode, 
+    visible: bool, 
+    buffer: Arc<wgpu::Buffer>, 
+    offset: wgpu::BufferAddress, 
     bind_group: wgpu::BindGroup, 
 }
 
+#[allow(dead_code)]
+impl ColoredObject {
+    /// #### 한국어 </br>
+    /// 표면 거칠기의 기본값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default surface roughness. </br>
+    /// 
+    pub const DEFAULT_ROUGHNESS: f32 = 0.5;
+
+    /// #### 한국어 </br>
+    /// 표면 거칠기의 최소값 입니다. 0에 가까우면 반사광이 한 점으로 모여 사라집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The minimum surface roughness. Close to 0, the specular highlight collapses to a point and disappears. </br>
+    /// 
+    pub const MIN_ROUGHNESS: f32 = 0.04;
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 이름을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the name of the object. </br>
+    /// 
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 좌표계에서 오브젝트의 사각형 메쉬를 감싸는 경계 구(중심, 반지름)를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the bounding sphere (center, radius) enclosing the object's quad mesh in world coordinates. </br>
+    /// 
+    pub fn bounding_sphere(&self) -> (glam::Vec3, f32) {
+        let center = self.transform.w_axis.truncate();
+        let right = self.transform.x_axis.truncate();
+        let up = self.transform.y_axis.truncate();
+        let normal = self.transform.z_axis.truncate();
+        let radius = (right + up).length().max((right - up).length()) + normal.length() * self.wobble.x.abs();
+        (center, radius)
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 좌표계에서 오브젝트의 사각형 메쉬를 감싸는 축 정렬 경계 상자(최소, 최대)를 가져옵니다. </br>
+    /// 사각형의 네 꼭짓점(`QUAD_VERTICES`)을 월드 변환 행렬로 변환하며, 흔들림(`wobble`)이 있는 경우 법선 방향으로 넓힙니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the axis-aligned bounding box (min, max) enclosing the object's quad mesh in world coordinates. </br>
+    /// The four corners of the quad (`QUAD_VERTICES`) are transformed by the world matrix, and widened along the normal if there is a wobble. </br>
+    /// 
+    #[inline]
+    pub fn world_aabb(&self) -> (glam::Vec3, glam::Vec3) {
+        Self::quad_aabb(&self.transform, self.wobble.x)
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬로 변환한 사각형 메쉬를 감싸는 축 정렬 경계 상자(최소, 최대)를 계산합니다. </br>
+    /// `wobble_amplitude`가 0이 아닌 경우 사각형의 법선 방향으로 그 크기만큼 넓힙니다. (참고: `world_aabb`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the axis-aligned bounding box (min, max) enclosing the quad mesh transformed by the transform matrix. </br>
+    /// If `wobble_amplitude` is not zero, it is widened by that amount along the normal of the quad. (see also: `world_aabb`) </br>
+    /// 
+    pub fn quad_aabb(transform: &glam::Mat4, wobble_amplitude: f32) -> (glam::Vec3, glam::Vec3) {
+        let normal = transform.z_axis.truncate() * wobble_amplitude.abs();
+        let mut min = glam::Vec3::splat(f32::INFINITY);
+        let mut max = glam::Vec3::splat(f32::NEG_INFINITY);
+        for corner in QUAD_VERTICES {
+            let corner = transform.transform_point3(glam::Vec3::from_array(corner.position));
+            min = min.min(corner - normal).min(corner + normal);
+            max = max.max(corner - normal).max(corner + normal);
+        }
+        (min, max)
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 강조되었는지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object is highlighted. </br>
+    /// 
+    #[inline]
+    pub fn is_highlighted(&self) -> bool {
+        self.flags & OBJECT_FLAG_HIGHLIGHTED != 0
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트를 강조하거나 강조를 해제합니다. 강조된 오브젝트는 강조 색상과 섞여 그려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Highlights or unhighlights the object. Highlighted objects are drawn blended with the highlight color. </br>
+    /// 
+    #[inline]
+    pub fn set_highlighted(&mut self, highlighted: bool) {
+        self.set_flag(OBJECT_FLAG_HIGHLIGHTED, highlighted);
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 그림자를 드리우는지 확인합니다. 그림자를 드리우지 않는 오브젝트는 그림자 패스에서 제외됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object casts shadows. Objects that do not cast shadows are skipped in the shadow pass. </br>
+    /// 
+    #[inline]
+    pub fn cast_shadow(&self) -> bool {
+        self.flags & OBJECT_FLAG_CAST_SHADOW != 0
+    }
+
+    #[inline]
+    pub fn set_cast_shadow(&mut self, cast_shadow: bool) {
+        self.set_flag(OBJECT_FLAG_CAST_SHADOW, cast_shadow);
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 그림자를 받는지 확인합니다. 그림자를 받지 않는 오브젝트는 쉐이더에서 그림자 샘플링을 건너뜁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object receives shadows. The shader skips shadow sampling for objects that do not receive shadows. </br>
+    /// 
+    #[inline]
+    pub fn receive_shadow(&self) -> bool {
+        self.flags & OBJECT_FLAG_RECEIVE_SHADOW != 0
+    }
+
+    #[inline]
+    pub fn set_receive_shadow(&mut self, receive_shadow: bool) {
+        self.set_flag(OBJECT_FLAG_RECEIVE_SHADOW, receive_shadow);
+    }
+
+    #[inline]
+    pub fn roughness(&self) -> f32 {
+        self.roughness
+    }
+
+    #[inline]
+    pub fn set_roughness(&mut self, roughness: f32) {
+        self.roughness = roughness.clamp(Self::MIN_ROUGHNESS, 1.0);
+    }
+
+    #[inline]
+    pub fn metallic(&self) -> f32 {
+        self.metallic
+    }
+
+    #[inline]
+    pub fn set_metallic(&mut self, metallic: f32) {
+        self.metallic = metallic.clamp(0.0, 1.0);
+    }
+
+    #[inline]
+    pub fn color(&self) -> glam::Vec4 {
+        self.color
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 색상을 설정합니다. </br>
+    /// 오브젝트가 속한 그리기 목록은 바뀌지 않으므로, 불투명 오브젝트의 알파 값은 `1.0`으로 유지해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the color of the object. </br>
+    /// The draw list the object belongs to does not change, so the alpha of an opaque object should stay `1.0`. </br>
+    /// 
+    #[inline]
+    pub fn set_color(&mut self, color: glam::Vec4) {
+        self.color = color;
+    }
+
+    #[inline]
+    pub fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
+    #[inline]
+    pub fn set_depth_bias(&mut self, depth_bias: f32) {
+        self.depth_bias = depth_bias;
+    }
+
+    #[inline]
+    pub fn emissive(&self) -> glam::Vec4 {
+        self.emissive
+    }
+
+    #[inline]
+    pub fn set_emissive(&mut self, emissive: glam::Vec4) {
+        self.emissive = emissive;
+    }
+
+    #[inline]
+    pub fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    #[inline]
+    pub fn set_blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 보이는지 확인합니다. (기본값: `true`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object is visible. (default: `true`) </br>
+    /// 
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트를 보이거나 숨깁니다. </br>
+    /// 숨겨진 오브젝트는 그리기 목록에 남아 있지만 모든 패스(그림자 패스 포함)에서 그려지지 않고, 선택(picking)되지도 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shows or hides the object. </br>
+    /// Hidden objects stay in their draw list, but are not drawn in any pass (including the shadow pass) and cannot be picked. </br>
+    /// 
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    #[inline]
+    fn set_flag(&mut self, flag: u32, value: bool) {
+        if value {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 광선과 오브젝트의 사각형 메쉬의 교차를 검사합니다. </br>
+    /// 교차하는 경우 광선의 매개변수 `t`(교차점 = `origin + direction * t`)를 반환합니다. </br>
+    /// 정점 변위(wobble) 효과는 고려하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Tests the ray against the object's quad mesh. </br>
+    /// Returns the ray parameter `t` (hit point = `origin + direction * t`) if they intersect. </br>
+    /// The vertex displacement (wobble) effect is not taken into account. </br>
+    /// 
+    pub fn intersect_ray(&self, ray: &Ray) -> Option<f32> {
+        // (한국어) 광선을 오브젝트의 로컬 좌표계로 변환합니다. 아핀 변환이므로 매개변수 `t`는 그대로 유지됩니다.
+        // (English Translation) Transforms the ray into the object's local space. Since it is an affine transform, the parameter `t` is preserved.
+        let inverse = self.transform.inverse();
+        let origin = inverse.transform_point3(ray.origin);
+        let direction = inverse.transform_vector3(ray.direction);
+        if direction.z.abs() <= f32::EPSILON {
+            return None;
+        }
+
+        // (한국어) 사각형 메쉬는 로컬 XY 평면의 [-1, 1] 범위에 있습니다.
+        // (English Translation) The quad mesh lies in the [-1, 1] range of the local XY plane.
+        let t = -origin.z / direction.z;
+        let hit = origin + direction * t;
+        (t >= 0.0 && hit.x.abs() <= 1.0 && hit.y.abs() <= 1.0).then_some(t)
+    }
+
+    /// #### 한국어 </br>
+    /// 쉐이더에 전달되는 오브젝트의 유니폼 데이터를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the uniform data of the object passed to the shader. </br>
+    /// 
+    #[inline]
+    pub fn uniform_data(&self) -> ColoredObjectUniformLayout {
+        ColoredObjectUniformLayout {
+            world_matrix: self.transform, 
+            color: self.color, 
+            wobble: self.wobble.extend(0.0).extend(0.0), 
+            emissive: self.emissive, 
+            flags: self.flags, 
+            roughness: self.roughness, 
+            metallic: self.metallic, 
+            depth_bias: self.depth_bias, 
+        }
+    }
+}
+
 impl GameObject for ColoredObject {
     #[inline]
     fn ref_world_transform(&self) -> &glam::Mat4 {
@@ -159,11 +903,8 @@ impl GameObject for ColoredObject {
 
 impl ShaderResource for ColoredObject {
     fn update_shader_resource(&self, queue: &wgpu::Queue) {
-        let data = ColoredObjectUniformLayout {
-            world_matrix: self.transform, 
-            color: self.color, 
-        };
-        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+        let data = self.uniform_data();
+        queue.write_buffer(&self.buffer, self.offset, bytemuck::bytes_of(&data));
     }
 
     #[inline]
@@ -171,3 +912,724 @@ impl ShaderResource for ColoredObject {
         &self.bind_group
     }
 }
+
+/// #### 한국어 </br>
+/// 인스턴스 정점 버퍼에 저장되는 인스턴스별 데이터 레이아웃 입니다. </br>
+/// 월드 행렬은 네 개의 `Float32x4` 속성(위치 5~8)으로, 색상은 하나의 `Float32x4` 속성(위치 9)으로 전달됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the per-instance data layout stored in the instance vertex buffer. </br>
+/// The world matrix is passed as four `Float32x4` attributes (locations 5-8), and the color as one `Float32x4` attribute (location 9). </br>
+/// 
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct InstanceLayout {
+    pub world_matrix: glam::Mat4, 
+    pub color: glam::Vec4, 
+}
+
+impl InstanceLayout {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 5] = wgpu::vertex_attr_array![
+        5 => Float32x4, 
+        6 => Float32x4, 
+        7 => Float32x4, 
+        8 => Float32x4, 
+        9 => Float32x4
+    ];
+
+    /// #### 한국어 </br>
+    /// 인스턴스 단위로 진행하는 정점 버퍼 레이아웃을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the vertex buffer layout that steps per instance. </br>
+    /// 
+    #[inline]
+    pub fn vertex_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            step_mode: wgpu::VertexStepMode::Instance, 
+            array_stride: mem::size_of::<Self>() as wgpu::BufferAddress, 
+            attributes: &Self::ATTRIBUTES, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 색상 오브젝트의 월드 행렬과 색상으로 인스턴스 데이터를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates instance data from the world matrix and color of a colored object. </br>
+    /// 
+    #[inline]
+    pub fn from_object(object: &ColoredObject) -> Self {
+        Self { 
+            world_matrix: object.transform, 
+            color: object.color, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 색상 오브젝트들의 월드 행렬과 색상을 인스턴스 정점 버퍼에 저장할 데이터로 묶습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Packs the world matrices and colors of colored objects into data to be stored in an instance vertex buffer. </br>
+/// 
+#[allow(dead_code)]
+pub fn pack_instances<'a, I>(objects: I) -> Vec<InstanceLayout>
+where I: IntoIterator<Item = &'a ColoredObject> {
+    objects.into_iter().map(InstanceLayout::from_object).collect()
+}
+
+/// #### 한국어 </br>
+/// 하나의 그리기 호출로 그려지는 인스턴스들의 묶음입니다. </br>
+/// 묶음 오브젝트의 월드 행렬은 모든 인스턴스의 월드 행렬에 곱해지며, 바인드 그룹 슬롯 1에 바인딩 됩니다. </br>
+/// 인스턴스 정점 버퍼는 정점 버퍼 슬롯 1에 바인딩 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A batch of instances drawn with a single draw call. </br>
+/// The world matrix of the batch object is multiplied with the world matrix of every instance, and is bound to bind group slot 1. </br>
+/// The instance vertex buffer is bound to vertex buffer slot 1. </br>
+/// 
+#[derive(Debug)]
+pub struct InstanceBatch {
+    object: ColoredObject, 
+    instance_buffer: wgpu::Buffer, 
+    instance_count: u32, 
+}
+
+#[allow(dead_code)]
+impl InstanceBatch {
+    pub fn new(
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        object: ColoredObject, 
+        instances: &[InstanceLayout]
+    ) -> Self {
+        let label = match object.name() {
+            Some(name) => format!("InstanceBuffer(ColoredObject:{})", name), 
+            None => "InstanceBuffer(ColoredObject)".to_string(), 
+        };
+
+        let instance_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&label), 
+                mapped_at_creation: false, 
+                size: mem::size_of_val(instances) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            },
+        );
+        queue.write_buffer(&instance_buffer, 0, bytemuck::cast_slice(instances));
+
+        Self { 
+            object, 
+            instance_buffer, 
+            instance_count: instances.len() as u32, 
+        }
+    }
+
+    #[inline]
+    pub fn ref_object(&self) -> &ColoredObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn mut_object(&mut self) -> &mut ColoredObject {
+        &mut self.object
+    }
+
+    #[inline]
+    pub fn ref_instance_buffer(&self) -> &wgpu::Buffer {
+        &self.instance_buffer
+    }
+
+    #[inline]
+    pub fn instance_count(&self) -> u32 {
+        self.instance_count
+    }
+
+    /// #### 한국어 </br>
+    /// 묶음의 처음 `count`개 인스턴스를 하나의 그리기 호출로 그립니다. </br>
+    /// 사각형 메쉬는 정점 버퍼 슬롯 0에 미리 바인딩 되어 있어야 합니다. </br>
+    /// `count`는 묶음의 인스턴스 수를 넘지 않도록 제한됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the first `count` instances of the batch with a single draw call. </br>
+    /// The quad mesh must already be bound to vertex buffer slot 0. </br>
+    /// `count` is clamped to the number of instances in the batch. </br>
+    /// 
+    pub fn draw_instanced<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>, count: u32) {
+        rpass.set_bind_group(1, &self.object.bind_group, &[]);
+        rpass.set_vertex_buffer(1, self.instance_buffer.slice(..));
+        rpass.draw(0..QUAD_VERTICES.len() as u32, 0..count.min(self.instance_count));
+    }
+}
+
+/// #### 한국어 </br>
+/// 사각형 대신 불러온 메쉬로 그려지는 색상 오브젝트 입니다. </br>
+/// 오브젝트의 월드 행렬과 색상은 바인드 그룹 슬롯 1에 바인딩 되며, 메쉬는 여러 오브젝트가 공유할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A colored object drawn with a loaded mesh instead of the quad. </br>
+/// The world matrix and color of the object are bound to bind group slot 1, and the mesh can be shared by several objects. </br>
+/// 
+#[derive(Debug)]
+pub struct MeshObject {
+    mesh: Arc<GpuMesh>, 
+    object: ColoredObject, 
+}
+
+#[allow(dead_code)]
+impl MeshObject {
+    #[inline]
+    pub fn new(mesh: Arc<GpuMesh>, object: ColoredObject) -> Self {
+        Self { mesh, object }
+    }
+
+    #[inline]
+    pub fn ref_mesh(&self) -> &GpuMesh {
+        &self.mesh
+    }
+
+    #[inline]
+    pub fn ref_object(&self) -> &ColoredObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn mut_object(&mut self) -> &mut ColoredObject {
+        &mut self.object
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트의 바인드 그룹과 메쉬를 바인딩하고 `draw_indexed`로 그립니다. </br>
+    /// 메쉬를 그린 후에는 사각형 메쉬를 다시 바인딩해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds the bind group of the object and the mesh, and draws it with `draw_indexed`. </br>
+    /// The quad mesh must be bound again after drawing the mesh. </br>
+    /// 
+    pub fn draw<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>) {
+        rpass.set_bind_group(1, &self.object.bind_group, &[]);
+        self.mesh.draw(rpass);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 부모-자식 변환 계층 구조의 노드 입니다. </br>
+/// `parent`는 같은 노드 배열 안에서 부모 노드의 인덱스이며, `local_transform`은 부모 좌표계 기준의 변환 행렬 입니다. </br>
+/// 노드의 인덱스는 같은 인덱스의 오브젝트와 짝을 이룹니다. (참고: `apply_world_transforms`) </br>
+/// 
+/// #### English (Translation) </br>
+/// A node of a parent-child transform hierarchy. </br>
+/// `parent` is the index of the parent node in the same node array, and `local_transform` is the transform relative to the parent's coordinate system. </br>
+/// A node's index pairs with the object at the same index. (see also: `apply_world_transforms`) </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SceneNode {
+    pub parent: Option<usize>, 
+    pub local_transform: glam::Mat4, 
+}
+
+impl SceneNode {
+    #[inline]
+    pub fn new(local_transform: glam::Mat4) -> Self {
+        Self { parent: None, local_transform }
+    }
+
+    #[inline]
+    pub fn with_parent(parent: usize, local_transform: glam::Mat4) -> Self {
+        Self { parent: Some(parent), local_transform }
+    }
+}
+
+/// #### 한국어 </br>
+/// 각 노드의 월드 변환 행렬을 계산합니다. 부모를 따라 올라가며 `부모의 월드 변환 * 로컬 변환`을 곱합니다. </br>
+/// 이미 계산된 노드의 결과는 다시 사용하므로 각 노드는 한 번만 계산됩니다. </br>
+/// 
+/// 부모 인덱스가 범위를 벗어나거나 부모를 따라가다 순환이 발견된 경우 경고를 출력하고, </br>
+/// 그 지점의 노드를 루트 노드로 취급하여 계산을 계속합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the world transform of each node. Walks up the parents multiplying `parent world transform * local transform`. </br>
+/// Results of already computed nodes are reused, so each node is computed only once. </br>
+/// 
+/// If a parent index is out of range, or a cycle is found while following the parents, a warning is printed </br>
+/// and the node at that point is treated as a root node so that the computation continues. </br>
+/// 
+pub fn compute_world_transforms(nodes: &[SceneNode]) -> Vec<glam::Mat4> {
+    let mut world_transforms: Vec<Option<glam::Mat4>> = vec![None; nodes.len()];
+    let mut chain = Vec::new();
+    for index in 0..nodes.len() {
+        // (한국어) 계산된 조상 노드나 루트 노드에 닿을 때까지 부모를 따라 올라갑니다.
+        // (English Translation) Walks up the parents until reaching a computed ancestor or a root node.
+        chain.clear();
+        let mut parent_world = glam::Mat4::IDENTITY;
+        let mut current = Some(index);
+        while let Some(node) = current {
+            if let Some(world) = world_transforms[node] {
+                parent_world = world;
+                break;
+            }
+
+            if chain.contains(&node) {
+                log::warn!("Cycle detected in the scene node hierarchy at node {}.", node);
+                break;
+            }
+            chain.push(node);
+
+            current = match nodes[node].parent {
+                Some(parent) if parent < nodes.len() => Some(parent), 
+                Some(parent) => {
+                    log::warn!("Scene node {} has an invalid parent index {}.", node, parent);
+                    None
+                }, 
+                None => None, 
+            };
+        }
+
+        for &node in chain.iter().rev() {
+            parent_world *= nodes[node].local_transform;
+            world_transforms[node] = Some(parent_world);
+        }
+    }
+
+    world_transforms.into_iter()
+        .map(|world| world.unwrap_or(glam::Mat4::IDENTITY))
+        .collect()
+}
+
+/// #### 한국어 </br>
+/// 노드 계층 구조의 월드 변환 행렬을 같은 인덱스의 오브젝트에 설정합니다. 짝이 없는 노드나 오브젝트는 무시됩니다. </br>
+/// 오브젝트의 GPU 데이터 레이아웃은 바뀌지 않으며, 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the world transforms of the node hierarchy on the objects at the same indices. Unpaired nodes or objects are ignored. </br>
+/// The GPU data layout of the objects does not change, and the change is reflected in the shader after `update_shader_resource` is called. </br>
+/// 
+pub fn apply_world_transforms(nodes: &[SceneNode], objects: &mut [ColoredObject]) {
+    let world_transforms = compute_world_transforms(nodes);
+    for (object, world) in objects.iter_mut().zip(world_transforms) {
+        *object.mut_world_transform() = world;
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 텍스처 오브젝트의 유니폼 데이터 레이아웃 입니다. </br>
+/// 색상 오브젝트와 같은 월드 행렬을 가지지만, 단색 대신 텍스처 좌표의 크기와 오프셋(`xy`: 크기, `zw`: 오프셋)을 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the textured object passed to the shader. </br>
+/// It has the same world matrix as colored objects, but holds the texture coordinate scale and offset (`xy`: scale, `zw`: offset) instead of a flat color. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TexturedObjectUniformLayout {
+    world_matrix: glam::Mat4, 
+    uv_transform: glam::Vec4, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+    _padding: u32, 
+}
+
+/// #### 한국어 </br>
+/// 텍스처 오브젝트의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 1) </br>
+/// 색상 오브젝트의 레이아웃 대신 텍스처 파이프라인(`Pipelines::textured`)에서 사용됩니다. </br>
+/// 
+/// - `binding 0`: 유니폼 버퍼 (`TexturedObjectUniformLayout`, 정점 및 프래그먼트 쉐이더) </br>
+/// - `binding 1`: 알베도 텍스처 (`texture_2d<f32>`, 필터링 가능, 프래그먼트 쉐이더) </br>
+/// - `binding 2`: 샘플러 (필터링, 프래그먼트 쉐이더) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of textured objects. (bind group slot 1) </br>
+/// Used by the textured pipeline (`Pipelines::textured`) in place of the colored object layout. </br>
+/// 
+/// - `binding 0`: uniform buffer (`TexturedObjectUniformLayout`, vertex and fragment shaders) </br>
+/// - `binding 1`: albedo texture (`texture_2d<f32>`, filterable, fragment shader) </br>
+/// - `binding 2`: sampler (filtering, fragment shader) </br>
+/// 
+pub fn create_textured_object_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(TexturedObject)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform, 
+                        has_dynamic_offset: false, 
+                        min_binding_size: wgpu::BufferSize::new(mem::size_of::<TexturedObjectUniformLayout>() as u64), 
+                    }, 
+                    count: None, 
+                }, 
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                        view_dimension: wgpu::TextureViewDimension::D2, 
+                        multisampled: false, 
+                    }, 
+                    count: None, 
+                }, 
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering), 
+                    count: None, 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 텍스처 오브젝트를 생성하는 빌더 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that creates a textured object. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TexturedObjectBuilder {
+    pub name: Option<String>, 
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub scale: glam::Vec3, 
+    pub uv_scale: glam::Vec2, 
+    pub uv_offset: glam::Vec2, 
+    pub roughness: Option<f32>, 
+    pub metallic: Option<f32>, 
+    pub address_mode: wgpu::AddressMode, 
+}
+
+impl Default for TexturedObjectBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            name: None, 
+            translation: glam::Vec3::ZERO, 
+            rotation: glam::Quat::IDENTITY, 
+            scale: glam::Vec3::ONE, 
+            uv_scale: glam::Vec2::ONE, 
+            uv_offset: glam::Vec2::ZERO, 
+            roughness: None, 
+            metallic: None, 
+            address_mode: wgpu::AddressMode::Repeat, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl TexturedObjectBuilder {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn set_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처 좌표의 크기와 오프셋을 설정합니다. 크기가 1보다 크면 텍스처가 반복됩니다. (`set_address_mode` 참고) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the scale and offset of the texture coordinates. A scale greater than 1 repeats the texture. (see `set_address_mode`) </br>
+    /// 
+    #[inline]
+    pub fn set_uv_transform(mut self, uv_scale: glam::Vec2, uv_offset: glam::Vec2) -> Self {
+        self.uv_scale = uv_scale;
+        self.uv_offset = uv_offset;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// `[0, 1]` 범위를 벗어난 텍스처 좌표의 처리 방법을 설정합니다. (기본값: `wgpu::AddressMode::Repeat`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets how texture coordinates outside the `[0, 1]` range are handled. (default: `wgpu::AddressMode::Repeat`) </br>
+    /// 
+    #[inline]
+    pub fn set_address_mode(mut self, address_mode: wgpu::AddressMode) -> Self {
+        self.address_mode = address_mode;
+        self
+    }
+
+    #[inline]
+    pub fn set_roughness(mut self, roughness: f32) -> Self {
+        self.roughness = Some(roughness);
+        self
+    }
+
+    #[inline]
+    pub fn set_metallic(mut self, metallic: f32) -> Self {
+        self.metallic = Some(metallic);
+        self
+    }
+
+    #[inline]
+    pub fn set_scale(mut self, scale: glam::Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    #[inline]
+    pub fn set_translation(mut self, translation: glam::Vec3) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    #[inline]
+    pub fn set_rotation(mut self, rotation: glam::Quat) -> Self {
+        self.rotation = rotation.normalize();
+        self
+    }
+
+    fn label(&self, kind: &str) -> String {
+        match self.name.as_deref() {
+            Some(name) => format!("{}(TexturedObject:{})", kind, name), 
+            None => format!("{}(TexturedObject)", kind), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// RGBA8 (sRGB) 픽셀 데이터로 알베도 텍스처를 만들어 텍스처 오브젝트를 생성합니다. </br>
+    /// 텍스처의 크기가 장치의 제한을 넘거나 데이터의 길이가 `width * height * 4`와 다른 경우 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a textured object, making the albedo texture from RGBA8 (sRGB) pixel data. </br>
+    /// Returns an error if the texture size exceeds the device limits or the data length differs from `width * height * 4`. </br>
+    /// 
+    pub fn build(
+        self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        width: u32, 
+        height: u32, 
+        data: &[u8]
+    ) -> Result<TexturedObject, ResourceError> {
+        let texture = resources::create_texture_rgba8(device, queue, Some(&self.label("Texture")), width, height, data)?;
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some(&self.label("Sampler")), 
+                address_mode_u: self.address_mode, 
+                address_mode_v: self.address_mode, 
+                address_mode_w: self.address_mode, 
+                mag_filter: wgpu::FilterMode::Linear, 
+                min_filter: wgpu::FilterMode::Linear, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }, 
+        );
+
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some(&self.label("UniformBuffer")), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<TexturedObjectUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&self.label("BindGroup")), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Ok(TexturedObject {
+            name: self.name, 
+            transform: glam::Mat4::from_scale_rotation_translation(
+                self.scale, 
+                self.rotation.normalize(), 
+                self.translation
+            ), 
+            uv_scale: self.uv_scale, 
+            uv_offset: self.uv_offset, 
+            flags: OBJECT_FLAG_CAST_SHADOW | OBJECT_FLAG_RECEIVE_SHADOW, 
+            roughness: self.roughness.unwrap_or(ColoredObject::DEFAULT_ROUGHNESS).clamp(ColoredObject::MIN_ROUGHNESS, 1.0), 
+            metallic: self.metallic.unwrap_or(0.0).clamp(0.0, 1.0), 
+            texture, 
+            buffer, 
+            bind_group, 
+        })
+    }
+}
+
+/// #### 한국어 </br>
+/// 알베도 텍스처를 입힌 사각형 오브젝트 입니다. 색상 오브젝트와 같은 사각형 메쉬(`QUAD_VERTICES`)를 사용합니다. </br>
+/// 
+/// 그리기 문맥의 `textured_objects`에 넣으면 불투명 패스가 메쉬 오브젝트 다음에 텍스처 파이프라인(`Pipelines::textured`)으로 </br>
+/// 카메라(슬롯 0), 이 오브젝트(슬롯 1, `create_textured_object_bind_group_layout`), 전역 설정(슬롯 2) 바인드 그룹을 </br>
+/// 설정하고 사각형 메쉬를 삼각형 띠로 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A quad object with an albedo texture. It uses the same quad mesh as colored objects (`QUAD_VERTICES`). </br>
+/// 
+/// When put in `textured_objects` of the draw context, the opaque pass draws it after the mesh objects with the textured pipeline (`Pipelines::textured`), </br>
+/// setting the bind groups of the camera (slot 0), this object (slot 1, `create_textured_object_bind_group_layout`) and the global settings (slot 2), </br>
+/// and drawing the quad mesh as a triangle strip. </br>
+/// 
+#[derive(Debug)]
+pub struct TexturedObject {
+    name: Option<String>, 
+    transform: glam::Mat4, 
+    uv_scale: glam::Vec2, 
+    uv_offset: glam::Vec2, 
+    flags: u32, 
+    roughness: f32, 
+    metallic: f32, 
+    texture: wgpu::Texture, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl TexturedObject {
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    #[inline]
+    pub fn ref_texture(&self) -> &wgpu::Texture {
+        &self.texture
+    }
+
+    #[inline]
+    pub fn uv_transform(&self) -> (glam::Vec2, glam::Vec2) {
+        (self.uv_scale, self.uv_offset)
+    }
+
+    #[inline]
+    pub fn set_uv_transform(&mut self, uv_scale: glam::Vec2, uv_offset: glam::Vec2) {
+        self.uv_scale = uv_scale;
+        self.uv_offset = uv_offset;
+    }
+
+    #[inline]
+    pub fn is_highlighted(&self) -> bool {
+        self.flags & OBJECT_FLAG_HIGHLIGHTED != 0
+    }
+
+    #[inline]
+    pub fn set_highlighted(&mut self, highlighted: bool) {
+        if highlighted {
+            self.flags |= OBJECT_FLAG_HIGHLIGHTED;
+        } else {
+            self.flags &= !OBJECT_FLAG_HIGHLIGHTED;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 쉐이더에 전달되는 오브젝트의 유니폼 데이터를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the uniform data of the object passed to the shader. </br>
+    /// 
+    #[inline]
+    pub fn uniform_data(&self) -> TexturedObjectUniformLayout {
+        TexturedObjectUniformLayout {
+            world_matrix: self.transform, 
+            uv_transform: glam::Vec4::new(self.uv_scale.x, self.uv_scale.y, self.uv_offset.x, self.uv_offset.y), 
+            flags: self.flags, 
+            roughness: self.roughness, 
+            metallic: self.metallic, 
+            _padding: 0, 
+        }
+    }
+}
+
+impl GameObject for TexturedObject {
+    #[inline]
+    fn ref_world_transform(&self) -> &glam::Mat4 {
+        &self.transform
+    }
+
+    #[inline]
+    fn mut_world_transform(&mut self) -> &mut glam::Mat4 {
+        &mut self.transform
+    }
+}
+
+impl ShaderResource for TexturedObject {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&self.uniform_data()));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visibility_defaults_to_true_and_toggles() {
+        let builder = ColordObjectBuilder::new();
+        assert_eq!(builder.visible, None);
+        assert!(builder.is_visible());
+
+        let builder = builder.set_visible(false);
+        assert!(!builder.is_visible());
+        assert!(builder.set_visible(true).is_visible());
+    }
+
+    #[test]
+    fn quad_aabb_of_axis_aligned_scaled_quad_is_exact() {
+        let transform = glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::new(2.0, 3.0, 1.0), 
+            glam::Quat::IDENTITY, 
+            glam::Vec3::new(1.0, -1.0, 4.0)
+        );
+        let (min, max) = ColoredObject::quad_aabb(&transform, 0.0);
+        assert_eq!(min, glam::Vec3::new(-1.0, -4.0, 4.0));
+        assert_eq!(max, glam::Vec3::new(3.0, 2.0, 4.0));
+
+        // (한국어) 흔들림은 법선(+Z) 방향으로만 상자를 넓힙니다.
+        // (English Translation) The wobble only widens the box along the normal (+Z).
+        let (min, max) = ColoredObject::quad_aabb(&transform, 0.25);
+        assert_eq!(min, glam::Vec3::new(-1.0, -4.0, 3.75));
+        assert_eq!(max, glam::Vec3::new(3.0, 2.0, 4.25));
+    }
+
+    #[test]
+    fn quad_aabb_covers_rotated_quad() {
+        // (한국어) Y축으로 90° 회전한 사각형은 X 방향으로 두께가 없고 Z 방향으로 펼쳐집니다.
+        // (English Translation) A quad rotated 90° around the Y axis has no thickness along X and spans along Z.
+        let transform = glam::Mat4::from_rotation_y(90.0f32.to_radians());
+        let (min, max) = ColoredObject::quad_aabb(&transform, 0.0);
+        assert!(min.abs_diff_eq(glam::Vec3::new(0.0, -1.0, -1.0), 1e-6));
+        assert!(max.abs_diff_eq(glam::Vec3::new(0.0, 1.0, 1.0), 1e-6));
+    }
+}
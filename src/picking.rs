@@ -0,0 +1,345 @@
+use std::mem;
+use std::sync::mpsc;
+use crate::interfaces::ShaderResource;
+use crate::pipeline::PickingPipelines;
+use crate::renderer::DrawContext;
+use crate::scene::ObjectBucket;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 오브젝트 ID 패스의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the object ID pass passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PickingUniformLayout {
+    pub object_id: u32, 
+    _padding: [u32; 3], 
+}
+
+/// #### 한국어 </br>
+/// 오브젝트 ID 패스의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 2) </br>
+/// 오브젝트마다 다른 슬롯을 가리키도록 동적 오프셋을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the object ID pass. (bind group slot 2) </br>
+/// It uses a dynamic offset so that each object points to a different slot. </br>
+/// 
+pub fn create_picking_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(Picking)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform, 
+                        has_dynamic_offset: true, 
+                        min_binding_size: wgpu::BufferSize::new(mem::size_of::<PickingUniformLayout>() as u64), 
+                    }, 
+                    count: None, 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// GPU에서 화면의 픽셀에 그려진 불투명한 오브젝트를 찾는 오브젝트 ID 피커 입니다. </br>
+/// 
+/// 오브젝트 ID 패스는 보이는 불투명한 오브젝트들을 정수 렌더 타겟(`PickingPipelines::ID_FORMAT`)에 그리며, </br>
+/// 각 오브젝트는 유니폼 버퍼의 자기 슬롯(동적 오프셋)에서 읽은 ID(`opaque_objects`의 인덱스 + 1)를 기록합니다. </br>
+/// 슬롯 간격은 장치의 `min_uniform_buffer_offset_alignment` 제한에 맞춰 정렬되며, ID는 바뀌지 않으므로 생성할 때 한 번만 씁니다. </br>
+/// 
+/// 여러 오브젝트가 한 픽셀에 겹치는 경우 장면과 같은 방향의 깊이 테스트로 카메라에 가장 가까운 오브젝트가 남습니다. </br>
+/// 깊이가 정확히 같은 경우(같은 평면) 먼저 그려진, 즉 인덱스가 작은 오브젝트가 남으며, 오브젝트별 깊이 바이어스로 우선순위를 바꿀 수 있습니다. </br>
+/// 데칼과 투명한 오브젝트는 그리지 않으므로 선택되지 않으며 불투명한 오브젝트를 가리지도 않습니다. </br>
+/// 
+/// 렌더 타겟은 창 크기와 같으며 창의 크기가 바뀔 때 [`ObjectPicker::resize`]로 다시 생성해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An object ID picker that finds the opaque object drawn at a pixel on the screen on the GPU. </br>
+/// 
+/// The object ID pass draws the visible opaque objects into an integer render target (`PickingPipelines::ID_FORMAT`), </br>
+/// and each object writes the ID (index in `opaque_objects` + 1) read from its own slot of the uniform buffer (dynamic offset). </br>
+/// The slot stride is aligned to the device's `min_uniform_buffer_offset_alignment` limit, and since the IDs never change they are written once at creation. </br>
+/// 
+/// If several objects overlap at a pixel, the depth test in the same direction as the scene keeps the object nearest to the camera. </br>
+/// If the depths are exactly equal (the same plane), the object drawn first, that is the one with the smaller index, is kept, and the per-object depth bias can change the priority. </br>
+/// Decals and transparent objects are not drawn, so they are never picked and do not occlude opaque objects. </br>
+/// 
+/// The render target has the size of the window and must be recreated with [`ObjectPicker::resize`] when the window is resized. </br>
+/// 
+#[derive(Debug)]
+pub struct ObjectPicker {
+    width: u32, 
+    height: u32, 
+    stride: wgpu::BufferAddress, 
+    id_texture: wgpu::Texture, 
+    id_view: wgpu::TextureView, 
+    depth_view: wgpu::TextureView, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+    readback_buffer: wgpu::Buffer, 
+}
+
+#[allow(dead_code)]
+impl ObjectPicker {
+    /// #### 한국어 </br>
+    /// 픽셀 하나를 읽어오는 버퍼의 크기 입니다. </br>
+    /// 텍스처를 버퍼로 복사할 때 `bytes_per_row`는 `COPY_BYTES_PER_ROW_ALIGNMENT` (256 바이트)의 배수여야 하므로, </br>
+    /// 4 바이트 픽셀 하나를 읽어도 한 행은 256 바이트를 차지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The size of the buffer that reads back a single pixel. </br>
+    /// When copying a texture into a buffer, `bytes_per_row` must be a multiple of `COPY_BYTES_PER_ROW_ALIGNMENT` (256 bytes), </br>
+    /// so a row takes 256 bytes even when reading a single 4 byte pixel. </br>
+    /// 
+    pub const READBACK_ROW_BYTES: u32 = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    /// #### 한국어 </br>
+    /// 최대 `capacity`개의 불투명한 오브젝트를 구분하는 오브젝트 ID 피커를 생성합니다. </br>
+    /// 인덱스가 `capacity` 이상인 오브젝트는 오브젝트 ID 패스에서 그려지지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an object ID picker that distinguishes up to `capacity` opaque objects. </br>
+    /// Objects with an index of `capacity` or more are not drawn in the object ID pass. </br>
+    /// 
+    pub fn new(
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        capacity: usize, 
+        width: u32, 
+        height: u32
+    ) -> Self {
+        let alignment = device.limits().min_uniform_buffer_offset_alignment as wgpu::BufferAddress;
+        let size = mem::size_of::<PickingUniformLayout>() as wgpu::BufferAddress;
+        let stride = size.div_ceil(alignment) * alignment;
+
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Picking)"), 
+                mapped_at_creation: false, 
+                size: stride * capacity.max(1) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        // (한국어) 각 슬롯에 오브젝트의 ID를 씁니다. 0은 오브젝트가 없는 픽셀을 위해 남겨둡니다.
+        // (English Translation) Writes the ID of the object into each slot. 0 is left for pixels without an object.
+        let mut staging = vec![0u8; (stride * capacity.max(1) as wgpu::BufferAddress) as usize];
+        for index in 0..capacity {
+            let data = PickingUniformLayout { object_id: index as u32 + 1, ..Default::default() };
+            let offset = (stride * index as wgpu::BufferAddress) as usize;
+            staging[offset..offset + size as usize].copy_from_slice(bytemuck::bytes_of(&data));
+        }
+        queue.write_buffer(&buffer, 0, &staging);
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Picking)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            wgpu::BufferBinding {
+                                buffer: &buffer, 
+                                offset: 0, 
+                                size: wgpu::BufferSize::new(size), 
+                            }
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        let readback_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("ReadbackBuffer(Picking)"), 
+                mapped_at_creation: false, 
+                size: Self::READBACK_ROW_BYTES as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let width = width.max(1);
+        let height = height.max(1);
+        let (id_texture, id_view, depth_view) = create_picking_targets(device, width, height);
+        Self {
+            width, 
+            height, 
+            stride, 
+            id_texture, 
+            id_view, 
+            depth_view, 
+            buffer, 
+            bind_group, 
+            readback_buffer, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트 ID 렌더 타겟과 깊이 버퍼를 주어진 크기로 다시 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the object ID render target and the depth buffer with the given size. </br>
+    /// 
+    pub fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        self.width = width.max(1);
+        self.height = height.max(1);
+        (self.id_texture, self.id_view, self.depth_view) = create_picking_targets(device, self.width, self.height);
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        (self.buffer.size() / self.stride) as usize
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 픽셀에 그려진 불투명한 오브젝트의 인덱스(`opaque_objects`)를 가져옵니다. </br>
+    /// 오브젝트 ID 패스를 그린 후 픽셀 하나를 읽어오며, GPU 작업이 끝날 때까지 기다리므로 클릭과 같이 가끔 호출해야 합니다. </br>
+    /// 픽셀에 오브젝트가 없거나, 픽셀이 렌더 타겟 밖에 있거나, 오브젝트 ID 패스 파이프라인이 없는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the index (in `opaque_objects`) of the opaque object drawn at the given pixel. </br>
+    /// It draws the object ID pass and reads back a single pixel, waiting until the GPU work is finished, so it should be called only occasionally, such as on a click. </br>
+    /// Returns `None` if there is no object at the pixel, the pixel is outside the render target, or there is no object ID pass pipeline. </br>
+    /// 
+    pub fn pick(
+        &self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        context: &DrawContext, 
+        camera_bind_group: &wgpu::BindGroup, 
+        pixel: glam::Vec2
+    ) -> Option<usize> {
+        let pipelines = context.pipelines.picking.as_ref()?;
+        if pixel.x < 0.0 || pixel.y < 0.0 || pixel.x >= self.width as f32 || pixel.y >= self.height as f32 {
+            return None;
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(Picking)"), 
+        });
+        self.record_id_pass(&mut encoder, context, pipelines, camera_bind_group);
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.id_texture, 
+                mip_level: 0, 
+                origin: wgpu::Origin3d { x: pixel.x as u32, y: pixel.y as u32, z: 0 }, 
+                aspect: wgpu::TextureAspect::All, 
+            }, 
+            wgpu::ImageCopyBuffer {
+                buffer: &self.readback_buffer, 
+                layout: wgpu::ImageDataLayout {
+                    offset: 0, 
+                    bytes_per_row: Some(Self::READBACK_ROW_BYTES), 
+                    rows_per_image: Some(1), 
+                }, 
+            }, 
+            wgpu::Extent3d {
+                width: 1, 
+                height: 1, 
+                depth_or_array_layers: 1, 
+            }
+        );
+        queue.submit(Some(encoder.finish()));
+
+        let (sender, receiver) = mpsc::channel();
+        let slice = self.readback_buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        if let Err(e) = receiver.recv().ok()? {
+            log::warn!("Failed to read the object ID: {}", e);
+            return None;
+        }
+
+        let object_id = {
+            let mapped = slice.get_mapped_range();
+            bytemuck::pod_read_unaligned::<u32>(&mapped[..mem::size_of::<u32>()])
+        };
+        self.readback_buffer.unmap();
+        (object_id as usize).checked_sub(1)
+    }
+
+    fn record_id_pass(
+        &self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        context: &DrawContext, 
+        pipelines: &PickingPipelines, 
+        camera_bind_group: &wgpu::BindGroup
+    ) {
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("RenderPass(Picking)"), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: &self.id_view, 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &self.depth_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(context.pipelines.settings.depth_clear_value()), 
+                        store: wgpu::StoreOp::Discard, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: None, 
+                occlusion_query_set: None, 
+            }
+        );
+
+        rpass.set_pipeline(&pipelines.id);
+        rpass.set_bind_group(0, camera_bind_group, &[]);
+        context.quad_mesh.bind(&mut rpass);
+        let objects = context.opaque_objects.iter()
+            .enumerate()
+            .take(self.capacity())
+            .filter(|&(index, object)| object.is_visible() && context.is_visible(ObjectBucket::Opaque, index));
+        for (index, object) in objects {
+            let offset = self.stride * index as wgpu::BufferAddress;
+            rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+            rpass.set_bind_group(2, &self.bind_group, &[offset as wgpu::DynamicOffset]);
+            context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+        }
+    }
+}
+
+fn create_picking_targets(device: &wgpu::Device, width: u32, height: u32) -> (wgpu::Texture, wgpu::TextureView, wgpu::TextureView) {
+    let size = wgpu::Extent3d {
+        width, 
+        height, 
+        depth_or_array_layers: 1, 
+    };
+    let id_texture = device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some("Texture(PickingId)"), 
+            size, 
+            format: PickingPipelines::ID_FORMAT, 
+            dimension: wgpu::TextureDimension::D2, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC, 
+            view_formats: &[], 
+        }, 
+    );
+    let id_view = id_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let depth_view = utils::create_depth_stencil_view(device, "Texture(PickingDepth)", PickingPipelines::DEPTH_FORMAT, width, height, 1);
+    (id_texture, id_view, depth_view)
+}
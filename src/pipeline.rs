@@ -1,21 +1,2344 @@
 use std::mem;
+use std::num::NonZeroU32;
+use crate::mesh::Vertex;
+use crate::objects::{BlendMode, ColoredObjectUniformLayout, InstanceLayout};
+use crate::shadow::ShadowBindGroupLayouts;
+use crate::skinning::SkinnedVertex;
 
 
 
+/// #### 한국어 </br>
+/// 모든 그래픽스 파이프라인에 공통으로 적용되는 설정입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings that are applied in common to all graphics pipelines. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipelineSettings {
+    pub reverse_z: bool, 
+    pub unclipped_depth: bool, 
+    pub color_format: wgpu::TextureFormat, 
+    pub depth_format: wgpu::TextureFormat, 
+    pub reveal_format: wgpu::TextureFormat, 
+    pub multiview: Option<NonZeroU32>, 
+    pub hdr: bool, 
+    pub bloom: bool, 
+}
+
+impl Default for PipelineSettings {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            reverse_z: false, 
+            unclipped_depth: false, 
+            color_format: wgpu::TextureFormat::Bgra8Unorm, 
+            depth_format: wgpu::TextureFormat::Depth32Float, 
+            reveal_format: wgpu::TextureFormat::R8Unorm, 
+            multiview: None, 
+            hdr: false, 
+            bloom: false, 
+        }
+    }
+}
+
+impl PipelineSettings {
+    /// #### 한국어 </br>
+    /// 깊이 테스트에 사용할 비교 함수를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the comparison function to use for depth testing. </br>
+    /// 
+    #[inline]
+    pub fn depth_compare(&self) -> wgpu::CompareFunction {
+        match self.reverse_z {
+            true => wgpu::CompareFunction::Greater, 
+            false => wgpu::CompareFunction::Less, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 버퍼를 지울 때 사용할 값을 가져옵니다. 역방향 깊이(reverse-Z)인 경우 가장 먼 깊이는 `0.0` 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the value used to clear the depth buffer. With reverse-Z, the farthest depth is `0.0`. </br>
+    /// 
+    #[inline]
+    pub fn depth_clear_value(&self) -> f32 {
+        match self.reverse_z {
+            true => 0.0, 
+            false => 1.0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 스테레오 렌더링에서 한 번의 패스로 그리는 뷰(눈)의 수 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of views (eyes) drawn in one pass in stereo rendering. </br>
+    /// 
+    pub const STEREO_VIEW_COUNT: u32 = 2;
+
+    /// #### 한국어 </br>
+    /// 투명 패스의 누적 값 렌더 타겟의 텍스처 형식 입니다. </br>
+    /// 가중치가 곱해진 색상의 합은 1을 넘을 수 있고 선형 공간에서 더해져야 하므로, 선형 부동 소수점 형식을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The texture format of the accumulated value render target of the transparent pass. </br>
+    /// The sum of weighted colors can exceed 1 and must be added in linear space, so a linear floating-point format is used. </br>
+    /// 
+    pub const ACCUM_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+    /// #### 한국어 </br>
+    /// 오브젝트를 그리는 정점 쉐이더의 진입점 이름을 가져옵니다. </br>
+    /// 스테레오 렌더링인 경우 `@builtin(view_index)`로 카메라 행렬을 선택하는 진입점을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the entry point name of the vertex shader that draws objects. </br>
+    /// For stereo rendering, uses the entry point that selects the camera matrices by `@builtin(view_index)`. </br>
+    /// 
+    #[inline]
+    pub fn vertex_entry_point(&self, instanced: bool) -> &'static str {
+        match (self.multiview.is_some(), instanced) {
+            (false, false) => "vs_main", 
+            (false, true) => "vs_instanced_main", 
+            (true, false) => "vs_stereo_main", 
+            (true, true) => "vs_stereo_instanced_main", 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 스키닝 메쉬를 그리는 정점 쉐이더의 진입점 이름을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the entry point name of the vertex shader that draws skinned meshes. </br>
+    /// 
+    #[inline]
+    pub fn skinned_vertex_entry_point(&self) -> &'static str {
+        match self.multiview.is_some() {
+            true => "vs_stereo_skinned_main", 
+            false => "vs_skinned_main", 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처 오브젝트를 그리는 정점 쉐이더의 진입점 이름을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the entry point name of the vertex shader that draws textured objects. </br>
+    /// 
+    #[inline]
+    pub fn textured_vertex_entry_point(&self) -> &'static str {
+        match self.multiview.is_some() {
+            true => "vs_stereo_textured_main", 
+            false => "vs_textured_main", 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 합성 패스의 프래그먼트 쉐이더 진입점 이름을 가져옵니다. </br>
+    /// 스테레오 렌더링인 경우 텍스처 배열에서 `@builtin(view_index)` 레이어를 읽는 진입점을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the entry point name of the fragment shader for the composite pass. </br>
+    /// For stereo rendering, uses the entry point that reads the `@builtin(view_index)` layer from the texture arrays. </br>
+    /// 
+    #[inline]
+    pub fn composite_entry_point(&self) -> &'static str {
+        match self.multiview.is_some() {
+            true => "fs_stereo_composite_pass", 
+            false => "fs_composite_pass", 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 장면을 그리는 파이프라인들의 색상 렌더 타겟 형식을 가져옵니다. </br>
+    /// HDR 렌더 타겟을 사용하는 경우 장면은 화면 대신 HDR 텍스처(`HdrPipelines::COLOR_FORMAT`)에 그려지고, </br>
+    /// 톤 매핑 패스만 `color_format`으로 화면에 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the color render target format of the pipelines that draw the scene. </br>
+    /// With the HDR render target, the scene is drawn into an HDR texture (`HdrPipelines::COLOR_FORMAT`) instead of the surface, </br>
+    /// and only the tone mapping pass writes to the surface with `color_format`. </br>
+    /// 
+    #[inline]
+    pub fn scene_color_format(&self) -> wgpu::TextureFormat {
+        match self.hdr {
+            true => HdrPipelines::COLOR_FORMAT, 
+            false => self.color_format, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 합성 패스의 깊이 스텐실 상태를 가져옵니다. </br>
+    /// 전체 화면 사각형이 불투명한 오브젝트의 깊이 값을 덮어쓰지 않도록 깊이를 쓰지 않으며, 깊이 비교는 항상 통과합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the depth stencil state of the composite pass. </br>
+    /// Depth is not written so that the fullscreen quad does not overwrite the depth values of opaque objects, and the depth comparison always passes. </br>
+    /// 
+    #[inline]
+    pub fn composite_depth_stencil(&self) -> wgpu::DepthStencilState {
+        wgpu::DepthStencilState {
+            format: self.depth_format, 
+            depth_compare: wgpu::CompareFunction::Always, 
+            depth_write_enabled: false, 
+            stencil: wgpu::StencilState::default(), 
+            bias: wgpu::DepthBiasState::default(),
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면을 그리는 데 사용되는 그래픽스 파이프라인 집합입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A set of graphics pipelines used to draw the scene. </br>
+/// 
+#[derive(Debug)]
+pub struct Pipelines {
+    pub settings: PipelineSettings, 
+    pub opaque: wgpu::RenderPipeline, 
+    pub transparent: wgpu::RenderPipeline, 
+    pub composite: wgpu::RenderPipeline, 
+    pub decal: wgpu::RenderPipeline, 
+    pub mesh: wgpu::RenderPipeline, 
+    pub wireframe: Option<wgpu::RenderPipeline>, 
+    pub opaque_instanced: Option<wgpu::RenderPipeline>, 
+    pub transparent_instanced: Option<wgpu::RenderPipeline>, 
+    pub skinned: Option<wgpu::RenderPipeline>, 
+    pub textured: Option<wgpu::RenderPipeline>, 
+    pub grid: Option<wgpu::RenderPipeline>, 
+    pub sky: Option<SkyPipelines>, 
+    pub shadow: Option<ShadowPipelines>, 
+    pub picking: Option<PickingPipelines>, 
+    pub sorted_transparent: Option<SortedTransparentPipelines>, 
+    pub oit_downsample: Option<OitDownsamplePipelines>, 
+    pub depth_peel: Option<DepthPeelPipelines>, 
+    pub depth_prepass: Option<DepthPrepassPipelines>, 
+    pub push_constants: Option<PushConstantPipelines>, 
+    pub hdr: Option<HdrPipelines>, 
+}
+
+/// #### 한국어 </br>
+/// 정렬된 알파 블렌딩 경로에서 블렌딩 방법별로 사용되는 그래픽스 파이프라인들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines used per blend mode in the sorted alpha blending path. </br>
+/// 
+#[derive(Debug)]
+pub struct SortedTransparentPipelines {
+    pub alpha_blend: wgpu::RenderPipeline, 
+    pub additive: wgpu::RenderPipeline, 
+    pub multiply: wgpu::RenderPipeline, 
+}
+
+#[allow(dead_code)]
+impl SortedTransparentPipelines {
+    #[inline]
+    pub fn get(&self, blend_mode: BlendMode) -> &wgpu::RenderPipeline {
+        match blend_mode {
+            BlendMode::AlphaBlend => &self.alpha_blend, 
+            BlendMode::Additive => &self.additive, 
+            BlendMode::Multiply => &self.multiply, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 하늘 배경을 그리는 그래픽스 파이프라인들 입니다. (참고: `sky::SkySettings`) </br>
+/// 큐브맵 파이프라인(`cubemap`)은 큐브 텍스처 바인드 그룹 레이아웃이 주어진 경우에만 생성됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines that draw the sky background. (see also: `sky::SkySettings`) </br>
+/// The cubemap pipeline (`cubemap`) is only created if a cube texture bind group layout was given. </br>
+/// 
+#[derive(Debug)]
+pub struct SkyPipelines {
+    pub gradient: wgpu::RenderPipeline, 
+    pub cubemap: Option<wgpu::RenderPipeline>, 
+}
+
+/// #### 한국어 </br>
+/// 방향광의 그림자를 그리는 그래픽스 파이프라인들 입니다. (참고: `shadow::ShadowMap`) </br>
+/// 
+/// - `depth`: 그림자를 드리우는 불투명한 오브젝트들을 빛의 시점에서 그림자 맵에 그리는 깊이 전용 파이프라인. </br>
+/// - `opaque`: 그림자 맵(바인드 그룹 슬롯 3)을 비교 샘플링하여 그림자를 받는 불투명 파이프라인. 그림자를 사용하는 동안 `Pipelines::opaque`와 푸시 상수 경로 대신 사용됩니다. </br>
+/// 
+/// 인스턴스 묶음, 데칼, 투명한 오브젝트는 그림자를 드리우거나 받지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines that draw the shadows of the directional light. (see also: `shadow::ShadowMap`) </br>
+/// 
+/// - `depth`: the depth-only pipeline that draws the opaque objects casting shadows into the shadow map from the light's point of view. </br>
+/// - `opaque`: the opaque pipeline that receives shadows by comparison sampling the shadow map (bind group slot 3). It replaces `Pipelines::opaque` and the push constant path while shadows are used. </br>
+/// 
+/// Instance batches, decals and transparent objects neither cast nor receive shadows. </br>
+/// 
+#[derive(Debug)]
+pub struct ShadowPipelines {
+    pub depth: wgpu::RenderPipeline, 
+    pub opaque: wgpu::RenderPipeline, 
+}
+
+impl ShadowPipelines {
+    /// #### 한국어 </br>
+    /// 그림자 맵의 깊이 형식 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The depth format of the shadow map. </br>
+    /// 
+    pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+    /// #### 한국어 </br>
+    /// 그림자 맵 패스의 하드웨어 깊이 편향 입니다. 빛에 비스듬한 표면일수록 깊이를 더 멀리 밀어 그림자 여드름을 줄입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The hardware depth bias of the shadow map pass. Pushes the depth of surfaces further away the more oblique they are to the light, reducing shadow acne. </br>
+    /// 
+    pub const DEPTH_BIAS: wgpu::DepthBiasState = wgpu::DepthBiasState {
+        constant: 2, 
+        slope_scale: 2.0, 
+        clamp: 0.0, 
+    };
+
+    /// #### 한국어 </br>
+    /// 그림자 맵 패스의 파이프라인 레이아웃에 있는 바인드 그룹의 수 입니다. (빛, 오브젝트, 전역 데이터) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of bind groups in the pipeline layout of the shadow map pass. (light, object, global data) </br>
+    /// 
+    pub const DEPTH_BIND_GROUPS: usize = 3;
+
+    /// #### 한국어 </br>
+    /// 그림자를 받는 불투명 패스의 파이프라인 레이아웃에 있는 바인드 그룹의 수 입니다. (카메라, 오브젝트, 전역 데이터, 그림자 맵) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of bind groups in the pipeline layout of the opaque pass receiving shadows. (camera, object, global data, shadow map) </br>
+    /// 
+    pub const OPAQUE_BIND_GROUPS: usize = 4;
+}
+
+/// #### 한국어 </br>
+/// 화면의 픽셀에 그려진 불투명한 오브젝트를 찾는 오브젝트 ID 패스의 그래픽스 파이프라인 입니다. (참고: `picking::ObjectPicker`) </br>
+/// 각 오브젝트의 ID(인덱스 + 1)를 정수 렌더 타겟에 기록하며, 0은 오브젝트가 없는 픽셀을 의미합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The graphics pipeline of the object ID pass that finds the opaque object drawn at a pixel on the screen. (see also: `picking::ObjectPicker`) </br>
+/// It writes the ID (index + 1) of each object into an integer render target, and 0 means a pixel without an object. </br>
+/// 
+#[derive(Debug)]
+pub struct PickingPipelines {
+    pub id: wgpu::RenderPipeline, 
+}
+
+impl PickingPipelines {
+    /// #### 한국어 </br>
+    /// 오브젝트 ID 렌더 타겟의 형식 입니다. 정수 형식은 블렌딩과 다중 샘플링 해결 없이 값을 그대로 저장합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The format of the object ID render target. An integer format stores values as is, without blending or multisample resolve. </br>
+    /// 
+    pub const ID_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R32Uint;
+
+    /// #### 한국어 </br>
+    /// 오브젝트 ID 패스의 깊이 형식 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The depth format of the object ID pass. </br>
+    /// 
+    pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+}
+
+/// #### 한국어 </br>
+/// 투명 패스를 낮은 해상도로 그릴 때 사용되는 그래픽스 파이프라인들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines used when the transparent pass is drawn at a lower resolution. </br>
+/// 
+#[derive(Debug)]
+pub struct OitDownsamplePipelines {
+    pub depth: wgpu::RenderPipeline, 
+    pub composite: wgpu::RenderPipeline, 
+}
+
+/// #### 한국어 </br>
+/// 오브젝트별 데이터(월드 행렬, 색상 등)를 유니폼 버퍼 대신 푸시 상수로 전달하는 그래픽스 파이프라인들 입니다. </br>
+/// 
+/// 오브젝트마다 바인드 그룹을 바꾸는 대신 그리기 직전에 `ColoredObject::uniform_data`를 푸시 상수로 기록하므로, </br>
+/// 작은 오브젝트가 많은 경우 바인드 그룹 전환 비용이 줄어듭니다. `PUSH_CONSTANTS` 기능이 필요하며, </br>
+/// 지원하지 않는 장치에서는 생성되지 않고 유니폼 버퍼 경로가 사용됩니다. </br>
+/// 
+/// 바인드 그룹 슬롯 1은 쉐이더에서 사용되지 않지만 파이프라인 레이아웃의 슬롯이 연속되어야 하므로 빈 바인드 그룹(`empty_bind_group`)을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines that pass per-object data (world matrix, color, etc.) as push constants instead of a uniform buffer. </br>
+/// 
+/// Instead of switching the bind group for every object, `ColoredObject::uniform_data` is written as push constants right before drawing, </br>
+/// which reduces the cost of bind group switches when there are many small objects. It requires the `PUSH_CONSTANTS` feature, </br>
+/// and on devices that do not support it, these pipelines are not created and the uniform buffer path is used. </br>
+/// 
+/// Bind group slot 1 is not used by the shader, but the slots of a pipeline layout must be contiguous, so an empty bind group (`empty_bind_group`) is used. </br>
+/// 
+#[derive(Debug)]
+pub struct PushConstantPipelines {
+    pub empty_bind_group: wgpu::BindGroup, 
+    pub opaque: wgpu::RenderPipeline, 
+    pub transparent: wgpu::RenderPipeline, 
+}
+
+impl PushConstantPipelines {
+    /// #### 한국어 </br>
+    /// 정점 쉐이더와 조각 쉐이더에서 읽는 푸시 상수 범위 입니다. (`ColoredObjectUniformLayout`의 크기) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The push constant range read by the vertex and fragment shaders. (the size of `ColoredObjectUniformLayout`) </br>
+    /// 
+    pub const RANGE: wgpu::PushConstantRange = wgpu::PushConstantRange {
+        stages: wgpu::ShaderStages::VERTEX_FRAGMENT, 
+        range: 0..mem::size_of::<ColoredObjectUniformLayout>() as u32, 
+    };
+}
+
+/// #### 한국어 </br>
+/// 불투명 패스 전에 깊이 값만 기록하는 깊이 사전 패스(depth pre-pass)의 그래픽스 파이프라인들 입니다. </br>
+/// 
+/// 사전 패스가 가장 가까운 표면의 깊이를 미리 기록하므로, 불투명 패스에서는 보이는 조각만 색상을 계산하여 </br>
+/// 겹쳐 그리기(overdraw)의 비용이 줄어듭니다. 대신 불투명한 오브젝트의 정점 처리가 두 번 필요하므로 </br>
+/// 조각 쉐이더의 비용이 큰 경우에만 이득이 있습니다. </br>
+/// 
+/// 사전 패스가 있는 경우 불투명 파이프라인(`opaque`, `opaque_instanced`)은 다음과 같이 생성됩니다. </br>
+/// - `depth_compare`: `Less`(역방향 깊이인 경우 `Greater`) 대신 `Equal`. 사전 패스가 기록한 깊이와 같은 조각만 통과합니다. </br>
+/// - `depth_write_enabled`: `true` 대신 `false`. 깊이 버퍼는 사전 패스에서 이미 완성됩니다. </br>
+/// 
+/// `Equal` 비교가 동작하려면 두 패스의 깊이 값이 비트 단위로 같아야 하므로, </br>
+/// 사전 패스의 정점 쉐이더와 불투명 패스의 정점 쉐이더는 같은 식으로 `@invariant` 위치를 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines of the depth pre-pass, which writes only depth values before the opaque pass. </br>
+/// 
+/// Since the pre-pass writes the depth of the nearest surfaces up front, the opaque pass computes the color of visible fragments only, </br>
+/// which reduces the cost of overdraw. In exchange the vertices of opaque objects are processed twice, </br>
+/// so it only pays off when the fragment shader is expensive. </br>
+/// 
+/// When the pre-pass exists, the opaque pipelines (`opaque`, `opaque_instanced`) are created as follows. </br>
+/// - `depth_compare`: `Equal` instead of `Less` (`Greater` with reverse-Z). Only fragments at the depth written by the pre-pass pass. </br>
+/// - `depth_write_enabled`: `false` instead of `true`. The depth buffer is already complete after the pre-pass. </br>
+/// 
+/// For the `Equal` comparison to work, the depth values of both passes must be bit-identical, </br>
+/// so the vertex shaders of the pre-pass and the opaque pass compute an `@invariant` position with the same expression. </br>
+/// 
+#[derive(Debug)]
+pub struct DepthPrepassPipelines {
+    pub prepass: wgpu::RenderPipeline, 
+    pub prepass_instanced: Option<wgpu::RenderPipeline>, 
+}
+
+/// #### 한국어 </br>
+/// 가중 블렌딩 OIT 대신 깊이 벗기기(depth peeling)로 투명한 오브젝트를 그릴 때 사용되는 그래픽스 파이프라인과 바인드 그룹 레이아웃 입니다. </br>
+/// 
+/// 한 번의 투명 패스와 합성 패스로 끝나는 가중 블렌딩 OIT와 달리, 레이어마다 두 개의 패스가 필요합니다. </br>
+/// 벗기기 패스는 이전 레이어보다 뒤에 있는 가장 가까운 투명 표면 하나를 그리고, </br>
+/// 블렌딩 패스는 그 레이어를 누적 텍스처에 앞에서 뒤 순서로(under 연산) 합칩니다. </br>
+/// 따라서 `layers`개의 레이어에 대해 `2 * layers + 1`번의 패스(마지막은 합성 패스)와 `layers`번의 투명 오브젝트 그리기가 필요하며, </br>
+/// 대신 겹치는 투명 표면의 색상이 정확합니다. `layers`보다 많이 겹친 표면은 그려지지 않습니다. </br>
+/// 
+/// - `depth_bind_group_layout`: 벗기기 패스의 바인드 그룹 슬롯 3. (장면 깊이 텍스처, 이전 레이어의 깊이 텍스처) </br>
+/// - `color_bind_group_layout`: 블렌딩 패스와 합성 패스의 바인드 그룹 슬롯 0. (읽을 색상 텍스처) </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines and bind group layouts used when drawing transparent objects with depth peeling instead of weighted blended OIT. </br>
+/// 
+/// Unlike weighted blended OIT, which finishes with one transparent pass and one composite pass, each layer needs two passes. </br>
+/// The peel pass draws the single nearest transparent surface behind the previous layer, </br>
+/// and the blend pass merges that layer into the accumulation texture front to back (under operator). </br>
+/// So `layers` layers need `2 * layers + 1` passes (the last is the composite pass) and `layers` draws of the transparent objects, </br>
+/// in exchange for accurate colors of overlapping transparent surfaces. Surfaces overlapping more than `layers` times are not drawn. </br>
+/// 
+/// - `depth_bind_group_layout`: bind group slot 3 of the peel pass. (scene depth texture, depth texture of the previous layer) </br>
+/// - `color_bind_group_layout`: bind group slot 0 of the blend and composite passes. (the color texture to read) </br>
+/// 
+#[derive(Debug)]
+pub struct DepthPeelPipelines {
+    pub layers: u32, 
+    pub depth_bind_group_layout: wgpu::BindGroupLayout, 
+    pub color_bind_group_layout: wgpu::BindGroupLayout, 
+    pub peel: wgpu::RenderPipeline, 
+    pub peel_instanced: Option<wgpu::RenderPipeline>, 
+    pub blend: wgpu::RenderPipeline, 
+    pub composite: wgpu::RenderPipeline, 
+}
+
+#[allow(dead_code)]
+impl DepthPeelPipelines {
+    /// #### 한국어 </br>
+    /// 레이어 수의 기본값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default number of layers. </br>
+    /// 
+    pub const DEFAULT_LAYERS: u32 = 4;
+
+    /// #### 한국어 </br>
+    /// 레이어 수의 최댓값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum number of layers. </br>
+    /// 
+    pub const MAX_LAYERS: u32 = 16;
+
+    /// #### 한국어 </br>
+    /// 레이어 색상과 누적 값을 저장하는 텍스처의 형식 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The format of the textures that store the layer colors and the accumulated value. </br>
+    /// 
+    pub const COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+}
+
+/// #### 한국어 </br>
+/// 장면을 HDR 렌더 타겟에 그린 후 톤 매핑하여 화면에 쓰는 그래픽스 파이프라인과 바인드 그룹 레이아웃 입니다. </br>
+/// 
+/// HDR 렌더 타겟을 사용하는 경우 장면(불투명 패스부터 합성 패스까지)은 화면 대신 `COLOR_FORMAT` 텍스처에 그려지므로 </br>
+/// 1보다 밝은 색상이 잘리지 않습니다. 마지막으로 `tonemap` 패스가 노출을 곱하고 톤 매핑 연산자를 적용하여 화면에 씁니다. (참고: `tonemap::ToneMapSettings`) </br>
+/// 전체 해상도의 `Rgba16Float` 텍스처(1920x1080에서 약 16 MB)와 장면 텍스처를 한 번 읽는 전체 화면 패스가 추가됩니다. </br>
+/// 
+/// - `texture_bind_group_layout`: 읽을 텍스처와 선형 필터링 샘플러. 후처리 패스들의 바인드 그룹 슬롯 0에 사용됩니다. </br>
+/// - `bloom`: 블룸을 사용하는 경우 톤 매핑 전에 그려지는 블룸 패스들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines and bind group layout that draw the scene into an HDR render target and then tonemap it to the surface. </br>
+/// 
+/// With the HDR render target, the scene (from the opaque pass to the composite pass) is drawn into a `COLOR_FORMAT` texture instead of the surface, </br>
+/// so colors brighter than 1 are not clipped. Finally, the `tonemap` pass multiplies the exposure, applies the tone mapping operator and writes to the surface. (see also: `tonemap::ToneMapSettings`) </br>
+/// It adds a full resolution `Rgba16Float` texture (about 16 MB at 1920x1080) and a full-screen pass that reads the scene texture once. </br>
+/// 
+/// - `texture_bind_group_layout`: the texture to read and a linear filtering sampler. Used for bind group slot 0 of the post-process passes. </br>
+/// - `bloom`: the bloom passes drawn before tone mapping, if bloom is used. </br>
+/// 
+#[derive(Debug)]
+pub struct HdrPipelines {
+    pub texture_bind_group_layout: wgpu::BindGroupLayout, 
+    pub tonemap: wgpu::RenderPipeline, 
+    pub bloom: Option<BloomPipelines>, 
+}
+
+impl HdrPipelines {
+    /// #### 한국어 </br>
+    /// 장면과 블러 텍스처의 형식 입니다. 1보다 밝은 색상을 잃지 않도록 부동 소수점 형식을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The format of the scene and blur textures. A floating-point format is used so that colors brighter than 1 are not lost. </br>
+    /// 
+    pub const COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+}
+
+/// #### 한국어 </br>
+/// 블룸 후처리의 그래픽스 파이프라인들 입니다. HDR 렌더 타겟이 필요합니다. (참고: `HdrPipelines`) </br>
+/// 
+/// 장면을 HDR 텍스처에 그린 후 톤 매핑 전에 네 번의 전체 화면 패스가 추가됩니다. </br>
+/// 1. `bright`: 장면에서 임계값(`RenderSettings::bloom_threshold`)보다 밝은 부분만 절반 해상도의 텍스처에 씁니다. </br>
+/// 2. `blur_horizontal`, `blur_vertical`: 두 절반 해상도 텍스처를 번갈아 사용하는(ping-pong) 분리 가능한 9탭 가우시안 블러 입니다. </br>
+/// 3. `composite`: 흐려진 밝은 부분을 강도(`RenderSettings::bloom_intensity`)만큼 HDR 장면 텍스처에 더합니다. (가산 블렌딩) </br>
+/// 
+/// 비용: 1920x1080에서 절반 해상도 텍스처 두 개(각 약 4 MB)가 추가로 필요하며, </br>
+/// 매 프레임 장면 텍스처를 한 번 읽고 한 번 더하며 절반 해상도에서 픽셀마다 9번씩 두 번 샘플링합니다. </br>
+/// 블러를 절반 해상도에서 하므로 전체 해상도에 비해 블러 패스의 비용은 약 1/4 이며, 같은 탭 수로 더 넓게 퍼집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Graphics pipelines of the bloom post-process. It requires the HDR render target. (see also: `HdrPipelines`) </br>
+/// 
+/// After the scene is drawn into the HDR texture, four full-screen passes are added before tone mapping. </br>
+/// 1. `bright`: writes only the parts of the scene brighter than the threshold (`RenderSettings::bloom_threshold`) into a half resolution texture. </br>
+/// 2. `blur_horizontal`, `blur_vertical`: a separable 9-tap Gaussian blur that alternates between two half resolution textures (ping-pong). </br>
+/// 3. `composite`: adds the blurred bright parts to the HDR scene texture scaled by the intensity (`RenderSettings::bloom_intensity`). (additive blending) </br>
+/// 
+/// Cost: at 1920x1080 it needs two extra half resolution textures (about 4 MB each), </br>
+/// and every frame it reads the scene texture once, adds to it once, and samples 9 times per pixel twice at half resolution. </br>
+/// Since the blur runs at half resolution, the blur passes cost about 1/4 of full resolution and spread wider with the same number of taps. </br>
+/// 
+#[derive(Debug)]
+pub struct BloomPipelines {
+    pub bright: wgpu::RenderPipeline, 
+    pub blur_horizontal: wgpu::RenderPipeline, 
+    pub blur_vertical: wgpu::RenderPipeline, 
+    pub composite: wgpu::RenderPipeline, 
+}
+
+/// #### 한국어 </br>
+/// 장치, 쉐이더 모듈, 바인드 그룹 레이아웃을 한 번만 받아서 모든 그래픽스 파이프라인을 생성하는 빌더입니다. </br>
+/// 빌더에 설정된 값은 모든 파이프라인에 동일하게 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A builder that takes the device, shader module and bind group layouts once and creates all graphics pipelines. </br>
+/// The values set on the builder are applied equally to all pipelines. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct PipelineSetBuilder<'a> {
+    pub device: &'a wgpu::Device, 
+    pub module: &'a wgpu::ShaderModule, 
+    pub camera_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    pub object_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    pub global_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    pub wboit_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    pub oit_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    pub depth_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub skeleton_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub textured_object_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub grid_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub sky_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub sky_cubemap_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub tonemap_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub shadow_bind_group_layouts: Option<&'a ShadowBindGroupLayouts>, 
+    pub picking_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+    pub settings: PipelineSettings, 
+    pub wireframe: bool, 
+    pub instanced: bool, 
+    pub sorted_transparent: bool, 
+    pub depth_peel_layers: Option<u32>, 
+    pub depth_prepass: bool, 
+    pub push_constants: bool, 
+}
+
+#[allow(dead_code)]
+impl<'a> PipelineSetBuilder<'a> {
+    #[inline]
+    pub fn new(
+        device: &'a wgpu::Device, 
+        module: &'a wgpu::ShaderModule, 
+        camera_bind_group_layout: &'a wgpu::BindGroupLayout, 
+        object_bind_group_layout: &'a wgpu::BindGroupLayout, 
+        global_bind_group_layout: &'a wgpu::BindGroupLayout, 
+        wboit_bind_group_layout: &'a wgpu::BindGroupLayout, 
+        oit_bind_group_layout: &'a wgpu::BindGroupLayout, 
+    ) -> Self {
+        Self { 
+            device, 
+            module, 
+            camera_bind_group_layout, 
+            object_bind_group_layout, 
+            global_bind_group_layout, 
+            wboit_bind_group_layout, 
+            oit_bind_group_layout, 
+            depth_bind_group_layout: None, 
+            skeleton_bind_group_layout: None, 
+            textured_object_bind_group_layout: None, 
+            grid_bind_group_layout: None, 
+            sky_bind_group_layout: None, 
+            sky_cubemap_bind_group_layout: None, 
+            tonemap_bind_group_layout: None, 
+            shadow_bind_group_layouts: None, 
+            picking_bind_group_layout: None, 
+            settings: PipelineSettings::default(), 
+            wireframe: false, 
+            instanced: false, 
+            sorted_transparent: false, 
+            depth_peel_layers: None, 
+            depth_prepass: false, 
+            push_constants: false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 파이프라인 설정을 한 번에 바꿉니다. 설정을 바꾸어 파이프라인을 다시 생성할 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Replaces all pipeline settings at once. Used when recreating the pipelines with changed settings. </br>
+    /// 
+    #[inline]
+    pub fn set_settings(mut self, settings: PipelineSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    #[inline]
+    pub fn set_reverse_z(mut self, reverse_z: bool) -> Self {
+        self.settings.reverse_z = reverse_z;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 클램핑(`PrimitiveState::unclipped_depth`)의 사용 여부를 설정합니다. </br>
+    /// 깊이 클램핑을 사용하면 근평면 앞이나 원평면 뒤의 도형이 잘리지 않고 깊이 값이 범위 안으로 고정됩니다. </br>
+    /// 그림자 맵에서 그림자를 드리우는 오브젝트가 잘리는 것을 막는 데 유용합니다. </br>
+    /// 
+    /// 이 설정은 `wgpu::Features::DEPTH_CLIP_CONTROL` 기능이 필요합니다. </br>
+    /// 장치가 기능을 지원하지 않는 경우 경고를 출력하고 일반적인 깊이 잘라내기를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use depth clamping (`PrimitiveState::unclipped_depth`). </br>
+    /// With depth clamping, geometry in front of the near plane or behind the far plane is not clipped and its depth value is clamped into range. </br>
+    /// This is useful for preventing shadow casters from being clipped in shadow maps. </br>
+    /// 
+    /// This setting requires the `wgpu::Features::DEPTH_CLIP_CONTROL` feature. </br>
+    /// If the device does not support the feature, a warning is printed and normal depth clipping is used. </br>
+    /// 
+    #[inline]
+    pub fn set_unclipped_depth(mut self, unclipped_depth: bool) -> Self {
+        self.settings.unclipped_depth = unclipped_depth;
+        self
+    }
+
+    #[inline]
+    pub fn set_color_format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.settings.color_format = format;
+        self
+    }
+
+    #[inline]
+    pub fn set_depth_format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.settings.depth_format = format;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 순서 독립적 투명도(OIT)의 노출 값 렌더 타겟 형식을 설정합니다. (기본값: `R8Unorm`) </br>
+    /// 많은 투명 레이어가 겹치는 경우 8비트 정밀도로 인해 띠 현상이나 깜빡임이 생길 수 있으며, </br>
+    /// `R16Float` 또는 `R16Unorm`을 사용하면 이를 줄일 수 있습니다. </br>
+    /// 
+    /// 16비트 형식은 노출 값 텍스처의 메모리를 두 배로 사용합니다. (1920x1080 기준 약 2MB에서 4MB) </br>
+    /// 장치에서 사용 가능한 형식은 `utils::select_reveal_format`으로 확인할 수 있습니다. </br>
+    /// `R16Unorm`은 `wgpu::Features::TEXTURE_FORMAT_16BIT_NORM` 기능이 필요하며, </br>
+    /// 장치가 기능을 지원하지 않는 경우 경고를 출력하고 `R8Unorm`을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the format of the revealage render target for order-independent transparency (OIT). (default: `R8Unorm`) </br>
+    /// When many transparent layers overlap, 8-bit precision can cause banding or flickering, </br>
+    /// and using `R16Float` or `R16Unorm` can reduce it. </br>
+    /// 
+    /// 16-bit formats use twice the memory for the revealage texture. (about 2MB to 4MB at 1920x1080) </br>
+    /// The formats available on the device can be checked with `utils::select_reveal_format`. </br>
+    /// `R16Unorm` requires the `wgpu::Features::TEXTURE_FORMAT_16BIT_NORM` feature, </br>
+    /// and if the device does not support the feature, a warning is printed and `R8Unorm` is used. </br>
+    /// 
+    #[inline]
+    pub fn set_reveal_format(mut self, format: wgpu::TextureFormat) -> Self {
+        self.settings.reveal_format = format;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 스테레오(멀티뷰) 렌더링의 사용 여부를 설정합니다. </br>
+    /// 사용하는 경우 모든 파이프라인이 `multiview: Some(2)`로 생성되어 두 눈을 한 번의 패스로 그리며, </br>
+    /// 렌더 타겟과 깊이 버퍼는 두 개의 레이어를 가진 텍스처 배열이어야 합니다. </br>
+    /// 
+    /// 이 설정은 `wgpu::Features::MULTIVIEW` 기능이 필요합니다. </br>
+    /// 장치가 기능을 지원하지 않는 경우 경고를 출력하고 일반 렌더링을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use stereo (multiview) rendering. </br>
+    /// If used, all pipelines are created with `multiview: Some(2)` and draw both eyes in one pass, </br>
+    /// and the render targets and depth buffer must be texture arrays with two layers. </br>
+    /// 
+    /// This setting requires the `wgpu::Features::MULTIVIEW` feature. </br>
+    /// If the device does not support the feature, a warning is printed and normal rendering is used. </br>
+    /// 
+    #[inline]
+    pub fn set_stereo(mut self, stereo: bool) -> Self {
+        self.settings.multiview = stereo
+            .then(|| NonZeroU32::new(PipelineSettings::STEREO_VIEW_COUNT))
+            .flatten();
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 와이어프레임 파이프라인의 생성 여부를 설정합니다. </br>
+    /// 장치가 `POLYGON_MODE_LINE` 기능을 지원하지 않는 경우 파이프라인은 생성되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the wireframe pipeline. </br>
+    /// If the device does not support the `POLYGON_MODE_LINE` feature, the pipeline is not created. </br>
+    /// 
+    #[inline]
+    pub fn set_wireframe(mut self, wireframe: bool) -> Self {
+        self.wireframe = wireframe;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 낮은 해상도의 투명 패스에 필요한 파이프라인의 생성 여부를 설정합니다. </br>
+    /// `Some`인 경우 주어진 깊이 텍스처 바인드 그룹 레이아웃으로 깊이 축소 파이프라인과 업샘플링 합성 파이프라인을 생성합니다. </br>
+    /// 스테레오 렌더링에서는 지원하지 않으며, 경고를 출력하고 파이프라인을 생성하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipelines needed for a low resolution transparent pass. </br>
+    /// If `Some`, creates the depth downsample pipeline and the upsampling composite pipeline with the given depth texture bind group layout. </br>
+    /// It is not supported in stereo rendering, in which case a warning is printed and the pipelines are not created. </br>
+    /// 
+    #[inline]
+    pub fn set_oit_downsample(mut self, depth_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.depth_bind_group_layout = depth_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 스키닝 메쉬를 그리는 파이프라인의 생성 여부를 설정합니다. </br>
+    /// `Some`인 경우 주어진 뼈대 바인드 그룹 레이아웃(바인드 그룹 슬롯 3)으로 불투명 스키닝 파이프라인을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipeline that draws skinned meshes. </br>
+    /// If `Some`, creates the opaque skinning pipeline with the given skeleton bind group layout (bind group slot 3). </br>
+    /// 
+    #[inline]
+    pub fn set_skinning(mut self, skeleton_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.skeleton_bind_group_layout = skeleton_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처 오브젝트를 그리는 파이프라인의 생성 여부를 설정합니다. </br>
+    /// `Some`인 경우 주어진 텍스처 오브젝트 바인드 그룹 레이아웃(바인드 그룹 슬롯 1)으로 불투명 텍스처 파이프라인을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipeline that draws textured objects. </br>
+    /// If `Some`, creates the opaque textured pipeline with the given textured object bind group layout (bind group slot 1). </br>
+    /// 
+    #[inline]
+    pub fn set_textured(mut self, textured_object_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.textured_object_bind_group_layout = textured_object_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 바닥 격자를 그리는 파이프라인의 생성 여부를 설정합니다. (참고: `grid::GridSettings`) </br>
+    /// `Some`인 경우 주어진 격자 바인드 그룹 레이아웃(바인드 그룹 슬롯 1)으로 격자 파이프라인을 생성하며, 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipeline that draws the ground grid. (see also: `grid::GridSettings`) </br>
+    /// If `Some`, creates the grid pipeline with the given grid bind group layout (bind group slot 1), and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_grid(mut self, grid_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.grid_bind_group_layout = grid_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 하늘 배경을 그리는 파이프라인의 생성 여부를 설정합니다. (참고: `SkyPipelines`) </br>
+    /// `Some`인 경우 주어진 하늘 바인드 그룹 레이아웃(바인드 그룹 슬롯 1)으로 그라디언트 파이프라인을 생성하며, </br>
+    /// `cubemap_bind_group_layout`(바인드 그룹 슬롯 2)도 주어진 경우 큐브맵 파이프라인을 함께 생성합니다. </br>
+    /// 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipelines that draw the sky background. (see also: `SkyPipelines`) </br>
+    /// If `Some`, creates the gradient pipeline with the given sky bind group layout (bind group slot 1), </br>
+    /// and if `cubemap_bind_group_layout` (bind group slot 2) is also given, creates the cubemap pipeline as well. </br>
+    /// It is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_sky(
+        mut self, 
+        sky_bind_group_layout: Option<&'a wgpu::BindGroupLayout>, 
+        cubemap_bind_group_layout: Option<&'a wgpu::BindGroupLayout>
+    ) -> Self {
+        self.sky_bind_group_layout = sky_bind_group_layout;
+        self.sky_cubemap_bind_group_layout = cubemap_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 정렬된 알파 블렌딩 경로의 파이프라인(블렌딩 방법마다 하나씩)의 생성 여부를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipelines of the sorted alpha blending path. (one per blend mode) </br>
+    /// 
+    #[inline]
+    pub fn set_sorted_transparent(mut self, sorted_transparent: bool) -> Self {
+        self.sorted_transparent = sorted_transparent;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 인스턴스 렌더링 파이프라인의 생성 여부를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the instanced rendering pipelines. </br>
+    /// 
+    #[inline]
+    pub fn set_instanced(mut self, instanced: bool) -> Self {
+        self.instanced = instanced;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 가중 블렌딩 OIT 대신 깊이 벗기기를 사용할지 여부와 벗길 레이어의 수를 설정합니다. (참고: `DepthPeelPipelines`) </br>
+    /// 레이어의 수는 1과 `DepthPeelPipelines::MAX_LAYERS` 사이로 제한됩니다. </br>
+    /// 스테레오 렌더링에서는 지원하지 않으며, 낮은 해상도의 투명 패스보다 우선합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use depth peeling instead of weighted blended OIT, and the number of layers to peel. (see also: `DepthPeelPipelines`) </br>
+    /// The number of layers is clamped between 1 and `DepthPeelPipelines::MAX_LAYERS`. </br>
+    /// It is not supported in stereo rendering, and takes precedence over the low resolution transparent pass. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_peeling(mut self, layers: Option<u32>) -> Self {
+        self.depth_peel_layers = layers.map(|layers| layers.clamp(1, DepthPeelPipelines::MAX_LAYERS));
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스 전에 깊이 사전 패스를 사용할지 여부를 설정합니다. (기본값: `false`, 참고: `DepthPrepassPipelines`) </br>
+    /// 조각 쉐이더의 비용이 큰 경우에만 켜는 것이 좋으며, 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use a depth pre-pass before the opaque pass. (default: `false`, see also: `DepthPrepassPipelines`) </br>
+    /// It is best enabled only when the fragment shader is expensive, and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_prepass(mut self, depth_prepass: bool) -> Self {
+        self.depth_prepass = depth_prepass;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트별 데이터를 푸시 상수로 전달하는 파이프라인의 생성 여부를 설정합니다. (참고: `PushConstantPipelines`) </br>
+    /// 장치가 `PUSH_CONSTANTS` 기능을 지원하지 않거나 푸시 상수의 최대 크기가 부족한 경우 경고 없이 유니폼 버퍼 경로를 사용하며, </br>
+    /// 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipelines that pass per-object data as push constants. (see also: `PushConstantPipelines`) </br>
+    /// If the device does not support the `PUSH_CONSTANTS` feature or its maximum push constant size is too small, the uniform buffer path is used without a warning, </br>
+    /// and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_push_constants(mut self, push_constants: bool) -> Self {
+        self.push_constants = push_constants;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸 후처리의 사용 여부를 설정합니다. (기본값: `false`, 참고: `BloomPipelines`) </br>
+    /// HDR 렌더 타겟(`set_tonemap`)이 필요하며, 파이프라인을 다시 생성해야 하므로 파이프라인 설정(`PipelineSettings::bloom`)에 포함됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use the bloom post-process. (default: `false`, see also: `BloomPipelines`) </br>
+    /// It requires the HDR render target (`set_tonemap`), and the pipelines must be recreated, so it is part of the pipeline settings (`PipelineSettings::bloom`). </br>
+    /// 
+    #[inline]
+    pub fn set_bloom(mut self, bloom: bool) -> Self {
+        self.settings.bloom = bloom;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 톤 매핑 패스의 쉐이더 레이아웃을 설정합니다. (참고: `HdrPipelines`) </br>
+    /// 주어진 경우 장면을 HDR 렌더 타겟에 그린 후 톤 매핑하여 화면에 쓰며, 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the shader layout of the tone mapping pass. (see also: `HdrPipelines`) </br>
+    /// If given, the scene is drawn into an HDR render target and then tonemapped to the surface, and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_tonemap(mut self, tonemap_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.tonemap_bind_group_layout = tonemap_bind_group_layout;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 방향광의 그림자를 그리는 파이프라인의 생성 여부를 설정합니다. (참고: `ShadowPipelines`) </br>
+    /// `Some`인 경우 주어진 그림자 쉐이더 레이아웃으로 그림자 맵 파이프라인과 그림자를 받는 불투명 파이프라인을 생성하며, 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the pipelines that draw the shadows of the directional light. (see also: `ShadowPipelines`) </br>
+    /// If `Some`, creates the shadow map pipeline and the opaque pipeline that receives shadows with the given shadow shader layouts, and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_shadow(mut self, shadow_bind_group_layouts: Option<&'a ShadowBindGroupLayouts>) -> Self {
+        self.shadow_bind_group_layouts = shadow_bind_group_layouts;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트 ID 패스 파이프라인의 생성 여부를 설정합니다. (참고: `PickingPipelines`) </br>
+    /// `Some`인 경우 주어진 오브젝트 ID 쉐이더 레이아웃(바인드 그룹 슬롯 2)으로 파이프라인을 생성하며, 스테레오 렌더링에서는 지원하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to create the object ID pass pipeline. (see also: `PickingPipelines`) </br>
+    /// If `Some`, creates the pipeline with the given object ID shader layout (bind group slot 2), and it is not supported in stereo rendering. </br>
+    /// 
+    #[inline]
+    pub fn set_picking(mut self, picking_bind_group_layout: Option<&'a wgpu::BindGroupLayout>) -> Self {
+        self.picking_bind_group_layout = picking_bind_group_layout;
+        self
+    }
+
+    pub fn build(mut self) -> Pipelines {
+        if self.settings.unclipped_depth && !self.device.features().contains(wgpu::Features::DEPTH_CLIP_CONTROL) {
+            log::warn!("Depth clamping requires the DEPTH_CLIP_CONTROL feature, which is not supported. Fall back to depth clipping.");
+            self.settings.unclipped_depth = false;
+        }
+
+        if self.settings.multiview.is_some() && !self.device.features().contains(wgpu::Features::MULTIVIEW) {
+            log::warn!("Stereo rendering requires the MULTIVIEW feature, which is not supported. Fall back to mono rendering.");
+            self.settings.multiview = None;
+        }
+
+        if self.settings.reveal_format == wgpu::TextureFormat::R16Unorm && !self.device.features().contains(wgpu::Features::TEXTURE_FORMAT_16BIT_NORM) {
+            log::warn!("R16Unorm revealage target requires the TEXTURE_FORMAT_16BIT_NORM feature, which is not supported. Fall back to R8Unorm.");
+            self.settings.reveal_format = wgpu::TextureFormat::R8Unorm;
+        }
+
+        if self.tonemap_bind_group_layout.is_some() && self.settings.multiview.is_some() {
+            log::warn!("HDR render target is not supported in stereo rendering. Fall back to writing the surface directly.");
+        }
+        self.settings.hdr = self.tonemap_bind_group_layout.is_some() && self.settings.multiview.is_none();
+
+        if self.settings.bloom && !self.settings.hdr {
+            log::warn!("Bloom requires the HDR render target. The bloom pass is not drawn.");
+            self.settings.bloom = false;
+        }
+
+        let bind_group_layouts = [
+            self.camera_bind_group_layout, 
+            self.object_bind_group_layout, 
+            self.global_bind_group_layout, 
+        ];
+        // (한국어) 투명 패스는 가중치 함수의 매개변수를 바인드 그룹 3에서 읽습니다.
+        // (English Translation) The transparent pass reads the weight function parameters from bind group 3.
+        let transparent_bind_group_layouts = [
+            self.camera_bind_group_layout, 
+            self.object_bind_group_layout, 
+            self.global_bind_group_layout, 
+            self.wboit_bind_group_layout, 
+        ];
+        if self.depth_prepass && self.settings.multiview.is_some() {
+            log::warn!("Depth pre-pass is not supported in stereo rendering. Fall back to the opaque pass only.");
+        }
+        let depth_prepass = self.depth_prepass && self.settings.multiview.is_none();
+
+        let opaque = create_opaque_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, None, false, depth_prepass);
+        let transparent = create_transparent_pipeline(self.device, self.module, &transparent_bind_group_layouts, &self.settings, None, false);
+
+        // (한국어) 
+        // 장치가 지원하는 경우 오브젝트별 데이터를 푸시 상수로 전달하는 파이프라인을 생성합니다.
+        // 장치를 생성할 때 기능과 크기 제한을 요청하므로, 지원하지 않는 경우에는 조용히 유니폼 버퍼 경로를 사용합니다.
+        // 
+        // (English Translation) 
+        // If the device supports it, creates the pipelines that pass per-object data as push constants.
+        // The feature and the size limit are requested when the device is created, so the uniform buffer path is used silently if unsupported.
+        // 
+        if self.push_constants && self.settings.multiview.is_some() {
+            log::warn!("Push constants are not supported in stereo rendering. Fall back to the object uniform buffers.");
+        }
+        let push_constants_supported = self.device.features().contains(wgpu::Features::PUSH_CONSTANTS)
+            && self.device.limits().max_push_constant_size >= PushConstantPipelines::RANGE.range.end;
+        let push_constants = (self.push_constants && push_constants_supported && self.settings.multiview.is_none()).then(|| {
+            let empty_bind_group_layout = self.device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("BindGroupLayout(Empty)"), 
+                    entries: &[], 
+                }
+            );
+            let empty_bind_group = self.device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some("BindGroup(Empty)"), 
+                    layout: &empty_bind_group_layout, 
+                    entries: &[], 
+                }
+            );
+            let bind_group_layouts = [
+                self.camera_bind_group_layout, 
+                &empty_bind_group_layout, 
+                self.global_bind_group_layout, 
+            ];
+            let transparent_bind_group_layouts = [
+                self.camera_bind_group_layout, 
+                &empty_bind_group_layout, 
+                self.global_bind_group_layout, 
+                self.wboit_bind_group_layout, 
+            ];
+            PushConstantPipelines { 
+                empty_bind_group, 
+                opaque: create_opaque_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, None, true, depth_prepass), 
+                transparent: create_transparent_pipeline(self.device, self.module, &transparent_bind_group_layouts, &self.settings, None, true), 
+            }
+        });
+
+        let decal = create_decal_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+
+        let mesh = create_mesh_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+
+        let wireframe = (self.wireframe && self.device.features().contains(wgpu::Features::POLYGON_MODE_LINE))
+            .then(|| create_wireframe_pipeline(self.device, self.module, &bind_group_layouts, &self.settings));
+
+        let instance_layout = InstanceLayout::vertex_buffer_layout();
+        let opaque_instanced = self.instanced
+            .then(|| create_opaque_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, Some(&instance_layout), false, depth_prepass));
+        let transparent_instanced = self.instanced
+            .then(|| create_transparent_pipeline(self.device, self.module, &transparent_bind_group_layouts, &self.settings, Some(&instance_layout), false));
+
+        let depth_prepass = depth_prepass.then(|| DepthPrepassPipelines {
+            prepass: create_depth_prepass_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, None), 
+            prepass_instanced: self.instanced
+                .then(|| create_depth_prepass_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, Some(&instance_layout))), 
+        });
+
+        let sorted_transparent = self.sorted_transparent.then(|| SortedTransparentPipelines {
+            alpha_blend: create_sorted_transparent_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, BlendMode::AlphaBlend), 
+            additive: create_sorted_transparent_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, BlendMode::Additive), 
+            multiply: create_sorted_transparent_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, BlendMode::Multiply), 
+        });
+
+        let skinned = self.skeleton_bind_group_layout.map(|skeleton_bind_group_layout| {
+            let bind_group_layouts = [
+                self.camera_bind_group_layout, 
+                self.object_bind_group_layout, 
+                self.global_bind_group_layout, 
+                skeleton_bind_group_layout, 
+            ];
+            create_skinned_pipeline(self.device, self.module, &bind_group_layouts, &self.settings)
+        });
+
+        let textured = self.textured_object_bind_group_layout.map(|textured_object_bind_group_layout| {
+            let bind_group_layouts = [
+                self.camera_bind_group_layout, 
+                textured_object_bind_group_layout, 
+                self.global_bind_group_layout, 
+            ];
+            create_textured_pipeline(self.device, self.module, &bind_group_layouts, &self.settings)
+        });
+
+        if self.grid_bind_group_layout.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Ground grid is not supported in stereo rendering. The grid is not drawn.");
+        }
+        let grid = self.grid_bind_group_layout
+            .filter(|_| self.settings.multiview.is_none())
+            .map(|grid_bind_group_layout| {
+                let bind_group_layouts = [self.camera_bind_group_layout, grid_bind_group_layout];
+                create_grid_pipeline(self.device, self.module, &bind_group_layouts, &self.settings)
+            });
+
+        if self.sky_bind_group_layout.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Sky background is not supported in stereo rendering. Fall back to the clear color.");
+        }
+        let sky = self.sky_bind_group_layout
+            .filter(|_| self.settings.multiview.is_none())
+            .map(|sky_bind_group_layout| {
+                let bind_group_layouts = [self.camera_bind_group_layout, sky_bind_group_layout];
+                let gradient = create_sky_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, false);
+                let cubemap = self.sky_cubemap_bind_group_layout.map(|cubemap_bind_group_layout| {
+                    let bind_group_layouts = [self.camera_bind_group_layout, sky_bind_group_layout, cubemap_bind_group_layout];
+                    create_sky_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, true)
+                });
+                SkyPipelines { gradient, cubemap }
+            });
+
+        if self.shadow_bind_group_layouts.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Shadows are not supported in stereo rendering. The shadow map is not drawn.");
+        }
+        let shadow = self.shadow_bind_group_layouts
+            .filter(|_| self.settings.multiview.is_none())
+            .map(|layouts| {
+                let bind_group_layouts: [_; ShadowPipelines::DEPTH_BIND_GROUPS] = [&layouts.light, self.object_bind_group_layout, self.global_bind_group_layout];
+                let depth = create_shadow_pipeline(self.device, self.module, &bind_group_layouts);
+                let bind_group_layouts: [_; ShadowPipelines::OPAQUE_BIND_GROUPS] = [
+                    self.camera_bind_group_layout, 
+                    self.object_bind_group_layout, 
+                    self.global_bind_group_layout, 
+                    &layouts.shadow_map, 
+                ];
+                let opaque = create_shadowed_opaque_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, depth_prepass.is_some());
+                ShadowPipelines { depth, opaque }
+            });
+
+        if self.picking_bind_group_layout.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Object picking is not supported in stereo rendering. The object ID pass is not drawn.");
+        }
+        let picking = self.picking_bind_group_layout
+            .filter(|_| self.settings.multiview.is_none())
+            .map(|picking_bind_group_layout| {
+                let bind_group_layouts = [self.camera_bind_group_layout, self.object_bind_group_layout, picking_bind_group_layout];
+                let id = create_picking_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+                PickingPipelines { id }
+            });
+
+        let bind_group_layouts = [self.oit_bind_group_layout, self.wboit_bind_group_layout];
+        let composite = create_composite_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+
+        if self.depth_peel_layers.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Depth peeling is not supported in stereo rendering. Fall back to weighted blended OIT.");
+        }
+        let depth_peel = self.depth_peel_layers
+            .filter(|_| self.settings.multiview.is_none())
+            .map(|layers| {
+                let depth_bind_group_layout = create_depth_peel_bind_group_layout(self.device);
+                let color_bind_group_layout = create_depth_peel_color_bind_group_layout(self.device);
+                let bind_group_layouts = [
+                    self.camera_bind_group_layout, 
+                    self.object_bind_group_layout, 
+                    self.global_bind_group_layout, 
+                    &depth_bind_group_layout, 
+                ];
+                let peel = create_depth_peel_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, None);
+                let peel_instanced = self.instanced
+                    .then(|| create_depth_peel_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, Some(&instance_layout)));
+                let bind_group_layouts = [&color_bind_group_layout];
+                let blend = create_depth_peel_resolve_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, false);
+                let composite = create_depth_peel_resolve_pipeline(self.device, self.module, &bind_group_layouts, &self.settings, true);
+                DepthPeelPipelines { 
+                    layers, 
+                    depth_bind_group_layout, 
+                    color_bind_group_layout, 
+                    peel, 
+                    peel_instanced, 
+                    blend, 
+                    composite, 
+                }
+            });
+
+        if self.depth_bind_group_layout.is_some() && self.settings.multiview.is_some() {
+            log::warn!("Low resolution transparent pass is not supported in stereo rendering. Fall back to full resolution.");
+        }
+        if self.depth_bind_group_layout.is_some() && depth_peel.is_some() {
+            log::warn!("Low resolution transparent pass is not supported with depth peeling. Fall back to full resolution.");
+        }
+        let oit_downsample = self.depth_bind_group_layout
+            .filter(|_| self.settings.multiview.is_none() && depth_peel.is_none())
+            .map(|depth_bind_group_layout| {
+                let bind_group_layouts = [self.oit_bind_group_layout, depth_bind_group_layout];
+                let depth = create_depth_downsample_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+                let bind_group_layouts = [self.oit_bind_group_layout, depth_bind_group_layout, depth_bind_group_layout, self.wboit_bind_group_layout];
+                let composite = create_upsampled_composite_pipeline(self.device, self.module, &bind_group_layouts, &self.settings);
+                OitDownsamplePipelines { depth, composite }
+            });
+
+        let hdr = self.tonemap_bind_group_layout
+            .filter(|_| self.settings.hdr)
+            .map(|tonemap_bind_group_layout| {
+                let texture_bind_group_layout = create_post_process_bind_group_layout(self.device);
+                let bind_group_layouts = [&texture_bind_group_layout, tonemap_bind_group_layout];
+                let tonemap = create_post_process_pipeline(self.device, self.module, &bind_group_layouts, "ToneMap", "fs_tonemap", self.settings.color_format, None);
+                let bloom = self.settings.bloom.then(|| {
+                    // (한국어) 밝은 부분 추출 패스와 합성 패스는 임계값과 강도를 바인드 그룹 1의 전역 유니폼에서 읽습니다.
+                    // (English Translation) The bright and composite passes read the threshold and intensity from the global uniform in bind group 1.
+                    let bind_group_layouts = [&texture_bind_group_layout, self.global_bind_group_layout];
+                    let additive = Some(BlendMode::Additive.blend_state());
+                    let bright = create_post_process_pipeline(self.device, self.module, &bind_group_layouts, "BloomBright", "fs_bloom_bright", HdrPipelines::COLOR_FORMAT, None);
+                    let composite = create_post_process_pipeline(self.device, self.module, &bind_group_layouts, "BloomComposite", "fs_bloom_composite", HdrPipelines::COLOR_FORMAT, additive);
+                    let bind_group_layouts = [&texture_bind_group_layout];
+                    let blur_horizontal = create_post_process_pipeline(self.device, self.module, &bind_group_layouts, "BloomBlurHorizontal", "fs_bloom_blur_horizontal", HdrPipelines::COLOR_FORMAT, None);
+                    let blur_vertical = create_post_process_pipeline(self.device, self.module, &bind_group_layouts, "BloomBlurVertical", "fs_bloom_blur_vertical", HdrPipelines::COLOR_FORMAT, None);
+                    BloomPipelines { 
+                        bright, 
+                        blur_horizontal, 
+                        blur_vertical, 
+                        composite, 
+                    }
+                });
+                HdrPipelines { 
+                    texture_bind_group_layout, 
+                    tonemap, 
+                    bloom, 
+                }
+            });
+
+        Pipelines { 
+            settings: self.settings, 
+            opaque, 
+            transparent, 
+            composite, 
+            decal, 
+            mesh, 
+            wireframe, 
+            opaque_instanced, 
+            transparent_instanced, 
+            skinned, 
+            textured, 
+            grid, 
+            sky, 
+            shadow, 
+            picking, 
+            sorted_transparent, 
+            oit_downsample, 
+            depth_peel, 
+            depth_prepass, 
+            push_constants, 
+            hdr, 
+        }
+    }
+}
+
 /// #### 한국어 </br>
 /// 불투명한 색상 오브젝트들을 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// `instance_layout`이 주어진 경우 인스턴스 렌더링을 위한 파이프라인을 생성합니다. </br>
+/// `depth_prepass`가 `true`인 경우 깊이 사전 패스가 기록한 깊이와 `Equal`로 비교하며 깊이를 쓰지 않습니다. (참고: `DepthPrepassPipelines`) </br>
+/// 방향광의 램버트 항은 정점 버퍼의 법선(위치 1)과 전역 유니폼의 `light_direction`으로 계산됩니다. (참고: `Vertex`, `LightSettings`) </br>
 ///
 /// #### English (Translation) </br>
-/// Create a graphics pipeline to draw opaque colored objects. </br>
+/// Create a graphics pipeline to draw opaque colored objects. </br>
+/// If `instance_layout` is given, creates a pipeline for instanced rendering. </br>
+/// If `depth_prepass` is `true`, it tests against the depth written by the depth pre-pass with `Equal` and does not write depth. (see also: `DepthPrepassPipelines`) </br>
+/// If `push_constants` is `true`, it reads the object data from push constants instead of bind group 1. (see also: `PushConstantPipelines`) </br>
+/// The Lambert term of the directional light is computed from the normal in the vertex buffer (location 1) and `light_direction` of the global uniform. (see also: `Vertex`, `LightSettings`) </br>
+/// 
+pub fn create_opaque_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    instance_layout: Option<&wgpu::VertexBufferLayout>, 
+    push_constants: bool, 
+    depth_prepass: bool, 
+) -> wgpu::RenderPipeline {
+    let push_constant_ranges = match push_constants {
+        true => vec![PushConstantPipelines::RANGE], 
+        false => vec![], 
+    };
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Opaque))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &push_constant_ranges, 
+        },
+    );
+
+    // (한국어) 인스턴스 레이아웃이 주어진 경우 두 번째 정점 버퍼(슬롯 1)에서 인스턴스별 속성을 읽습니다.
+    // (English Translation) If an instance layout is given, per-instance attributes are read from the second vertex buffer (slot 1).
+    let vertex_layout = Vertex::vertex_buffer_layout();
+    let (label, entry_point, buffers) = match (instance_layout, push_constants) {
+        (Some(instance_layout), _) => (
+            "RenderPipeline(ColoredObject(Opaque, Instanced))", 
+            settings.vertex_entry_point(true), 
+            vec![vertex_layout, instance_layout.clone()]
+        ), 
+        (None, true) => (
+            "RenderPipeline(ColoredObject(Opaque, PushConstants))", 
+            "vs_push_constant_main", 
+            vec![vertex_layout]
+        ), 
+        (None, false) => (
+            "RenderPipeline(ColoredObject(Opaque))", 
+            settings.vertex_entry_point(false), 
+            vec![vertex_layout]
+        ), 
+    };
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(label), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point, 
+                buffers: &buffers, 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: !depth_prepass, 
+                depth_compare: match depth_prepass {
+                    true => wgpu::CompareFunction::Equal, 
+                    false => settings.depth_compare(), 
+                }, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: match push_constants {
+                    true => "fs_opaque_push_constant_main", 
+                    false => "fs_opaque_main", 
+                }, 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 그림자 맵(바인드 그룹 슬롯 3)을 읽어 그림자를 받는 불투명한 색상 오브젝트들의 그래픽스 파이프라인을 생성합니다. (참고: `ShadowPipelines`) </br>
+/// 조각 쉐이더(`fs_opaque_shadowed_main`)를 제외하면 `create_opaque_pipeline`의 파이프라인과 같습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline of opaque colored objects that receive shadows by reading the shadow map (bind group slot 3). (see also: `ShadowPipelines`) </br>
+/// Except for the fragment shader (`fs_opaque_shadowed_main`), it is the same as the pipeline of `create_opaque_pipeline`. </br>
+/// 
+pub fn create_shadowed_opaque_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    depth_prepass: bool, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Opaque, Shadowed))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(ColoredObject(Opaque, Shadowed))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.vertex_entry_point(false), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: !depth_prepass, 
+                depth_compare: match depth_prepass {
+                    true => wgpu::CompareFunction::Equal, 
+                    false => settings.depth_compare(), 
+                }, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_opaque_shadowed_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 그림자를 드리우는 불투명한 색상 오브젝트들을 빛의 시점에서 그림자 맵에 그리는 그래픽스 파이프라인을 생성합니다. (참고: `ShadowPipelines`) </br>
+/// 색상 렌더 타겟과 조각 쉐이더 없이 정점 쉐이더(`vs_shadow`)만 사용합니다. </br>
+/// 그림자 맵은 장면의 렌더 타겟과 관계없으므로 다중 샘플링, 역방향 깊이, 멀티뷰 설정을 사용하지 않습니다. </br>
+/// 사각형은 양면이므로 면을 컬링하지 않으며, 그림자 여드름을 줄이기 위해 `ShadowPipelines::DEPTH_BIAS`를 사용합니다. </br>
+/// 흔들림 변형이 전역 데이터의 시간을 읽으므로 바인드 그룹 슬롯 2에 전역 바인드 그룹이 필요합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws the opaque colored objects casting shadows into the shadow map from the light's point of view. (see also: `ShadowPipelines`) </br>
+/// It uses only a vertex shader (`vs_shadow`), without color render targets or a fragment shader. </br>
+/// The shadow map is independent of the scene render targets, so it does not use the multisampling, reverse-z or multiview settings. </br>
+/// Quads are double sided so faces are not culled, and `ShadowPipelines::DEPTH_BIAS` is used to reduce shadow acne. </br>
+/// The wobble deformation reads the time of the global data, so the global bind group is required at bind group slot 2. </br>
+/// 
+pub fn create_shadow_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Shadow)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Shadow)"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: "vs_shadow", 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                cull_mode: None, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: ShadowPipelines::DEPTH_FORMAT, 
+                depth_write_enabled: true, 
+                depth_compare: wgpu::CompareFunction::Less, 
+                stencil: wgpu::StencilState::default(), 
+                bias: ShadowPipelines::DEPTH_BIAS, 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: None, 
+            multiview: None,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 색상 오브젝트들의 ID를 정수 렌더 타겟에 기록하는 오브젝트 ID 패스의 그래픽스 파이프라인을 생성합니다. (참고: `PickingPipelines`) </br>
+/// 정점 쉐이더는 깊이 사전 패스와 같은 `vs_depth_prepass`를 사용하므로 오브젝트별 깊이 바이어스도 같게 적용됩니다. </br>
+/// ID 패스는 전용 깊이 버퍼를 사용하므로 다중 샘플링과 멀티뷰 설정을 사용하지 않지만, 깊이 비교는 장면과 같은 방향(역방향 깊이)을 따릅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline of the object ID pass that writes the IDs of opaque colored objects into an integer render target. (see also: `PickingPipelines`) </br>
+/// The vertex shader is `vs_depth_prepass`, the same as the depth pre-pass, so the per-object depth bias is applied the same way. </br>
+/// The ID pass uses its own depth buffer, so it does not use the multisampling and multiview settings, but the depth comparison follows the scene (reverse-z). </br>
+/// 
+pub fn create_picking_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Picking))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(ColoredObject(Picking))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: "vs_depth_prepass", 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: PickingPipelines::DEPTH_FORMAT, 
+                depth_write_enabled: true, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_picking", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        format: PickingPipelines::ID_FORMAT, 
+                        blend: None, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: None,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 색상 오브젝트들의 깊이 값만 기록하는 깊이 사전 패스의 그래픽스 파이프라인을 생성합니다. </br>
+/// 색상 렌더 타겟과 조각 쉐이더 없이 정점 쉐이더(`vs_depth_prepass`)만 사용합니다. </br>
+/// `instance_layout`이 주어진 경우 인스턴스 렌더링을 위한 파이프라인을 생성합니다. </br>
+///
+/// #### English (Translation) </br>
+/// Create a graphics pipeline of the depth pre-pass that writes only the depth values of opaque colored objects. </br>
+/// It uses only a vertex shader (`vs_depth_prepass`), without color render targets or a fragment shader. </br>
+/// If `instance_layout` is given, creates a pipeline for instanced rendering. </br>
+/// 
+pub fn create_depth_prepass_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    instance_layout: Option<&wgpu::VertexBufferLayout>, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(DepthPrepass))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let vertex_layout = Vertex::vertex_buffer_layout();
+    let (label, entry_point, buffers) = match instance_layout {
+        Some(instance_layout) => (
+            "RenderPipeline(ColoredObject(DepthPrepass, Instanced))", 
+            "vs_depth_prepass_instanced", 
+            vec![vertex_layout, instance_layout.clone()]
+        ), 
+        None => (
+            "RenderPipeline(ColoredObject(DepthPrepass))", 
+            "vs_depth_prepass", 
+            vec![vertex_layout]
+        ), 
+    };
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(label), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point, 
+                buffers: &buffers, 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: true, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: None, 
+            multiview: settings.multiview,
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 불투명한 스키닝 메쉬를 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 정점 버퍼는 `SkinnedVertex` 레이아웃을 가지며, 32비트 인덱스 버퍼로 삼각형 목록을 그립니다. </br>
+/// 바인드 그룹 슬롯 3에 뼈 행렬 저장 버퍼가 필요합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline to draw opaque skinned meshes. </br>
+/// The vertex buffer has the `SkinnedVertex` layout, and triangle lists are drawn with a 32-bit index buffer. </br>
+/// Requires the bone matrix storage buffer at bind group slot 3. </br>
+/// 
+pub fn create_skinned_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(SkinnedMesh(Opaque))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(SkinnedMesh(Opaque))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.skinned_vertex_entry_point(), 
+                buffers: &[SkinnedVertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: true, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_opaque_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 불투명한 메쉬 오브젝트들을 그리는 그래픽스 파이프라인을 생성합니다. (참고: `MeshObject`) </br>
+/// 정점 버퍼는 `Vertex` 레이아웃을 가지며, 32비트 인덱스 버퍼로 삼각형 목록을 그립니다. </br>
+/// 깊이 사전 패스에 기록되지 않으므로 항상 깊이를 비교하고 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline to draw opaque mesh objects. (see also: `MeshObject`) </br>
+/// The vertex buffer has the `Vertex` layout, and triangle lists are drawn with a 32-bit index buffer. </br>
+/// Since they are not written in the depth pre-pass, it always tests and writes depth. </br>
+/// 
+pub fn create_mesh_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(MeshObject(Opaque))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(MeshObject(Opaque))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.vertex_entry_point(false), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: true, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_opaque_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 텍스처 오브젝트들을 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 색상 오브젝트와 같은 사각형 메쉬를 사용하며, 바인드 그룹 슬롯 1에 텍스처 오브젝트 바인드 그룹이 필요합니다. </br>
+/// 알파 값이 0.5보다 작은 텍셀은 잘라냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline to draw opaque textured objects. </br>
+/// It uses the same quad mesh as colored objects, and requires the textured object bind group at bind group slot 1. </br>
+/// Texels with an alpha below 0.5 are cut out. </br>
+/// 
+pub fn create_textured_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(TexturedObject(Opaque))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(TexturedObject(Opaque))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.textured_vertex_entry_point(), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: true, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_textured_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 정렬된 알파 블렌딩 경로에서 투명 오브젝트를 색상 렌더 타겟에 직접 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 블렌딩 방법마다 서로 다른 파이프라인이 필요하며, 오브젝트는 뒤에서 앞으로 정렬되어 그려져야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws transparent objects directly into the color render target in the sorted alpha blending path. </br>
+/// Each blend mode needs a different pipeline, and the objects must be drawn sorted back to front. </br>
+/// 
+pub fn create_sorted_transparent_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    blend_mode: BlendMode, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(SortedTransparent))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let label = format!("RenderPipeline(ColoredObject(SortedTransparent, {:?}))", blend_mode);
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&label), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.vertex_entry_point(false), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: false, 
+                depth_compare: settings.depth_compare(), 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_sorted_transparent_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(blend_mode.blend_state()), 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 투명한 색상 오브젝트를 그리는 기본 그래픽스 파이프라인을 생성합니다. </br>
+/// `instance_layout`이 주어진 경우 인스턴스 렌더링을 위한 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a default graphics pipeline to draw transparent colored object. </br>
+/// If `instance_layout` is given, creates a pipeline for instanced rendering. </br>
+/// If `push_constants` is `true`, it reads the object data from push constants instead of bind group 1. (see also: `PushConstantPipelines`) </br>
+/// 
+pub fn create_transparent_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    instance_layout: Option<&wgpu::VertexBufferLayout>, 
+    push_constants: bool, 
+) -> wgpu::RenderPipeline {
+    let push_constant_ranges = match push_constants {
+        true => vec![PushConstantPipelines::RANGE], 
+        false => vec![], 
+    };
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Transparent))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &push_constant_ranges,
+        },
+    );
+
+    // (한국어) 인스턴스 레이아웃이 주어진 경우 두 번째 정점 버퍼(슬롯 1)에서 인스턴스별 속성을 읽습니다.
+    // (English Translation) If an instance layout is given, per-instance attributes are read from the second vertex buffer (slot 1).
+    let vertex_layout = Vertex::vertex_buffer_layout();
+    let (label, entry_point, buffers) = match (instance_layout, push_constants) {
+        (Some(instance_layout), _) => (
+            "RenderPipeline(ColoredObject(Transparent, Instanced))", 
+            settings.vertex_entry_point(true), 
+            vec![vertex_layout, instance_layout.clone()]
+        ), 
+        (None, true) => (
+            "RenderPipeline(ColoredObject(Transparent, PushConstants))", 
+            "vs_push_constant_main", 
+            vec![vertex_layout]
+        ), 
+        (None, false) => (
+            "RenderPipeline(ColoredObject(Transparent))", 
+            settings.vertex_entry_point(false), 
+            vec![vertex_layout]
+        ), 
+    };
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(label), 
+            layout: Some(&pipeline_layout), 
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            }, 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point, 
+                buffers: &buffers, 
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_compare: settings.depth_compare(), 
+                depth_write_enabled: false, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: match push_constants {
+                    true => "fs_transparent_push_constant_pass", 
+                    false => "fs_transparent_pass", 
+                }, 
+                targets: &[
+                    // (한국어) 
+                    // 첫 번째 렌더 타겟: (RGB * 가중치, Alpha * 가중치)를 RGBA로 저장하하는 누적 값.
+                    // 최소 `Rgba16Float`의 정밀도를 가져야 한다.
+                    // 
+                    // (English Translation)
+                    // First Render Target: Accumulated value (RGB * Weight, Alpha * Weight) stored as RGBA.
+                    // It must have a precision of at least `Rgba16Float`.
+                    //
+                    Some(wgpu::ColorTargetState {
+                        format: PipelineSettings::ACCUM_FORMAT, 
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One, 
+                                dst_factor: wgpu::BlendFactor::One, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One, 
+                                dst_factor: wgpu::BlendFactor::One, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }, 
+                        }), 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                    // (한국어)
+                    // 두 번째 렌더 타겟: 이전의 색이 얼마만큼 노출이 될 수 있는지에 대한 노출 값.
+                    // 최소 `R8`의 정밀도를 가져야 한다. (`R16Float`, `R16Unorm`도 같은 블렌딩을 사용한다.)
+                    // 
+                    // (English Translation)
+                    // Second Render Target: Revealage value of how much of the previous color can be exposed. 
+                    // It must have a precision of at least `R8`. (`R16Float` and `R16Unorm` use the same blending.)
+                    // 
+                    Some(wgpu::ColorTargetState {
+                        format: settings.reveal_format, 
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::Zero, 
+                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                                operation: wgpu::BlendOperation::Add, 
+                            },
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::Zero, 
+                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                                operation: wgpu::BlendOperation::Add, 
+                            }
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 색상 오브젝트와 투명한 색상 오브젝트를 합성하는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 전체 화면 사각형이 불투명한 오브젝트의 깊이 값을 덮어쓰지 않도록 깊이 쓰기를 비활성화 합니다. </br>
+/// 투명한 오브젝트는 이미 투명 패스에서 깊이 테스트를 거쳤으므로 깊이 비교는 항상 통과합니다. </br>
+/// 바인드 그룹 0은 누적 값과 노출 값, 1은 누적 가중치의 상한을 담은 가중치 함수 유니폼 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline to composite opaque and transparent colored objects. </br>
+/// 
+/// Depth writes are disabled so that the fullscreen quad does not overwrite the depth values of opaque objects. </br>
+/// Transparent objects have already been depth tested in the transparent pass, so the depth comparison always passes. </br>
+/// Bind group 0 is the accumulated and revealage values, and 1 is the weight function uniform holding the cap on the accumulated weight. </br>
+/// 
+pub fn create_composite_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Composite))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[],
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(ColoredObject(Composite))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_composite_pass", 
+                buffers: &[],
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            }, 
+            depth_stencil: Some(settings.composite_depth_stencil()), 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point: settings.composite_entry_point(), 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+    
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 바닥 격자를 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 정점 버퍼 없이 전체 화면 삼각형(`vs_grid`)을 그리며, 조각 쉐이더(`fs_grid`)가 y = 0 평면과의 교차점의 깊이를 직접 출력합니다. </br>
+/// 불투명 패스의 깊이 버퍼로 깊이 테스트를 하므로 오브젝트에 가려지지만, 깊이를 쓰지 않아 뒤따르는 데칼과 투명 패스에는 영향을 주지 않습니다. </br>
+/// 격자선은 알파 블렌딩으로 불투명한 표면 위에 섞입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws the ground grid. </br>
+/// 
+/// It draws a full-screen triangle (`vs_grid`) without vertex buffers, and the fragment shader (`fs_grid`) outputs the depth of the intersection with the y = 0 plane itself. </br>
+/// It is depth tested against the depth buffer of the opaque pass so it is occluded by objects, but it does not write depth so it does not affect the following decals and transparent pass. </br>
+/// The grid lines are mixed over opaque surfaces with alpha blending. </br>
+/// 
+pub fn create_grid_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Grid)"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Grid)"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_grid", 
+                buffers: &[], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_compare: settings.depth_compare(), 
+                depth_write_enabled: false, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point: "fs_grid", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 하늘 배경을 그리는 그래픽스 파이프라인을 생성합니다. `cubemap`이 `true`인 경우 큐브 텍스처를 샘플링합니다. </br>
+/// 
+/// 하늘은 불투명 패스에서 가장 먼저 정점 버퍼 없이 전체 화면 삼각형으로 그려지며, 원평면(가장 먼 깊이)에 놓입니다. </br>
+/// 깊이 비교는 `LessEqual`(역방향 깊이인 경우 `GreaterEqual`)이고 깊이를 쓰지 않습니다. </br>
+/// - 불투명 패스가 깊이 버퍼를 지우는 값(`PipelineSettings::depth_clear_value`)이 원평면의 깊이와 같으므로, </br>
+///   깊이 버퍼를 지운 경우 모든 픽셀에서 통과하고 그 위에 오브젝트들이 일반적인 깊이 테스트로 그려집니다. </br>
+/// - 깊이 사전 패스가 깊이 버퍼를 채운 경우 아무것도 그려지지 않은 픽셀에서만 통과하므로, 가려지는 픽셀의 하늘은 계산되지 않습니다. </br>
+/// - 깊이를 쓰지 않으므로 바닥 격자, 데칼, 투명 패스에는 영향을 주지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws the sky background. If `cubemap` is `true`, it samples a cube texture. </br>
+/// 
+/// The sky is drawn first in the opaque pass as a full-screen triangle without vertex buffers, and is placed on the far plane (the farthest depth). </br>
+/// The depth comparison is `LessEqual` (`GreaterEqual` with reverse-Z) and depth is not written. </br>
+/// - The value the opaque pass clears the depth buffer to (`PipelineSettings::depth_clear_value`) equals the depth of the far plane, </br>
+///   so when the depth buffer was cleared it passes at every pixel, and objects are drawn over it with the usual depth test. </br>
+/// - When the depth pre-pass filled the depth buffer it only passes where nothing was drawn, so the sky is not computed for occluded pixels. </br>
+/// - Since it does not write depth, it does not affect the ground grid, decals or the transparent pass. </br>
+/// 
+pub fn create_sky_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    cubemap: bool, 
+) -> wgpu::RenderPipeline {
+    let (label, entry_point) = match cubemap {
+        true => ("Sky(Cubemap)", "fs_sky_cubemap"), 
+        false => ("Sky(Gradient)", "fs_sky_gradient"), 
+    };
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some(&format!("PipelineLayout({})", label)), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let (vertex_entry_point, depth_compare) = match settings.reverse_z {
+        true => ("vs_sky_reverse_z", wgpu::CompareFunction::GreaterEqual), 
+        false => ("vs_sky", wgpu::CompareFunction::LessEqual), 
+    };
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("RenderPipeline({})", label)), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: vertex_entry_point, 
+                buffers: &[], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_compare, 
+                depth_write_enabled: false, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point, 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 색상 오브젝트의 모서리를 그리는 와이어프레임 그래픽스 파이프라인을 생성합니다. </br>
+/// 숨은 선(hidden-line) 모드에서 불투명 오브젝트를 채운 후에 같은 깊이 버퍼 위에 그려집니다. </br>
+/// 
+/// 모서리가 채워진 면과 z-fighting을 일으키지 않도록 카메라 쪽으로 작은 깊이 바이어스를 적용합니다. </br>
+/// 이 파이프라인은 `wgpu::Features::POLYGON_MODE_LINE` 기능이 필요합니다. </br>
+/// 기능을 지원하지 않는 장치에서는 숨은 선 모드를 사용할 수 없으며 불투명한 면만 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a wireframe graphics pipeline that draws the edges of opaque colored objects. </br>
+/// In hidden-line mode, it is drawn over the same depth buffer after the opaque objects are filled. </br>
+/// 
+/// A small depth bias towards the camera is applied so that the edges do not z-fight with the filled faces. </br>
+/// This pipeline requires the `wgpu::Features::POLYGON_MODE_LINE` feature. </br>
+/// On devices that do not support the feature, hidden-line mode is unavailable and only the opaque faces are drawn. </br>
+/// 
+pub fn create_wireframe_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(Wireframe))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    // (한국어) 역방향 깊이를 사용하는 경우 카메라 쪽 방향은 깊이 값이 커지는 방향입니다.
+    // (English Translation) When using reverse depth, the direction towards the camera is where the depth value increases.
+    let (constant, slope_scale, depth_compare) = match settings.reverse_z {
+        true => (2, 1.0, wgpu::CompareFunction::GreaterEqual), 
+        false => (-2, -1.0, wgpu::CompareFunction::LessEqual), 
+    };
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(ColoredObject(Wireframe))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: &module, 
+                entry_point: settings.vertex_entry_point(false), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Line, 
+                unclipped_depth: settings.unclipped_depth, 
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: settings.depth_format, 
+                depth_write_enabled: false, 
+                depth_compare, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState {
+                    constant, 
+                    slope_scale, 
+                    clamp: 0.0, 
+                }, 
+            }),
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: &module, 
+                entry_point: "fs_wireframe_main", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: None, 
+                        format: settings.scene_color_format(), 
+                        write_mask: wgpu::ColorWrites::ALL,
+                    }),
+                ],
+            }),
+            multiview: settings.multiview,
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 불투명한 표면 위에 겹쳐 그리는 데칼(decal) 오브젝트의 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 데칼은 불투명 오브젝트를 그린 후, 투명 오브젝트를 그리기 전에 그려집니다. </br>
+/// 깊이 비교는 항상 통과(`Always`)하고 깊이 쓰기를 하지 않으므로 표면과 z-fighting을 일으키지 않고, </br>
+/// 뒤따르는 투명 패스의 깊이 테스트에도 영향을 주지 않습니다. </br>
+/// 데칼은 블렌딩 없이 불투명한 색상으로 덮어쓰며, 가장 나중에 그려진 데칼이 위에 보입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline for decal objects drawn over opaque surfaces. </br>
 /// 
-pub fn create_opaque_pipeline(
+/// Decals are drawn after opaque objects and before transparent objects. </br>
+/// The depth comparison always passes (`Always`) and depth is not written, so decals do not z-fight with surfaces </br>
+/// and do not affect the depth test of the following transparent pass. </br>
+/// Decals overwrite with an opaque color without blending, and the decal drawn last appears on top. </br>
+/// 
+pub fn create_decal_pipeline(
     device: &wgpu::Device, 
     module: &wgpu::ShaderModule, 
-    bind_group_layouts: &[&wgpu::BindGroupLayout]
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
 ) -> wgpu::RenderPipeline {
     let pipeline_layout = device.create_pipeline_layout(
         &wgpu::PipelineLayoutDescriptor {
-            label: Some("PipelineLayout(ColoredObject(Opaque))"), 
+            label: Some("PipelineLayout(ColoredObject(Decal))"), 
             bind_group_layouts, 
             push_constant_ranges: &[], 
         },
@@ -23,35 +2346,24 @@ pub fn create_opaque_pipeline(
 
     let pipeline = device.create_render_pipeline(
         &wgpu::RenderPipelineDescriptor {
-            label: Some("RenderPipeline(ColoredObject(Opaque))"), 
+            label: Some("RenderPipeline(ColoredObject(Decal))"), 
             layout: Some(&pipeline_layout), 
             vertex: wgpu::VertexState {
                 module: &module, 
-                entry_point: "vs_main", 
-                buffers: &[
-                    wgpu::VertexBufferLayout {
-                        step_mode: wgpu::VertexStepMode::Vertex, 
-                        array_stride: mem::size_of::<[f32; 3]>() as wgpu::BufferAddress, 
-                        attributes: &[
-                            wgpu::VertexAttribute {
-                                shader_location: 0, 
-                                format: wgpu::VertexFormat::Float32x3, 
-                                offset: 0, 
-                            },
-                        ],
-                    },
-                ],
+                entry_point: settings.vertex_entry_point(false), 
+                buffers: &[Vertex::vertex_buffer_layout()], 
             },
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleStrip, 
                 strip_index_format: Some(wgpu::IndexFormat::Uint16), 
                 polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
                 ..Default::default()
             },
             depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float, 
-                depth_write_enabled: true, 
-                depth_compare: wgpu::CompareFunction::Less, 
+                format: settings.depth_format, 
+                depth_write_enabled: false, 
+                depth_compare: wgpu::CompareFunction::Always, 
                 stencil: wgpu::StencilState::default(), 
                 bias: wgpu::DepthBiasState::default(), 
             }),
@@ -62,12 +2374,12 @@ pub fn create_opaque_pipeline(
                 targets: &[
                     Some(wgpu::ColorTargetState {
                         blend: None, 
-                        format: wgpu::TextureFormat::Bgra8Unorm, 
+                        format: settings.scene_color_format(), 
                         write_mask: wgpu::ColorWrites::ALL,
                     }),
                 ],
             }),
-            multiview: None,
+            multiview: settings.multiview,
         },
     );
 
@@ -75,114 +2387,130 @@ pub fn create_opaque_pipeline(
 }
 
 /// #### 한국어 </br>
-/// 투명한 색상 오브젝트를 그리는 기본 그래픽스 파이프라인을 생성합니다. </br>
+/// 장면의 깊이 버퍼를 투명 패스의 낮은 해상도 깊이 버퍼로 축소하는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 각 낮은 해상도 픽셀은 덮는 영역에서 가장 먼 깊이 값을 저장합니다. </br>
+/// 가장 가까운 깊이 값을 사용하면 불투명 오브젝트의 경계에서 그 뒤의 투명 오브젝트가 잘려나가기 때문입니다. </br>
+/// 바인드 그룹 0은 누적 값 텍스처의 크기를 읽는 데, 바인드 그룹 1은 장면의 깊이 텍스처를 읽는 데 사용됩니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create a default graphics pipeline to draw transparent colored object. </br>
+/// Create a graphics pipeline that downsamples the scene depth buffer into the low resolution depth buffer of the transparent pass. </br>
 /// 
-pub fn create_transparent_pipeline(
+/// Each low resolution pixel stores the farthest depth value in the area it covers, </br>
+/// because using the nearest depth value would cut off transparent objects behind the edges of opaque objects. </br>
+/// Bind group 0 is used to read the size of the accumulation texture, and bind group 1 to read the scene depth texture. </br>
+/// 
+pub fn create_depth_downsample_pipeline(
     device: &wgpu::Device, 
     module: &wgpu::ShaderModule, 
-    bind_group_layouts: &[&wgpu::BindGroupLayout]
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
 ) -> wgpu::RenderPipeline {
     let pipeline_layout = device.create_pipeline_layout(
         &wgpu::PipelineLayoutDescriptor {
-            label: Some("PipelineLayout(ColoredObject(Transparent))"), 
+            label: Some("PipelineLayout(DepthDownsample)"), 
             bind_group_layouts, 
-            push_constant_ranges: &[],
+            push_constant_ranges: &[], 
         },
     );
 
+    // (한국어) 역방향 깊이를 사용하는 경우 가장 먼 깊이 값은 가장 작은 값입니다.
+    // (English Translation) When using reverse depth, the farthest depth value is the smallest value.
+    let entry_point = match settings.reverse_z {
+        true => "fs_downsample_depth_reverse_z", 
+        false => "fs_downsample_depth", 
+    };
+
     let pipeline = device.create_render_pipeline(
         &wgpu::RenderPipelineDescriptor {
-            label: Some("RenderPipeline(ColoredObject(Transparent))"), 
+            label: Some("RenderPipeline(DepthDownsample)"), 
             layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_composite_pass", 
+                buffers: &[], 
+            },
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleStrip, 
                 strip_index_format: Some(wgpu::IndexFormat::Uint16), 
                 polygon_mode: wgpu::PolygonMode::Fill, 
                 ..Default::default()
             }, 
-            vertex: wgpu::VertexState { 
-                module: &module, 
-                entry_point: "vs_main", 
-                buffers: &[
-                    wgpu::VertexBufferLayout {
-                        step_mode: wgpu::VertexStepMode::Vertex, 
-                        array_stride: mem::size_of::<[f32; 3]>() as wgpu::BufferAddress, 
-                        attributes: &[
-                            wgpu::VertexAttribute {
-                                shader_location: 0, 
-                                format: wgpu::VertexFormat::Float32x3, 
-                                offset: 0, 
-                            },
-                        ],
-                    },
-                ], 
-            },
             depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float, 
-                depth_compare: wgpu::CompareFunction::Less, 
-                depth_write_enabled: false, 
+                format: settings.depth_format, 
+                depth_compare: wgpu::CompareFunction::Always, 
+                depth_write_enabled: true, 
                 stencil: wgpu::StencilState::default(), 
                 bias: wgpu::DepthBiasState::default(), 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point, 
+                targets: &[], 
             }),
+            multiview: None, 
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 낮은 해상도의 누적 값과 노출 값을 업샘플링하여 불투명한 색상 오브젝트와 합성하는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 쉐이더가 장면의 깊이 텍스처를 읽으므로 렌더 패스에 깊이 버퍼를 첨부하지 않습니다. </br>
+/// 바인드 그룹 0은 누적 값과 노출 값, 1은 장면의 깊이 텍스처, 2는 낮은 해상도의 깊이 텍스처, 3은 누적 가중치의 상한을 담은 가중치 함수 유니폼 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that upsamples the low resolution accumulated and revealage values and composites them with opaque colored objects. </br>
+/// 
+/// Since the shader reads the scene depth texture, no depth buffer is attached to the render pass. </br>
+/// Bind group 0 is the accumulated and revealage values, 1 is the scene depth texture, 2 is the low resolution depth texture, and 3 is the weight function uniform holding the cap on the accumulated weight. </br>
+/// 
+pub fn create_upsampled_composite_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(ColoredObject(UpsampledComposite))"), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(ColoredObject(UpsampledComposite))"), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_composite_pass", 
+                buffers: &[], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            depth_stencil: None, 
             multisample: wgpu::MultisampleState::default(), 
             fragment: Some(wgpu::FragmentState {
-                module: &module, 
-                entry_point: "fs_transparent_pass", 
+                module, 
+                entry_point: "fs_upsampled_composite_pass", 
                 targets: &[
-                    // (한국어) 
-                    // 첫 번째 렌더 타겟: (RGB * 가중치, Alpha * 가중치)를 RGBA로 저장하하는 누적 값.
-                    // 최소 `Rgba16Float`의 정밀도를 가져야 한다.
-                    // 
-                    // (English Translation)
-                    // First Render Target: Accumulated value (RGB * Weight, Alpha * Weight) stored as RGBA.
-                    // It must have a precision of at least `Rgba16Float`.
-                    //
                     Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::Rgba16Float, 
-                        blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One, 
-                                dst_factor: wgpu::BlendFactor::One, 
-                                operation: wgpu::BlendOperation::Add, 
-                            }, 
-                            alpha: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::One, 
-                                dst_factor: wgpu::BlendFactor::One, 
-                                operation: wgpu::BlendOperation::Add, 
-                            }, 
-                        }), 
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                        format: settings.scene_color_format(), 
                         write_mask: wgpu::ColorWrites::ALL, 
                     }),
-                    // (한국어)
-                    // 두 번째 렌더 타겟: 이전의 색이 얼마만큼 노출이 될 수 있는지에 대한 노출 값.
-                    // 최소 `R8`의 정밀도를 가져야 한다.
-                    // 
-                    // (English Translation)
-                    // Second Render Target: Revealage value of how much of the previous color can be exposed. 
-                    // It must have a precision of at least `R8`.
-                    // 
-                    Some(wgpu::ColorTargetState {
-                        format: wgpu::TextureFormat::R8Unorm, 
-                        blend: Some(wgpu::BlendState {
-                            color: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::Zero, 
-                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
-                                operation: wgpu::BlendOperation::Add, 
-                            },
-                            alpha: wgpu::BlendComponent {
-                                src_factor: wgpu::BlendFactor::Zero, 
-                                dst_factor: wgpu::BlendFactor::OneMinusSrc, 
-                                operation: wgpu::BlendOperation::Add, 
-                            }
-                        }),
-                        write_mask: wgpu::ColorWrites::ALL,
-                    }),
                 ],
             }),
-            multiview: None
+            multiview: None, 
         },
     );
 
@@ -190,61 +2518,389 @@ pub fn create_transparent_pipeline(
 }
 
 /// #### 한국어 </br>
-/// 불투명한 색상 오브젝트와 투명한 색상 오브젝트를 합성하는 그래픽스 파이프라인을 생성합니다. </br>
+/// 깊이 벗기기 패스에서 읽는 깊이 텍스처들의 바인드 그룹 레이아웃을 생성합니다. (바인드 그룹 슬롯 3) </br>
+/// - `binding 0`: 장면(불투명한 오브젝트)의 깊이 텍스처 </br>
+/// - `binding 1`: 이전 레이어의 깊이 텍스처 </br>
 /// 
 /// #### English (Translation) </br>
-/// Create a graphics pipeline to composite opaque and transparent colored objects. </br>
+/// Creates the bind group layout of the depth textures read by the depth peel pass. (bind group slot 3) </br>
+/// - `binding 0`: depth texture of the scene (opaque objects) </br>
+/// - `binding 1`: depth texture of the previous layer </br>
 /// 
-pub fn create_composite_pipeline(
+pub fn create_depth_peel_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    let depth_entry = |binding: u32| wgpu::BindGroupLayoutEntry {
+        binding, 
+        visibility: wgpu::ShaderStages::FRAGMENT, 
+        ty: wgpu::BindingType::Texture { 
+            sample_type: wgpu::TextureSampleType::Depth, 
+            view_dimension: wgpu::TextureViewDimension::D2, 
+            multisampled: false 
+        }, 
+        count: None, 
+    };
+
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(DepthPeel)"), 
+            entries: &[depth_entry(0), depth_entry(1)], 
+        }
+    )
+}
+
+/// #### 한국어 </br>
+/// 깊이 벗기기의 블렌딩 패스와 합성 패스에서 읽는 색상 텍스처의 바인드 그룹 레이아웃을 생성합니다. (바인드 그룹 슬롯 0) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the bind group layout of the color texture read by the blend and composite passes of depth peeling. (bind group slot 0) </br>
+/// 
+pub fn create_depth_peel_color_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(DepthPeelColor)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture { 
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false }, 
+                        view_dimension: wgpu::TextureViewDimension::D2, 
+                        multisampled: false 
+                    }, 
+                    count: None, 
+                },
+            ],
+        }
+    )
+}
+
+/// #### 한국어 </br>
+/// 투명한 색상 오브젝트에서 한 레이어를 벗겨내는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// 쉐이더는 불투명한 오브젝트에 가려졌거나 이전 레이어보다 앞에 있는(같은 깊이 포함) 프래그먼트를 버리고, </br>
+/// 남은 프래그먼트 중 가장 가까운 것이 깊이 테스트로 선택되어 레이어의 깊이 버퍼에 기록됩니다. </br>
+/// 레이어 색상은 블렌딩 없이 미리 곱한 알파(premultiplied alpha)로 저장됩니다. </br>
+/// `instance_layout`이 주어진 경우 인스턴스 렌더링을 위한 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that peels one layer off the transparent colored objects. </br>
+/// 
+/// The shader discards fragments occluded by opaque objects or in front of (including at the same depth as) the previous layer, </br>
+/// and the nearest remaining fragment is selected by the depth test and written to the depth buffer of the layer. </br>
+/// The layer color is stored as premultiplied alpha without blending. </br>
+/// If `instance_layout` is given, creates a pipeline for instanced rendering. </br>
+/// 
+pub fn create_depth_peel_pipeline(
     device: &wgpu::Device, 
     module: &wgpu::ShaderModule, 
     bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    instance_layout: Option<&wgpu::VertexBufferLayout>, 
 ) -> wgpu::RenderPipeline {
     let pipeline_layout = device.create_pipeline_layout(
         &wgpu::PipelineLayoutDescriptor {
-            label: Some("PipelineLayout(ColoredObject(Composite))"), 
+            label: Some("PipelineLayout(ColoredObject(DepthPeel))"), 
             bind_group_layouts, 
-            push_constant_ranges: &[],
+            push_constant_ranges: &[], 
         },
     );
 
+    let vertex_layout = Vertex::vertex_buffer_layout();
+    let (label, entry_point, buffers) = match instance_layout {
+        Some(instance_layout) => (
+            "RenderPipeline(ColoredObject(DepthPeel, Instanced))", 
+            settings.vertex_entry_point(true), 
+            vec![vertex_layout, instance_layout.clone()]
+        ), 
+        None => (
+            "RenderPipeline(ColoredObject(DepthPeel))", 
+            settings.vertex_entry_point(false), 
+            vec![vertex_layout]
+        ), 
+    };
+
+    // (한국어) 역방향 깊이를 사용하는 경우 앞과 뒤의 비교가 반대입니다.
+    // (English Translation) When using reverse depth, the front and back comparisons are reversed.
+    let fragment_entry_point = match settings.reverse_z {
+        true => "fs_depth_peel_reverse_z", 
+        false => "fs_depth_peel", 
+    };
+
     let pipeline = device.create_render_pipeline(
         &wgpu::RenderPipelineDescriptor {
-            label: Some("RenderPipeline(ColoredObject(Composite))"), 
+            label: Some(label), 
             layout: Some(&pipeline_layout), 
             vertex: wgpu::VertexState {
                 module, 
-                entry_point: "vs_composite_pass", 
-                buffers: &[],
+                entry_point, 
+                buffers: &buffers, 
             },
             primitive: wgpu::PrimitiveState {
                 topology: wgpu::PrimitiveTopology::TriangleStrip, 
                 strip_index_format: Some(wgpu::IndexFormat::Uint16), 
                 polygon_mode: wgpu::PolygonMode::Fill, 
+                unclipped_depth: settings.unclipped_depth, 
                 ..Default::default()
             }, 
             depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float, 
-                depth_compare: wgpu::CompareFunction::Less, 
+                format: settings.depth_format, 
+                depth_compare: settings.depth_compare(), 
                 depth_write_enabled: true, 
                 stencil: wgpu::StencilState::default(), 
-                bias: wgpu::DepthBiasState::default(),
+                bias: wgpu::DepthBiasState::default(), 
             }), 
             multisample: wgpu::MultisampleState::default(), 
             fragment: Some(wgpu::FragmentState {
                 module, 
-                entry_point: "fs_composite_pass", 
+                entry_point: fragment_entry_point, 
                 targets: &[
                     Some(wgpu::ColorTargetState {
-                        blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
-                        format: wgpu::TextureFormat::Bgra8Unorm, 
+                        blend: None, 
+                        format: DepthPeelPipelines::COLOR_FORMAT, 
                         write_mask: wgpu::ColorWrites::ALL, 
                     }),
                 ],
             }),
-            multiview: None,
+            multiview: None, 
         },
     );
-    
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 깊이 벗기기에서 전체 화면 사각형으로 색상 텍스처를 읽어 렌더 타겟에 합치는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// `composite`가 `false`인 경우 블렌딩 패스 입니다. 벗겨낸 레이어를 누적 텍스처의 뒤에 합칩니다. (under 연산) </br>
+/// `dst.rgb += (1 - dst.a) * src.rgb`, `dst.a += (1 - dst.a) * src.a` </br>
+/// 
+/// `composite`가 `true`인 경우 합성 패스 입니다. 누적 텍스처를 불투명한 장면 위에 미리 곱한 알파로 합칩니다. (over 연산) </br>
+/// 겹치지 않는 투명 표면의 결과는 가중 블렌딩 OIT의 합성 패스와 같습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that reads a color texture with a fullscreen quad and merges it into the render target in depth peeling. </br>
+/// 
+/// If `composite` is `false`, this is the blend pass. It merges the peeled layer behind the accumulation texture. (under operator) </br>
+/// `dst.rgb += (1 - dst.a) * src.rgb`, `dst.a += (1 - dst.a) * src.a` </br>
+/// 
+/// If `composite` is `true`, this is the composite pass. It merges the accumulation texture over the opaque scene with premultiplied alpha. (over operator) </br>
+/// For non-overlapping transparent surfaces, the result is the same as the composite pass of weighted blended OIT. </br>
+/// 
+pub fn create_depth_peel_resolve_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    settings: &PipelineSettings, 
+    composite: bool, 
+) -> wgpu::RenderPipeline {
+    let (label, format, blend) = match composite {
+        true => (
+            "DepthPeelComposite", 
+            settings.scene_color_format(), 
+            wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING
+        ), 
+        false => {
+            let under = wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::OneMinusDstAlpha, 
+                dst_factor: wgpu::BlendFactor::One, 
+                operation: wgpu::BlendOperation::Add, 
+            };
+            ("DepthPeelBlend", DepthPeelPipelines::COLOR_FORMAT, wgpu::BlendState { color: under, alpha: under })
+        }, 
+    };
+
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some(&format!("PipelineLayout(ColoredObject({}))", label)), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("RenderPipeline(ColoredObject({}))", label)), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_composite_pass", 
+                buffers: &[], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleStrip, 
+                strip_index_format: Some(wgpu::IndexFormat::Uint16), 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point: "fs_depth_peel_resolve_pass", 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend: Some(blend), 
+                        format, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: None, 
+        },
+    );
+
+    return pipeline;
+}
+
+/// #### 한국어 </br>
+/// 후처리 패스(톤 매핑, 블룸)에서 읽는 텍스처와 선형 필터링 샘플러의 바인드 그룹 레이아웃을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the bind group layout of the texture and the linear filtering sampler read by the post-process passes. </br>
+/// 
+pub fn create_post_process_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(PostProcess)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture { 
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                        view_dimension: wgpu::TextureViewDimension::D2, 
+                        multisampled: false 
+                    }, 
+                    count: None, 
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering), 
+                    count: None, 
+                },
+            ],
+        }
+    )
+}
+
+/// #### 한국어 </br>
+/// 후처리(톤 매핑, 블룸)의 한 패스를 그리는 그래픽스 파이프라인을 생성합니다. (참고: `HdrPipelines`, `BloomPipelines`) </br>
+/// 
+/// 정점 버퍼 없이 전체 화면 삼각형(`vs_post_process`)을 그리며, `blend`가 `None`인 경우 렌더 타겟 전체를 덮어씁니다. </br>
+/// 깊이 버퍼를 사용하지 않으므로 렌더 패스에 깊이 버퍼를 첨부하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a graphics pipeline that draws one pass of the post-process (tone mapping, bloom). (see also: `HdrPipelines`, `BloomPipelines`) </br>
+/// 
+/// It draws a full-screen triangle (`vs_post_process`) without vertex buffers, and overwrites the whole render target if `blend` is `None`. </br>
+/// It does not use depth, so no depth buffer is attached to the render pass. </br>
+/// 
+pub fn create_post_process_pipeline(
+    device: &wgpu::Device, 
+    module: &wgpu::ShaderModule, 
+    bind_group_layouts: &[&wgpu::BindGroupLayout], 
+    label: &str, 
+    entry_point: &str, 
+    format: wgpu::TextureFormat, 
+    blend: Option<wgpu::BlendState>, 
+) -> wgpu::RenderPipeline {
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some(&format!("PipelineLayout({})", label)), 
+            bind_group_layouts, 
+            push_constant_ranges: &[], 
+        },
+    );
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("RenderPipeline({})", label)), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module, 
+                entry_point: "vs_post_process", 
+                buffers: &[], 
+            },
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList, 
+                polygon_mode: wgpu::PolygonMode::Fill, 
+                ..Default::default()
+            }, 
+            depth_stencil: None, 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module, 
+                entry_point, 
+                targets: &[
+                    Some(wgpu::ColorTargetState {
+                        blend, 
+                        format, 
+                        write_mask: wgpu::ColorWrites::ALL, 
+                    }),
+                ],
+            }),
+            multiview: None, 
+        },
+    );
+
     return pipeline;
 }
+
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use super::*;
+
+    /// #### 한국어 </br>
+    /// 쉐이더(`shaders/shader.wgsl`)의 진입점이 호출하는 함수들까지 포함하여 사용하는 바인드 그룹 번호들을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the bind group numbers used by an entry point of the shader (`shaders/shader.wgsl`), including the functions it calls. </br>
+    /// 
+    fn used_bind_groups(entry_point: &str) -> BTreeSet<u32> {
+        let source = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/shader.wgsl"));
+        let module = naga::front::wgsl::parse_str(source).unwrap();
+        let info = naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+            .validate(&module)
+            .unwrap();
+        let index = module.entry_points.iter().position(|ep| ep.name == entry_point).unwrap();
+        let function_info = info.get_entry_point(index);
+        module.global_variables.iter()
+            .filter(|(handle, _)| !function_info[*handle].is_empty())
+            .filter_map(|(_, variable)| variable.binding.as_ref().map(|binding| binding.group))
+            .collect()
+    }
+
+    #[test]
+    fn shadow_pipeline_layouts_cover_used_bind_groups() {
+        // (한국어) 쉐이더가 사용하는 모든 바인드 그룹은 파이프라인 레이아웃에 있어야 합니다. 그렇지 않으면 파이프라인 생성이 실패합니다.
+        // (English Translation) Every bind group used by the shader must be in the pipeline layout, otherwise creating the pipeline fails.
+        let used = used_bind_groups("vs_shadow");
+        assert!(used.iter().all(|&group| (group as usize) < ShadowPipelines::DEPTH_BIND_GROUPS), "{:?}", used);
+
+        let mut used = used_bind_groups("vs_main");
+        used.extend(used_bind_groups("fs_opaque_shadowed_main"));
+        assert!(used.iter().all(|&group| (group as usize) < ShadowPipelines::OPAQUE_BIND_GROUPS), "{:?}", used);
+    }
+
+    #[test]
+    fn composite_passes_read_the_weight_function_uniform() {
+        // (한국어) 합성 패스는 누적 값과 노출 값에 더해 누적 가중치의 상한을 담은 가중치 함수 유니폼을 읽습니다.
+        // (English Translation) The composite passes read the weight function uniform holding the cap on the accumulated weight, in addition to the accumulated and revealage values.
+        assert_eq!(used_bind_groups("fs_composite_pass"), BTreeSet::from([0, 1]));
+        assert_eq!(used_bind_groups("fs_upsampled_composite_pass"), BTreeSet::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn composite_pass_keeps_opaque_depth() {
+        for reverse_z in [false, true] {
+            let settings = PipelineSettings { reverse_z, ..Default::default() };
+            let depth_stencil = settings.composite_depth_stencil();
+            assert!(!depth_stencil.depth_write_enabled);
+            assert_eq!(depth_stencil.depth_compare, wgpu::CompareFunction::Always);
+            assert_eq!(depth_stencil.format, settings.depth_format);
+            assert!(!depth_stencil.stencil.is_enabled());
+        }
+    }
+}
@@ -0,0 +1,2141 @@
+use std::fmt::{self, Write};
+use std::sync::Arc;
+use crate::camera::PerspectiveCameraBuilder;
+use crate::interfaces::{
+    GameCamera, 
+    GameObject, 
+    ShaderResource, 
+};
+use crate::gpu_timing::{PassTimestamps, TimedPass};
+use crate::grid::GridSettings;
+use crate::mesh::GpuMesh;
+use crate::objects::{
+    ColoredObject, 
+    InstanceBatch, 
+    MeshObject, 
+    TexturedObject, 
+};
+use crate::pipeline::{
+    BloomPipelines, 
+    DepthPeelPipelines, 
+    HdrPipelines, 
+    PipelineSetBuilder, 
+    PipelineSettings, 
+    Pipelines, 
+    PushConstantPipelines, 
+    ShadowPipelines, 
+    SortedTransparentPipelines, 
+};
+use crate::resources::{self, ResourceError};
+use crate::scene::{
+    CullingResult, 
+    ObjectBucket, 
+    ObjectId, 
+};
+use crate::settings::RenderSettings;
+use crate::shadow::ShadowMap;
+use crate::skinning::SkinnedObject;
+use crate::sky::{SkyMode, SkySettings};
+use crate::tonemap::ToneMapSettings;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 장면을 그릴 때 사용되는 중간 렌더 타겟들 입니다. (누적 값, 노출 값, 깊이 버퍼) </br>
+/// 스테레오 렌더링인 경우 각 렌더 타겟은 눈마다 하나의 레이어를 가진 텍스처 배열입니다. </br>
+/// 투명 패스를 낮은 해상도로 그리는 경우 누적 값과 노출 값은 낮은 해상도를 가지며, `downsample`에 추가 리소스가 저장됩니다. </br>
+/// HDR 렌더 타겟을 사용하는 경우 장면을 그리는 HDR 텍스처(와 블룸의 블러 텍스처들)가 `hdr`에 저장됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Intermediate render targets used when drawing the scene. (accumulated values, revealage values, depth buffer) </br>
+/// For stereo rendering, each render target is a texture array with one layer per eye. </br>
+/// If the transparent pass is drawn at a lower resolution, the accumulated and revealage values have the lower resolution, </br>
+/// and the additional resources are stored in `downsample`. </br>
+/// With the HDR render target, the HDR texture the scene is drawn into (and the blur textures of bloom) are stored in `hdr`. </br>
+/// 
+#[derive(Debug)]
+pub struct FrameTargets {
+    pub accum_texture_view: wgpu::TextureView, 
+    pub reveal_texture_view: wgpu::TextureView, 
+    pub depth_stencil_view: wgpu::TextureView, 
+    pub oit_bind_group: wgpu::BindGroup, 
+    pub downsample: Option<OitDownsampleTargets>, 
+    pub depth_peel: Option<DepthPeelTargets>, 
+    pub hdr: Option<HdrTargets>, 
+}
+
+/// #### 한국어 </br>
+/// 낮은 해상도의 투명 패스에 사용되는 리소스들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Resources used by the low resolution transparent pass. </br>
+/// 
+#[derive(Debug)]
+pub struct OitDownsampleTargets {
+    pub depth_stencil_view: wgpu::TextureView, 
+    pub scene_depth_bind_group: wgpu::BindGroup, 
+    pub oit_depth_bind_group: wgpu::BindGroup, 
+}
+
+/// #### 한국어 </br>
+/// 깊이 벗기기에 사용되는 리소스들 입니다. (참고: `DepthPeelPipelines`) </br>
+/// 두 레이어 깊이 버퍼는 번갈아 사용됩니다. 레이어 `i`는 `depth_views[i % 2]`에 깊이를 쓰고, </br>
+/// 다른 깊이 버퍼를 이전 레이어의 깊이로 읽는 `depth_bind_groups[(i + 1) % 2]`를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Resources used by depth peeling. (see also: `DepthPeelPipelines`) </br>
+/// The two layer depth buffers are used alternately. Layer `i` writes its depth to `depth_views[i % 2]`, </br>
+/// and uses `depth_bind_groups[(i + 1) % 2]`, which reads the other depth buffer as the depth of the previous layer. </br>
+/// 
+#[derive(Debug)]
+pub struct DepthPeelTargets {
+    pub depth_views: [wgpu::TextureView; 2], 
+    pub depth_bind_groups: [wgpu::BindGroup; 2], 
+    pub layer_texture_view: wgpu::TextureView, 
+    pub layer_bind_group: wgpu::BindGroup, 
+    pub accum_texture_view: wgpu::TextureView, 
+    pub accum_bind_group: wgpu::BindGroup, 
+}
+
+impl DepthPeelTargets {
+    pub fn new(
+        device: &wgpu::Device, 
+        pipelines: &DepthPeelPipelines, 
+        scene_depth_view: &wgpu::TextureView, 
+        depth_format: wgpu::TextureFormat, 
+        width: u32, 
+        height: u32
+    ) -> Self {
+        let depth_views = [
+            utils::create_depth_stencil_view(device, "DepthStencilBuffer(DepthPeel0)", depth_format, width, height, 1), 
+            utils::create_depth_stencil_view(device, "DepthStencilBuffer(DepthPeel1)", depth_format, width, height, 1), 
+        ];
+        let layer_texture_view = create_target_view(device, "DepthPeelLayer", DepthPeelPipelines::COLOR_FORMAT, width, height, 1);
+        let accum_texture_view = create_target_view(device, "DepthPeelAccumulate", DepthPeelPipelines::COLOR_FORMAT, width, height, 1);
+
+        let create_depth_bind_group = |label: &str, previous_depth_view: &wgpu::TextureView| {
+            device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some(label), 
+                    layout: &pipelines.depth_bind_group_layout, 
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0, 
+                            resource: wgpu::BindingResource::TextureView(scene_depth_view), 
+                        }, 
+                        wgpu::BindGroupEntry {
+                            binding: 1, 
+                            resource: wgpu::BindingResource::TextureView(previous_depth_view), 
+                        }, 
+                    ], 
+                }, 
+            )
+        };
+        let depth_bind_groups = [
+            create_depth_bind_group("BindGroup(DepthPeel0)", &depth_views[0]), 
+            create_depth_bind_group("BindGroup(DepthPeel1)", &depth_views[1]), 
+        ];
+
+        let create_color_bind_group = |label: &str, view: &wgpu::TextureView| {
+            device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some(label), 
+                    layout: &pipelines.color_bind_group_layout, 
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0, 
+                            resource: wgpu::BindingResource::TextureView(view), 
+                        }, 
+                    ], 
+                }, 
+            )
+        };
+        let layer_bind_group = create_color_bind_group("BindGroup(DepthPeelLayer)", &layer_texture_view);
+        let accum_bind_group = create_color_bind_group("BindGroup(DepthPeelAccumulate)", &accum_texture_view);
+
+        Self {
+            depth_views, 
+            depth_bind_groups, 
+            layer_texture_view, 
+            layer_bind_group, 
+            accum_texture_view, 
+            accum_bind_group, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// HDR 렌더 타겟에 사용되는 리소스들 입니다. (참고: `HdrPipelines`) </br>
+/// 장면은 전체 해상도의 `scene_texture_view`에 그려지고, 톤 매핑 패스는 `scene_bind_group`으로 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Resources used by the HDR render target. (see also: `HdrPipelines`) </br>
+/// The scene is drawn into the full resolution `scene_texture_view`, and the tone mapping pass reads it through `scene_bind_group`. </br>
+/// 
+#[derive(Debug)]
+pub struct HdrTargets {
+    pub scene_texture_view: wgpu::TextureView, 
+    pub scene_bind_group: wgpu::BindGroup, 
+    pub bloom: Option<BloomTargets>, 
+}
+
+/// #### 한국어 </br>
+/// 블룸 후처리에 사용되는 리소스들 입니다. (참고: `BloomPipelines`) </br>
+/// 두 블러 텍스처는 절반 해상도로 번갈아 사용됩니다. </br>
+/// 밝은 부분 추출 패스는 `blur_views[0]`에, 가로 블러는 `blur_views[1]`에, 세로 블러는 다시 `blur_views[0]`에 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Resources used by the bloom post-process. (see also: `BloomPipelines`) </br>
+/// The two blur textures are used alternately at half resolution. </br>
+/// The bright pass writes to `blur_views[0]`, the horizontal blur to `blur_views[1]`, and the vertical blur back to `blur_views[0]`. </br>
+/// 
+#[derive(Debug)]
+pub struct BloomTargets {
+    pub blur_views: [wgpu::TextureView; 2], 
+    pub blur_bind_groups: [wgpu::BindGroup; 2], 
+}
+
+impl HdrTargets {
+    pub fn new(
+        device: &wgpu::Device, 
+        pipelines: &HdrPipelines, 
+        width: u32, 
+        height: u32
+    ) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sampler(PostProcess)"), 
+            address_mode_u: wgpu::AddressMode::ClampToEdge, 
+            address_mode_v: wgpu::AddressMode::ClampToEdge, 
+            mag_filter: wgpu::FilterMode::Linear, 
+            min_filter: wgpu::FilterMode::Linear, 
+            ..Default::default()
+        });
+
+        let create_bind_group = |label: &str, view: &wgpu::TextureView| {
+            device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some(label), 
+                    layout: &pipelines.texture_bind_group_layout, 
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0, 
+                            resource: wgpu::BindingResource::TextureView(view), 
+                        }, 
+                        wgpu::BindGroupEntry {
+                            binding: 1, 
+                            resource: wgpu::BindingResource::Sampler(&sampler), 
+                        }, 
+                    ], 
+                }, 
+            )
+        };
+
+        let scene_texture_view = create_target_view(device, "HdrScene", HdrPipelines::COLOR_FORMAT, width, height, 1);
+        let scene_bind_group = create_bind_group("BindGroup(HdrScene)", &scene_texture_view);
+
+        let bloom = pipelines.bloom.as_ref().map(|_| {
+            let (blur_width, blur_height) = ((width / 2).max(1), (height / 2).max(1));
+            let blur_views = [
+                create_target_view(device, "BloomBlur0", HdrPipelines::COLOR_FORMAT, blur_width, blur_height, 1), 
+                create_target_view(device, "BloomBlur1", HdrPipelines::COLOR_FORMAT, blur_width, blur_height, 1), 
+            ];
+            let blur_bind_groups = [
+                create_bind_group("BindGroup(BloomBlur0)", &blur_views[0]), 
+                create_bind_group("BindGroup(BloomBlur1)", &blur_views[1]), 
+            ];
+            BloomTargets { blur_views, blur_bind_groups }
+        });
+
+        Self {
+            scene_texture_view, 
+            scene_bind_group, 
+            bloom, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl FrameTargets {
+    /// #### 한국어 </br>
+    /// 투명 패스 해상도 배율의 최솟값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The minimum value of the transparent pass resolution scale. </br>
+    /// 
+    pub const MIN_OIT_SCALE: f32 = 0.25;
+
+    pub fn new(
+        device: &wgpu::Device, 
+        oit_bind_group_layout: &wgpu::BindGroupLayout, 
+        reveal_format: wgpu::TextureFormat, 
+        depth_format: wgpu::TextureFormat, 
+        width: u32, 
+        height: u32, 
+        layers: u32
+    ) -> Self {
+        let depth_stencil_view = utils::create_depth_stencil_view(device, "DepthStencilBuffer", depth_format, width, height, layers);
+        let (accum_texture_view, reveal_texture_view, oit_bind_group) = create_oit_targets(
+            device, 
+            oit_bind_group_layout, 
+            reveal_format, 
+            width, 
+            height, 
+            layers
+        );
+
+        Self {
+            accum_texture_view, 
+            reveal_texture_view, 
+            depth_stencil_view, 
+            oit_bind_group, 
+            downsample: None, 
+            depth_peel: None, 
+            hdr: None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 투명 패스를 장면 해상도의 `oit_scale` 배율로 그리는 중간 렌더 타겟들을 생성합니다. </br>
+    /// 누적 값, 노출 값과 투명 패스의 깊이 버퍼는 낮은 해상도를 가지며, 합성 패스에서 업샘플링 됩니다. </br>
+    /// 배율은 [`FrameTargets::MIN_OIT_SCALE`]과 1.0 사이로 제한되며, 1.0인 경우 [`FrameTargets::new`]와 같습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the intermediate render targets that draw the transparent pass at `oit_scale` times the scene resolution. </br>
+    /// The accumulated values, revealage values, and the depth buffer of the transparent pass have the lower resolution and are upsampled in the composite pass. </br>
+    /// The scale is clamped between [`FrameTargets::MIN_OIT_SCALE`] and 1.0, and if it is 1.0 this is the same as [`FrameTargets::new`]. </br>
+    /// 
+    pub fn new_downsampled(
+        device: &wgpu::Device, 
+        oit_bind_group_layout: &wgpu::BindGroupLayout, 
+        depth_bind_group_layout: &wgpu::BindGroupLayout, 
+        settings: &PipelineSettings, 
+        width: u32, 
+        height: u32, 
+        oit_scale: f32
+    ) -> Self {
+        let (reveal_format, depth_format) = (settings.reveal_format, settings.depth_format);
+        let oit_scale = oit_scale.clamp(Self::MIN_OIT_SCALE, 1.0);
+        if oit_scale >= 1.0 {
+            return Self::new(device, oit_bind_group_layout, reveal_format, depth_format, width, height, 1);
+        }
+
+        let oit_width = ((width as f32 * oit_scale).ceil() as u32).max(1);
+        let oit_height = ((height as f32 * oit_scale).ceil() as u32).max(1);
+
+        let depth_stencil_view = utils::create_depth_stencil_view(device, "DepthStencilBuffer", depth_format, width, height, 1);
+        let oit_depth_stencil_view = utils::create_depth_stencil_view(device, "DepthStencilBuffer(Transparent)", depth_format, oit_width, oit_height, 1);
+        let (accum_texture_view, reveal_texture_view, oit_bind_group) = create_oit_targets(
+            device, 
+            oit_bind_group_layout, 
+            reveal_format, 
+            oit_width, 
+            oit_height, 
+            1
+        );
+
+        // (한국어) 깊이 축소 패스와 업샘플링 합성 패스에서 읽는 깊이 텍스처의 바인드 그룹을 생성합니다.
+        // (English Translation) Creates the bind groups of the depth textures read by the depth downsample pass and the upsampling composite pass.
+        let create_depth_bind_group = |label: &str, view: &wgpu::TextureView| {
+            device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some(label), 
+                    layout: depth_bind_group_layout, 
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0, 
+                            resource: wgpu::BindingResource::TextureView(view), 
+                        }, 
+                    ], 
+                }, 
+            )
+        };
+        let scene_depth_bind_group = create_depth_bind_group("BindGroup(SceneDepth)", &depth_stencil_view);
+        let oit_depth_bind_group = create_depth_bind_group("BindGroup(TransparentDepth)", &oit_depth_stencil_view);
+
+        Self {
+            accum_texture_view, 
+            reveal_texture_view, 
+            depth_stencil_view, 
+            oit_bind_group, 
+            downsample: Some(OitDownsampleTargets {
+                depth_stencil_view: oit_depth_stencil_view, 
+                scene_depth_bind_group, 
+                oit_depth_bind_group, 
+            }), 
+            depth_peel: None, 
+            hdr: None, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면을 그리는 데 사용되는 GPU 리소스들 입니다. (그래픽스 파이프라인, 중간 렌더 타겟, 스테레오 렌더 타겟) </br>
+/// 파이프라인과 렌더 타겟은 같은 설정(샘플 수, 깊이 형식, 노출 값 형식 등)으로 생성되어야 하므로, </br>
+/// 설정이 바뀌면 따로 고치지 않고 [`FrameResources::rebuild`]로 한 번에 다시 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// GPU resources used to draw the scene. (graphics pipelines, intermediate render targets, stereo render target) </br>
+/// The pipelines and render targets must be created with the same settings (sample count, depth format, revealage format, etc.), </br>
+/// so when the settings change they are not patched separately but recreated together with [`FrameResources::rebuild`]. </br>
+/// 
+#[derive(Debug)]
+pub struct FrameResources {
+    pub pipelines: Pipelines, 
+    pub targets: FrameTargets, 
+    pub stereo_target: Option<StereoTarget>, 
+    oit_scale: f32, 
+    width: u32, 
+    height: u32, 
+}
+
+#[allow(dead_code)]
+impl FrameResources {
+    /// #### 한국어 </br>
+    /// 빌더의 설정으로 파이프라인을 생성하고, 주어진 화면 크기에 맞는 렌더 타겟들을 생성합니다. </br>
+    /// 빌더에 깊이 텍스처 바인드 그룹 레이아웃이 설정된 경우 투명 패스를 `oit_scale` 배율로 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the pipelines with the builder's settings and the render targets that fit the given screen size. </br>
+    /// If the builder has a depth texture bind group layout, the transparent pass is drawn at `oit_scale` times. </br>
+    /// 
+    pub fn new(builder: PipelineSetBuilder<'_>, oit_scale: f32, width: u32, height: u32) -> Self {
+        let pipelines = builder.build();
+        let (targets, stereo_target) = create_frame_targets(&builder, &pipelines, oit_scale, width, height);
+        Self { pipelines, targets, stereo_target, oit_scale, width, height }
+    }
+
+    #[inline]
+    pub fn settings(&self) -> &PipelineSettings {
+        &self.pipelines.settings
+    }
+
+    #[inline]
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 크기가 바뀐 경우 렌더 타겟들만 다시 생성합니다. 파이프라인은 화면 크기와 무관하므로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates only the render targets when the screen size changes. The pipelines do not depend on the screen size and are kept. </br>
+    /// 
+    pub fn resize(&mut self, builder: &PipelineSetBuilder<'_>, width: u32, height: u32) {
+        let (targets, stereo_target) = create_frame_targets(builder, &self.pipelines, self.oit_scale, width, height);
+        self.targets = targets;
+        self.stereo_target = stereo_target;
+        self.width = width;
+        self.height = height;
+    }
+
+    /// #### 한국어 </br>
+    /// 새 파이프라인 설정으로 모든 파이프라인과 렌더 타겟을 다시 생성합니다. 설정이 같은 경우 아무것도 하지 않고 `false`를 반환합니다. </br>
+    /// 
+    /// 1. 이전 프레임에 제출된 작업이 모두 끝날 때 까지 기다립니다. (`device.poll(Wait)`) </br>
+    /// 2. 새 설정으로 파이프라인을 먼저 생성한 후, 실제로 적용된 설정(장치 기능에 따른 대체 포함)으로 렌더 타겟을 생성합니다. </br>
+    /// 3. 모든 리소스가 준비된 후에 한 번에 교체합니다. 이전 리소스는 교체와 함께 해제됩니다. </br>
+    /// 
+    /// 따라서 프레임 중간에 설정이 바뀌어도 파이프라인과 렌더 타겟의 형식이 서로 어긋난 상태로 그려지지 않습니다. </br>
+    /// 스테레오 렌더링의 전환은 쉐이더 모듈과 바인드 그룹 레이아웃이 바뀌어야 하므로 지원하지 않으며, 경고를 출력하고 현재 값을 유지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates all pipelines and render targets with the new pipeline settings. If the settings are the same, does nothing and returns `false`. </br>
+    /// 
+    /// 1. Waits until all work submitted in previous frames is finished. (`device.poll(Wait)`) </br>
+    /// 2. Creates the pipelines with the new settings first, then creates the render targets with the settings actually applied (including fallbacks for device features). </br>
+    /// 3. Swaps everything at once after all resources are ready. The previous resources are released with the swap. </br>
+    /// 
+    /// Therefore, even if the settings change mid-run, the pipelines and render targets are never drawn with mismatched formats. </br>
+    /// Switching stereo rendering is not supported because it requires a different shader module and bind group layouts, </br>
+    /// in which case a warning is printed and the current value is kept. </br>
+    /// 
+    pub fn rebuild(&mut self, builder: PipelineSetBuilder<'_>, mut settings: PipelineSettings) -> bool {
+        if settings.multiview != self.pipelines.settings.multiview {
+            log::warn!("Switching stereo rendering requires recreating the shader module and bind group layouts, which is not supported. Keep the current value.");
+            settings.multiview = self.pipelines.settings.multiview;
+        }
+
+        if settings == self.pipelines.settings {
+            return false;
+        }
+
+        // (한국어) 이전 리소스를 사용하는 작업이 모두 끝날 때 까지 기다립니다.
+        // (English Translation) Waits until all work using the previous resources is finished.
+        builder.device.poll(wgpu::Maintain::Wait);
+
+        let pipelines = builder.set_settings(settings).build();
+        let (targets, stereo_target) = create_frame_targets(&builder, &pipelines, self.oit_scale, self.width, self.height);
+        log::info!("Rebuilt frame resources: {:?} -> {:?}", self.pipelines.settings, pipelines.settings);
+
+        *self = Self { 
+            pipelines, 
+            targets, 
+            stereo_target, 
+            oit_scale: self.oit_scale, 
+            width: self.width, 
+            height: self.height, 
+        };
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 파이프라인에 실제로 적용된 설정으로 빌더에서 파이프라인 집합을 생성합니다. 현재 파이프라인은 바뀌지 않습니다. </br>
+    /// 쉐이더 모듈만 바꾼 빌더로 새 파이프라인을 만든 후 `replace_pipelines`로 교체하는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a pipeline set from the builder with the settings actually applied to the current pipelines. The current pipelines are unchanged. </br>
+    /// Used to create new pipelines from a builder with only the shader module changed, and then swap them in with `replace_pipelines`. </br>
+    /// 
+    pub fn build_pipelines(&self, builder: PipelineSetBuilder<'_>) -> Pipelines {
+        builder.set_settings(self.pipelines.settings).build()
+    }
+
+    /// #### 한국어 </br>
+    /// 파이프라인만 교체합니다. 렌더 타겟은 유지되므로, 새 파이프라인은 같은 설정으로 생성되어야 합니다. (`build_pipelines`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Swaps only the pipelines. The render targets are kept, so the new pipelines must be created with the same settings. (`build_pipelines`) </br>
+    /// 
+    pub fn replace_pipelines(&mut self, pipelines: Pipelines) {
+        debug_assert_eq!(pipelines.settings, self.pipelines.settings);
+        self.pipelines = pipelines;
+    }
+}
+
+/// #### 한국어 </br>
+/// 창의 화면(스왑체인)에 장면을 그리는 렌더러 입니다. </br>
+/// 장치, 명령 대기열, 화면과 화면 설정, 그리고 장면을 그리는 데 사용되는 GPU 리소스([`FrameResources`])를 소유합니다. </br>
+/// 
+/// 화면 크기나 표시 모드가 바뀔 때 화면 설정과 렌더 타겟이 함께 갱신되어야 하므로, 화면을 재설정하는 코드는 이 곳에만 있습니다. </br>
+/// 장면의 오브젝트와 카메라는 소유하지 않으며, 매 프레임 [`DrawContext`]로 전달됩니다. </br>
+/// 창이 없는 경우(`headless`)에는 화면 대신 텍스처에 그리므로 이 구조체를 사용하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The renderer that draws the scene to the surface (swapchain) of a window. </br>
+/// It owns the device, the command queue, the surface with its configuration, and the GPU resources used to draw the scene ([`FrameResources`]). </br>
+/// 
+/// The surface configuration and the render targets must be updated together when the screen size or the present mode changes, </br>
+/// so the code that reconfigures the surface lives only here. </br>
+/// It does not own the objects or the camera of the scene, which are passed every frame with a [`DrawContext`]. </br>
+/// Without a window (`headless`), the scene is drawn into a texture instead of a surface, so this struct is not used. </br>
+/// 
+#[derive(Debug)]
+pub struct Renderer {
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    surface: Arc<wgpu::Surface<'static>>, 
+    config: wgpu::SurfaceConfiguration, 
+    pub resources: FrameResources, 
+}
+
+#[allow(dead_code)]
+impl Renderer {
+    /// #### 한국어 </br>
+    /// 주어진 설정으로 화면을 설정하고, 화면 크기에 맞는 파이프라인과 렌더 타겟들을 생성합니다. (참고: [`FrameResources::new`]) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Configures the surface with the given configuration, and creates the pipelines and render targets that fit the surface size. (see also: [`FrameResources::new`]) </br>
+    /// 
+    pub fn new(
+        device: Arc<wgpu::Device>, 
+        queue: Arc<wgpu::Queue>, 
+        surface: Arc<wgpu::Surface<'static>>, 
+        config: wgpu::SurfaceConfiguration, 
+        builder: PipelineSetBuilder<'_>, 
+        oit_scale: f32
+    ) -> Self {
+        surface.configure(&device, &config);
+        let resources = FrameResources::new(builder, oit_scale, config.width, config.height);
+        Self { device, queue, surface, config, resources }
+    }
+
+    #[inline]
+    pub fn device(&self) -> &Arc<wgpu::Device> {
+        &self.device
+    }
+
+    #[inline]
+    pub fn queue(&self) -> &Arc<wgpu::Queue> {
+        &self.queue
+    }
+
+    #[inline]
+    pub fn surface(&self) -> &Arc<wgpu::Surface<'static>> {
+        &self.surface
+    }
+
+    #[inline]
+    pub fn config(&self) -> &wgpu::SurfaceConfiguration {
+        &self.config
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 크기가 바뀐 경우 이전 작업이 모두 끝날 때 까지 기다린 후, 화면을 재설정하고 렌더 타겟들을 다시 생성합니다. </br>
+    /// 크기가 0인 경우(창이 최소화된 경우) 화면을 설정할 수 없으므로 아무것도 하지 않고 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// When the screen size changes, waits until all previous work is finished, then reconfigures the surface and recreates the render targets. </br>
+    /// If the size is zero (the window is minimized), the surface cannot be configured, so does nothing and returns `false`. </br>
+    /// 
+    pub fn resize(&mut self, builder: &PipelineSetBuilder<'_>, width: u32, height: u32) -> bool {
+        if width == 0 || height == 0 {
+            return false;
+        }
+
+        self.device.poll(wgpu::Maintain::Wait);
+        self.config.width = width;
+        self.config.height = height;
+        self.surface.configure(&self.device, &self.config);
+        self.resources.resize(builder, width, height);
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 표시 모드를 바꾸고 화면을 재설정합니다. 표시 모드가 같은 경우 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the present mode and reconfigures the surface. Does nothing if the present mode is the same. </br>
+    /// 
+    pub fn set_present_mode(&mut self, present_mode: wgpu::PresentMode) {
+        if self.config.present_mode != present_mode {
+            self.config.present_mode = present_mode;
+            self.surface.configure(&self.device, &self.config);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 프레임의 화면 텍스처를 가져옵니다. </br>
+    /// 화면을 잃었거나(Lost) 오래된(Outdated) 경우 저장된 설정으로 화면을 재설정하고 `None`을 반환합니다. </br>
+    /// 시간 초과(Timeout)인 경우 `None`을 반환하며, 메모리가 부족한(OutOfMemory) 경우에만 실패합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the surface texture of the next frame. </br>
+    /// If the surface is lost or outdated, reconfigures it with the stored configuration and returns `None`. </br>
+    /// On timeout it returns `None`, and only running out of memory is a hard failure. </br>
+    /// 
+    pub fn acquire_frame(&self) -> Option<wgpu::SurfaceTexture> {
+        match self.surface.get_current_texture() {
+            Ok(frame) => Some(frame), 
+            Err(e @ (wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated)) => {
+                log::warn!("Failed to acquire the next surface texture: {}. Reconfigure the surface.", e);
+                self.surface.configure(&self.device, &self.config);
+                None
+            },
+            Err(wgpu::SurfaceError::Timeout) => {
+                log::warn!("Timed out acquiring the next surface texture. Skip this frame.");
+                None
+            },
+            Err(e @ wgpu::SurfaceError::OutOfMemory) => {
+                panic!("Failed to acquire the next surface texture: {}", e);
+            },
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 장면의 렌더 패스들을 기록합니다. (참고: [`record_scene_passes`]) </br>
+    /// 스테레오 렌더링인 경우 두 눈의 장면을 스테레오 렌더 타겟에 그린 후 화면 텍스처에 나란히 복사합니다. </br>
+    /// `context`의 파이프라인은 이 렌더러의 리소스(`resources.pipelines`)여야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the render passes of the scene. (see also: [`record_scene_passes`]) </br>
+    /// For stereo rendering, the scenes of both eyes are drawn into the stereo render target and then copied side by side into the surface texture. </br>
+    /// The pipelines of `context` must be the resources of this renderer (`resources.pipelines`). </br>
+    /// 
+    pub fn render(
+        &self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        context: &DrawContext, 
+        camera_bind_group: &wgpu::BindGroup, 
+        frame: &wgpu::SurfaceTexture, 
+        frame_view: &wgpu::TextureView
+    ) {
+        record_scene_passes(
+            encoder, 
+            context, 
+            camera_bind_group, 
+            &self.resources.targets, 
+            self.resources.stereo_target.as_ref().map_or(frame_view, |stereo_target| &stereo_target.view)
+        );
+
+        if let Some(stereo_target) = self.resources.stereo_target.as_ref() {
+            stereo_target.copy_to_surface(encoder, &frame.texture);
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 파이프라인에 실제로 적용된 설정에 맞는 중간 렌더 타겟과 스테레오 렌더 타겟을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the intermediate render targets and the stereo render target that match the settings actually applied to the pipelines. </br>
+/// 
+fn create_frame_targets(
+    builder: &PipelineSetBuilder<'_>, 
+    pipelines: &Pipelines, 
+    oit_scale: f32, 
+    width: u32, 
+    height: u32
+) -> (FrameTargets, Option<StereoTarget>) {
+    let settings = &pipelines.settings;
+    let views = settings.multiview.map_or(1, |views| views.get());
+    let mut targets = match (pipelines.oit_downsample.as_ref(), builder.depth_bind_group_layout) {
+        (Some(_), Some(depth_bind_group_layout)) => FrameTargets::new_downsampled(
+            builder.device, 
+            builder.oit_bind_group_layout, 
+            depth_bind_group_layout, 
+            settings, 
+            width, 
+            height, 
+            oit_scale
+        ), 
+        _ => FrameTargets::new(
+            builder.device, 
+            builder.oit_bind_group_layout, 
+            settings.reveal_format, 
+            settings.depth_format, 
+            width / views, 
+            height, 
+            views
+        ), 
+    };
+    targets.depth_peel = pipelines.depth_peel.as_ref().map(|depth_peel| DepthPeelTargets::new(
+        builder.device, 
+        depth_peel, 
+        &targets.depth_stencil_view, 
+        settings.depth_format, 
+        width, 
+        height
+    ));
+    targets.hdr = pipelines.hdr.as_ref().map(|hdr| HdrTargets::new(
+        builder.device, 
+        hdr, 
+        width, 
+        height
+    ));
+    let stereo_target = settings.multiview.map(|_| StereoTarget::new(
+        builder.device, 
+        settings.color_format, 
+        width / views, 
+        height
+    ));
+    (targets, stereo_target)
+}
+
+/// #### 한국어 </br>
+/// 렌더 타겟으로 사용하고 쉐이더에서 읽을 수 있는 텍스처 뷰를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a texture view that is used as a render target and can be read in shaders. </br>
+/// 
+fn create_target_view(
+    device: &wgpu::Device, 
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    width: u32, 
+    height: u32, 
+    layers: u32
+) -> wgpu::TextureView {
+    device.create_texture(&utils::render_target_descriptor(label, format, width, height, layers))
+        .create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        })
+}
+
+/// #### 한국어 </br>
+/// 누적 값을 저장할 텍스처 뷰를 생성합니다. (`PipelineSettings::ACCUM_FORMAT`) </br>
+/// sRGB 형식이 아닌 선형 형식을 사용하며, 감마 보정은 합성 패스가 화면에 쓸 때 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a texture view to store accumulated values. (`PipelineSettings::ACCUM_FORMAT`) </br>
+/// A linear format is used instead of an sRGB format, and gamma correction is applied when the composite pass writes to the surface. </br>
+/// 
+#[inline]
+fn create_accum_view(device: &wgpu::Device, width: u32, height: u32, layers: u32) -> wgpu::TextureView {
+    create_target_view(device, "Accumulate", PipelineSettings::ACCUM_FORMAT, width, height, layers)
+}
+
+/// #### 한국어 </br>
+/// 노출 값을 저장할 텍스처 뷰를 생성합니다. 형식은 장치에 따라 선택됩니다. (참고: `utils::select_reveal_format`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a texture view to store revealage values. The format is selected per device. (see also: `utils::select_reveal_format`) </br>
+/// 
+#[inline]
+fn create_reveal_view(device: &wgpu::Device, reveal_format: wgpu::TextureFormat, width: u32, height: u32, layers: u32) -> wgpu::TextureView {
+    create_target_view(device, "Revealage", reveal_format, width, height, layers)
+}
+
+/// #### 한국어 </br>
+/// 누적 값과 노출 값을 저장할 텍스처 뷰와 그 바인드 그룹을 생성합니다. </br>
+/// 합성 패스에서 업샘플링 할 때 사용하는 선형 필터링 샘플러도 바인드 그룹에 포함됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the texture views that store the accumulated and revealage values and their bind group. </br>
+/// The linear filtering sampler used for upsampling in the composite pass is also included in the bind group. </br>
+/// 
+fn create_oit_targets(
+    device: &wgpu::Device, 
+    oit_bind_group_layout: &wgpu::BindGroupLayout, 
+    reveal_format: wgpu::TextureFormat, 
+    width: u32, 
+    height: u32, 
+    layers: u32
+) -> (wgpu::TextureView, wgpu::TextureView, wgpu::BindGroup) {
+    let accum_texture_view = create_accum_view(device, width, height, layers);
+    let reveal_texture_view = create_reveal_view(device, reveal_format, width, height, layers);
+
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("Sampler(WeightedBlendedOIT)"), 
+        address_mode_u: wgpu::AddressMode::ClampToEdge, 
+        address_mode_v: wgpu::AddressMode::ClampToEdge, 
+        mag_filter: wgpu::FilterMode::Linear, 
+        min_filter: wgpu::FilterMode::Linear, 
+        ..Default::default()
+    });
+
+    // (한국어) 누적 값과 노출 값의 바인드 그룹을 생성합니다.
+    // (English Translation) Creates a bind group of accumulated and revealage values.
+    let oit_bind_group = device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(WeightedBlendedOIT)"), 
+            layout: &oit_bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(&accum_texture_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(&reveal_texture_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Sampler(&sampler), 
+                }, 
+            ], 
+        }, 
+    );
+
+    (accum_texture_view, reveal_texture_view, oit_bind_group)
+}
+
+/// #### 한국어 </br>
+/// 장면을 그리는 렌더 패스들의 레이블 입니다. </br>
+/// 프레임 번호를 붙일 때 매 프레임 새 문자열을 할당하지 않도록 문자열 버퍼를 재사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The labels of the render passes that draw the scene. </br>
+/// The string buffers are reused so that no new string is allocated every frame when the frame number is appended. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassLabels {
+    opaque: String, 
+    transparent: String, 
+    composite: String, 
+}
+
+impl PassLabels {
+    const OPAQUE: &'static str = "RenderPass(Opaque)";
+    const TRANSPARENT: &'static str = "RenderPass(Transparent)";
+    const COMPOSITE: &'static str = "RenderPass(Composite)";
+
+    /// #### 한국어 </br>
+    /// 레이블에 프레임 번호를 붙입니다. `None`인 경우 프레임 번호 없는 레이블을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Appends the frame number to the labels. If `None`, labels without a frame number are used. </br>
+    /// 
+    pub fn set_frame_index(&mut self, frame_index: Option<u64>) {
+        for (label, base) in [
+            (&mut self.opaque, Self::OPAQUE), 
+            (&mut self.transparent, Self::TRANSPARENT), 
+            (&mut self.composite, Self::COMPOSITE), 
+        ] {
+            label.clear();
+            label.push_str(base);
+            if let Some(frame_index) = frame_index {
+                let _ = write!(label, "#{}", frame_index);
+            }
+        }
+    }
+}
+
+impl Default for PassLabels {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            opaque: Self::OPAQUE.to_string(), 
+            transparent: Self::TRANSPARENT.to_string(), 
+            composite: Self::COMPOSITE.to_string(), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 누적 값과 노출 값 텍스처를 읽는 합성 패스의 바인드 그룹 레이아웃을 생성합니다. </br>
+/// 스테레오 렌더링인 경우 `view_dimension`은 `D2Array` 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the bind group layout of the composite pass that reads the accumulated and revealage textures. </br>
+/// For stereo rendering, `view_dimension` is `D2Array`. </br>
+/// 
+pub fn create_oit_bind_group_layout(device: &wgpu::Device, view_dimension: wgpu::TextureViewDimension) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(WeightedBlendedOIT)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture { 
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                        view_dimension, 
+                        multisampled: false 
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture { 
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                        view_dimension, 
+                        multisampled: false 
+                    }, 
+                    count: None, 
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering), 
+                    count: None, 
+                },
+            ],
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 장면을 그리는 데 필요한 리소스들을 묶은 구조체 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A structure that bundles the resources needed to draw the scene. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct DrawContext<'a> {
+    pub pipelines: &'a Pipelines, 
+    pub settings: &'a RenderSettings, 
+    pub quad_mesh: &'a GpuMesh, 
+    pub opaque_objects: &'a [ColoredObject], 
+    pub decal_objects: &'a [ColoredObject], 
+    pub transparent_objects: &'a [ColoredObject], 
+    pub opaque_batches: &'a [InstanceBatch], 
+    pub transparent_batches: &'a [InstanceBatch], 
+    pub mesh_objects: &'a [MeshObject], 
+    pub textured_objects: &'a [TexturedObject], 
+    pub skinned_objects: &'a [SkinnedObject], 
+    pub hidden_line: bool, 
+    pub culling: Option<&'a CullingResult>, 
+    pub pass_labels: &'a PassLabels, 
+    pub timestamps: Option<&'a PassTimestamps>, 
+    pub sorted_transparent: Option<&'a [usize]>, 
+    pub grid: Option<&'a GridSettings>, 
+    pub sky: Option<&'a SkySettings>, 
+    pub tonemap: Option<&'a ToneMapSettings>, 
+    pub shadow: Option<&'a ShadowMap>, 
+}
+
+impl<'a> DrawContext<'a> {
+    /// #### 한국어 </br>
+    /// 오브젝트가 컬링되지 않았는지 확인합니다. 컬링 결과가 없는 경우 항상 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object survived culling. Always returns `true` if there is no culling result. </br>
+    /// 
+    #[inline]
+    pub fn is_visible(&self, bucket: ObjectBucket, index: usize) -> bool {
+        self.culling.map_or(true, |culling| culling.is_visible(ObjectId { bucket, index }))
+    }
+
+    /// #### 한국어 </br>
+    /// 그리기 목록에서 숨겨지지 않고 컬링되지 않은 오브젝트들을 순회합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Iterates over the objects in the draw list that are not hidden and survived culling. </br>
+    /// 
+    pub fn visible_objects(&self, bucket: ObjectBucket, objects: &'a [ColoredObject]) -> impl Iterator<Item = &'a ColoredObject> + '_ {
+        objects.iter()
+            .enumerate()
+            .filter(move |&(index, object)| object.is_visible() && self.is_visible(bucket, index))
+            .map(|(_, object)| object)
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 그림자를 그리는 경우 그림자 파이프라인과 그림자 맵을 가져옵니다. </br>
+    /// 파이프라인과 그림자 맵이 모두 있고, 그림자와 방향광이 모두 켜져 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the shadow pipelines and the shadow map if shadows are drawn this frame. </br>
+    /// Both the pipelines and the shadow map must exist, and both shadows and the directional light must be enabled. </br>
+    /// 
+    pub fn active_shadow(&self) -> Option<(&'a ShadowPipelines, &'a ShadowMap)> {
+        self.pipelines.shadow.as_ref()
+            .zip(self.shadow)
+            .filter(|(_, shadow)| shadow.enabled() && self.settings.light().enabled)
+    }
+}
+
+/// #### 한국어 </br>
+/// 불투명 패스, 투명 패스, 합성 패스를 커맨드 버퍼에 기록합니다. </br>
+/// HDR 렌더 타겟을 사용하는 경우 장면을 HDR 텍스처에 그리고 (블룸 패스들을 그린 후) 톤 매핑 패스가 `render_target_view`에 결과를 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the opaque pass, transparent pass, and composite pass into the command buffer. </br>
+/// With the HDR render target, the scene is drawn into an HDR texture and (after the bloom passes) the tone mapping pass writes the result to `render_target_view`. </br>
+/// 
+pub fn record_scene_passes(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    camera_bind_group: &wgpu::BindGroup, 
+    targets: &FrameTargets, 
+    render_target_view: &wgpu::TextureView
+) {
+    if let Some((shadow_pipelines, shadow)) = context.active_shadow() {
+        record_shadow_pass(encoder, context, shadow_pipelines, shadow);
+    }
+
+    match (targets.hdr.as_ref(), context.pipelines.hdr.as_ref()) {
+        (Some(hdr_targets), Some(hdr_pipelines)) => {
+            record_scene_color_passes(encoder, context, camera_bind_group, targets, &hdr_targets.scene_texture_view);
+            if let (Some(bloom_targets), Some(bloom_pipelines)) = (hdr_targets.bloom.as_ref(), hdr_pipelines.bloom.as_ref()) {
+                record_bloom_passes(encoder, context, hdr_targets, bloom_targets, bloom_pipelines);
+            }
+            record_tonemap_pass(encoder, context, hdr_targets, hdr_pipelines, render_target_view);
+        }, 
+        _ => record_scene_color_passes(encoder, context, camera_bind_group, targets, render_target_view), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 그림자를 드리우는 불투명한 오브젝트들을 빛의 시점에서 그림자 맵에 그리는 패스를 커맨드 버퍼에 기록합니다. (참고: `ShadowPipelines`) </br>
+/// 빛의 시점은 카메라와 다르므로 카메라의 컬링 결과를 사용하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the pass that draws the opaque objects casting shadows into the shadow map from the light's point of view into the command buffer. (see also: `ShadowPipelines`) </br>
+/// The light's point of view differs from the camera, so the camera's culling result is not used. </br>
+/// 
+fn record_shadow_pass(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    pipelines: &ShadowPipelines, 
+    shadow: &ShadowMap
+) {
+    let mut rpass = encoder.begin_render_pass(
+        &wgpu::RenderPassDescriptor {
+            label: Some("RenderPass(Shadow)"), 
+            color_attachments: &[], 
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: shadow.ref_depth_view(), 
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0), 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+                stencil_ops: None, 
+            }), 
+            timestamp_writes: None, 
+            occlusion_query_set: None, 
+        }
+    );
+
+    rpass.set_pipeline(&pipelines.depth);
+    rpass.set_bind_group(0, shadow.ref_light_bind_group(), &[]);
+    rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+    context.quad_mesh.bind(&mut rpass);
+    for object in context.opaque_objects.iter().filter(|object| object.is_visible() && object.cast_shadow()) {
+        rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+        context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 전체 화면 삼각형 하나를 그리는 후처리 패스를 커맨드 버퍼에 기록합니다. `bind_groups`는 슬롯 0부터 차례로 설정됩니다. </br>
+/// `clear`가 `true`인 경우 렌더 타겟을 지운 후 그리고, `false`인 경우 기존 내용 위에 블렌딩합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records a post-process pass that draws one full-screen triangle into the command buffer. `bind_groups` are set in order from slot 0. </br>
+/// If `clear` is `true`, the render target is cleared before drawing, and if `false`, it is blended over the existing contents. </br>
+/// 
+fn record_post_process_pass(
+    encoder: &mut wgpu::CommandEncoder, 
+    label: &str, 
+    pipeline: &wgpu::RenderPipeline, 
+    bind_groups: &[&wgpu::BindGroup], 
+    view: &wgpu::TextureView, 
+    clear: bool
+) {
+    let mut rpass = encoder.begin_render_pass(
+        &wgpu::RenderPassDescriptor {
+            label: Some(label), 
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view, 
+                    resolve_target: None, 
+                    ops: wgpu::Operations {
+                        load: match clear {
+                            true => wgpu::LoadOp::Clear(wgpu::Color::BLACK), 
+                            false => wgpu::LoadOp::Load, 
+                        }, 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                }), 
+            ], 
+            depth_stencil_attachment: None, 
+            timestamp_writes: None, 
+            occlusion_query_set: None, 
+        }, 
+    );
+
+    rpass.set_pipeline(pipeline);
+    for (index, bind_group) in bind_groups.iter().enumerate() {
+        rpass.set_bind_group(index as u32, bind_group, &[]);
+    }
+    rpass.draw(0..3, 0..1);
+}
+
+/// #### 한국어 </br>
+/// 블룸 후처리의 밝은 부분 추출 패스, 가로/세로 블러 패스, 합성 패스를 커맨드 버퍼에 기록합니다. (참고: `BloomPipelines`) </br>
+/// 합성 패스는 흐려진 밝은 부분을 HDR 장면 텍스처에 더하므로, 이후의 톤 매핑 패스가 함께 톤 매핑합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the bright pass, the horizontal/vertical blur passes and the composite pass of the bloom post-process into the command buffer. (see also: `BloomPipelines`) </br>
+/// The composite pass adds the blurred bright parts to the HDR scene texture, so the following tone mapping pass tonemaps them together. </br>
+/// 
+fn record_bloom_passes(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    hdr_targets: &HdrTargets, 
+    targets: &BloomTargets, 
+    pipelines: &BloomPipelines
+) {
+    let global_bind_group = context.settings.ref_bind_group();
+    record_post_process_pass(encoder, "RenderPass(BloomBright)", &pipelines.bright, &[&hdr_targets.scene_bind_group, global_bind_group], &targets.blur_views[0], true);
+    record_post_process_pass(encoder, "RenderPass(BloomBlurHorizontal)", &pipelines.blur_horizontal, &[&targets.blur_bind_groups[0]], &targets.blur_views[1], true);
+    record_post_process_pass(encoder, "RenderPass(BloomBlurVertical)", &pipelines.blur_vertical, &[&targets.blur_bind_groups[1]], &targets.blur_views[0], true);
+    record_post_process_pass(encoder, "RenderPass(BloomComposite)", &pipelines.composite, &[&targets.blur_bind_groups[0], global_bind_group], &hdr_targets.scene_texture_view, false);
+}
+
+/// #### 한국어 </br>
+/// HDR 장면 텍스처를 톤 매핑하여 `render_target_view`에 쓰는 패스를 커맨드 버퍼에 기록합니다. (참고: `HdrPipelines`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the pass that tonemaps the HDR scene texture and writes it to `render_target_view` into the command buffer. (see also: `HdrPipelines`) </br>
+/// 
+fn record_tonemap_pass(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    targets: &HdrTargets, 
+    pipelines: &HdrPipelines, 
+    render_target_view: &wgpu::TextureView
+) {
+    let tonemap = context.tonemap.expect("The HDR render target requires the tone mapping settings.");
+    record_post_process_pass(encoder, "RenderPass(ToneMap)", &pipelines.tonemap, &[&targets.scene_bind_group, tonemap.ref_bind_group()], render_target_view, true);
+}
+
+/// #### 한국어 </br>
+/// 장면의 색상을 `render_target_view`에 그리는 패스들(깊이 사전 패스부터 합성 패스까지)을 커맨드 버퍼에 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the passes that draw the scene color into `render_target_view` (from the depth pre-pass to the composite pass) into the command buffer. </br>
+/// 
+fn record_scene_color_passes(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    camera_bind_group: &wgpu::BindGroup, 
+    targets: &FrameTargets, 
+    render_target_view: &wgpu::TextureView
+) {
+    // (한국어) 투명 패스를 낮은 해상도로 그리는 경우 필요한 렌더 타겟과 파이프라인 입니다.
+    // (English Translation) The render targets and pipelines needed if the transparent pass is drawn at a lower resolution.
+    let downsample = targets.downsample.as_ref().map(|downsample| {
+        let pipelines = context.pipelines.oit_downsample.as_ref()
+            .expect("Downsampled frame targets require the OIT downsample pipelines.");
+        (downsample, pipelines)
+    });
+
+    // (한국어) 깊이 사전 패스를 사용하는 경우 불투명한 오브젝트들의 깊이 값만 먼저 기록합니다.
+    // (English Translation) If the depth pre-pass is used, writes only the depth values of opaque objects first.
+    if let Some(prepass_pipelines) = context.pipelines.depth_prepass.as_ref() {
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("RenderPass(DepthPrepass)"), 
+                color_attachments: &[], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &targets.depth_stencil_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(context.pipelines.settings.depth_clear_value()), 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: None, 
+                occlusion_query_set: None, 
+            }
+        );
+
+        rpass.set_pipeline(&prepass_pipelines.prepass);
+        rpass.set_bind_group(0, camera_bind_group, &[]);
+        rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+        context.quad_mesh.bind(&mut rpass);
+        for object in context.visible_objects(ObjectBucket::Opaque, context.opaque_objects) {
+            rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+            context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+        }
+
+        if let Some(instanced_pipeline) = prepass_pipelines.prepass_instanced.as_ref() {
+            rpass.set_pipeline(instanced_pipeline);
+            draw_instance_batches(&mut rpass, context.opaque_batches);
+        }
+    }
+
+    {
+        // <1>
+        // (한국어)
+        // 불투명한 색상 오브젝트들을 그립니다.
+        // 
+        // 이때, 깊이 버퍼를 이용하여 오브젝트들의 깊이 값을 저장합니다.
+        // 
+        // (English Translation)
+        // Draws opaque colored objects.
+        // 
+        // At this time, the depth value of the objects is stored using the depth buffer.
+        // 
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some(&context.pass_labels.opaque), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: render_target_view, 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: match context.settings.clear_color_target() {
+                                true => wgpu::LoadOp::Clear(context.settings.clear_color_value()), 
+                                false => wgpu::LoadOp::Load, 
+                            }, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &targets.depth_stencil_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        // (한국어) 깊이 사전 패스가 있는 경우 사전 패스가 기록한 깊이 값을 불러옵니다.
+                        // (English Translation) If there is a depth pre-pass, loads the depth values written by the pre-pass.
+                        load: match context.pipelines.depth_prepass.is_some() {
+                            true => wgpu::LoadOp::Load, 
+                            false => wgpu::LoadOp::Clear(context.pipelines.settings.depth_clear_value()), 
+                        }, 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Opaque)), 
+                occlusion_query_set: None, 
+            }, 
+        );
+
+        rpass.set_bind_group(0, camera_bind_group, &[]);
+
+        // (한국어) 
+        // 오브젝트들보다 먼저 원평면에 하늘 배경을 그립니다. 깊이를 쓰지 않으므로 오브젝트들은 그 위에 그대로 그려집니다.
+        // 큐브맵 하늘은 바인드 그룹 슬롯 2를 사용하므로 전역 설정 바인드 그룹보다 먼저 그립니다.
+        // 
+        // (English Translation) 
+        // Draws the sky background on the far plane before the objects. Since it does not write depth, objects are drawn over it as usual.
+        // The cubemap sky uses bind group slot 2, so it is drawn before the global settings bind group is set.
+        // 
+        if let (Some(sky_pipelines), Some(sky)) = (context.pipelines.sky.as_ref(), context.sky) {
+            if sky.enabled() {
+                match (sky.mode(), sky_pipelines.cubemap.as_ref(), sky.ref_cubemap_bind_group()) {
+                    (SkyMode::Cubemap, Some(cubemap_pipeline), Some(cubemap_bind_group)) => {
+                        rpass.set_pipeline(cubemap_pipeline);
+                        rpass.set_bind_group(2, cubemap_bind_group, &[]);
+                    }, 
+                    _ => rpass.set_pipeline(&sky_pipelines.gradient), 
+                }
+                rpass.set_bind_group(1, sky.ref_bind_group(), &[]);
+                rpass.draw(0..3, 0..1);
+            }
+        }
+
+        rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+        context.quad_mesh.bind(&mut rpass);
+        // (한국어) 그림자를 그리는 경우 그림자 맵을 읽는 파이프라인이 푸시 상수 경로보다 우선합니다.
+        // (English Translation) If shadows are drawn, the pipeline reading the shadow map takes precedence over the push constant path.
+        match (context.active_shadow(), context.pipelines.push_constants.as_ref()) {
+            (Some((shadow_pipelines, shadow)), _) => {
+                rpass.set_pipeline(&shadow_pipelines.opaque);
+                rpass.set_bind_group(3, shadow.ref_bind_group(), &[]);
+                for object in context.visible_objects(ObjectBucket::Opaque, context.opaque_objects) {
+                    rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                    context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+                }
+            }, 
+            (None, Some(push_constant_pipelines)) => {
+                rpass.set_pipeline(&push_constant_pipelines.opaque);
+                draw_objects_with_push_constants(&mut rpass, context, &push_constant_pipelines.empty_bind_group, ObjectBucket::Opaque, context.opaque_objects);
+            }, 
+            (None, None) => {
+                rpass.set_pipeline(&context.pipelines.opaque);
+                for object in context.visible_objects(ObjectBucket::Opaque, context.opaque_objects) {
+                    rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                    context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+                }
+            }, 
+        }
+
+        if let Some(instanced_pipeline) = context.pipelines.opaque_instanced.as_ref() {
+            rpass.set_pipeline(instanced_pipeline);
+            draw_instance_batches(&mut rpass, context.opaque_batches);
+        }
+
+        // (한국어) 불러온 메쉬로 그려지는 오브젝트들을 인덱스 버퍼로 그린 후, 데칼을 위해 사각형 메쉬를 다시 바인딩합니다.
+        // (English Translation) Draws the objects with loaded meshes using their index buffers, then binds the quad mesh again for the decals.
+        if context.mesh_objects.iter().any(|mesh_object| mesh_object.ref_object().is_visible()) {
+            rpass.set_pipeline(&context.pipelines.mesh);
+            for mesh_object in context.mesh_objects.iter().filter(|mesh_object| mesh_object.ref_object().is_visible()) {
+                mesh_object.draw(&mut rpass);
+            }
+            context.quad_mesh.bind(&mut rpass);
+        }
+
+        // (한국어) 스키닝 파이프라인이 있는 경우 스키닝 오브젝트들을 뼈 행렬로 변형하여 그린 후, 사각형 메쉬를 다시 바인딩합니다.
+        // (English Translation) If the skinning pipeline exists, draws the skinned objects deformed by their bone matrices, then binds the quad mesh again.
+        if let Some(skinned_pipeline) = context.pipelines.skinned.as_ref() {
+            if context.skinned_objects.iter().any(|skinned_object| skinned_object.ref_object().is_visible()) {
+                rpass.set_pipeline(skinned_pipeline);
+                for skinned_object in context.skinned_objects.iter().filter(|skinned_object| skinned_object.ref_object().is_visible()) {
+                    skinned_object.draw(&mut rpass);
+                }
+                context.quad_mesh.bind(&mut rpass);
+            }
+        }
+
+        // (한국어) 텍스처 파이프라인이 있는 경우 알베도 텍스처를 입힌 오브젝트들을 사각형 메쉬로 그립니다.
+        // (English Translation) If the textured pipeline exists, draws the objects with albedo textures using the quad mesh.
+        if let Some(textured_pipeline) = context.pipelines.textured.as_ref().filter(|_| !context.textured_objects.is_empty()) {
+            rpass.set_pipeline(textured_pipeline);
+            for object in context.textured_objects.iter() {
+                rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+            }
+        }
+
+        // (한국어) 바닥 평면을 포함한 불투명한 오브젝트들을 그린 후에 바닥 격자를 그립니다. 정점 버퍼 없이 전체 화면 삼각형 하나를 그립니다.
+        // (English Translation) Draws the ground grid after the opaque objects, including the ground plane. Draws one full-screen triangle without vertex buffers.
+        if let (Some(grid_pipeline), Some(grid)) = (context.pipelines.grid.as_ref(), context.grid) {
+            if grid.enabled() {
+                rpass.set_pipeline(grid_pipeline);
+                rpass.set_bind_group(1, grid.ref_bind_group(), &[]);
+                rpass.draw(0..3, 0..1);
+            }
+        }
+
+        // (한국어) 불투명한 표면 위에 데칼 오브젝트들을 그립니다.
+        // (English Translation) Draws decal objects over opaque surfaces.
+        rpass.set_pipeline(&context.pipelines.decal);
+        for object in context.visible_objects(ObjectBucket::Decal, context.decal_objects) {
+            rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+            context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+        }
+
+        // (한국어)
+        // 숨은 선 모드인 경우 채워진 면 위에 모서리를 그립니다.
+        // 깊이 버퍼에 의해 가려진 모서리는 그려지지 않습니다.
+        // 
+        // (English Translation)
+        // In hidden-line mode, draws the edges over the filled faces.
+        // Edges occluded by the depth buffer are not drawn.
+        // 
+        if let Some(wireframe_pipeline) = context.pipelines.wireframe.as_ref().filter(|_| context.hidden_line) {
+            rpass.set_pipeline(wireframe_pipeline);
+            for object in context.visible_objects(ObjectBucket::Opaque, context.opaque_objects) {
+                rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+            }
+        }
+    }
+
+    // (한국어) 정렬된 순서가 주어진 경우 투명 패스와 합성 패스 대신 정렬된 알파 블렌딩 패스를 기록합니다.
+    // (English Translation) If a sorted order is given, records the sorted alpha blending pass instead of the transparent and composite passes.
+    if let Some((order, sorted_pipelines)) = context.sorted_transparent.zip(context.pipelines.sorted_transparent.as_ref()) {
+        record_sorted_transparent_pass(encoder, context, camera_bind_group, targets, sorted_pipelines, order, render_target_view);
+        return;
+    }
+
+    // (한국어) 깊이 벗기기를 사용하는 경우 투명 패스와 합성 패스 대신 레이어별 패스를 기록합니다.
+    // (English Translation) If depth peeling is used, records the per-layer passes instead of the transparent and composite passes.
+    if let Some((peel_targets, peel_pipelines)) = targets.depth_peel.as_ref().zip(context.pipelines.depth_peel.as_ref()) {
+        record_depth_peel_passes(encoder, context, camera_bind_group, peel_targets, peel_pipelines, render_target_view);
+        return;
+    }
+
+    if let Some((downsample, pipelines)) = downsample {
+        // (한국어) 장면의 깊이 버퍼를 투명 패스의 낮은 해상도 깊이 버퍼로 축소합니다.
+        // (English Translation) Downsamples the scene depth buffer into the low resolution depth buffer of the transparent pass.
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("RenderPass(DepthDownsample)"), 
+                color_attachments: &[], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &downsample.depth_stencil_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(context.pipelines.settings.depth_clear_value()), 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: None, 
+                occlusion_query_set: None, 
+            }, 
+        );
+
+        rpass.set_pipeline(&pipelines.depth);
+        rpass.set_bind_group(0, &targets.oit_bind_group, &[]);
+        rpass.set_bind_group(1, &downsample.scene_depth_bind_group, &[]);
+        rpass.draw(0..4, 0..1);
+    }
+
+    {
+        // <2>
+        // (한국어)
+        // 투명한 색상의 오브젝트들을 그립니다.
+        // 
+        // 누적 값을 저장하는 버퍼는 0으로, 노출 값을 저장하는 버퍼는 1로 초기화 합니다.
+        // 
+        // 깊이 버퍼를 읽어서 투명한 오브젝트가 가려지는지 확인하고, 가려지는 투명한 오브젝트는 그리지 않습니다.
+        // 
+        // (English Translation)
+        // Draws transparent colored objects.
+        // 
+        // The buffer that stores the accumulate value is initialized to 0, 
+        // and the buffer that stores the revealage value is initialized to 1.
+        // 
+        // Reads the depth buffer to determine whether transparent objects are occluded, 
+        // and does not draw transparent objects that are occluded.
+        // 
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some(&context.pass_labels.transparent), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: &targets.accum_texture_view, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color {
+                                r: 0.0, 
+                                g: 0.0, 
+                                b: 0.0, 
+                                a: 0.0, 
+                            }), 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                        resolve_target: None, 
+                    }), 
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: &targets.reveal_texture_view, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color {
+                                r: 1.0, 
+                                g: 1.0, 
+                                b: 1.0, 
+                                a: 1.0, 
+                            }), 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                        resolve_target: None, 
+                    }), 
+                ], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    view: downsample.map_or(&targets.depth_stencil_view, |(downsample, _)| &downsample.depth_stencil_view), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Transparent)), 
+                occlusion_query_set: None, 
+            }
+        );
+
+        rpass.set_bind_group(0, camera_bind_group, &[]);
+        rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+        rpass.set_bind_group(3, context.settings.wboit().ref_bind_group(), &[]);
+        context.quad_mesh.bind(&mut rpass);
+        match context.pipelines.push_constants.as_ref() {
+            Some(push_constant_pipelines) => {
+                rpass.set_pipeline(&push_constant_pipelines.transparent);
+                draw_objects_with_push_constants(&mut rpass, context, &push_constant_pipelines.empty_bind_group, ObjectBucket::Transparent, context.transparent_objects);
+            }, 
+            None => {
+                rpass.set_pipeline(&context.pipelines.transparent);
+                for object in context.visible_objects(ObjectBucket::Transparent, context.transparent_objects) {
+                    rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                    context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+                }
+            }, 
+        }
+
+        if let Some(instanced_pipeline) = context.pipelines.transparent_instanced.as_ref() {
+            rpass.set_pipeline(instanced_pipeline);
+            draw_instance_batches(&mut rpass, context.transparent_batches);
+        }
+    }
+
+    if let Some((downsample, pipelines)) = downsample {
+        // <3>
+        // (한국어) 
+        // 낮은 해상도의 누적 값과 노출 값을 업샘플링하여 불투명한 색상의 오브젝트와 합성합니다.
+        // 쉐이더에서 장면의 깊이 텍스처를 읽으므로 깊이 버퍼를 첨부하지 않습니다.
+        // 
+        // (English Translation) 
+        // Upsamples the low resolution accumulated and revealage values and combines them with opaque colored objects.
+        // No depth buffer is attached since the shader reads the scene depth texture.
+        // 
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some(&context.pass_labels.composite), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: render_target_view, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                        resolve_target: None, 
+                    }), 
+                ], 
+                depth_stencil_attachment: None, 
+                timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Composite)), 
+                occlusion_query_set: None, 
+            }
+        );
+
+        rpass.set_pipeline(&pipelines.composite);
+        rpass.set_bind_group(0, &targets.oit_bind_group, &[]);
+        rpass.set_bind_group(1, &downsample.scene_depth_bind_group, &[]);
+        rpass.set_bind_group(2, &downsample.oit_depth_bind_group, &[]);
+        rpass.set_bind_group(3, context.settings.wboit().ref_bind_group(), &[]);
+        rpass.draw(0..4, 0..1);
+    } else {
+        // <3>
+        // (한국어) 불투명한 색상의 오브젝트와 투명한 색상의 오브젝트를 합성합니다.
+        // (English Translation) Combines opaque colored objects with transparent colored objects.
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some(&context.pass_labels.composite), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: render_target_view, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                        resolve_target: None, 
+                    }), 
+                ], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &targets.depth_stencil_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Composite)), 
+                occlusion_query_set: None, 
+            }
+        );
+
+        rpass.set_pipeline(&context.pipelines.composite);
+        rpass.set_bind_group(0, &targets.oit_bind_group, &[]);
+        rpass.set_bind_group(1, context.settings.wboit().ref_bind_group(), &[]);
+        rpass.set_vertex_buffer(0, context.quad_mesh.ref_vertex_buffer().slice(..));
+        rpass.draw(0..4, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 깊이 벗기기의 패스들을 커맨드 버퍼에 기록합니다. 불투명 패스가 먼저 기록되어 있어야 합니다. </br>
+/// 
+/// 1. 두 번째 레이어 깊이 버퍼를 가장 가까운 깊이로 지워, 첫 번째 레이어가 모든 투명 표면을 대상으로 하도록 합니다. </br>
+/// 2. 레이어마다 벗기기 패스와 블렌딩 패스를 기록합니다. </br>
+/// 3. 누적 텍스처를 렌더 타겟에 합성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the passes of depth peeling into the command buffer. The opaque pass must already be recorded. </br>
+/// 
+/// 1. Clears the second layer depth buffer to the nearest depth, so that the first layer considers every transparent surface. </br>
+/// 2. Records a peel pass and a blend pass per layer. </br>
+/// 3. Composites the accumulation texture onto the render target. </br>
+/// 
+fn record_depth_peel_passes(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    camera_bind_group: &wgpu::BindGroup, 
+    peel_targets: &DepthPeelTargets, 
+    peel_pipelines: &DepthPeelPipelines, 
+    render_target_view: &wgpu::TextureView
+) {
+    // (한국어) 가장 먼 깊이를 지우는 값으로 사용하므로, 가장 가까운 깊이는 그 반대 값입니다.
+    // (English Translation) The farthest depth is used as the clear value, so the nearest depth is the opposite value.
+    let far_depth = context.pipelines.settings.depth_clear_value();
+    let near_depth = 1.0 - far_depth;
+    encoder.begin_render_pass(
+        &wgpu::RenderPassDescriptor {
+            label: Some("RenderPass(DepthPeelClear)"), 
+            color_attachments: &[], 
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &peel_targets.depth_views[1], 
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(near_depth), 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+                stencil_ops: None, 
+            }), 
+            timestamp_writes: None, 
+            occlusion_query_set: None, 
+        }, 
+    );
+
+    let last_layer = peel_pipelines.layers - 1;
+    for layer in 0..peel_pipelines.layers {
+        let current = (layer % 2) as usize;
+        let previous = ((layer + 1) % 2) as usize;
+
+        // (한국어) 모든 레이어의 패스를 하나의 투명 패스 구간으로 측정합니다.
+        // (English Translation) The passes of all layers are measured as one transparent pass span.
+        let timestamp_writes = context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Transparent));
+        let peel_timestamp_writes = timestamp_writes.clone()
+            .filter(|_| layer == 0)
+            .map(|writes| wgpu::RenderPassTimestampWrites { end_of_pass_write_index: None, ..writes });
+        let blend_timestamp_writes = timestamp_writes
+            .filter(|_| layer == last_layer)
+            .map(|writes| wgpu::RenderPassTimestampWrites { beginning_of_pass_write_index: None, ..writes });
+
+        {
+            // (한국어) 이전 레이어 뒤에 있는 가장 가까운 투명 표면을 벗겨냅니다.
+            // (English Translation) Peels the nearest transparent surface behind the previous layer.
+            let mut rpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(DepthPeel)"), 
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: &peel_targets.layer_texture_view, 
+                            resolve_target: None, 
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                                store: wgpu::StoreOp::Store, 
+                            }, 
+                        }), 
+                    ], 
+                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                        view: &peel_targets.depth_views[current], 
+                        depth_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(far_depth), 
+                            store: wgpu::StoreOp::Store, 
+                        }), 
+                        stencil_ops: None, 
+                    }), 
+                    timestamp_writes: peel_timestamp_writes, 
+                    occlusion_query_set: None, 
+                }, 
+            );
+
+            rpass.set_pipeline(&peel_pipelines.peel);
+            rpass.set_bind_group(0, camera_bind_group, &[]);
+            rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+            rpass.set_bind_group(3, &peel_targets.depth_bind_groups[previous], &[]);
+            context.quad_mesh.bind(&mut rpass);
+            for object in context.visible_objects(ObjectBucket::Transparent, context.transparent_objects) {
+                rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+                context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+            }
+
+            if let Some(instanced_pipeline) = peel_pipelines.peel_instanced.as_ref() {
+                rpass.set_pipeline(instanced_pipeline);
+                draw_instance_batches(&mut rpass, context.transparent_batches);
+            }
+        }
+
+        {
+            // (한국어) 벗겨낸 레이어를 누적 텍스처의 뒤에 합칩니다. 첫 번째 레이어에서 누적 텍스처를 지웁니다.
+            // (English Translation) Merges the peeled layer behind the accumulation texture. The accumulation texture is cleared on the first layer.
+            let mut rpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(DepthPeelBlend)"), 
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: &peel_targets.accum_texture_view, 
+                            resolve_target: None, 
+                            ops: wgpu::Operations {
+                                load: match layer {
+                                    0 => wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), 
+                                    _ => wgpu::LoadOp::Load, 
+                                }, 
+                                store: wgpu::StoreOp::Store, 
+                            }, 
+                        }), 
+                    ], 
+                    depth_stencil_attachment: None, 
+                    timestamp_writes: blend_timestamp_writes, 
+                    occlusion_query_set: None, 
+                }, 
+            );
+
+            rpass.set_pipeline(&peel_pipelines.blend);
+            rpass.set_bind_group(0, &peel_targets.layer_bind_group, &[]);
+            rpass.draw(0..4, 0..1);
+        }
+    }
+
+    // (한국어) 누적 텍스처를 불투명한 색상의 오브젝트 위에 합성합니다.
+    // (English Translation) Composites the accumulation texture over the opaque colored objects.
+    let mut rpass = encoder.begin_render_pass(
+        &wgpu::RenderPassDescriptor {
+            label: Some(&context.pass_labels.composite), 
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view: render_target_view, 
+                    resolve_target: None, 
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                }), 
+            ], 
+            depth_stencil_attachment: None, 
+            timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Composite)), 
+            occlusion_query_set: None, 
+        }
+    );
+
+    rpass.set_pipeline(&peel_pipelines.composite);
+    rpass.set_bind_group(0, &peel_targets.accum_bind_group, &[]);
+    rpass.draw(0..4, 0..1);
+}
+
+/// #### 한국어 </br>
+/// 투명한 오브젝트들을 주어진 순서(뒤에서 앞으로)대로 렌더 타겟에 직접 섞어 그립니다. 불투명 패스가 먼저 기록되어 있어야 합니다. </br>
+/// 누적 값과 노출 값을 사용하지 않으므로 합성 패스가 없으며, 각 오브젝트의 블렌딩 방법(`BlendMode`)에 맞는 파이프라인을 사용합니다. </br>
+/// 
+/// 인스턴스 묶음은 인스턴스 사이의 순서를 정할 수 없으므로 이 경로에서 그려지지 않습니다. </br>
+/// 타임스탬프는 투명 패스에만 기록되므로, 이 경로에서 합성 패스의 GPU 시간은 의미가 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Blends the transparent objects directly into the render target in the given (back to front) order. The opaque pass must already be recorded. </br>
+/// The accumulated and revealage values are not used, so there is no composite pass, and each object uses the pipeline of its blend mode (`BlendMode`). </br>
+/// 
+/// Instance batches are not drawn in this path because the order between instances cannot be determined. </br>
+/// Timestamps are written only for the transparent pass, so the GPU time of the composite pass is meaningless in this path. </br>
+/// 
+fn record_sorted_transparent_pass(
+    encoder: &mut wgpu::CommandEncoder, 
+    context: &DrawContext, 
+    camera_bind_group: &wgpu::BindGroup, 
+    targets: &FrameTargets, 
+    sorted_pipelines: &SortedTransparentPipelines, 
+    order: &[usize], 
+    render_target_view: &wgpu::TextureView
+) {
+    let mut rpass = encoder.begin_render_pass(
+        &wgpu::RenderPassDescriptor {
+            label: Some(&context.pass_labels.transparent), 
+            color_attachments: &[
+                Some(wgpu::RenderPassColorAttachment {
+                    view: render_target_view, 
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }, 
+                    resolve_target: None, 
+                }), 
+            ], 
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &targets.depth_stencil_view, 
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load, 
+                    store: wgpu::StoreOp::Store, 
+                }), 
+                stencil_ops: None, 
+            }), 
+            timestamp_writes: context.timestamps.map(|timestamps| timestamps.pass_writes(TimedPass::Transparent)), 
+            occlusion_query_set: None, 
+        }
+    );
+
+    rpass.set_bind_group(0, camera_bind_group, &[]);
+    rpass.set_bind_group(2, context.settings.ref_bind_group(), &[]);
+    context.quad_mesh.bind(&mut rpass);
+    let objects = order.iter()
+        .filter(|&&index| context.is_visible(ObjectBucket::Transparent, index))
+        .filter_map(|&index| context.transparent_objects.get(index))
+        .filter(|object| object.is_visible());
+    for object in objects {
+        rpass.set_pipeline(sorted_pipelines.get(object.blend_mode()));
+        rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+        context.quad_mesh.draw_instanced(&mut rpass, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 투명한 오브젝트들의 인덱스를 카메라 공간 깊이에 따라 뒤에서 앞으로 정렬합니다. </br>
+/// 각 오브젝트의 위치(월드 변환 행렬의 이동 성분)를 `get_camera_transform`으로 변환하여 깊이를 계산하며, </br>
+/// 안정 정렬을 사용하므로 깊이가 같은 오브젝트들은 원래 순서를 유지하여 프레임 사이에 깜빡이지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sorts the indices of the transparent objects back to front by camera space depth. </br>
+/// The depth is computed by transforming each object's position (the translation of its world transform) with `get_camera_transform`, </br>
+/// and a stable sort is used so that objects at the same depth keep their original order and do not flicker between frames. </br>
+/// 
+pub fn sort_back_to_front(camera: &dyn GameCamera, objects: &[ColoredObject], order: &mut Vec<usize>) {
+    // (한국어) 카메라가 바라보는 방향이 양수가 되도록 좌표계에 맞는 뷰 공간 깊이를 사용합니다.
+    // (English Translation) Uses the view space depth of the coordinate system so that the direction the camera looks at is positive.
+    let camera_transform = camera.get_camera_transform();
+    let handedness = camera.handedness();
+    let depth = |index: usize| handedness.view_depth(camera_transform.transform_point3(objects[index].ref_world_transform().w_axis.truncate()));
+
+    order.clear();
+    order.extend(0..objects.len());
+    order.sort_by(|&a, &b| depth(b).total_cmp(&depth(a)));
+}
+
+/// #### 한국어 </br>
+/// 그리기 목록에서 컬링되지 않은 오브젝트들을 푸시 상수 파이프라인으로 그립니다. (참고: `PushConstantPipelines`) </br>
+/// 바인드 그룹 슬롯 1에는 빈 바인드 그룹을 바인딩하고, 오브젝트마다 유니폼 데이터를 푸시 상수로 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Draws the objects in the draw list that survived culling with a push constant pipeline. (see also: `PushConstantPipelines`) </br>
+/// Binds the empty bind group to bind group slot 1 and writes the uniform data of each object as push constants. </br>
+/// 
+fn draw_objects_with_push_constants<'a>(
+    rpass: &mut wgpu::RenderPass<'a>, 
+    context: &DrawContext<'a>, 
+    empty_bind_group: &'a wgpu::BindGroup, 
+    bucket: ObjectBucket, 
+    objects: &'a [ColoredObject]
+) {
+    rpass.set_bind_group(1, empty_bind_group, &[]);
+    for object in context.visible_objects(bucket, objects) {
+        rpass.set_push_constants(PushConstantPipelines::RANGE.stages, 0, bytemuck::bytes_of(&object.uniform_data()));
+        context.quad_mesh.draw_instanced(rpass, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 인스턴스 묶음들을 그립니다. 인스턴스 정점 버퍼는 정점 버퍼 슬롯 1에 바인딩 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Draws instance batches. The instance vertex buffer is bound to vertex buffer slot 1. </br>
+/// 
+fn draw_instance_batches<'a>(rpass: &mut wgpu::RenderPass<'a>, batches: &'a [InstanceBatch]) {
+    for batch in batches.iter() {
+        batch.draw_instanced(rpass, batch.instance_count());
+    }
+}
+
+/// #### 한국어 </br>
+/// 큐브맵의 여섯 면의 (앞 방향, 오른쪽 방향, 위 방향) 입니다. </br>
+/// 배열 레이어 순서는 `+X, -X, +Y, -Y, +Z, -Z` 입니다. </br>
+/// 
+/// 큐브맵의 면은 왼손 좌표계로 정의되어 있으므로 오른쪽 방향은 `앞 x 위`의 반대 방향입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The (forward, right, up) directions of the six faces of a cubemap. </br>
+/// The array layer order is `+X, -X, +Y, -Y, +Z, -Z`. </br>
+/// 
+/// Since cubemap faces are defined in a left-handed coordinate system, the right direction is opposite to `forward x up`. </br>
+/// 
+pub const CUBE_FACES: [(glam::Vec3, glam::Vec3, glam::Vec3); 6] = [
+    (glam::Vec3::X, glam::Vec3::NEG_Z, glam::Vec3::Y), 
+    (glam::Vec3::NEG_X, glam::Vec3::Z, glam::Vec3::Y), 
+    (glam::Vec3::Y, glam::Vec3::X, glam::Vec3::NEG_Z), 
+    (glam::Vec3::NEG_Y, glam::Vec3::X, glam::Vec3::Z), 
+    (glam::Vec3::Z, glam::Vec3::X, glam::Vec3::Y), 
+    (glam::Vec3::NEG_Z, glam::Vec3::NEG_X, glam::Vec3::Y), 
+];
+
+/// #### 한국어 </br>
+/// 장면을 캡처한 큐브맵 텍스처 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A cubemap texture capturing the scene. </br>
+/// 
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct CubemapCapture {
+    pub texture: wgpu::Texture, 
+    pub cube_view: wgpu::TextureView, 
+    pub face_views: [wgpu::TextureView; 6], 
+}
+
+/// #### 한국어 </br>
+/// 주어진 위치에서 축에 정렬된 여섯 방향으로 장면을 그려 큐브맵 텍스처를 생성합니다. </br>
+/// 각 면은 90° 시야각을 가진 카메라로 `D2Array` 텍스처의 각 레이어에 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Draws the scene in six axis-aligned directions from the given position to create a cubemap texture. </br>
+/// Each face is drawn into each layer of a `D2Array` texture with a camera that has a 90° field of view. </br>
+/// 
+/// #### 한국어 </br>
+/// 면의 크기가 장치의 제한을 넘는 경우 `ResourceError::TextureTooLarge`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `ResourceError::TextureTooLarge` if the face size exceeds the device limits. </br>
+/// 
+pub fn render_cubemap(
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    context: &DrawContext, 
+    camera_bind_group_layout: &wgpu::BindGroupLayout, 
+    oit_bind_group_layout: &wgpu::BindGroupLayout, 
+    position: glam::Vec3, 
+    face_size: u32
+) -> Result<CubemapCapture, ResourceError> {
+    resources::validate_texture_size(&device.limits(), face_size, face_size)?;
+
+    let texture = device.create_texture(
+        &wgpu::TextureDescriptor {
+            label: Some("Cubemap"), 
+            size: wgpu::Extent3d {
+                width: face_size, 
+                height: face_size, 
+                depth_or_array_layers: 6, 
+            }, 
+            format: context.pipelines.settings.color_format, 
+            dimension: wgpu::TextureDimension::D2, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::COPY_SRC, 
+            view_formats: &[], 
+        }, 
+    );
+
+    let face_views: [wgpu::TextureView; 6] = std::array::from_fn(|face| {
+        texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("CubeFace"), 
+            dimension: Some(wgpu::TextureViewDimension::D2), 
+            base_array_layer: face as u32, 
+            array_layer_count: Some(1), 
+            ..Default::default()
+        })
+    });
+
+    let cube_view = texture.create_view(&wgpu::TextureViewDescriptor {
+        label: Some("Cubemap"), 
+        dimension: Some(wgpu::TextureViewDimension::Cube), 
+        array_layer_count: Some(6), 
+        ..Default::default()
+    });
+
+    // (한국어) 모든 면이 같은 크기를 가지므로 중간 렌더 타겟을 재사용합니다.
+    // (English Translation) Since all faces have the same size, the intermediate render targets are reused.
+    let mut targets = FrameTargets::new(
+        device, 
+        oit_bind_group_layout, 
+        context.pipelines.settings.reveal_format, 
+        context.pipelines.settings.depth_format, 
+        face_size, 
+        face_size, 
+        1
+    );
+    targets.hdr = context.pipelines.hdr.as_ref().map(|hdr| HdrTargets::new(device, hdr, face_size, face_size));
+
+    // (한국어) 각 면은 서로 다른 방향을 바라보므로 주 카메라의 컬링 결과를 사용하지 않습니다.
+    // (English Translation) Each face looks in a different direction, so the main camera's culling result is not used.
+    // (한국어) 정렬된 순서도 주 카메라 기준이므로 사용하지 않습니다.
+    // (English Translation) The sorted order is also relative to the main camera, so it is not used either.
+    let context = &DrawContext { culling: None, timestamps: None, sorted_transparent: None, ..*context };
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("CommandEncoder(Cubemap)"), 
+    });
+    let mut cameras = Vec::with_capacity(CUBE_FACES.len());
+    for (face, &(forward, right, up)) in CUBE_FACES.iter().enumerate() {
+        // (한국어)
+        // 카메라의 z축은 바라보는 방향의 반대 방향입니다.
+        // 큐브맵 면의 좌표계에 맞추기 위해 변환 행렬의 축을 직접 설정합니다.
+        // 
+        // (English Translation)
+        // The camera's z-axis is opposite to the viewing direction.
+        // The axes of the transformation matrix are set directly to match the coordinate system of the cubemap face.
+        // 
+        let mut camera = PerspectiveCameraBuilder::new(90.0f32.to_radians(), 1.0, 0.001, 1000.0)
+            .set_name(&format!("CubeFace{}", face))
+            .set_reverse_z(context.pipelines.settings.reverse_z)
+            .build(device, camera_bind_group_layout);
+        *camera.mut_world_transform() = glam::Mat4::from_cols(
+            (right, 0.0).into(), 
+            (up, 0.0).into(), 
+            (-forward, 0.0).into(), 
+            (position, 1.0).into()
+        );
+        camera.update_shader_resource(queue);
+
+        record_scene_passes(&mut encoder, context, camera.ref_bind_group(), &targets, &face_views[face]);
+        cameras.push(camera);
+    }
+    queue.submit(Some(encoder.finish()));
+
+    Ok(CubemapCapture {
+        texture, 
+        cube_view, 
+        face_views, 
+    })
+}
+
+/// #### 한국어 </br>
+/// 스테레오 렌더링에서 두 눈의 장면을 그리는 색상 렌더 타겟 입니다. (두 개의 레이어를 가진 텍스처 배열) </br>
+/// 그린 후에는 [`StereoTarget::copy_to_surface`]로 두 눈을 화면의 왼쪽과 오른쪽 절반에 나란히 복사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The color render target that draws the scene for both eyes in stereo rendering. (a texture array with two layers) </br>
+/// After drawing, [`StereoTarget::copy_to_surface`] copies both eyes side by side into the left and right halves of the screen. </br>
+/// 
+#[derive(Debug)]
+pub struct StereoTarget {
+    pub texture: wgpu::Texture, 
+    pub view: wgpu::TextureView, 
+}
+
+impl StereoTarget {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, eye_width: u32, height: u32) -> Self {
+        let texture = device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Stereo"), 
+                size: wgpu::Extent3d {
+                    width: eye_width, 
+                    height, 
+                    depth_or_array_layers: 2, 
+                }, 
+                format, 
+                dimension: wgpu::TextureDimension::D2, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC, 
+                view_formats: &[], 
+            }, 
+        );
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(wgpu::TextureViewDimension::D2Array), 
+            ..Default::default()
+        });
+
+        Self { texture, view }
+    }
+
+    /// #### 한국어 </br>
+    /// 왼쪽 눈(레이어 0)과 오른쪽 눈(레이어 1)을 화면 텍스처의 왼쪽과 오른쪽 절반에 복사합니다. </br>
+    /// 화면 텍스처는 `COPY_DST` 용도로 설정되어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Copies the left eye (layer 0) and right eye (layer 1) into the left and right halves of the surface texture. </br>
+    /// The surface texture must be configured with the `COPY_DST` usage. </br>
+    /// 
+    pub fn copy_to_surface(&self, encoder: &mut wgpu::CommandEncoder, surface_texture: &wgpu::Texture) {
+        let size = self.texture.size();
+        for layer in 0..size.depth_or_array_layers {
+            encoder.copy_texture_to_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &self.texture, 
+                    mip_level: 0, 
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: layer }, 
+                    aspect: wgpu::TextureAspect::All, 
+                }, 
+                wgpu::ImageCopyTexture {
+                    texture: surface_texture, 
+                    mip_level: 0, 
+                    origin: wgpu::Origin3d { x: size.width * layer, y: 0, z: 0 }, 
+                    aspect: wgpu::TextureAspect::All, 
+                }, 
+                wgpu::Extent3d {
+                    width: size.width, 
+                    height: size.height, 
+                    depth_or_array_layers: 1, 
+                }
+            );
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장치 기능 협상이 끝난 후 실제로 선택된 렌더링 구성입니다. </br>
+/// 요청한 값과 다를 수 있으므로 로그로 출력하거나 버그 보고에 첨부하는 데 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The rendering configuration actually selected after capability negotiation. </br>
+/// It may differ from what was requested, so it is used for logging or attaching to bug reports. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    pub adapter_info: wgpu::AdapterInfo, 
+    pub features: wgpu::Features, 
+    pub surface_format: wgpu::TextureFormat, 
+    pub present_mode: wgpu::PresentMode, 
+    pub depth_format: wgpu::TextureFormat, 
+    pub reveal_format: wgpu::TextureFormat, 
+    pub reverse_z: bool, 
+    pub unclipped_depth: bool, 
+    pub multiview: Option<u32>, 
+}
+
+/// #### 한국어 </br>
+/// 어댑터, 장치, 스왑체인 설정과 파이프라인 설정에서 실제로 선택된 렌더링 구성을 모읍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Gathers the rendering configuration actually selected from the adapter, device, swapchain configuration and pipeline settings. </br>
+/// 
+pub fn effective_config(
+    adapter: &wgpu::Adapter, 
+    device: &wgpu::Device, 
+    surface_config: &wgpu::SurfaceConfiguration, 
+    settings: &PipelineSettings
+) -> EffectiveConfig {
+    EffectiveConfig { 
+        adapter_info: adapter.get_info(), 
+        features: device.features(), 
+        surface_format: surface_config.format, 
+        present_mode: surface_config.present_mode, 
+        depth_format: settings.depth_format, 
+        reveal_format: settings.reveal_format, 
+        reverse_z: settings.reverse_z, 
+        unclipped_depth: settings.unclipped_depth, 
+        multiview: settings.multiview.map(|views| views.get()), 
+    }
+}
+
+impl fmt::Display for EffectiveConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f, 
+            "adapter: {} ({:?}, {:?}, driver: {} {})", 
+            self.adapter_info.name, 
+            self.adapter_info.device_type, 
+            self.adapter_info.backend, 
+            self.adapter_info.driver, 
+            self.adapter_info.driver_info
+        )?;
+        writeln!(f, "features: {:?}", self.features)?;
+        writeln!(f, "surface format: {:?}", self.surface_format)?;
+        writeln!(f, "present mode: {:?}", self.present_mode)?;
+        writeln!(f, "depth format: {:?} (reverse z: {}, unclipped depth: {})", self.depth_format, self.reverse_z, self.unclipped_depth)?;
+        writeln!(f, "revealage format: {:?}", self.reveal_format)?;
+        match self.multiview {
+            Some(views) => write!(f, "multiview: {} views", views), 
+            None => write!(f, "multiview: disabled"), 
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+
+
+
+/// #### 한국어 </br>
+/// 장치의 제한을 넘는 리소스를 생성하려고 할 때 발생하는 오류 입니다. </br>
+/// `wgpu`의 리소스 생성 함수 내부에서 알기 어려운 드라이버 오류가 발생하기 전에 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An error that occurs when trying to create a resource that exceeds the device limits. </br>
+/// It is checked before a cryptic driver error occurs inside the `wgpu` resource creation functions. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceError {
+    TextureTooLarge { width: u32, height: u32, max_dimension: u32 }, 
+    BufferTooLarge { size: u64, max_size: u64 }, 
+    TooManyVertices { vertex_count: u64, max_count: u64 }, 
+    TextureDataSizeMismatch { expected: u64, actual: u64 }, 
+}
+
+impl fmt::Display for ResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TextureTooLarge { width, height, max_dimension } => write!(
+                f, 
+                "texture size {}x{} exceeds the maximum 2D texture dimension {}", 
+                width, 
+                height, 
+                max_dimension
+            ), 
+            Self::BufferTooLarge { size, max_size } => write!(
+                f, 
+                "buffer size {} bytes exceeds the maximum buffer size {} bytes", 
+                size, 
+                max_size
+            ), 
+            Self::TooManyVertices { vertex_count, max_count } => write!(
+                f, 
+                "vertex count {} exceeds the maximum {} addressable by the index format", 
+                vertex_count, 
+                max_count
+            ), 
+            Self::TextureDataSizeMismatch { expected, actual } => write!(
+                f, 
+                "texture data is {} bytes but the texture size requires {} bytes", 
+                actual, 
+                expected
+            ), 
+        }
+    }
+}
+
+impl std::error::Error for ResourceError { }
+
+
+/// #### 한국어 </br>
+/// 2D 텍스처의 크기가 장치의 `max_texture_dimension_2d` 제한 안에 있는지 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the size of a 2D texture is within the device's `max_texture_dimension_2d` limit. </br>
+/// 
+pub fn validate_texture_size(limits: &wgpu::Limits, width: u32, height: u32) -> Result<(), ResourceError> {
+    let max_dimension = limits.max_texture_dimension_2d;
+    if width > max_dimension || height > max_dimension {
+        return Err(ResourceError::TextureTooLarge { width, height, max_dimension });
+    }
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 버퍼의 크기가 장치의 `max_buffer_size` 제한 안에 있는지 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the size of a buffer is within the device's `max_buffer_size` limit. </br>
+/// 
+pub fn validate_buffer_size(limits: &wgpu::Limits, size: u64) -> Result<(), ResourceError> {
+    let max_size = limits.max_buffer_size;
+    if size > max_size {
+        return Err(ResourceError::BufferTooLarge { size, max_size });
+    }
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 메쉬의 정점 버퍼와 인덱스 버퍼가 장치의 제한 안에 있는지, </br>
+/// 그리고 모든 정점을 인덱스 형식으로 가리킬 수 있는지 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the vertex and index buffers of a mesh are within the device limits, </br>
+/// and whether every vertex can be addressed by the index format. </br>
+/// 
+#[allow(dead_code)]
+pub fn validate_mesh_size(
+    limits: &wgpu::Limits, 
+    vertex_count: u64, 
+    vertex_stride: u64, 
+    index_count: u64, 
+    index_format: wgpu::IndexFormat
+) -> Result<(), ResourceError> {
+    let (index_size, max_count) = match index_format {
+        wgpu::IndexFormat::Uint16 => (2, u16::MAX as u64 + 1), 
+        wgpu::IndexFormat::Uint32 => (4, u32::MAX as u64 + 1), 
+    };
+
+    if vertex_count > max_count {
+        return Err(ResourceError::TooManyVertices { vertex_count, max_count });
+    }
+
+    validate_buffer_size(limits, vertex_count.saturating_mul(vertex_stride))?;
+    validate_buffer_size(limits, index_count.saturating_mul(index_size))
+}
+
+/// #### 한국어 </br>
+/// 텍스처를 불러오지 못한 경우 대신 사용하는 "텍스처 없음" 텍스처의 크기 입니다. (텍셀 단위) </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the "missing texture" used instead when a texture fails to load. (in texels) </br>
+/// 
+pub const MISSING_TEXTURE_SIZE: u32 = 16;
+
+/// #### 한국어 </br>
+/// "텍스처 없음" 텍스처의 한 칸의 크기 입니다. (텍셀 단위) </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of one cell of the "missing texture". (in texels) </br>
+/// 
+const MISSING_TEXTURE_CELL: u32 = 4;
+
+/// #### 한국어 </br>
+/// 자홍색과 검은색 바둑판 무늬의 "텍스처 없음" 텍스처 데이터를 생성합니다. (`Rgba8UnormSrgb`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Generates the data of the magenta and black checkerboard "missing texture". (`Rgba8UnormSrgb`) </br>
+/// 
+#[allow(dead_code)]
+pub fn missing_texture_data(size: u32) -> Vec<u8> {
+    const MAGENTA: [u8; 4] = [255, 0, 255, 255];
+    const BLACK: [u8; 4] = [0, 0, 0, 255];
+
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let odd = (x / MISSING_TEXTURE_CELL + y / MISSING_TEXTURE_CELL) % 2 == 1;
+            data.extend_from_slice(if odd { &BLACK } else { &MAGENTA });
+        }
+    }
+    data
+}
+
+/// #### 한국어 </br>
+/// RGBA8 데이터로 2D 텍스처를 생성합니다. (`Rgba8UnormSrgb`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a 2D texture from RGBA8 data. (`Rgba8UnormSrgb`) </br>
+/// 
+/// #### 한국어 </br>
+/// 크기가 장치의 제한을 넘거나 데이터의 길이가 크기와 맞지 않는 경우 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns an error if the size exceeds the device limits or the data length does not match the size. </br>
+/// 
+#[allow(dead_code)]
+pub fn create_texture_rgba8(
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    label: Option<&str>, 
+    width: u32, 
+    height: u32, 
+    data: &[u8]
+) -> Result<wgpu::Texture, ResourceError> {
+    validate_texture_size(&device.limits(), width, height)?;
+    let expected = width as u64 * height as u64 * 4;
+    if data.len() as u64 != expected {
+        return Err(ResourceError::TextureDataSizeMismatch { expected, actual: data.len() as u64 });
+    }
+
+    let size = wgpu::Extent3d {
+        width, 
+        height, 
+        depth_or_array_layers: 1, 
+    };
+    let texture = device.create_texture(
+        &wgpu::TextureDescriptor {
+            label, 
+            size, 
+            format: wgpu::TextureFormat::Rgba8UnormSrgb, 
+            dimension: wgpu::TextureDimension::D2, 
+            mip_level_count: 1, 
+            sample_count: 1, 
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+            view_formats: &[], 
+        }, 
+    );
+    queue.write_texture(
+        wgpu::ImageCopyTexture {
+            texture: &texture, 
+            mip_level: 0, 
+            origin: wgpu::Origin3d::ZERO, 
+            aspect: wgpu::TextureAspect::All, 
+        }, 
+        data, 
+        wgpu::ImageDataLayout {
+            offset: 0, 
+            bytes_per_row: Some(width * 4), 
+            rows_per_image: Some(height), 
+        }, 
+        size
+    );
+    Ok(texture)
+}
+
+/// #### 한국어 </br>
+/// 불러온 이미지(너비, 높이, RGBA8 데이터)로 텍스처를 생성합니다. </br>
+/// 이미지를 불러오지 못했거나 텍스처를 생성할 수 없는 경우 경고를 출력하고 "텍스처 없음" 텍스처를 반환합니다. </br>
+/// 오브젝트는 계속 그려지며, 바둑판 무늬로 잘못된 텍스처를 쉽게 알아볼 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a texture from a loaded image (width, height, RGBA8 data). </br>
+/// If the image failed to load or the texture cannot be created, logs a warning and returns the "missing texture". </br>
+/// The object keeps rendering, and the checkerboard makes the wrong texture easy to spot. </br>
+/// 
+#[allow(dead_code)]
+pub fn create_texture_or_missing<E: fmt::Display>(
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    label: &str, 
+    image: Result<(u32, u32, Vec<u8>), E>
+) -> wgpu::Texture {
+    let error = match image {
+        Ok((width, height, data)) => match create_texture_rgba8(device, queue, Some(label), width, height, &data) {
+            Ok(texture) => return texture, 
+            Err(e) => e.to_string(), 
+        }, 
+        Err(e) => e.to_string(), 
+    };
+
+    log::warn!("Failed to load texture '{}': {}. Using the missing texture instead.", label, error);
+    let data = missing_texture_data(MISSING_TEXTURE_SIZE);
+    create_texture_rgba8(device, queue, Some(label), MISSING_TEXTURE_SIZE, MISSING_TEXTURE_SIZE, &data)
+        .expect("The missing texture must always be valid.")
+}
@@ -0,0 +1,409 @@
+use crate::animation::Animator;
+use crate::camera::Frustum;
+use crate::interfaces::{
+    GameCamera, 
+    Ray, 
+};
+use crate::objects::{
+    ColoredObject, 
+    ObjectUniformPool, 
+};
+
+
+
+/// #### 한국어 </br>
+/// 장면에서 오브젝트가 속한 그리기 목록 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The draw list that an object belongs to in the scene. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectBucket {
+    Opaque, 
+    Decal, 
+    Transparent, 
+}
+
+/// #### 한국어 </br>
+/// 장면의 오브젝트를 가리키는 식별자 입니다. (그리기 목록, 목록 안의 위치) </br>
+/// 
+/// #### English (Translation) </br>
+/// An identifier that refers to an object in the scene. (draw list, position in the list) </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObjectId {
+    pub bucket: ObjectBucket, 
+    pub index: usize, 
+}
+
+/// #### 한국어 </br>
+/// 절두체 컬링의 결과 입니다. 컬링되지 않은 오브젝트의 목록과 그리기 목록별 가시성 비트를 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The result of frustum culling. Stores the list of objects that survived culling and the visibility bits per draw list. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CullingResult {
+    visible: Vec<ObjectId>, 
+    opaque: Vec<bool>, 
+    decal: Vec<bool>, 
+    transparent: Vec<bool>, 
+}
+
+#[allow(dead_code)]
+impl CullingResult {
+    /// #### 한국어 </br>
+    /// 컬링되지 않은 오브젝트들의 식별자를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the identifiers of the objects that survived culling. </br>
+    /// 
+    #[inline]
+    pub fn visible(&self) -> &[ObjectId] {
+        &self.visible
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트가 컬링되지 않았는지 확인합니다. 컬링 결과에 없는 오브젝트는 보이는 것으로 간주합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the object survived culling. Objects missing from the culling result are considered visible. </br>
+    /// 
+    #[inline]
+    pub fn is_visible(&self, id: ObjectId) -> bool {
+        let flags = match id.bucket {
+            ObjectBucket::Opaque => &self.opaque, 
+            ObjectBucket::Decal => &self.decal, 
+            ObjectBucket::Transparent => &self.transparent, 
+        };
+        flags.get(id.index).copied().unwrap_or(true)
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면을 그릴 때 사용되는 카메라와 색상 오브젝트들을 소유합니다. </br>
+/// 활성 카메라는 실행 중에 교체할 수 있으며, 교체된 카메라는 다음 프레임에 갱신됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Owns the camera and colored objects used when drawing the scene. </br>
+/// The active camera can be swapped at runtime, and the swapped camera is updated on the next frame. </br>
+/// 
+#[derive(Debug)]
+pub struct Scene {
+    pub object_pool: ObjectUniformPool, 
+    pub opaque_objects: Vec<ColoredObject>, 
+    pub decal_objects: Vec<ColoredObject>, 
+    pub transparent_objects: Vec<ColoredObject>, 
+    camera: Box<dyn GameCamera>, 
+    camera_dirty: bool, 
+    frozen_frustum: Option<Frustum>, 
+    culling: CullingResult, 
+    highlighted: Option<ObjectId>, 
+    animators: Vec<(ObjectId, Box<dyn Animator>)>, 
+}
+
+#[allow(dead_code)]
+impl Scene {
+    pub fn new(camera: Box<dyn GameCamera>, object_pool: ObjectUniformPool) -> Self {
+        Self { 
+            object_pool, 
+            opaque_objects: Vec::new(), 
+            decal_objects: Vec::new(), 
+            transparent_objects: Vec::new(), 
+            camera, 
+            camera_dirty: true, 
+            frozen_frustum: None, 
+            culling: CullingResult::default(), 
+            highlighted: None, 
+            animators: Vec::new(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 활성 카메라를 교체하고 이전 카메라를 반환합니다. </br>
+    /// 새 카메라는 다음 [`Scene::update_all`] 호출에서 갱신됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Swaps the active camera and returns the previous camera. </br>
+    /// The new camera is updated on the next [`Scene::update_all`] call. </br>
+    /// 
+    pub fn set_camera(&mut self, camera: Box<dyn GameCamera>) -> Box<dyn GameCamera> {
+        self.camera_dirty = true;
+        std::mem::replace(&mut self.camera, camera)
+    }
+
+    /// #### 한국어 </br>
+    /// 활성 카메라를 빌려옵니다. (reference ver) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Borrows the active camera. (reference ver) </br>
+    /// 
+    #[inline]
+    pub fn ref_camera(&self) -> &dyn GameCamera {
+        self.camera.as_ref()
+    }
+
+    /// #### 한국어 </br>
+    /// 활성 카메라를 빌려옵니다. (mutable ver) </br>
+    /// 카메라는 다음 [`Scene::update_all`] 호출에서 갱신됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Borrows the active camera. (mutable ver) </br>
+    /// The camera is updated on the next [`Scene::update_all`] call. </br>
+    /// 
+    #[inline]
+    pub fn mut_camera(&mut self) -> &mut dyn GameCamera {
+        self.camera_dirty = true;
+        self.camera.as_mut()
+    }
+
+    /// #### 한국어 </br>
+    /// 오브젝트를 컬링할 때 사용하는 절두체를 가져옵니다. </br>
+    /// 절두체가 고정된 경우 고정된 시점의 절두체를, 그렇지 않은 경우 활성 카메라의 절두체를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the frustum used to cull objects. </br>
+    /// Returns the frustum at the time it was frozen if frozen, otherwise the frustum of the active camera. </br>
+    /// 
+    pub fn culling_frustum(&self) -> Frustum {
+        self.frozen_frustum.unwrap_or_else(|| self.camera.frustum())
+    }
+
+    /// #### 한국어 </br>
+    /// 컬링 절두체를 고정하거나 고정을 해제합니다. </br>
+    /// 고정하는 경우 현재 활성 카메라의 뷰-투영 행렬로 절두체를 만들어 고정을 해제할 때 까지 사용합니다. </br>
+    /// 카메라를 계속 움직이면서 컬링된 오브젝트를 확인하는 디버깅 용도로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Freezes or unfreezes the culling frustum. </br>
+    /// When frozen, a frustum is made from the current view-projection matrix of the active camera and used until unfrozen. </br>
+    /// Used for debugging to check culled objects while continuing to move the camera. </br>
+    /// 
+    pub fn freeze_culling_frustum(&mut self, freeze: bool) {
+        self.frozen_frustum = None;
+        if freeze {
+            self.frozen_frustum = Some(self.culling_frustum());
+        }
+    }
+
+    #[inline]
+    pub fn is_culling_frustum_frozen(&self) -> bool {
+        self.frozen_frustum.is_some()
+    }
+
+    /// #### 한국어 </br>
+    /// 컬링 절두체로 모든 오브젝트를 컬링하고 결과를 저장합니다. </br>
+    /// 저장된 결과는 그리기에 사용되며, 게임 로직에서도 [`Scene::last_visible`]로 읽을 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Culls all objects with the culling frustum and stores the result. </br>
+    /// The stored result is used for drawing and can also be read by game logic through [`Scene::last_visible`]. </br>
+    /// 
+    pub fn cull(&mut self) -> &CullingResult {
+        let frustum = self.culling_frustum();
+        let test = |objects: &[ColoredObject]| -> Vec<bool> {
+            objects.iter()
+                .map(|object| {
+                    let (center, radius) = object.bounding_sphere();
+                    frustum.intersects_sphere(center, radius)
+                })
+                .collect()
+        };
+
+        let culling = &mut self.culling;
+        culling.opaque = test(&self.opaque_objects);
+        culling.decal = test(&self.decal_objects);
+        culling.transparent = test(&self.transparent_objects);
+
+        culling.visible.clear();
+        for (bucket, flags) in [
+            (ObjectBucket::Opaque, &culling.opaque), 
+            (ObjectBucket::Decal, &culling.decal), 
+            (ObjectBucket::Transparent, &culling.transparent), 
+        ] {
+            culling.visible.extend(flags.iter()
+                .enumerate()
+                .filter(|(_, &visible)| visible)
+                .map(|(index, _)| ObjectId { bucket, index })
+            );
+        }
+
+        return &self.culling;
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 [`Scene::cull`] 호출의 결과를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the result of the last [`Scene::cull`] call. </br>
+    /// 
+    #[inline]
+    pub fn culling_result(&self) -> &CullingResult {
+        &self.culling
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 [`Scene::cull`] 호출에서 컬링되지 않은 오브젝트들의 식별자를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the identifiers of the objects that survived the last [`Scene::cull`] call. </br>
+    /// 
+    #[inline]
+    pub fn last_visible(&self) -> &[ObjectId] {
+        self.culling.visible()
+    }
+
+    /// #### 한국어 </br>
+    /// 식별자가 가리키는 오브젝트를 빌려옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Borrows the object referred to by the identifier. </br>
+    /// 
+    pub fn get_object(&self, id: ObjectId) -> Option<&ColoredObject> {
+        match id.bucket {
+            ObjectBucket::Opaque => self.opaque_objects.get(id.index), 
+            ObjectBucket::Decal => self.decal_objects.get(id.index), 
+            ObjectBucket::Transparent => self.transparent_objects.get(id.index), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 식별자가 가리키는 오브젝트를 빌려옵니다. (mutable ver) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Borrows the object referred to by the identifier. (mutable ver) </br>
+    /// 
+    pub fn get_object_mut(&mut self, id: ObjectId) -> Option<&mut ColoredObject> {
+        match id.bucket {
+            ObjectBucket::Opaque => self.opaque_objects.get_mut(id.index), 
+            ObjectBucket::Decal => self.decal_objects.get_mut(id.index), 
+            ObjectBucket::Transparent => self.transparent_objects.get_mut(id.index), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자를 드리우는 보이는 오브젝트들을 가져옵니다. 그림자 패스는 이 오브젝트들만 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the visible objects that cast shadows. The shadow pass only draws these objects. </br>
+    /// 
+    pub fn shadow_casters(&self) -> impl Iterator<Item = &ColoredObject> {
+        self.opaque_objects.iter()
+            .chain(self.decal_objects.iter())
+            .chain(self.transparent_objects.iter())
+            .filter(|object| object.is_visible() && object.cast_shadow())
+    }
+
+    /// #### 한국어 </br>
+    /// 광선과 교차하는 가장 가까운 오브젝트의 식별자를 가져옵니다. 교차하는 오브젝트가 없는 경우 `None`을 반환합니다. </br>
+    /// 숨겨진 오브젝트는 건너뜁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the identifier of the nearest object intersecting the ray. Returns `None` if no object intersects it. </br>
+    /// Hidden objects are skipped. </br>
+    /// 
+    pub fn pick(&self, ray: &Ray) -> Option<ObjectId> {
+        let mut nearest: Option<(ObjectId, f32)> = None;
+        for (bucket, objects) in [
+            (ObjectBucket::Opaque, &self.opaque_objects), 
+            (ObjectBucket::Decal, &self.decal_objects), 
+            (ObjectBucket::Transparent, &self.transparent_objects), 
+        ] {
+            for (index, object) in objects.iter().enumerate().filter(|(_, object)| object.is_visible()) {
+                // (한국어) 데칼은 바닥면과 같은 평면에 놓이므로, 거리가 같은 경우 나중 그리기 목록의 오브젝트를 선택합니다.
+                // (English Translation) Decals lie on the same plane as the floor, so objects in a later draw list win ties.
+                if let Some(t) = object.intersect_ray(ray) {
+                    if nearest.map_or(true, |(_, nearest_t)| t <= nearest_t) {
+                        nearest = Some((ObjectId { bucket, index }, t));
+                    }
+                }
+            }
+        }
+        nearest.map(|(id, _)| id)
+    }
+
+    /// #### 한국어 </br>
+    /// 강조된 오브젝트의 식별자를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the identifier of the highlighted object. </br>
+    /// 
+    #[inline]
+    pub fn highlighted(&self) -> Option<ObjectId> {
+        self.highlighted
+    }
+
+    /// #### 한국어 </br>
+    /// 강조할 오브젝트를 설정합니다. 이전에 강조된 오브젝트는 강조가 해제되며, `None`인 경우 아무것도 강조하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the object to highlight. The previously highlighted object is unhighlighted, and nothing is highlighted if `None`. </br>
+    /// 
+    pub fn set_highlighted(&mut self, id: Option<ObjectId>) {
+        if self.highlighted == id {
+            return;
+        }
+
+        let previous = self.highlighted;
+        if let Some(object) = previous.and_then(|id| self.get_object_mut(id)) {
+            object.set_highlighted(false);
+        }
+        if let Some(object) = id.and_then(|id| self.get_object_mut(id)) {
+            object.set_highlighted(true);
+        }
+        self.highlighted = id;
+    }
+
+    /// #### 한국어 </br>
+    /// 식별자가 가리키는 오브젝트에 애니메이터를 추가합니다. 여러 애니메이터를 추가하면 추가한 순서대로 적용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds an animator to the object referred to by the identifier. Several animators are applied in the order they were added. </br>
+    /// 
+    pub fn add_animator(&mut self, id: ObjectId, animator: Box<dyn Animator>) {
+        self.animators.push((id, animator));
+    }
+
+    /// #### 한국어 </br>
+    /// 경과한 게임 시간만큼 모든 애니메이터를 갱신합니다. 장면의 쉐이더 리소스를 갱신하기 전에 매 프레임 호출합니다. </br>
+    /// 식별자가 가리키는 오브젝트가 없는 애니메이터는 건너뜁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates all animators by the elapsed game time. Called every frame before updating the shader resources of the scene. </br>
+    /// Animators whose identifier refers to no object are skipped. </br>
+    /// 
+    pub fn animate(&mut self, elapsed_time_sec: f32) {
+        let mut animators = std::mem::take(&mut self.animators);
+        for (id, animator) in animators.iter_mut() {
+            if let Some(object) = self.get_object_mut(*id) {
+                animator.update(object, elapsed_time_sec);
+            }
+        }
+        self.animators = animators;
+    }
+
+    /// #### 한국어 </br>
+    /// 장면의 쉐이더 리소스를 갱신합니다. 카메라는 변경된 경우에만 갱신합니다. </br>
+    /// 오브젝트 풀을 사용하는 오브젝트들의 유니폼 데이터는 하나의 배열로 모아 한 번의 쓰기로 갱신합니다. </br>
+    /// 실행한 버퍼 쓰기 횟수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the shader resources of the scene. The camera is only updated if it has changed. </br>
+    /// The uniform data of objects using the object pool is gathered into one array and updated with a single write. </br>
+    /// Returns the number of buffer writes issued. </br>
+    /// 
+    pub fn update_all(&mut self, queue: &wgpu::Queue) -> usize {
+        let mut writes = 0;
+        if self.camera_dirty {
+            self.camera.update_shader_resource(queue);
+            self.camera_dirty = false;
+            writes += 1;
+        }
+
+        let objects = self.opaque_objects.iter()
+            .chain(self.decal_objects.iter())
+            .chain(self.transparent_objects.iter());
+        writes += self.object_pool.write_all(queue, objects);
+        return writes;
+    }
+}
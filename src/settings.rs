@@ -0,0 +1,973 @@
+use std::mem;
+use std::cell::Cell;
+use crate::interfaces::ShaderResource;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 전역 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the global uniform data layout passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GlobalUniformLayout {
+    pub hsv_adjust: glam::Vec3, 
+    pub time: f32, 
+    pub fog_color: glam::Vec4, 
+    pub fog_params: glam::Vec4, 
+    pub highlight_color: glam::Vec4, 
+    pub light_direction: glam::Vec4, 
+    pub light_color: glam::Vec4, 
+    pub ambient_sky: glam::Vec4, 
+    pub ambient_ground: glam::Vec4, 
+    pub bloom_params: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 가중치 혼합 OIT의 가중치 함수 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the weighted blended OIT weight function passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WboitUniformLayout {
+    pub alpha_params: glam::Vec4, 
+    pub depth_params: glam::Vec4, 
+    pub clamp_params: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// 안개의 밀도가 거리에 따라 감소하는 방식 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// How the fog density falls off with distance. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FogFalloff {
+    /// `factor = exp(-density * distance)`
+    #[default]
+    Exponential, 
+    /// `factor = exp(-(density * distance)^2)`
+    ExponentialSquared, 
+}
+
+/// #### 한국어 </br>
+/// 뷰 공간 거리에 따라 오브젝트의 색상을 안개 색상으로 섞는 거리 안개 설정 입니다. </br>
+/// 안개는 색상 조정 이후에 적용되며, 투명한 오브젝트는 가중치를 곱하기 전의 색상에 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Distance fog settings that blend an object's color toward the fog color based on view-space distance. </br>
+/// The fog is applied after color adjustment, and for transparent objects it is applied to the color before weighting. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FogSettings {
+    pub color: glam::Vec3, 
+    pub density: f32, 
+    pub falloff: FogFalloff, 
+    pub enabled: bool, 
+}
+
+impl Default for FogSettings {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            color: glam::Vec3::ZERO, 
+            density: 0.05, 
+            falloff: FogFalloff::Exponential, 
+            enabled: false, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면을 비추는 방향광 설정 입니다. </br>
+/// 켜져 있는 경우 오브젝트는 거칠기와 금속성을 사용하는 Cook-Torrance BRDF(GGX, Smith, Schlick)로 음영 처리됩니다. </br>
+/// `direction`은 빛이 나아가는 방향(월드 좌표계)이며, `ambient`는 빛을 받지 않는 면에도 더해지는 알베도의 비율 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings of the directional light illuminating the scene. </br>
+/// When enabled, objects are shaded with a Cook-Torrance BRDF (GGX, Smith, Schlick) using their roughness and metalness. </br>
+/// `direction` is the direction the light travels (in world space), and `ambient` is the fraction of the albedo added even to unlit faces. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightSettings {
+    pub direction: glam::Vec3, 
+    pub color: glam::Vec3, 
+    pub intensity: f32, 
+    pub ambient: f32, 
+    pub enabled: bool, 
+}
+
+impl Default for LightSettings {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            direction: glam::Vec3::new(-0.5, -1.0, -0.3), 
+            color: glam::Vec3::ONE, 
+            intensity: 3.0, 
+            ambient: 0.15, 
+            enabled: false, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 반구형 환경광 설정 입니다. </br>
+/// 켜져 있는 경우 방향광의 균일한 환경광(`LightSettings::ambient`) 대신, 월드 좌표계의 법선이 위를 향할수록 </br>
+/// `sky_color`에, 아래를 향할수록 `ground_color`에 가까운 환경광을 알베도에 곱해 더합니다. </br>
+/// 빛을 받지 않는 면이 완전히 검게 보이지 않도록 하며, 방향광이 켜져 있을 때만 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Hemispheric ambient light settings. </br>
+/// When enabled, instead of the uniform ambient of the directional light (`LightSettings::ambient`), an ambient term that is closer to </br>
+/// `sky_color` as the world space normal faces up and closer to `ground_color` as it faces down is multiplied by the albedo and added. </br>
+/// It keeps unlit faces from turning pure black, and it is applied only while the directional light is enabled. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmbientSettings {
+    pub sky_color: glam::Vec3, 
+    pub ground_color: glam::Vec3, 
+    pub enabled: bool, 
+}
+
+impl Default for AmbientSettings {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            sky_color: glam::Vec3::new(0.25, 0.3, 0.4), 
+            ground_color: glam::Vec3::new(0.1, 0.08, 0.06), 
+            enabled: true, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 장면 전체에 적용되는 렌더링 설정입니다. </br>
+/// 설정 값은 전역 유니폼 버퍼를 통해 쉐이더에 전달됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Rendering settings applied to the entire scene. </br>
+/// The setting values are passed to the shader through the global uniform buffer. </br>
+/// 
+#[derive(Debug)]
+pub struct RenderSettings {
+    hsv_adjust: glam::Vec3, 
+    time: f32, 
+    fog: FogSettings, 
+    light: LightSettings, 
+    ambient: AmbientSettings, 
+    clear_color_target: bool, 
+    clear_color: glam::Vec4, 
+    frame_pass_labels: bool, 
+    hover_highlight: bool, 
+    highlight_color: glam::Vec4, 
+    bloom_threshold: f32, 
+    bloom_intensity: f32, 
+    blocking_poll: bool, 
+    wboit: WboitParams, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl RenderSettings {
+    /// #### 한국어 </br>
+    /// 색상 조정이 적용되지 않는 HSV 조정 값입니다. (색조 이동, 채도 배율, 명도 배율) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The HSV adjustment value with no color adjustment applied. (hue shift, saturation multiplier, value multiplier) </br>
+    /// 
+    pub const IDENTITY_HSV_ADJUST: glam::Vec3 = glam::Vec3::new(0.0, 1.0, 1.0);
+
+    /// #### 한국어 </br>
+    /// 강조된 오브젝트의 기본 강조 색상입니다. (rgb: 색상, a: 섞는 비율) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default highlight color of highlighted objects. (rgb: color, a: blend strength) </br>
+    /// 
+    pub const DEFAULT_HIGHLIGHT_COLOR: glam::Vec4 = glam::Vec4::new(1.0, 1.0, 1.0, 0.35);
+
+    /// #### 한국어 </br>
+    /// 불투명 패스를 시작할 때 색상 렌더 타겟을 지우는 기본 배경 색상입니다. (검은색) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default background color the color render target is cleared to at the start of the opaque pass. (black) </br>
+    /// 
+    pub const DEFAULT_CLEAR_COLOR: glam::Vec4 = glam::Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    /// #### 한국어 </br>
+    /// 밝은 배경과 어두운 배경에서 투명도를 확인하기 위한 배경 색상 목록입니다. (검은색, 어두운 회색, 밝은 회색, 흰색) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A list of background colors to check transparency against light and dark backgrounds. (black, dark gray, light gray, white) </br>
+    /// 
+    pub const CLEAR_COLOR_PRESETS: [glam::Vec4; 4] = [
+        Self::DEFAULT_CLEAR_COLOR, 
+        glam::Vec4::new(0.05, 0.05, 0.05, 1.0), 
+        glam::Vec4::new(0.6, 0.6, 0.6, 1.0), 
+        glam::Vec4::new(1.0, 1.0, 1.0, 1.0), 
+    ];
+
+    /// #### 한국어 </br>
+    /// 블룸의 밝은 부분 추출 패스에서 사용하는 기본 밝기 임계값 입니다. (색상 채널의 최댓값 기준) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default brightness threshold used by the bright pass of bloom. (based on the maximum color channel) </br>
+    /// 
+    pub const DEFAULT_BLOOM_THRESHOLD: f32 = 1.0;
+
+    /// #### 한국어 </br>
+    /// 흐려진 밝은 부분을 장면에 더할 때 곱하는 기본 강도 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default intensity multiplied when adding the blurred bright parts to the scene. </br>
+    /// 
+    pub const DEFAULT_BLOOM_INTENSITY: f32 = 0.6;
+
+    pub fn new(
+        device: &wgpu::Device, 
+        bind_group_layout: &wgpu::BindGroupLayout, 
+        wboit_bind_group_layout: &wgpu::BindGroupLayout, 
+    ) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Global)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<GlobalUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Global)"), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            hsv_adjust: Self::IDENTITY_HSV_ADJUST, 
+            time: 0.0, 
+            fog: FogSettings::default(), 
+            light: LightSettings::default(), 
+            ambient: AmbientSettings::default(), 
+            clear_color_target: true, 
+            clear_color: Self::DEFAULT_CLEAR_COLOR, 
+            frame_pass_labels: cfg!(debug_assertions), 
+            hover_highlight: true, 
+            highlight_color: Self::DEFAULT_HIGHLIGHT_COLOR, 
+            bloom_threshold: Self::DEFAULT_BLOOM_THRESHOLD, 
+            bloom_intensity: Self::DEFAULT_BLOOM_INTENSITY, 
+            blocking_poll: true, 
+            wboit: WboitParams::new(device, wboit_bind_group_layout), 
+            buffer, 
+            bind_group, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 오브젝트의 기본 색상에 적용되는 HSV 조정 값을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the HSV adjustment value applied to the base color of all objects. </br>
+    /// 
+    #[inline]
+    pub fn hsv_adjust(&self) -> glam::Vec3 {
+        self.hsv_adjust
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 오브젝트의 기본 색상에 적용되는 HSV 조정 값을 설정합니다. </br>
+    /// `x`는 색조 이동(1.0 = 360°), `y`는 채도 배율, `z`는 명도 배율 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the HSV adjustment value applied to the base color of all objects. </br>
+    /// `x` is the hue shift (1.0 = 360°), `y` is the saturation multiplier, and `z` is the value multiplier. </br>
+    /// 
+    #[inline]
+    pub fn set_hsv_adjust(&mut self, hsv_adjust: glam::Vec3) {
+        self.hsv_adjust = hsv_adjust;
+    }
+
+    #[inline]
+    pub fn set_hue_shift(&mut self, hue_shift: f32) {
+        self.hsv_adjust.x = hue_shift;
+    }
+
+    #[inline]
+    pub fn set_saturation_scale(&mut self, saturation_scale: f32) {
+        self.hsv_adjust.y = saturation_scale;
+    }
+
+    #[inline]
+    pub fn set_value_scale(&mut self, value_scale: f32) {
+        self.hsv_adjust.z = value_scale;
+    }
+
+    /// #### 한국어 </br>
+    /// 쉐이더 애니메이션에 사용되는 시간(초)을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the time (in seconds) used for shader animations. </br>
+    /// 
+    #[inline]
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time;
+    }
+
+    /// #### 한국어 </br>
+    /// 거리 안개 설정을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the distance fog settings. </br>
+    /// 
+    #[inline]
+    pub fn fog(&self) -> &FogSettings {
+        &self.fog
+    }
+
+    #[inline]
+    pub fn set_fog(&mut self, fog: FogSettings) {
+        self.fog = fog;
+    }
+
+    #[inline]
+    pub fn set_fog_enabled(&mut self, enabled: bool) {
+        self.fog.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn set_fog_color(&mut self, color: glam::Vec3) {
+        self.fog.color = color;
+    }
+
+    #[inline]
+    pub fn set_fog_density(&mut self, density: f32) {
+        self.fog.density = density.max(0.0);
+    }
+
+    #[inline]
+    pub fn set_fog_falloff(&mut self, falloff: FogFalloff) {
+        self.fog.falloff = falloff;
+    }
+
+    #[inline]
+    pub fn light(&self) -> &LightSettings {
+        &self.light
+    }
+
+    #[inline]
+    pub fn set_light(&mut self, light: LightSettings) {
+        self.light = light;
+    }
+
+    #[inline]
+    pub fn set_light_enabled(&mut self, enabled: bool) {
+        self.light.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn set_light_direction(&mut self, direction: glam::Vec3) {
+        self.light.direction = direction;
+    }
+
+    #[inline]
+    pub fn set_light_color(&mut self, color: glam::Vec3, intensity: f32) {
+        self.light.color = color;
+        self.light.intensity = intensity;
+    }
+
+    #[inline]
+    pub fn ambient(&self) -> &AmbientSettings {
+        &self.ambient
+    }
+
+    #[inline]
+    pub fn set_ambient(&mut self, ambient: AmbientSettings) {
+        self.ambient = ambient;
+    }
+
+    #[inline]
+    pub fn set_ambient_enabled(&mut self, enabled: bool) {
+        self.ambient.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn set_ambient_colors(&mut self, sky_color: glam::Vec3, ground_color: glam::Vec3) {
+        self.ambient.sky_color = sky_color;
+        self.ambient.ground_color = ground_color;
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스를 시작할 때 색상 렌더 타겟을 지우는지 여부를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets whether to clear the color render target at the start of the opaque pass. </br>
+    /// 
+    #[inline]
+    pub fn clear_color_target(&self) -> bool {
+        self.clear_color_target
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스를 시작할 때 색상 렌더 타겟을 지우는지 여부를 설정합니다. (기본값: `true`) </br>
+    /// `false`인 경우 `LoadOp::Load`를 사용하여 이전 내용 위에 장면을 그립니다. (잔상, 누적 효과) </br>
+    /// 
+    /// 주의: 스왑체인의 텍스처는 여러 장을 번갈아 사용하며, 표시(present) 이후 내용이 보존된다는 보장이 없습니다. </br>
+    /// 플랫폼과 백엔드에 따라 이전 프레임 대신 오래된 프레임이나 정의되지 않은 내용이 보일 수 있습니다. </br>
+    /// 안정적인 누적 효과가 필요하다면 화면 밖 텍스처에 그린 후 스왑체인에 복사해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to clear the color render target at the start of the opaque pass. (default: `true`) </br>
+    /// If `false`, `LoadOp::Load` is used and the scene is drawn over the previous contents. (trails, accumulation effects) </br>
+    /// 
+    /// Caution: the swapchain rotates through several textures, and their contents are not guaranteed to be preserved after presenting. </br>
+    /// Depending on the platform and backend, an older frame or undefined contents may appear instead of the previous frame. </br>
+    /// If a reliable accumulation effect is needed, draw into an offscreen texture and copy it to the swapchain. </br>
+    /// 
+    #[inline]
+    pub fn set_clear_color_target(&mut self, clear_color_target: bool) {
+        self.clear_color_target = clear_color_target;
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스를 시작할 때 색상 렌더 타겟을 지우는 배경 색상을 가져옵니다. (선형 공간의 RGBA) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the background color the color render target is cleared to at the start of the opaque pass. (RGBA in linear space) </br>
+    /// 
+    #[inline]
+    pub fn clear_color(&self) -> glam::Vec4 {
+        self.clear_color
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스를 시작할 때 색상 렌더 타겟을 지우는 배경 색상을 설정합니다. (기본값: `DEFAULT_CLEAR_COLOR`) </br>
+    /// 쉐이더의 출력과 같이 선형 공간의 색상이며, sRGB 화면 형식인 경우 쓰기 시 감마 보정이 적용됩니다. </br>
+    /// `clear_color_target`이 `false`인 경우 사용되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the background color the color render target is cleared to at the start of the opaque pass. (default: `DEFAULT_CLEAR_COLOR`) </br>
+    /// Like the shader output it is a color in linear space, and gamma correction is applied on write for sRGB surface formats. </br>
+    /// Not used if `clear_color_target` is `false`. </br>
+    /// 
+    #[inline]
+    pub fn set_clear_color(&mut self, clear_color: glam::Vec4) {
+        self.clear_color = clear_color;
+    }
+
+    /// #### 한국어 </br>
+    /// 배경 색상을 렌더 패스의 `LoadOp::Clear`에 사용하는 `wgpu::Color`로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the background color into a `wgpu::Color` used for the `LoadOp::Clear` of a render pass. </br>
+    /// 
+    #[inline]
+    pub fn clear_color_value(&self) -> wgpu::Color {
+        let color = self.clear_color.as_dvec4();
+        wgpu::Color { r: color.x, g: color.y, b: color.z, a: color.w }
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스 레이블에 프레임 번호를 붙이는지 여부를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets whether to append the frame number to render pass labels. </br>
+    /// 
+    #[inline]
+    pub fn frame_pass_labels(&self) -> bool {
+        self.frame_pass_labels
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 패스 레이블에 프레임 번호를 붙이는지 여부를 설정합니다. (예: `RenderPass(Opaque)#1234`) </br>
+    /// GPU 캡처 도구에서 캡처한 프레임을 구분하는 데 사용하며, 기본값은 디버그 빌드에서만 `true` 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to append the frame number to render pass labels. (e.g. `RenderPass(Opaque)#1234`) </br>
+    /// Used to tell captured frames apart in GPU capture tools, and the default is `true` only in debug builds. </br>
+    /// 
+    #[inline]
+    pub fn set_frame_pass_labels(&mut self, frame_pass_labels: bool) {
+        self.frame_pass_labels = frame_pass_labels;
+    }
+
+    /// #### 한국어 </br>
+    /// 커서 아래에 있는 오브젝트를 강조하는지 여부를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets whether to highlight the object under the cursor. </br>
+    /// 
+    #[inline]
+    pub fn hover_highlight(&self) -> bool {
+        self.hover_highlight
+    }
+
+    /// #### 한국어 </br>
+    /// 커서 아래에 있는 오브젝트를 강조하는지 여부를 설정합니다. (기본값: `true`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to highlight the object under the cursor. (default: `true`) </br>
+    /// 
+    #[inline]
+    pub fn set_hover_highlight(&mut self, hover_highlight: bool) {
+        self.hover_highlight = hover_highlight;
+    }
+
+    /// #### 한국어 </br>
+    /// 강조된 오브젝트의 강조 색상을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the highlight color of highlighted objects. </br>
+    /// 
+    #[inline]
+    pub fn highlight_color(&self) -> glam::Vec4 {
+        self.highlight_color
+    }
+
+    /// #### 한국어 </br>
+    /// 강조된 오브젝트의 강조 색상을 설정합니다. </br>
+    /// 오브젝트의 색상은 `a` 값의 비율로 `rgb` 색상과 섞이며, 투명도는 바뀌지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the highlight color of highlighted objects. </br>
+    /// The object's color is blended toward the `rgb` color by the ratio `a`, and its alpha is unchanged. </br>
+    /// 
+    #[inline]
+    pub fn set_highlight_color(&mut self, highlight_color: glam::Vec4) {
+        self.highlight_color = highlight_color;
+    }
+
+    /// #### 한국어 </br>
+    /// 가중치 혼합 OIT의 가중치 함수 매개변수를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the weight function parameters of weighted blended OIT. </br>
+    /// 
+    #[inline]
+    pub fn wboit(&self) -> &WboitParams {
+        &self.wboit
+    }
+
+    /// #### 한국어 </br>
+    /// 가중치 혼합 OIT의 가중치 함수 매개변수를 변경 가능하게 가져옵니다. </br>
+    /// 변경 사항은 `update_shader_resource`를 호출한 후 쉐이더에 반영됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the weight function parameters of weighted blended OIT as mutable. </br>
+    /// The change is reflected in the shader after `update_shader_resource` is called. </br>
+    /// 
+    #[inline]
+    pub fn mut_wboit(&mut self) -> &mut WboitParams {
+        &mut self.wboit
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸의 밝기 임계값을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the brightness threshold of bloom. </br>
+    /// 
+    #[inline]
+    pub fn bloom_threshold(&self) -> f32 {
+        self.bloom_threshold
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸의 밝기 임계값을 설정합니다. (기본값: `DEFAULT_BLOOM_THRESHOLD`) </br>
+    /// 색상 채널의 최댓값이 임계값보다 큰 픽셀만 번지며, 0인 경우 장면 전체가 번집니다. 음수는 0으로 제한됩니다. </br>
+    /// 블룸 파이프라인이 없는 경우(`PipelineSettings::bloom`) 이 값은 사용되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the brightness threshold of bloom. (default: `DEFAULT_BLOOM_THRESHOLD`) </br>
+    /// Only pixels whose maximum color channel exceeds the threshold bleed, and with 0 the whole scene bleeds. Negative values are clamped to 0. </br>
+    /// This value is unused if there are no bloom pipelines (`PipelineSettings::bloom`). </br>
+    /// 
+    #[inline]
+    pub fn set_bloom_threshold(&mut self, bloom_threshold: f32) {
+        self.bloom_threshold = bloom_threshold.max(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸의 강도를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the intensity of bloom. </br>
+    /// 
+    #[inline]
+    pub fn bloom_intensity(&self) -> f32 {
+        self.bloom_intensity
+    }
+
+    /// #### 한국어 </br>
+    /// 흐려진 밝은 부분을 장면에 더할 때 곱하는 블룸의 강도를 설정합니다. (기본값: `DEFAULT_BLOOM_INTENSITY`) 음수는 0으로 제한됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the intensity of bloom multiplied when adding the blurred bright parts to the scene. (default: `DEFAULT_BLOOM_INTENSITY`) Negative values are clamped to 0. </br>
+    /// 
+    #[inline]
+    pub fn set_bloom_intensity(&mut self, bloom_intensity: f32) {
+        self.bloom_intensity = bloom_intensity.max(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 매 프레임 시작 시 이전 GPU 작업이 끝날 때까지 기다리는지 여부를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets whether to wait for the previous GPU work to finish at the start of every frame. </br>
+    /// 
+    #[inline]
+    pub fn blocking_poll(&self) -> bool {
+        self.blocking_poll
+    }
+
+    /// #### 한국어 </br>
+    /// 매 프레임 시작 시 이전 GPU 작업이 끝날 때까지 기다리는지 여부를 설정합니다. (기본값: `true`) </br>
+    /// 
+    /// `true`인 경우 `Maintain::Wait`를 사용합니다. CPU와 GPU가 번갈아 동작하므로 입력 지연은 가장 짧지만 처리량이 줄어듭니다. </br>
+    /// `false`인 경우 `Maintain::Poll`을 사용하여 기다리지 않고, 스왑체인의 최대 프레임 지연(`desired_maximum_frame_latency`)이 </br>
+    /// CPU가 앞서가는 것을 제한합니다. CPU와 GPU가 겹쳐서 동작하므로 처리량은 늘어나지만 프레임 지연만큼 입력 지연이 늘어납니다. </br>
+    /// 
+    /// 유니폼 버퍼는 `Queue::write_buffer`로 갱신되며, 이는 스테이징 버퍼를 거쳐 제출 순서대로 복사되므로 </br>
+    /// GPU가 이전 프레임에서 사용 중인 버퍼를 덮어쓰지 않습니다. 버퍼를 직접 매핑하여 쓰는 경우에는 이 보장이 없습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to wait for the previous GPU work to finish at the start of every frame. (default: `true`) </br>
+    /// 
+    /// If `true`, `Maintain::Wait` is used. The CPU and GPU take turns, so input latency is the lowest but throughput is reduced. </br>
+    /// If `false`, `Maintain::Poll` is used and does not wait; the swapchain's maximum frame latency (`desired_maximum_frame_latency`) </br>
+    /// limits how far the CPU runs ahead. The CPU and GPU overlap, so throughput increases but input latency grows by the frame latency. </br>
+    /// 
+    /// Uniform buffers are updated with `Queue::write_buffer`, which copies through a staging buffer in submission order, </br>
+    /// so buffers the GPU is still using for a previous frame are not overwritten. This guarantee does not hold for buffers mapped and written directly. </br>
+    /// 
+    #[inline]
+    pub fn set_blocking_poll(&mut self, blocking_poll: bool) {
+        self.blocking_poll = blocking_poll;
+    }
+}
+
+impl ShaderResource for RenderSettings {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        let data = GlobalUniformLayout {
+            hsv_adjust: self.hsv_adjust, 
+            time: self.time, 
+            fog_color: self.fog.color.extend(1.0), 
+            fog_params: glam::Vec4::new(
+                self.fog.density, 
+                match self.fog.falloff {
+                    FogFalloff::Exponential => 0.0, 
+                    FogFalloff::ExponentialSquared => 1.0, 
+                }, 
+                self.fog.enabled as u32 as f32, 
+                0.0
+            ), 
+            highlight_color: self.highlight_color, 
+            light_direction: self.light.direction.normalize_or_zero().extend(self.light.enabled as u32 as f32), 
+            light_color: (self.light.color * self.light.intensity).extend(self.light.ambient), 
+            ambient_sky: self.ambient.sky_color.extend(self.ambient.enabled as u32 as f32), 
+            ambient_ground: self.ambient.ground_color.extend(1.0), 
+            bloom_params: glam::Vec4::new(self.bloom_threshold, self.bloom_intensity, 0.0, 0.0), 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+        self.wboit.update_shader_resource(queue);
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 가중치 혼합 OIT의 투명 패스에서 조각의 가중치를 계산하는 함수의 매개변수 입니다. </br>
+/// 쉐이더(`fs_transparent_pass`)는 다음 식을 사용합니다. (McGuire and Bavoil, 2013) </br>
+/// 
+/// `w = clamp((min(1, a * alpha_scale) + alpha_bias)^alpha_exponent * depth_scale * (1 - z * depth_range)^depth_exponent, min, max)` </br>
+/// 
+/// `a`는 조각의 알파 값, `z`는 `[0, 1]` 범위의 조각의 깊이 값이며, `min`과 `max`는 </br>
+/// `WboitParams::MIN_WEIGHT`와 `WboitParams::max_weight` 입니다. </br>
+/// `depth_range`를 1에 가깝게 하거나 `depth_exponent`를 높이면 먼 조각의 가중치가 더 빠르게 줄어듭니다. </br>
+/// 
+/// 카메라 유니폼과 같이 값이 바뀐 경우에만 (`dirty`) `update_shader_resource`에서 유니폼 버퍼를 다시 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parameters of the function that computes the weight of a fragment in the transparent pass of weighted blended OIT. </br>
+/// The shader (`fs_transparent_pass`) uses the following formula. (McGuire and Bavoil, 2013) </br>
+/// 
+/// `w = clamp((min(1, a * alpha_scale) + alpha_bias)^alpha_exponent * depth_scale * (1 - z * depth_range)^depth_exponent, min, max)` </br>
+/// 
+/// `a` is the alpha of the fragment, `z` is the depth of the fragment in the `[0, 1]` range, and `min` and `max` are </br>
+/// `WboitParams::MIN_WEIGHT` and `WboitParams::max_weight`. </br>
+/// Moving `depth_range` closer to 1 or raising `depth_exponent` down-weights far fragments more aggressively. </br>
+/// 
+/// Like the camera uniform, `update_shader_resource` rewrites the uniform buffer only if the values changed (`dirty`). </br>
+/// 
+#[derive(Debug)]
+pub struct WboitParams {
+    alpha_scale: f32, 
+    alpha_bias: f32, 
+    alpha_exponent: f32, 
+    depth_scale: f32, 
+    depth_range: f32, 
+    depth_exponent: f32, 
+    max_weight: f32, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl WboitParams {
+    pub const DEFAULT_ALPHA_SCALE: f32 = 10.0;
+    pub const DEFAULT_ALPHA_BIAS: f32 = 0.01;
+    pub const DEFAULT_ALPHA_EXPONENT: f32 = 3.0;
+    pub const DEFAULT_DEPTH_SCALE: f32 = 1e8;
+    pub const DEFAULT_DEPTH_RANGE: f32 = 0.9;
+    pub const DEFAULT_DEPTH_EXPONENT: f32 = 3.0;
+
+    /// #### 한국어 </br>
+    /// 투명한 오브젝트의 조각 하나가 가질 수 있는 가중치의 최소값 입니다. (가중치 함수의 하한) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The minimum weight a single fragment of a transparent object can have. (the lower bound of the weight function) </br>
+    /// 
+    pub const MIN_WEIGHT: f32 = 1e-2;
+
+    /// #### 한국어 </br>
+    /// 투명한 오브젝트의 조각 하나가 가질 수 있는 가중치의 기본 최대값 입니다. (가중치 함수의 원래 상한) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The default maximum weight a single fragment of a transparent object can have. (the original upper bound of the weight function) </br>
+    /// 
+    pub const DEFAULT_MAX_WEIGHT: f32 = 3e3;
+
+    /// #### 한국어 </br>
+    /// 가중치 최대값의 목록입니다. 겹친 층이 많은 장면에서 결과를 비교하는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A list of maximum weights. Used to compare the results in scenes with many overlapping layers. </br>
+    /// 
+    pub const MAX_WEIGHT_PRESETS: [f32; 3] = [Self::DEFAULT_MAX_WEIGHT, 3e2, 3e1];
+
+    /// #### 한국어 </br>
+    /// 합성 패스에서 누적 가중치의 상한을 정하는 층의 수 입니다. 누적 가중치는 `max_weight * MAX_ACCUMULATED_LAYERS`로 제한됩니다. </br>
+    /// 기본 최대값에서 이 상한은 반정밀도 부동소수점의 최댓값(65504)보다 작습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The number of layers that sets the upper bound of the accumulated weight in the composite pass. The accumulated weight is limited to `max_weight * MAX_ACCUMULATED_LAYERS`. </br>
+    /// With the default maximum, this bound stays below the largest half-precision float (65504). </br>
+    /// 
+    pub const MAX_ACCUMULATED_LAYERS: f32 = 16.0;
+
+    pub fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Wboit)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<WboitUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Wboit)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            alpha_scale: Self::DEFAULT_ALPHA_SCALE, 
+            alpha_bias: Self::DEFAULT_ALPHA_BIAS, 
+            alpha_exponent: Self::DEFAULT_ALPHA_EXPONENT, 
+            depth_scale: Self::DEFAULT_DEPTH_SCALE, 
+            depth_range: Self::DEFAULT_DEPTH_RANGE, 
+            depth_exponent: Self::DEFAULT_DEPTH_EXPONENT, 
+            max_weight: Self::DEFAULT_MAX_WEIGHT, 
+            dirty: Cell::new(true), 
+            buffer, 
+            bind_group, 
+        }
+    }
+
+    #[inline]
+    pub fn alpha_scale(&self) -> f32 {
+        self.alpha_scale
+    }
+
+    #[inline]
+    pub fn alpha_bias(&self) -> f32 {
+        self.alpha_bias
+    }
+
+    #[inline]
+    pub fn alpha_exponent(&self) -> f32 {
+        self.alpha_exponent
+    }
+
+    #[inline]
+    pub fn depth_scale(&self) -> f32 {
+        self.depth_scale
+    }
+
+    #[inline]
+    pub fn depth_range(&self) -> f32 {
+        self.depth_range
+    }
+
+    #[inline]
+    pub fn depth_exponent(&self) -> f32 {
+        self.depth_exponent
+    }
+
+    /// #### 한국어 </br>
+    /// 투명한 오브젝트의 조각 하나가 누적 값에 기여하는 가중치의 최대값을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the maximum weight with which a single transparent fragment contributes to the accumulation. </br>
+    /// 
+    #[inline]
+    pub fn max_weight(&self) -> f32 {
+        self.max_weight
+    }
+
+    /// #### 한국어 </br>
+    /// 투명한 오브젝트의 조각 하나가 누적 값에 기여하는 가중치의 최대값을 설정합니다. (기본값: `DEFAULT_MAX_WEIGHT`) </br>
+    /// 투명 패스는 조각마다 가중치를 이 값으로 제한하고, 합성 패스는 누적 가중치를 `max_weight * MAX_ACCUMULATED_LAYERS`로 제한합니다. </br>
+    /// 
+    /// 가중치 혼합 OIT는 깊이에 따른 가중치로 겹친 층들의 평균 색상을 계산하므로, 층이 많이 겹치면 </br>
+    /// 가까운 층의 색상이 먼 층들에 묻혀 색이 바랜 결과가 나옵니다. 최대값을 낮추면 누적 가중치가 커지는 것을 제한하여 </br>
+    /// 몇몇 층이 결과를 지배하지 않도록 할 수 있고, 높이면 가까운 층이 더 강하게 드러납니다. </br>
+    /// 이 값은 물리적인 정확성 대신 결과를 조절하기 위한 값이며, 올바른 값은 장면에 따라 다릅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the maximum weight with which a single transparent fragment contributes to the accumulation. (default: `DEFAULT_MAX_WEIGHT`) </br>
+    /// The transparent pass limits the weight of each fragment to this value, and the composite pass limits the accumulated weight to `max_weight * MAX_ACCUMULATED_LAYERS`. </br>
+    /// 
+    /// Weighted blended OIT computes the average color of overlapping layers with depth-based weights, so when many layers overlap, </br>
+    /// the color of the near layers is buried under the far layers and the result looks washed out. Lowering the maximum limits how much </br>
+    /// the accumulated weight can grow so that a few layers do not dominate the result, and raising it lets the near layers stand out more. </br>
+    /// This value trades physical correctness for controllability, and the right value depends on the scene. </br>
+    /// 
+    #[inline]
+    pub fn set_max_weight(&mut self, max_weight: f32) {
+        self.max_weight = max_weight.max(Self::MIN_WEIGHT);
+        self.mark_dirty();
+    }
+
+    /// #### 한국어 </br>
+    /// 알파 값에 대한 가중치 항의 매개변수를 설정합니다. (`alpha_scale`, `alpha_bias`, `alpha_exponent`) </br>
+    /// 음수 값은 0으로 고정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the parameters of the alpha term of the weight. (`alpha_scale`, `alpha_bias`, `alpha_exponent`) </br>
+    /// Negative values are clamped to 0. </br>
+    /// 
+    #[inline]
+    pub fn set_alpha_params(&mut self, scale: f32, bias: f32, exponent: f32) {
+        self.alpha_scale = scale.max(0.0);
+        self.alpha_bias = bias.max(0.0);
+        self.alpha_exponent = exponent.max(0.0);
+        self.mark_dirty();
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 값에 따라 가중치가 줄어드는 정도를 설정합니다. </br>
+    /// `range`는 가장 먼 깊이에서 줄어드는 비율로 `[0, 1]` 범위로 고정되며, `exponent`는 음수인 경우 0으로 고정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets how much the weight falls off with depth. </br>
+    /// `range` is the fraction removed at the farthest depth and is clamped to `[0, 1]`, and `exponent` is clamped to 0 if negative. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_falloff(&mut self, range: f32, exponent: f32) {
+        self.depth_range = range.clamp(0.0, 1.0);
+        self.depth_exponent = exponent.max(0.0);
+        self.mark_dirty();
+    }
+
+    /// #### 한국어 </br>
+    /// 가중치 전체에 곱해지는 배율을 설정합니다. 음수 값은 0으로 고정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the multiplier applied to the whole weight. Negative values are clamped to 0. </br>
+    /// 
+    #[inline]
+    pub fn set_depth_scale(&mut self, scale: f32) {
+        self.depth_scale = scale.max(0.0);
+        self.mark_dirty();
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 매개변수를 기본값으로 되돌립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resets all parameters to their default values. </br>
+    /// 
+    pub fn reset(&mut self) {
+        self.alpha_scale = Self::DEFAULT_ALPHA_SCALE;
+        self.alpha_bias = Self::DEFAULT_ALPHA_BIAS;
+        self.alpha_exponent = Self::DEFAULT_ALPHA_EXPONENT;
+        self.depth_scale = Self::DEFAULT_DEPTH_SCALE;
+        self.depth_range = Self::DEFAULT_DEPTH_RANGE;
+        self.depth_exponent = Self::DEFAULT_DEPTH_EXPONENT;
+        self.max_weight = Self::DEFAULT_MAX_WEIGHT;
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+
+    #[inline]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty.get()
+    }
+}
+
+impl ShaderResource for WboitParams {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        let data = WboitUniformLayout {
+            alpha_params: glam::Vec4::new(self.alpha_scale, self.alpha_bias, self.alpha_exponent, 0.0), 
+            depth_params: glam::Vec4::new(self.depth_scale, self.depth_range, self.depth_exponent, 0.0), 
+            clamp_params: glam::Vec4::new(Self::MIN_WEIGHT, self.max_weight, self.max_weight * Self::MAX_ACCUMULATED_LAYERS, 0.0), 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime};
+
+
+
+/// #### 한국어 </br>
+/// 실행 중에 디스크의 쉐이더 파일(`shaders/shader.wgsl`)이 바뀌었는지 확인하고 쉐이더 모듈을 다시 생성합니다. </br>
+/// 파일 감시 대신 일정 간격(`POLL_INTERVAL`)마다 수정 시간을 확인하며, `hot-reload` 기능이 켜진 경우에만 컴파일됩니다. </br>
+/// 기능이 꺼진 빌드(릴리즈 빌드 등)는 계속 `include_wgsl!`로 포함된 쉐이더를 사용합니다. </br>
+/// 
+/// 스테레오 렌더링인 경우 `shaders/stereo.wgsl`을, 푸시 상수를 지원하는 경우 `shaders/push_constants.wgsl`을 디스크에서 읽어 덧붙이며, </br>
+/// 파일들 중 하나가 바뀌면 다시 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks at runtime whether the shader file on disk (`shaders/shader.wgsl`) changed and recreates the shader module. </br>
+/// Instead of watching the file, it checks the modification time at a fixed interval (`POLL_INTERVAL`), and it is only compiled with the `hot-reload` feature. </br>
+/// Builds without the feature (release builds, etc.) keep using the shader embedded with `include_wgsl!`. </br>
+/// 
+/// `shaders/stereo.wgsl` for stereo rendering and `shaders/push_constants.wgsl` when push constants are supported are also read from disk and appended, </br>
+/// and the module is recreated if any of the files changes. </br>
+/// 
+#[derive(Debug)]
+pub struct ShaderWatcher {
+    paths: Vec<PathBuf>, 
+    last_modified: Option<SystemTime>, 
+    last_poll: Instant, 
+}
+
+#[allow(dead_code)]
+impl ShaderWatcher {
+    /// #### 한국어 </br>
+    /// 쉐이더 파일의 수정 시간을 확인하는 간격 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The interval at which the modification time of the shader files is checked. </br>
+    /// 
+    pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    pub fn new(stereo: bool, push_constants: bool) -> Self {
+        let shader_dir = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders"));
+        let mut paths = vec![shader_dir.join("shader.wgsl")];
+        if stereo {
+            paths.push(shader_dir.join("stereo.wgsl"));
+        }
+        if push_constants {
+            paths.push(shader_dir.join("push_constants.wgsl"));
+        }
+
+        let mut watcher = Self {
+            paths, 
+            last_modified: None, 
+            last_poll: Instant::now(), 
+        };
+        watcher.last_modified = watcher.modified();
+        log::info!("Shader hot-reload enabled. Watching {:?}", watcher.paths);
+        watcher
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 확인한 이후 쉐이더 파일이 바뀌었는지 확인합니다. </br>
+    /// `POLL_INTERVAL`이 지나지 않은 경우 파일을 확인하지 않고 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the shader files changed since the last check. </br>
+    /// Returns `false` without checking the files if `POLL_INTERVAL` has not passed. </br>
+    /// 
+    pub fn poll_changed(&mut self) -> bool {
+        if self.last_poll.elapsed() < Self::POLL_INTERVAL {
+            return false;
+        }
+        self.last_poll = Instant::now();
+
+        // (한국어) 편집기가 파일을 저장하는 도중에는 수정 시간을 읽지 못할 수 있으므로, 다음 확인에서 다시 시도합니다.
+        // (English Translation) The modification time may not be readable while an editor is saving the file, so it is retried at the next check.
+        match self.modified() {
+            Some(modified) if Some(modified) != self.last_modified => {
+                self.last_modified = Some(modified);
+                true
+            }, 
+            _ => false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 디스크에서 쉐이더 파일을 읽어 쉐이더 모듈을 생성합니다. </br>
+    /// 쉐이더 검증(naga)에 실패한 경우 오류 메시지를 반환하며, 장치의 오류 처리기로 전달되지 않으므로 프로그램이 멈추지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the shader files from disk and creates a shader module. </br>
+    /// If shader validation (naga) fails, the error message is returned and not forwarded to the device's error handler, so the program does not crash. </br>
+    /// 
+    pub fn load_module(&self, device: &wgpu::Device) -> Result<wgpu::ShaderModule, String> {
+        let mut source = String::new();
+        for path in self.paths.iter() {
+            let text = fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            source.push_str(&text);
+            source.push('\n');
+        }
+
+        validate(device, || device.create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label: Some("ShaderModule(HotReload)"), 
+                source: wgpu::ShaderSource::Wgsl(Cow::Owned(source)), 
+            }
+        ))
+    }
+
+    /// #### 한국어 </br>
+    /// 쉐이더 파일들 중 가장 최근의 수정 시간을 가져옵니다. 하나라도 읽지 못한 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the latest modification time of the shader files. Returns `None` if any of them cannot be read. </br>
+    /// 
+    fn modified(&self) -> Option<SystemTime> {
+        self.paths.iter()
+            .map(|path| fs::metadata(path).and_then(|metadata| metadata.modified()).ok())
+            .try_fold(SystemTime::UNIX_EPOCH, |latest, modified| modified.map(|modified| latest.max(modified)))
+    }
+}
+
+/// #### 한국어 </br>
+/// 검증 오류 범위 안에서 GPU 리소스를 생성합니다. </br>
+/// 검증에 실패한 경우 생성된 (유효하지 않은) 리소스를 버리고 오류 메시지를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates GPU resources inside a validation error scope. </br>
+/// If validation fails, the created (invalid) resources are discarded and the error message is returned. </br>
+/// 
+pub fn validate<T, F>(device: &wgpu::Device, create: F) -> Result<T, String>
+where F: FnOnce() -> T {
+    device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let value = create();
+    match pollster::block_on(device.pop_error_scope()) {
+        Some(e) => Err(e.to_string()), 
+        None => Ok(value), 
+    }
+}
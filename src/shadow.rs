@@ -0,0 +1,454 @@
+use std::mem;
+use std::cell::Cell;
+use crate::interfaces::ShaderResource;
+use crate::pipeline::ShadowPipelines;
+use crate::resources::{self, ResourceError};
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 그림자 맵의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the shadow map passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ShadowUniformLayout {
+    pub light_view_projection: glam::Mat4, 
+    pub params: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// 그림자를 드리우는 방향광 입니다. </br>
+/// 
+/// 방향광은 위치가 없으므로, 그림자 맵은 `center`를 중심으로 하는 상자를 빛의 방향으로 정사영하여 그립니다. </br>
+/// 상자는 빛의 방향에 수직인 평면에서 `half_extent`, 빛의 방향으로 `half_depth`의 절반 크기를 가지며, </br>
+/// 상자 밖의 오브젝트는 그림자를 드리우지 않고 상자 밖의 표면은 항상 빛을 받습니다. </br>
+/// 상자가 작을수록 그림자 맵의 텍셀이 장면에서 작아지므로 그림자가 선명해집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A directional light that casts shadows. </br>
+/// 
+/// A directional light has no position, so the shadow map is drawn by orthographically projecting a box centered on `center` along the light direction. </br>
+/// The box has the half size `half_extent` on the plane perpendicular to the light direction, and `half_depth` along the light direction. </br>
+/// Objects outside the box do not cast shadows, and surfaces outside the box are always lit. </br>
+/// The smaller the box, the smaller the shadow map texels are in the scene, so the shadows become sharper. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalLight {
+    pub direction: glam::Vec3, 
+    pub center: glam::Vec3, 
+    pub half_extent: glam::Vec2, 
+    pub half_depth: f32, 
+}
+
+impl Default for DirectionalLight {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            direction: glam::Vec3::new(-0.5, -1.0, -0.3), 
+            center: glam::Vec3::ZERO, 
+            half_extent: glam::Vec2::splat(10.0), 
+            half_depth: 20.0, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DirectionalLight {
+    /// #### 한국어 </br>
+    /// 빛의 뷰 행렬을 계산합니다. 빛은 상자의 가장 가까운 면의 중심에서 빛의 방향을 바라봅니다. </br>
+    /// 빛의 방향이 거의 수직인 경우 위쪽 방향으로 z축을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the view matrix of the light. The light looks along the light direction from the center of the nearest face of the box. </br>
+    /// If the light direction is nearly vertical, the z-axis is used as the up direction. </br>
+    /// 
+    pub fn view_transform(&self) -> glam::Mat4 {
+        let direction = self.direction.try_normalize().unwrap_or(glam::Vec3::NEG_Y);
+        let up = match direction.y.abs() > 0.99 {
+            true => glam::Vec3::Z, 
+            false => glam::Vec3::Y, 
+        };
+        glam::Mat4::look_to_rh(self.center - direction * self.half_depth, direction, up)
+    }
+
+    /// #### 한국어 </br>
+    /// 빛의 정사영 투영 행렬을 계산합니다. 그림자 맵은 카메라의 역방향 깊이 설정과 관계없이 `[0, 1]`의 깊이를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the orthographic projection matrix of the light. The shadow map uses a `[0, 1]` depth regardless of the camera's reverse-z setting. </br>
+    /// 
+    pub fn projection_transform(&self) -> glam::Mat4 {
+        glam::Mat4::orthographic_rh(
+            -self.half_extent.x, 
+            self.half_extent.x, 
+            -self.half_extent.y, 
+            self.half_extent.y, 
+            0.0, 
+            2.0 * self.half_depth
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 월드 좌표계를 빛의 클립 좌표계로 변환하는 뷰-투영 행렬을 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the view-projection matrix that transforms world space into the clip space of the light. </br>
+    /// 
+    #[inline]
+    pub fn view_projection(&self) -> glam::Mat4 {
+        self.projection_transform() * self.view_transform()
+    }
+}
+
+/// #### 한국어 </br>
+/// 그림자 맵 패스와 그림자를 받는 불투명 패스의 쉐이더 레이아웃 입니다. </br>
+/// 
+/// - `light`: 빛의 뷰-투영 유니폼. 그림자 맵 패스의 바인드 그룹 슬롯 0에 사용됩니다. </br>
+/// - `shadow_map`: 빛의 뷰-투영 유니폼, 그림자 맵 깊이 텍스처, 비교 샘플러. 불투명 패스의 바인드 그룹 슬롯 3에 사용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The shader layouts of the shadow map pass and of the opaque pass that receives shadows. </br>
+/// 
+/// - `light`: the view-projection uniform of the light. Used for bind group slot 0 of the shadow map pass. </br>
+/// - `shadow_map`: the view-projection uniform of the light, the shadow map depth texture and a comparison sampler. Used for bind group slot 3 of the opaque pass. </br>
+/// 
+#[derive(Debug)]
+pub struct ShadowBindGroupLayouts {
+    pub light: wgpu::BindGroupLayout, 
+    pub shadow_map: wgpu::BindGroupLayout, 
+}
+
+impl ShadowBindGroupLayouts {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let light = utils::create_uniform_bind_group_layout(device, "BindGroupLayout(ShadowLight)", wgpu::ShaderStages::VERTEX);
+        let shadow_map = device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(ShadowMap)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None, 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Depth, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false, 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self { light, shadow_map }
+    }
+}
+
+/// #### 한국어 </br>
+/// 방향광의 그림자 맵 입니다. (참고: `pipeline::ShadowPipelines`) </br>
+/// 
+/// 매 프레임 불투명 패스 전에 그림자를 드리우는 불투명한 오브젝트들(`ColoredObject::cast_shadow`)을 빛의 시점에서 </br>
+/// `ShadowPipelines::DEPTH_FORMAT` 깊이 텍스처에 그리고, 불투명 패스는 비교 샘플러로 3x3 PCF 필터링하여 그림자의 양을 구합니다. </br>
+/// 그림자 맵의 크기는 창 크기와 관계없으며, 창 크기가 바뀌어도 다시 생성되지 않습니다. 크기는 `resize`로만 바뀝니다. </br>
+/// 
+/// 그림자 여드름(shadow acne): 그림자 맵의 깊이는 텍셀 단위로 양자화되므로, 빛을 받는 표면이 자기 자신에게 가려진 것처럼 </br>
+/// 줄무늬 모양의 그림자가 생길 수 있습니다. 이를 줄이기 위해 두 가지 편향을 사용합니다. </br>
+/// - 그림자 맵 패스의 파이프라인은 기울기에 비례하는 하드웨어 깊이 편향(`ShadowPipelines::DEPTH_BIAS`)을 사용합니다. </br>
+/// - 불투명 패스는 비교하기 전에 표면의 깊이에서 `max(slope_bias * (1 - N·L), constant_bias)`를 뺍니다. </br>
+///   빛에 비스듬한 표면일수록 한 텍셀이 덮는 깊이 범위가 커지므로 더 큰 편향이 필요합니다. </br>
+/// 
+/// 편향이 너무 크면 그림자가 오브젝트에서 떨어져 보이므로(peter panning), 상자 크기와 그림자 맵 크기에 맞추어 조절해야 합니다. </br>
+/// 
+/// 카메라 유니폼과 같이 값이 바뀐 경우에만 (`dirty`) `update_shader_resource`에서 유니폼 버퍼를 다시 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The shadow map of a directional light. (see also: `pipeline::ShadowPipelines`) </br>
+/// 
+/// Every frame before the opaque pass, the opaque objects that cast shadows (`ColoredObject::cast_shadow`) are drawn from the light's point of view </br>
+/// into a `ShadowPipelines::DEPTH_FORMAT` depth texture, and the opaque pass filters it with a comparison sampler (3x3 PCF) to get the amount of shadow. </br>
+/// The size of the shadow map is independent of the window size, and it is not recreated when the window is resized. The size only changes with `resize`. </br>
+/// 
+/// Shadow acne: the depth of the shadow map is quantized per texel, so lit surfaces can get striped shadows as if they were occluded by themselves. </br>
+/// Two biases are used to reduce it. </br>
+/// - The pipeline of the shadow map pass uses a hardware depth bias that scales with the slope (`ShadowPipelines::DEPTH_BIAS`). </br>
+/// - The opaque pass subtracts `max(slope_bias * (1 - N·L), constant_bias)` from the surface depth before the comparison. </br>
+///   The more oblique a surface is to the light, the larger the depth range one texel covers, so a larger bias is needed. </br>
+/// 
+/// Too much bias detaches the shadows from the objects (peter panning), so it should be tuned to the box size and the shadow map size. </br>
+/// 
+/// Like the camera uniform, `update_shader_resource` rewrites the uniform buffer only if the values changed (`dirty`). </br>
+/// 
+#[derive(Debug)]
+pub struct ShadowMap {
+    light: DirectionalLight, 
+    size: u32, 
+    constant_bias: f32, 
+    slope_bias: f32, 
+    enabled: bool, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    light_bind_group: wgpu::BindGroup, 
+    depth_view: wgpu::TextureView, 
+    sampler: wgpu::Sampler, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl ShadowMap {
+    pub const DEFAULT_SIZE: u32 = 2048;
+    pub const DEFAULT_CONSTANT_BIAS: f32 = 0.0005;
+    pub const DEFAULT_SLOPE_BIAS: f32 = 0.003;
+
+    pub fn new(device: &wgpu::Device, layouts: &ShadowBindGroupLayouts) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Shadow)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ShadowUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let light_bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(ShadowLight)"), 
+                layout: &layouts.light, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        // (한국어) 
+        // 비교 샘플러는 텍셀의 깊이와 기준 깊이를 비교한 결과(0 또는 1)를 선형 필터링하므로 하드웨어 PCF가 적용됩니다.
+        // 그림자 맵 밖은 쉐이더에서 빛을 받는 것으로 처리합니다.
+        // 
+        // (English Translation) 
+        // The comparison sampler linearly filters the results (0 or 1) of comparing the texel depths with the reference depth, so hardware PCF is applied.
+        // Outside the shadow map is treated as lit in the shader.
+        // 
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sampler(ShadowMap)"), 
+            address_mode_u: wgpu::AddressMode::ClampToEdge, 
+            address_mode_v: wgpu::AddressMode::ClampToEdge, 
+            address_mode_w: wgpu::AddressMode::ClampToEdge, 
+            mag_filter: wgpu::FilterMode::Linear, 
+            min_filter: wgpu::FilterMode::Linear, 
+            compare: Some(wgpu::CompareFunction::LessEqual), 
+            ..Default::default()
+        });
+
+        let size = Self::DEFAULT_SIZE.min(device.limits().max_texture_dimension_2d);
+        let depth_view = utils::create_depth_stencil_view(device, "ShadowMap", ShadowPipelines::DEPTH_FORMAT, size, size, 1);
+        let bind_group = create_shadow_map_bind_group(device, &layouts.shadow_map, &buffer, &depth_view, &sampler);
+
+        Self {
+            light: DirectionalLight::default(), 
+            size, 
+            constant_bias: Self::DEFAULT_CONSTANT_BIAS, 
+            slope_bias: Self::DEFAULT_SLOPE_BIAS, 
+            enabled: true, 
+            dirty: Cell::new(true), 
+            buffer, 
+            light_bind_group, 
+            depth_view, 
+            sampler, 
+            bind_group, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자 맵의 크기(한 변의 텍셀 수)를 바꾸고 깊이 텍스처와 바인드 그룹을 다시 생성합니다. </br>
+    /// 크기가 장치의 제한을 넘는 경우 `ResourceError::TextureTooLarge`를 반환하고 기존 그림자 맵을 유지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the size (texels per side) of the shadow map and recreates the depth texture and the bind group. </br>
+    /// Returns `ResourceError::TextureTooLarge` and keeps the existing shadow map if the size exceeds the device limits. </br>
+    /// 
+    pub fn resize(&mut self, device: &wgpu::Device, layouts: &ShadowBindGroupLayouts, size: u32) -> Result<(), ResourceError> {
+        resources::validate_texture_size(&device.limits(), size, size)?;
+        if size == self.size {
+            return Ok(());
+        }
+
+        self.depth_view = utils::create_depth_stencil_view(device, "ShadowMap", ShadowPipelines::DEPTH_FORMAT, size, size, 1);
+        self.bind_group = create_shadow_map_bind_group(device, &layouts.shadow_map, &self.buffer, &self.depth_view, &self.sampler);
+        self.size = size;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    #[inline]
+    pub fn light(&self) -> &DirectionalLight {
+        &self.light
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자를 드리우는 방향광을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the directional light that casts shadows. </br>
+    /// 
+    #[inline]
+    pub fn set_light(&mut self, light: DirectionalLight) {
+        if self.light != light {
+            self.light = light;
+            self.mark_dirty();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 빛의 방향만 바꿉니다. 렌더링 설정의 방향광(`settings::LightSettings::direction`)과 맞추는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes only the light direction. Used to match the directional light of the rendering settings (`settings::LightSettings::direction`). </br>
+    /// 
+    #[inline]
+    pub fn set_light_direction(&mut self, direction: glam::Vec3) {
+        self.set_light(DirectionalLight { direction, ..self.light });
+    }
+
+    #[inline]
+    pub fn bias(&self) -> (f32, f32) {
+        (self.constant_bias, self.slope_bias)
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스가 비교 전에 빼는 깊이 편향을 설정합니다. 음수는 0으로 제한됩니다. </br>
+    /// (기본값: `DEFAULT_CONSTANT_BIAS`, `DEFAULT_SLOPE_BIAS`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the depth bias the opaque pass subtracts before the comparison. Negative values are clamped to 0. </br>
+    /// (default: `DEFAULT_CONSTANT_BIAS`, `DEFAULT_SLOPE_BIAS`) </br>
+    /// 
+    #[inline]
+    pub fn set_bias(&mut self, constant_bias: f32, slope_bias: f32) {
+        self.constant_bias = constant_bias.max(0.0);
+        self.slope_bias = slope_bias.max(0.0);
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자의 사용 여부를 설정합니다. (기본값: `true`) 꺼져 있는 경우 그림자 맵 패스를 건너뛰고 그림자 없는 불투명 파이프라인을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to use shadows. (default: `true`) If disabled, the shadow map pass is skipped and the opaque pipeline without shadows is used. </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn ref_depth_view(&self) -> &wgpu::TextureView {
+        &self.depth_view
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자 맵 패스의 빛 바인드 그룹(바인드 그룹 슬롯 0)을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the light bind group of the shadow map pass (bind group slot 0). </br>
+    /// 
+    #[inline]
+    pub fn ref_light_bind_group(&self) -> &wgpu::BindGroup {
+        &self.light_bind_group
+    }
+
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+}
+
+impl ShaderResource for ShadowMap {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        let data = ShadowUniformLayout {
+            light_view_projection: self.light.view_projection(), 
+            params: glam::Vec4::new(self.constant_bias, self.slope_bias, 0.0, 0.0), 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    /// #### 한국어 </br>
+    /// 불투명 패스가 그림자 맵을 읽는 바인드 그룹(바인드 그룹 슬롯 3)을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the bind group the opaque pass reads the shadow map from (bind group slot 3). </br>
+    /// 
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+fn create_shadow_map_bind_group(
+    device: &wgpu::Device, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    buffer: &wgpu::Buffer, 
+    depth_view: &wgpu::TextureView, 
+    sampler: &wgpu::Sampler
+) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(ShadowMap)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::Buffer(
+                        buffer.as_entire_buffer_binding()
+                    ), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::TextureView(depth_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 2, 
+                    resource: wgpu::BindingResource::Sampler(sampler), 
+                }, 
+            ], 
+        }, 
+    )
+}
@@ -0,0 +1,341 @@
+use std::mem;
+use crate::interfaces::ShaderResource;
+use crate::objects::ColoredObject;
+
+
+
+/// #### 한국어 </br>
+/// 스키닝 메쉬의 정점 데이터 레이아웃 입니다. </br>
+/// 각 정점은 최대 네 개의 뼈(`joints`, `Uint16x4`)에 가중치(`weights`, `Float32x4`)만큼 영향을 받습니다. </br>
+/// 위치 0~3의 정점 속성으로 전달되며, 쉐이더의 `SkinnedVertexInput`과 일치해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex data layout of a skinned mesh. </br>
+/// Each vertex is influenced by up to four bones (`joints`, `Uint16x4`) by their weights (`weights`, `Float32x4`). </br>
+/// Passed as the vertex attributes at locations 0-3, and must match `SkinnedVertexInput` in the shader. </br>
+/// 
+#[repr(C)]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SkinnedVertex {
+    pub position: [f32; 3], 
+    pub normal: [f32; 3], 
+    pub joints: [u16; 4], 
+    pub weights: [f32; 4], 
+}
+
+impl SkinnedVertex {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        0 => Float32x3, 
+        1 => Float32x3, 
+        2 => Uint16x4, 
+        3 => Float32x4
+    ];
+
+    /// #### 한국어 </br>
+    /// 새 정점을 생성합니다. 가중치의 합이 1이 되도록 정규화하며, 합이 0인 경우 첫 번째 뼈에 모든 가중치를 줍니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new vertex. The weights are normalized to sum to one, and if the sum is zero, the first bone gets all the weight. </br>
+    /// 
+    pub fn new(position: glam::Vec3, normal: glam::Vec3, joints: [u16; 4], weights: glam::Vec4) -> Self {
+        let weights = weights.max(glam::Vec4::ZERO);
+        let sum = weights.dot(glam::Vec4::ONE);
+        let weights = match sum > f32::EPSILON {
+            true => weights / sum, 
+            false => glam::Vec4::X, 
+        };
+
+        Self {
+            position: position.to_array(), 
+            normal: normal.to_array(), 
+            joints, 
+            weights: weights.to_array(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 정점 단위로 진행하는 정점 버퍼 레이아웃을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the vertex buffer layout that steps per vertex. </br>
+    /// 
+    #[inline]
+    pub fn vertex_buffer_layout() -> wgpu::VertexBufferLayout<'static> {
+        wgpu::VertexBufferLayout {
+            step_mode: wgpu::VertexStepMode::Vertex, 
+            array_stride: mem::size_of::<Self>() as wgpu::BufferAddress, 
+            attributes: &Self::ATTRIBUTES, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 뼈 행렬 저장 버퍼의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 3) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the bone matrix storage buffer. (bind group slot 3) </br>
+/// 
+pub fn create_skeleton_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(Skeleton)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::VERTEX, 
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true }, 
+                        has_dynamic_offset: false, 
+                        min_binding_size: None
+                    }, 
+                    count: None, 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 스키닝 메쉬를 움직이는 뼈대 입니다. </br>
+/// 각 뼈 행렬은 오브젝트의 로컬 좌표계에서 `뼈의 현재 변환 * 역 바인드 행렬` 이며, 저장 버퍼에 올라갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A skeleton that animates skinned meshes. </br>
+/// Each bone matrix is `current bone transform * inverse bind matrix` in the local space of the object, and is uploaded to a storage buffer. </br>
+/// 
+#[derive(Debug)]
+pub struct Skeleton {
+    bone_matrices: Vec<glam::Mat4>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+impl Skeleton {
+    /// #### 한국어 </br>
+    /// 모든 뼈 행렬이 단위 행렬인 뼈대를 생성합니다. (바인드 자세) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a skeleton whose bone matrices are all identity. (bind pose) </br>
+    /// 
+    pub fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout, bone_count: usize) -> Self {
+        let bone_matrices = vec![glam::Mat4::IDENTITY; bone_count.max(1)];
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("StorageBuffer(Skeleton)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of_val(bone_matrices.as_slice()) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Skeleton)"), 
+                layout: &bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self { bone_matrices, buffer, bind_group }
+    }
+
+    /// #### 한국어 </br>
+    /// 뼈 행렬을 설정합니다. 범위를 벗어난 뼈는 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets a bone matrix. Out-of-range bones are ignored. </br>
+    /// 
+    #[inline]
+    pub fn set_bone_matrix(&mut self, index: usize, matrix: glam::Mat4) {
+        if let Some(bone) = self.bone_matrices.get_mut(index) {
+            *bone = matrix;
+        }
+    }
+}
+
+impl ShaderResource for Skeleton {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(&self.bone_matrices));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// #### 한국어 </br>
+/// GPU 버퍼에 올라간 스키닝 메쉬 입니다. (정점 버퍼, 32비트 인덱스 버퍼) </br>
+/// 
+/// #### English (Translation) </br>
+/// A skinned mesh uploaded to GPU buffers. (vertex buffer, 32-bit index buffer) </br>
+/// 
+#[derive(Debug)]
+pub struct SkinnedMesh {
+    vertex_buffer: wgpu::Buffer, 
+    index_buffer: wgpu::Buffer, 
+    index_count: u32, 
+}
+
+impl SkinnedMesh {
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue, vertices: &[SkinnedVertex], indices: &[u32]) -> Self {
+        let vertex_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("VertexBuffer(SkinnedMesh)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of_val(vertices) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+        queue.write_buffer(&vertex_buffer, 0, bytemuck::cast_slice(vertices));
+
+        let index_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("IndexBuffer(SkinnedMesh)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of_val(indices) as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+        queue.write_buffer(&index_buffer, 0, bytemuck::cast_slice(indices));
+
+        Self {
+            vertex_buffer, 
+            index_buffer, 
+            index_count: indices.len() as u32, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 스키닝 메쉬를 그립니다. 오브젝트는 바인드 그룹 슬롯 1에, 뼈대는 바인드 그룹 슬롯 3에 바인딩 됩니다. </br>
+    /// 스키닝 파이프라인(`Pipelines::skinned`)과 카메라, 전역 바인드 그룹은 미리 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the skinned mesh. The object is bound to bind group slot 1, and the skeleton to bind group slot 3. </br>
+    /// The skinning pipeline (`Pipelines::skinned`) and the camera and global bind groups must already be set. </br>
+    /// 
+    pub fn draw<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>, object: &'pass ColoredObject, skeleton: &'pass Skeleton) {
+        rpass.set_bind_group(1, object.ref_bind_group(), &[]);
+        rpass.set_bind_group(3, skeleton.ref_bind_group(), &[]);
+        rpass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        rpass.set_index_buffer(self.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        rpass.draw_indexed(0..self.index_count, 0, 0..1);
+    }
+}
+
+/// #### 한국어 </br>
+/// 두 개의 뼈로 휘어지는 사각기둥 메쉬의 정점과 인덱스를 생성합니다. </br>
+/// 기둥은 `y = 0`에서 `y = height`까지 서 있으며, 아래쪽 절반은 뼈 0, 위쪽 절반은 뼈 1을 따르고 가운데 높이에서는 두 뼈가 반씩 섞입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the vertices and indices of a square column mesh that bends with two bones. </br>
+/// The column stands from `y = 0` to `y = height`; the lower half follows bone 0, the upper half follows bone 1, and the two bones blend half and half at the middle height. </br>
+/// 
+pub fn two_bone_column(width: f32, height: f32) -> (Vec<SkinnedVertex>, Vec<u32>) {
+    let half = width * 0.5;
+    // (한국어) 각 옆면의 바깥 방향 법선과 면을 가로지르는 방향 입니다.
+    // (English Translation) The outward normal of each side face and the direction across the face.
+    let faces = [
+        (glam::Vec3::Z, glam::Vec3::X), 
+        (glam::Vec3::X, glam::Vec3::NEG_Z), 
+        (glam::Vec3::NEG_Z, glam::Vec3::NEG_X), 
+        (glam::Vec3::NEG_X, glam::Vec3::Z), 
+    ];
+    let rows = [(0.0, glam::Vec4::X), (0.5, glam::Vec4::new(0.5, 0.5, 0.0, 0.0)), (1.0, glam::Vec4::Y)];
+
+    let mut vertices = Vec::with_capacity(faces.len() * rows.len() * 2);
+    let mut indices = Vec::with_capacity(faces.len() * (rows.len() - 1) * 6);
+    for (normal, across) in faces {
+        let base = vertices.len() as u32;
+        for (t, weights) in rows {
+            let center = normal * half + glam::Vec3::Y * (height * t);
+            vertices.push(SkinnedVertex::new(center - across * half, normal, [0, 1, 0, 0], weights));
+            vertices.push(SkinnedVertex::new(center + across * half, normal, [0, 1, 0, 0], weights));
+        }
+        for row in 0..(rows.len() as u32 - 1) {
+            let i = base + row * 2;
+            indices.extend_from_slice(&[i, i + 1, i + 3, i, i + 3, i + 2]);
+        }
+    }
+    (vertices, indices)
+}
+
+/// #### 한국어 </br>
+/// 스키닝 메쉬, 색상 오브젝트와 뼈대를 묶은 오브젝트 입니다. </br>
+/// 오브젝트의 월드 행렬과 색상은 바인드 그룹 슬롯 1에, 뼈 행렬은 바인드 그룹 슬롯 3에 바인딩 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An object that bundles a skinned mesh, a colored object and a skeleton. </br>
+/// The world matrix and color of the object are bound to bind group slot 1, and the bone matrices to bind group slot 3. </br>
+/// 
+#[derive(Debug)]
+pub struct SkinnedObject {
+    mesh: SkinnedMesh, 
+    object: ColoredObject, 
+    skeleton: Skeleton, 
+}
+
+impl SkinnedObject {
+    #[inline]
+    pub fn new(mesh: SkinnedMesh, object: ColoredObject, skeleton: Skeleton) -> Self {
+        Self { mesh, object, skeleton }
+    }
+
+    #[inline]
+    pub fn ref_object(&self) -> &ColoredObject {
+        &self.object
+    }
+
+    #[inline]
+    pub fn ref_skeleton(&self) -> &Skeleton {
+        &self.skeleton
+    }
+
+    #[inline]
+    pub fn mut_skeleton(&mut self) -> &mut Skeleton {
+        &mut self.skeleton
+    }
+
+    /// #### 한국어 </br>
+    /// 스키닝 메쉬를 그립니다. 메쉬를 그린 후에는 사각형 메쉬를 다시 바인딩해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the skinned mesh. The quad mesh must be bound again after drawing the mesh. </br>
+    /// 
+    #[inline]
+    pub fn draw<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>) {
+        self.mesh.draw(rpass, &self.object, &self.skeleton);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_bone_column_blends_the_bones_by_height() {
+        let (vertices, indices) = two_bone_column(0.5, 2.0);
+        assert_eq!(vertices.len(), 24);
+        assert_eq!(indices.len(), 48);
+        assert!(indices.iter().all(|&index| (index as usize) < vertices.len()));
+        for vertex in vertices.iter() {
+            let expected = if vertex.position[1] < 0.5 {
+                [1.0, 0.0, 0.0, 0.0]
+            } else if vertex.position[1] < 1.5 {
+                [0.5, 0.5, 0.0, 0.0]
+            } else {
+                [0.0, 1.0, 0.0, 0.0]
+            };
+            assert_eq!(vertex.weights, expected);
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use std::mem;
+use std::cell::Cell;
+use crate::interfaces::ShaderResource;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 하늘 배경의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of the sky background passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SkyUniformLayout {
+    pub top_color: glam::Vec4, 
+    pub bottom_color: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// 하늘 배경을 그리는 방법 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// How the sky background is drawn. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SkyMode {
+    /// #### 한국어 </br>
+    /// 바라보는 방향의 높이에 따라 아래쪽 색상에서 위쪽 색상으로 섞습니다. 텍스처가 필요하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Blends from the bottom color to the top color by the height of the view direction. No texture is required. </br>
+    /// 
+    #[default]
+    Gradient, 
+
+    /// #### 한국어 </br>
+    /// 바라보는 방향으로 큐브 텍스처를 샘플링합니다. `SkySettings::set_cubemap`으로 큐브 텍스처를 설정해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Samples a cube texture in the view direction. The cube texture must be set with `SkySettings::set_cubemap`. </br>
+    /// 
+    Cubemap, 
+}
+
+/// #### 한국어 </br>
+/// 모든 오브젝트 뒤에 그려지는 하늘 배경의 설정 입니다. </br>
+/// 
+/// 하늘은 메쉬 없이 전체 화면 삼각형(`vs_sky`)으로 불투명 패스에서 가장 먼저 그려집니다. </br>
+/// 조각 쉐이더는 카메라 유니폼의 뷰 행렬의 역행렬(카메라 위치)과 뷰-투영 행렬의 역행렬로 각 픽셀의 바라보는 방향을 구합니다. </br>
+/// 기본값은 텍스처가 필요하지 않은 두 색상의 수직 그라디언트(`SkyMode::Gradient`)이며, </br>
+/// 큐브 텍스처(`SkyMode::Cubemap`)는 `set_cubemap`으로 큐브 텍스처 바인드 그룹을 설정한 경우에만 사용할 수 있습니다. </br>
+/// 
+/// 하늘을 그리는 경우 화면 전체를 덮으므로 배경 색상(`RenderSettings::clear_color`)은 보이지 않습니다. </br>
+/// 깊이와의 관계는 `pipeline::create_sky_pipeline`을 참고하세요. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings of the sky background drawn behind all objects. </br>
+/// 
+/// The sky is drawn without a mesh as a full-screen triangle (`vs_sky`), first in the opaque pass. </br>
+/// The fragment shader computes the view direction of each pixel with the inverse view matrix (camera position) and the inverse view-projection matrix of the camera uniform. </br>
+/// The default is a vertical gradient of two colors that needs no texture (`SkyMode::Gradient`), </br>
+/// and the cube texture (`SkyMode::Cubemap`) is only available if a cube texture bind group was set with `set_cubemap`. </br>
+/// 
+/// When the sky is drawn it covers the whole screen, so the background color (`RenderSettings::clear_color`) is not visible. </br>
+/// See `pipeline::create_sky_pipeline` for how it interacts with depth. </br>
+/// 
+#[derive(Debug)]
+pub struct SkySettings {
+    enabled: bool, 
+    mode: SkyMode, 
+    top_color: glam::Vec4, 
+    bottom_color: glam::Vec4, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+    cubemap_bind_group: Option<wgpu::BindGroup>, 
+}
+
+#[allow(dead_code)]
+impl SkySettings {
+    pub const DEFAULT_TOP_COLOR: glam::Vec4 = glam::Vec4::new(0.15, 0.35, 0.75, 1.0);
+    pub const DEFAULT_BOTTOM_COLOR: glam::Vec4 = glam::Vec4::new(0.75, 0.8, 0.85, 1.0);
+
+    pub fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(Sky)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<SkyUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(Sky)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            enabled: true, 
+            mode: SkyMode::Gradient, 
+            top_color: Self::DEFAULT_TOP_COLOR, 
+            bottom_color: Self::DEFAULT_BOTTOM_COLOR, 
+            dirty: Cell::new(true), 
+            buffer, 
+            bind_group, 
+            cubemap_bind_group: None, 
+        }
+    }
+
+    #[inline]
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// #### 한국어 </br>
+    /// 하늘을 그리는지 여부를 설정합니다. 그리지 않는 경우 배경 색상으로 지워진 화면이 보입니다. (기본값: `true`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether to draw the sky. If not drawn, the screen cleared with the background color is visible. (default: `true`) </br>
+    /// 
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    #[inline]
+    pub fn mode(&self) -> SkyMode {
+        self.mode
+    }
+
+    /// #### 한국어 </br>
+    /// 하늘을 그리는 방법을 설정합니다. </br>
+    /// 큐브 텍스처가 설정되지 않은 상태에서 `SkyMode::Cubemap`을 설정하면 경고를 출력하고 그라디언트를 유지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets how the sky is drawn. </br>
+    /// If `SkyMode::Cubemap` is set while no cube texture is set, a warning is printed and the gradient is kept. </br>
+    /// 
+    pub fn set_mode(&mut self, mode: SkyMode) {
+        if mode == SkyMode::Cubemap && self.cubemap_bind_group.is_none() {
+            log::warn!("Sky cubemap mode requires a cube texture. Fall back to the gradient.");
+            self.mode = SkyMode::Gradient;
+            return;
+        }
+        self.mode = mode;
+    }
+
+    #[inline]
+    pub fn top_color(&self) -> glam::Vec4 {
+        self.top_color
+    }
+
+    #[inline]
+    pub fn bottom_color(&self) -> glam::Vec4 {
+        self.bottom_color
+    }
+
+    /// #### 한국어 </br>
+    /// 그라디언트의 위쪽(바로 위를 바라볼 때)과 아래쪽(바로 아래를 바라볼 때) 색상을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the top (looking straight up) and bottom (looking straight down) colors of the gradient. </br>
+    /// 
+    #[inline]
+    pub fn set_gradient(&mut self, top_color: glam::Vec4, bottom_color: glam::Vec4) {
+        self.top_color = top_color;
+        self.bottom_color = bottom_color;
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn ref_cubemap_bind_group(&self) -> Option<&wgpu::BindGroup> {
+        self.cubemap_bind_group.as_ref()
+    }
+
+    /// #### 한국어 </br>
+    /// 큐브맵 모드에서 샘플링할 큐브 텍스처 바인드 그룹을 설정합니다. (참고: `create_sky_cubemap_bind_group`) </br>
+    /// `None`을 설정하면 그라디언트 모드로 돌아갑니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the cube texture bind group sampled in cubemap mode. (see also: `create_sky_cubemap_bind_group`) </br>
+    /// Setting `None` returns to gradient mode. </br>
+    /// 
+    pub fn set_cubemap(&mut self, cubemap_bind_group: Option<wgpu::BindGroup>) {
+        if cubemap_bind_group.is_none() {
+            self.mode = SkyMode::Gradient;
+        }
+        self.cubemap_bind_group = cubemap_bind_group;
+    }
+
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+}
+
+impl ShaderResource for SkySettings {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        let data = SkyUniformLayout {
+            top_color: self.top_color, 
+            bottom_color: self.bottom_color, 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// #### 한국어 </br>
+/// 하늘 배경의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 1) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the sky background. (bind group slot 1) </br>
+/// 
+#[inline]
+pub fn create_sky_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    utils::create_uniform_bind_group_layout(device, "BindGroupLayout(Sky)", wgpu::ShaderStages::FRAGMENT)
+}
+
+/// #### 한국어 </br>
+/// 하늘 큐브 텍스처의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 2, 큐브 텍스처와 샘플러) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the sky cube texture. (bind group slot 2, cube texture and sampler) </br>
+/// 
+pub fn create_sky_cubemap_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(SkyCubemap)"), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                        view_dimension: wgpu::TextureViewDimension::Cube, 
+                        multisampled: false, 
+                    }, 
+                    count: None, 
+                }, 
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1, 
+                    visibility: wgpu::ShaderStages::FRAGMENT, 
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering), 
+                    count: None, 
+                }, 
+            ], 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 주어진 큐브 텍스처 뷰로 하늘 큐브 텍스처 바인드 그룹을 생성합니다. </br>
+/// 뷰의 차원은 `TextureViewDimension::Cube`여야 합니다. (예: `renderer::CubemapCapture::cube_view`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a sky cube texture bind group with the given cube texture view. </br>
+/// The dimension of the view must be `TextureViewDimension::Cube`. (e.g. `renderer::CubemapCapture::cube_view`) </br>
+/// 
+pub fn create_sky_cubemap_bind_group(
+    device: &wgpu::Device, 
+    bind_group_layout: &wgpu::BindGroupLayout, 
+    cube_view: &wgpu::TextureView
+) -> wgpu::BindGroup {
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        label: Some("Sampler(SkyCubemap)"), 
+        address_mode_u: wgpu::AddressMode::ClampToEdge, 
+        address_mode_v: wgpu::AddressMode::ClampToEdge, 
+        address_mode_w: wgpu::AddressMode::ClampToEdge, 
+        mag_filter: wgpu::FilterMode::Linear, 
+        min_filter: wgpu::FilterMode::Linear, 
+        ..Default::default()
+    });
+
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(SkyCubemap)"), 
+            layout: bind_group_layout, 
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0, 
+                    resource: wgpu::BindingResource::TextureView(cube_view), 
+                }, 
+                wgpu::BindGroupEntry {
+                    binding: 1, 
+                    resource: wgpu::BindingResource::Sampler(&sampler), 
+                }, 
+            ], 
+        }, 
+    )
+}
@@ -17,12 +17,32 @@ pub struct GameTimer<const NUM_SAMPLE: usize = 50> {
     cnt_frame_times: usize, 
 
     elapsed_time_sec: f64,
+    frame_time_sec: f64, 
+    total_time_sec: f64, 
+    total_game_time_sec: f64, 
     fps_elapsed_time_sec: f64, 
     frame_per_seconds: u64, 
     frame_rate: u64, 
+    frame_count: u64, 
+
+    fixed_step_accumulator_sec: f64, 
+
+    time_scale: f64, 
+    paused: bool, 
 }
 
+#[allow(dead_code)]
 impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
+    /// #### 한국어 </br>
+    /// `consume_fixed_steps` 함수가 한 프레임에 반환하는 고정 단계의 최대 수 입니다. </br>
+    /// 오랫동안 멈춘 후 밀린 단계를 한꺼번에 처리하느라 프레임이 더 느려지는 현상(spiral of death)을 막습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum number of fixed steps the `consume_fixed_steps` function returns in one frame. </br>
+    /// Prevents the spiral of death where catching up on steps after a long stall makes frames even slower. </br>
+    /// 
+    pub const MAX_FIXED_STEPS: u32 = 8;
+
     #[inline]
     pub fn new() -> Self {
         let timepoint = Instant::now();
@@ -32,9 +52,16 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
             frame_times: [0.0; NUM_SAMPLES], 
             cnt_frame_times: 0, 
             elapsed_time_sec: 0.0, 
+            frame_time_sec: 0.0, 
+            total_time_sec: 0.0, 
+            total_game_time_sec: 0.0, 
             fps_elapsed_time_sec: 0.0, 
             frame_per_seconds: 0, 
             frame_rate: 0,
+            frame_count: 0, 
+            fixed_step_accumulator_sec: 0.0, 
+            time_scale: 1.0, 
+            paused: false, 
         }
     }
 
@@ -51,6 +78,20 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
             .as_secs_f64();
 
         self.previous_timepoint = self.current_timepoint;
+        self.advance(elapsed_time_sec);
+    }
+
+    /// #### 한국어 </br>
+    /// 측정한 프레임 시간(`elapsed_time_sec`, 초 단위)만큼 타이머를 진행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the timer by a measured frame time (`elapsed_time_sec`, in seconds). </br>
+    /// 
+    fn advance(&mut self, elapsed_time_sec: f64) {
+        self.frame_time_sec = elapsed_time_sec;
+        self.total_time_sec += elapsed_time_sec;
+        self.total_game_time_sec += elapsed_time_sec * self.game_time_scale();
+        self.fixed_step_accumulator_sec += elapsed_time_sec;
 
         if (self.elapsed_time_sec - elapsed_time_sec).abs() < 1.0 {
             self.frame_times.copy_within(0..(NUM_SAMPLES - 1), 1);
@@ -58,6 +99,7 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
             self.cnt_frame_times = (self.cnt_frame_times + 1).min(NUM_SAMPLES);
         }
 
+        self.frame_count += 1;
         self.frame_per_seconds += 1;
         self.fps_elapsed_time_sec += elapsed_time_sec;
         if self.fps_elapsed_time_sec > 1.0 {
@@ -76,13 +118,428 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
         }
     }
 
+    /// #### 한국어 </br>
+    /// 평균을 낸 프레임 시간에 시간 배율을 곱한 게임 시간을 가져옵니다. 일시 정지된 경우 `0.0`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the game time, which is the averaged frame time multiplied by the time scale. Returns `0.0` while paused. </br>
+    /// 
     #[inline]
     pub fn elapsed_time_sec(&self) -> f32 {
+        (self.elapsed_time_sec * self.game_time_scale()) as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 시간 배율과 일시 정지의 영향을 받지 않는 평균을 낸 실제 프레임 시간을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the averaged real frame time, unaffected by the time scale and pausing. </br>
+    /// 
+    #[inline]
+    pub fn real_elapsed_time_sec(&self) -> f32 {
         self.elapsed_time_sec as f32
     }
 
+    /// #### 한국어 </br>
+    /// 게임 시간의 배율을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the scale of game time. </br>
+    /// 
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 게임 시간의 배율을 설정합니다. (예: `0.5`는 슬로우 모션) 음수는 `0.0`으로 제한됩니다. </br>
+    /// 초당 프레임 수와 프레임 시간은 실제 시간을 사용하므로 영향을 받지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the scale of game time. (e.g. `0.5` is slow motion) Negative values are clamped to `0.0`. </br>
+    /// The frames per second and frame time use real time, so they are not affected. </br>
+    /// 
+    #[inline]
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0) as f64;
+    }
+
+    /// #### 한국어 </br>
+    /// 게임 시간을 멈춥니다. 시간 배율은 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Freezes game time. The time scale is kept. </br>
+    /// 
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 멈춘 게임 시간을 설정된 시간 배율로 다시 흐르게 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resumes frozen game time at the configured time scale. </br>
+    /// 
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// #### 한국어 </br>
+    /// 일시 정지를 반영한 게임 시간의 배율 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The scale of game time with pausing taken into account. </br>
+    /// 
+    #[inline]
+    fn game_time_scale(&self) -> f64 {
+        match self.paused {
+            true => 0.0, 
+            false => self.time_scale, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 평균을 내지 않은 마지막 프레임의 시간을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the time of the last frame without averaging. </br>
+    /// 
+    #[inline]
+    pub fn frame_time_sec(&self) -> f32 {
+        self.frame_time_sec as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 타이머가 생성된 이후로 흐른 전체 시간을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the total time elapsed since the timer was created. </br>
+    /// 
+    #[inline]
+    pub fn total_time_sec(&self) -> f32 {
+        self.total_time_sec as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 타이머가 생성된 이후로 흐른 게임 시간의 합을 가져옵니다. 시간 배율이 적용되며, 일시 정지된 동안은 늘어나지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the total game time elapsed since the timer was created. The time scale is applied, and it does not advance while paused. </br>
+    /// 
+    #[inline]
+    pub fn total_game_time_sec(&self) -> f32 {
+        self.total_game_time_sec as f32
+    }
+
     #[inline]
     pub fn frame_rate(&self) -> u32 {
         self.frame_rate as u32
     }
-}
\ No newline at end of file
+
+    /// #### 한국어 </br>
+    /// 평균을 낸 프레임 시간으로 계산한 초당 프레임 수를 가져옵니다. </br>
+    /// 첫 번째 `tick` 함수를 호출하기 전처럼 평균 프레임 시간이 0인 경우 `0.0`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the frames per second computed from the averaged frame time. </br>
+    /// Returns `0.0` if the averaged frame time is zero, such as before the first call to the `tick` function. </br>
+    /// 
+    #[inline]
+    pub fn fps(&self) -> f32 {
+        match self.elapsed_time_sec > 0.0 {
+            true => (1.0 / self.elapsed_time_sec) as f32, 
+            false => 0.0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 평균을 낸 프레임 시간을 밀리초 단위로 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the averaged frame time in milliseconds. </br>
+    /// 
+    #[inline]
+    pub fn frame_time_ms(&self) -> f32 {
+        (self.elapsed_time_sec * 1000.0) as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 타이머가 생성된 이후로 `tick` 함수를 호출한 횟수를 가져옵니다. (프레임 번호) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the number of times the `tick` function has been called since the timer was created. (frame number) </br>
+    /// 
+    #[inline]
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// #### 한국어 </br>
+    /// `tick` 함수가 누적한 실제 경과 시간에서 `step_sec` 길이의 고정 단계들을 꺼내고, 이번 프레임에 실행할 단계의 수를 반환합니다. </br>
+    /// 남은 시간은 다음 프레임으로 이월됩니다. </br>
+    /// 누적 시간은 실제 시간이므로 시간 배율과 일시 정지의 영향을 받지 않습니다. </br>
+    /// 단계의 수는 `MAX_FIXED_STEPS`로 제한되며, 제한을 넘은 경우 밀린 시간은 버리고 한 단계보다 짧은 나머지만 남깁니다. </br>
+    /// `step_sec`가 0 이하인 경우 `0`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Takes fixed steps of `step_sec` length out of the real elapsed time accumulated by the `tick` function, </br>
+    /// and returns the number of steps to run this frame. The remaining time is carried over to the next frame. </br>
+    /// The accumulated time is real time, so it is not affected by the time scale or pausing. </br>
+    /// The number of steps is capped at `MAX_FIXED_STEPS`, and if the cap is exceeded, </br>
+    /// the backlog is discarded and only the remainder shorter than one step is kept. </br>
+    /// Returns `0` if `step_sec` is zero or less. </br>
+    /// 
+    pub fn consume_fixed_steps(&mut self, step_sec: f32) -> u32 {
+        if step_sec <= 0.0 {
+            return 0;
+        }
+
+        let step_sec = step_sec as f64;
+        let steps = (self.fixed_step_accumulator_sec / step_sec).floor();
+        if steps > Self::MAX_FIXED_STEPS as f64 {
+            self.fixed_step_accumulator_sec %= step_sec;
+            Self::MAX_FIXED_STEPS
+        } else {
+            self.fixed_step_accumulator_sec -= steps * step_sec;
+            steps as u32
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 고정 단계로 아직 처리하지 않은 누적 시간을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the accumulated time not yet consumed as fixed steps. </br>
+    /// 
+    #[inline]
+    pub fn fixed_step_accumulator_sec(&self) -> f32 {
+        self.fixed_step_accumulator_sec as f32
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 최근 프레임 시간들을 저장하는 고정 크기의 원형 버퍼 입니다. </br>
+/// 버퍼가 가득 찬 경우 가장 오래된 값을 덮어씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A fixed-size ring buffer that stores recent frame times. </br>
+/// When the buffer is full, the oldest value is overwritten. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct FrameTimeHistory<const NUM_SAMPLES: usize = 240> {
+    samples: [f32; NUM_SAMPLES], 
+    head: usize, 
+    len: usize, 
+}
+
+#[allow(dead_code)]
+impl<const NUM_SAMPLES: usize> FrameTimeHistory<NUM_SAMPLES> {
+    #[inline]
+    pub fn new() -> Self {
+        Self { 
+            samples: [0.0; NUM_SAMPLES], 
+            head: 0, 
+            len: 0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 프레임 시간(초)을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a frame time (in seconds). </br>
+    /// 
+    #[inline]
+    pub fn push(&mut self, frame_time_sec: f32) {
+        self.samples[self.head] = frame_time_sec;
+        self.head = (self.head + 1) % NUM_SAMPLES;
+        self.len = (self.len + 1).min(NUM_SAMPLES);
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// #### 한국어 </br>
+    /// 저장된 프레임 시간들을 오래된 순서대로 순회합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Iterates over the stored frame times from oldest to newest. </br>
+    /// 
+    pub fn iter(&self) -> impl Iterator<Item = f32> + '_ {
+        let start = (self.head + NUM_SAMPLES - self.len) % NUM_SAMPLES;
+        (0..self.len).map(move |i| self.samples[(start + i) % NUM_SAMPLES])
+    }
+
+    pub fn average(&self) -> f32 {
+        match self.len {
+            0 => 0.0, 
+            len => self.iter().sum::<f32>() / len as f32, 
+        }
+    }
+
+    pub fn min(&self) -> f32 {
+        self.iter().reduce(f32::min).unwrap_or(0.0)
+    }
+
+    pub fn max(&self) -> f32 {
+        self.iter().reduce(f32::max).unwrap_or(0.0)
+    }
+
+    /// #### 한국어 </br>
+    /// 프레임 시간의 백분위 값을 가져옵니다. (`percentile`: 0.0 ~ 1.0) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the percentile value of the frame times. (`percentile`: 0.0 to 1.0) </br>
+    /// 
+    pub fn percentile(&self, percentile: f32) -> f32 {
+        if self.len == 0 {
+            return 0.0;
+        }
+
+        let mut sorted: Vec<f32> = self.iter().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let index = ((sorted.len() - 1) as f32 * percentile.clamp(0.0, 1.0)).round() as usize;
+        return sorted[index];
+    }
+}
+
+impl<const NUM_SAMPLES: usize> Default for FrameTimeHistory<NUM_SAMPLES> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_approx(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn constant_frames_give_smoothed_fps() {
+        let mut timer = GameTimer::<50>::new();
+        assert_eq!(timer.fps(), 0.0);
+        assert_eq!(timer.frame_time_ms(), 0.0);
+
+        for _ in 0..120 {
+            timer.advance(0.0166);
+        }
+        assert!((timer.fps() - 60.24).abs() < 0.01, "{}", timer.fps());
+        assert_approx(timer.frame_time_ms(), 16.6);
+
+        // (한국어) 일시 정지와 시간 배율은 실제 프레임 시간으로 계산하는 초당 프레임 수에 영향을 주지 않습니다.
+        // (English Translation) Pausing and the time scale do not affect the frames per second computed from the real frame time.
+        timer.pause();
+        timer.advance(0.0166);
+        assert_approx(timer.frame_time_ms(), 16.6);
+    }
+
+    #[test]
+    fn time_scale_half_slows_game_time() {
+        let mut timer = GameTimer::<4>::new();
+        timer.set_time_scale(0.5);
+        timer.advance(0.2);
+        assert_approx(timer.elapsed_time_sec(), 0.1);
+        assert_approx(timer.real_elapsed_time_sec(), 0.2);
+        assert_approx(timer.total_game_time_sec(), 0.1);
+        assert_approx(timer.total_time_sec(), 0.2);
+    }
+
+    #[test]
+    fn time_scale_zero_and_pause_freeze_game_time() {
+        let mut timer = GameTimer::<4>::new();
+        timer.set_time_scale(0.0);
+        timer.advance(0.2);
+        assert_approx(timer.elapsed_time_sec(), 0.0);
+        assert_approx(timer.total_game_time_sec(), 0.0);
+
+        timer.set_time_scale(1.0);
+        timer.pause();
+        timer.advance(0.2);
+        assert_approx(timer.elapsed_time_sec(), 0.0);
+        assert_approx(timer.real_elapsed_time_sec(), 0.2);
+        assert_approx(timer.total_game_time_sec(), 0.0);
+    }
+
+    #[test]
+    fn resume_restores_normal_speed() {
+        let mut timer = GameTimer::<4>::new();
+        timer.pause();
+        timer.advance(0.2);
+        timer.resume();
+        timer.advance(0.2);
+        assert_approx(timer.elapsed_time_sec(), 0.2);
+        assert_approx(timer.total_game_time_sec(), 0.2);
+        assert_approx(timer.total_time_sec(), 0.4);
+    }
+
+    #[test]
+    fn fixed_steps_carry_the_remainder() {
+        let mut timer = GameTimer::<4>::new();
+        timer.advance(0.6);
+        assert_eq!(timer.consume_fixed_steps(0.25), 2);
+        assert_approx(timer.fixed_step_accumulator_sec(), 0.1);
+
+        timer.advance(0.2);
+        assert_eq!(timer.consume_fixed_steps(0.25), 1);
+        assert_approx(timer.fixed_step_accumulator_sec(), 0.05);
+
+        timer.advance(0.1);
+        assert_eq!(timer.consume_fixed_steps(0.25), 0);
+        assert_approx(timer.fixed_step_accumulator_sec(), 0.15);
+    }
+
+    #[test]
+    fn fixed_steps_are_capped_after_a_stall() {
+        let mut timer = GameTimer::<4>::new();
+        timer.advance(10.1);
+        assert_eq!(timer.consume_fixed_steps(0.25), GameTimer::<4>::MAX_FIXED_STEPS);
+        assert_approx(timer.fixed_step_accumulator_sec(), 0.1);
+        assert_eq!(timer.consume_fixed_steps(0.25), 0);
+    }
+
+    #[test]
+    fn fixed_steps_use_real_time() {
+        let mut timer = GameTimer::<4>::new();
+        timer.set_time_scale(0.5);
+        timer.pause();
+        timer.advance(0.5);
+        assert_eq!(timer.consume_fixed_steps(0.25), 2);
+    }
+
+    #[test]
+    fn fixed_steps_reject_non_positive_step() {
+        let mut timer = GameTimer::<4>::new();
+        timer.advance(1.0);
+        assert_eq!(timer.consume_fixed_steps(0.0), 0);
+        assert_eq!(timer.consume_fixed_steps(-1.0), 0);
+        assert_approx(timer.fixed_step_accumulator_sec(), 1.0);
+    }
+}
@@ -0,0 +1,208 @@
+use std::mem;
+use std::cell::Cell;
+use crate::interfaces::ShaderResource;
+use crate::utils;
+
+
+
+/// #### 한국어 </br>
+/// 쉐이더에 전달되는 톤 매핑의 유니폼 데이터 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the uniform data layout of tone mapping passed to the shader. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(bytemuck::Pod, bytemuck::Zeroable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ToneMapUniformLayout {
+    pub params: glam::Vec4, 
+}
+
+/// #### 한국어 </br>
+/// HDR 색상을 화면에 표시할 수 있는 `[0, 1]` 범위로 압축하는 톤 매핑 연산자 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The tone mapping operator that compresses HDR colors into the displayable `[0, 1]` range. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ToneMapOperator {
+    /// #### 한국어 </br>
+    /// `color / (1 + color)`. 색조를 유지하지만 밝은 부분의 대비가 낮고 전체적으로 어둡게 보입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// `color / (1 + color)`. It keeps the hue, but bright areas have low contrast and the image looks darker overall. </br>
+    /// 
+    Reinhard, 
+
+    /// #### 한국어 </br>
+    /// ACES 필름 곡선의 근사 입니다. (Narkowicz, 2015) 어두운 부분의 대비를 높이고 밝은 부분을 부드럽게 포화시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// An approximation of the ACES filmic curve. (Narkowicz, 2015) It raises the contrast of dark areas and saturates bright areas smoothly. </br>
+    /// 
+    #[default]
+    Aces, 
+}
+
+#[allow(dead_code)]
+impl ToneMapOperator {
+    /// #### 한국어 </br>
+    /// 다음 연산자를 가져옵니다. 연산자를 비교할 때 차례로 바꾸는 데 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the next operator. Used to cycle through the operators when comparing them. </br>
+    /// 
+    #[inline]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Reinhard => Self::Aces, 
+            Self::Aces => Self::Reinhard, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// HDR 렌더 타겟을 화면에 쓰는 톤 매핑 패스의 설정 입니다. (참고: `pipeline::HdrPipelines`) </br>
+/// 
+/// 톤 매핑 패스는 노출(`exposure`)을 곱한 HDR 색상에 연산자를 적용합니다. </br>
+/// 화면의 색상 형식이 sRGB 형식이 아닌 경우(`utils::select_surface_format`의 대체 형식) 쉐이더가 직접 감마 보정을 적용하므로, </br>
+/// 생성할 때 화면의 색상 형식이 필요합니다. </br>
+/// 
+/// 카메라 유니폼과 같이 값이 바뀐 경우에만 (`dirty`) `update_shader_resource`에서 유니폼 버퍼를 다시 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings of the tone mapping pass that writes the HDR render target to the surface. (see also: `pipeline::HdrPipelines`) </br>
+/// 
+/// The tone mapping pass applies the operator to the HDR color multiplied by the exposure (`exposure`). </br>
+/// If the surface color format is not an sRGB format (the fallback of `utils::select_surface_format`), the shader applies gamma correction itself, </br>
+/// so the surface color format is required on creation. </br>
+/// 
+/// Like the camera uniform, `update_shader_resource` rewrites the uniform buffer only if the values changed (`dirty`). </br>
+/// 
+#[derive(Debug)]
+pub struct ToneMapSettings {
+    operator: ToneMapOperator, 
+    exposure: f32, 
+    encode_srgb: bool, 
+    dirty: Cell<bool>, 
+    buffer: wgpu::Buffer, 
+    bind_group: wgpu::BindGroup, 
+}
+
+#[allow(dead_code)]
+impl ToneMapSettings {
+    pub const DEFAULT_EXPOSURE: f32 = 1.0;
+
+    pub fn new(device: &wgpu::Device, bind_group_layout: &wgpu::BindGroupLayout, output_format: wgpu::TextureFormat) -> Self {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(ToneMap)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<ToneMapUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(ToneMap)"), 
+                layout: bind_group_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Self {
+            operator: ToneMapOperator::default(), 
+            exposure: Self::DEFAULT_EXPOSURE, 
+            encode_srgb: !output_format.is_srgb(), 
+            dirty: Cell::new(true), 
+            buffer, 
+            bind_group, 
+        }
+    }
+
+    #[inline]
+    pub fn operator(&self) -> ToneMapOperator {
+        self.operator
+    }
+
+    /// #### 한국어 </br>
+    /// 톤 매핑 연산자를 설정합니다. (기본값: `ToneMapOperator::Aces`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the tone mapping operator. (default: `ToneMapOperator::Aces`) </br>
+    /// 
+    #[inline]
+    pub fn set_operator(&mut self, operator: ToneMapOperator) {
+        self.operator = operator;
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn exposure(&self) -> f32 {
+        self.exposure
+    }
+
+    /// #### 한국어 </br>
+    /// 톤 매핑 전에 HDR 색상에 곱하는 노출을 설정합니다. (기본값: `DEFAULT_EXPOSURE`) 음수는 0으로 제한됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the exposure multiplied into the HDR color before tone mapping. (default: `DEFAULT_EXPOSURE`) Negative values are clamped to 0. </br>
+    /// 
+    #[inline]
+    pub fn set_exposure(&mut self, exposure: f32) {
+        self.exposure = exposure.max(0.0);
+        self.mark_dirty();
+    }
+
+    #[inline]
+    pub fn mark_dirty(&self) {
+        self.dirty.set(true);
+    }
+}
+
+impl ShaderResource for ToneMapSettings {
+    fn update_shader_resource(&self, queue: &wgpu::Queue) {
+        // (한국어) 마지막으로 버퍼를 쓴 이후 바뀐 것이 없는 경우 건너뜁니다.
+        // (English Translation) Skips if nothing has changed since the buffer was last written.
+        if !self.dirty.replace(false) {
+            return;
+        }
+
+        let data = ToneMapUniformLayout {
+            params: glam::Vec4::new(
+                self.exposure, 
+                match self.operator {
+                    ToneMapOperator::Reinhard => 0.0, 
+                    ToneMapOperator::Aces => 1.0, 
+                }, 
+                self.encode_srgb as u32 as f32, 
+                0.0
+            ), 
+        };
+        queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&data));
+    }
+
+    #[inline]
+    fn ref_bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+/// #### 한국어 </br>
+/// 톤 매핑 패스의 쉐이더 레이아웃을 생성합니다. (바인드 그룹 슬롯 1) </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shader layout of the tone mapping pass. (bind group slot 1) </br>
+/// 
+#[inline]
+pub fn create_tonemap_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    utils::create_uniform_bind_group_layout(device, "BindGroupLayout(ToneMap)", wgpu::ShaderStages::FRAGMENT)
+}
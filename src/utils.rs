@@ -3,13 +3,75 @@ use winit::window::Window;
 
 
 
+/// #### 한국어 </br>
+/// 사용할 렌더링 어뎁터를 고르는 방법 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// How to pick the rendering adapter to use. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdapterSelector {
+    /// #### 한국어 </br>
+    /// `Instance::enumerate_adapters`가 반환한 순서의 번호로 고릅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Picks by the index in the order returned by `Instance::enumerate_adapters`. </br>
+    /// 
+    Index(usize), 
+
+    /// #### 한국어 </br>
+    /// 어뎁터 이름의 일부로 고릅니다. (대소문자 구분 없음) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Picks by a part of the adapter name. (case-insensitive) </br>
+    /// 
+    Name(String), 
+}
+
+#[allow(dead_code)]
+impl AdapterSelector {
+    /// #### 한국어 </br>
+    /// 문자열이 숫자인 경우 번호로, 그렇지 않은 경우 이름의 일부로 해석합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Interprets the string as an index if it is a number, otherwise as a part of the name. </br>
+    /// 
+    pub fn parse(value: &str) -> Self {
+        match value.parse::<usize>() {
+            Ok(index) => Self::Index(index), 
+            Err(_) => Self::Name(value.to_lowercase()), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 어뎁터가 선택 조건에 맞는지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the adapter matches the selection. </br>
+    /// 
+    fn matches(&self, index: usize, info: &wgpu::AdapterInfo) -> bool {
+        match self {
+            Self::Index(i) => *i == index, 
+            Self::Name(name) => info.name.to_lowercase().contains(name.as_str()), 
+        }
+    }
+}
+
 /// #### 한국어 </br>
 /// 렌더링 시스템을 초기화 합니다. </br>
+/// `adapter_selector`가 주어진 경우 조건에 맞는 어뎁터를 사용하며, 찾지 못한 경우 기본 어뎁터를 사용합니다. </br>
+/// 장치는 `device_request`의 기능과 제한 중 어뎁터가 지원하는 것만으로 생성됩니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Initialize the rendering system. </br>
+/// If `adapter_selector` is given, the matching adapter is used, and the default adapter is used if none is found. </br>
+/// The device is created with only the features and limits of `device_request` that the adapter supports. </br>
 /// 
-pub fn setup_rendering_system(window: Arc<Window>) -> (
+pub fn setup_rendering_system(
+    window: Arc<Window>, 
+    adapter_selector: Option<&AdapterSelector>, 
+    device_request: &DeviceRequest
+) -> (
     Arc<wgpu::Instance>, 
     Arc<wgpu::Surface<'static>>, 
     Arc<wgpu::Adapter>, 
@@ -18,11 +80,120 @@ pub fn setup_rendering_system(window: Arc<Window>) -> (
 ) {
     let instance = create_render_instance();
     let surface = create_render_surface(&instance, window.clone());
-    let adapter = create_render_adapter(&instance, &surface);
-    let (device, queue) = create_render_device_and_queue(&adapter);
+    let adapter = create_render_adapter(&instance, Some(&surface), adapter_selector);
+    let (device, queue) = create_render_device_and_queue(&adapter, device_request);
     (instance, surface, adapter, device, queue)
 }
 
+/// #### 한국어 </br>
+/// 창과 표면(surface) 없이 렌더링 시스템을 초기화 합니다. </br>
+/// 화면 밖 텍스처에만 그리는 경우(자동화된 이미지 테스트 등)에 사용하며, 표면 생성과 표시(present)는 생략됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Initialize the rendering system without a window and surface. </br>
+/// Used when drawing only into offscreen textures (automated image tests, etc.), and surface creation and presenting are skipped. </br>
+/// 
+pub fn setup_headless_rendering_system(
+    adapter_selector: Option<&AdapterSelector>, 
+    device_request: &DeviceRequest
+) -> (
+    Arc<wgpu::Instance>, 
+    Arc<wgpu::Adapter>, 
+    Arc<wgpu::Device>, 
+    Arc<wgpu::Queue>, 
+) {
+    let instance = create_render_instance();
+    let adapter = create_render_adapter(&instance, None, adapter_selector);
+    let (device, queue) = create_render_device_and_queue(&adapter, device_request);
+    (instance, adapter, device, queue)
+}
+
+/// #### 한국어 </br>
+/// 유니폼 버퍼 하나를 바인딩 0에 가지는 바인드 그룹 레이아웃을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a bind group layout with a single uniform buffer at binding 0. </br>
+/// 
+pub fn create_uniform_bind_group_layout(
+    device: &wgpu::Device, 
+    label: &str, 
+    visibility: wgpu::ShaderStages
+) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some(label), 
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, 
+                    visibility, 
+                    ty: wgpu::BindingType::Buffer { 
+                        ty: wgpu::BufferBindingType::Uniform, 
+                        has_dynamic_offset: false, 
+                        min_binding_size: None 
+                    }, 
+                    count: None,
+                },
+            ],
+        },
+    )
+}
+
+/// #### 한국어 </br>
+/// 깊이(스텐실) 버퍼의 텍스처 뷰를 생성합니다. 모든 깊이 버퍼는 이 함수로 생성됩니다. </br>
+/// 깊이 축소 패스, 깊이 벗기기 패스, 그림자 패스가 쉐이더에서 읽을 수 있도록 `TEXTURE_BINDING` 용도를 함께 가집니다. </br>
+/// 뷰가 텍스처를 유지하므로 텍스처는 반환하지 않으며, 화면 크기가 바뀌면 새 뷰로 교체하면 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a texture view of a depth (stencil) buffer. Every depth buffer is created with this function. </br>
+/// It also has the `TEXTURE_BINDING` usage so that the depth downsample, depth peeling and shadow passes can read it in shaders. </br>
+/// The view keeps the texture alive, so the texture is not returned, and on a resize the view is simply replaced with a new one. </br>
+/// 
+pub fn create_depth_stencil_view(
+    device: &wgpu::Device, 
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    width: u32, 
+    height: u32, 
+    layers: u32
+) -> wgpu::TextureView {
+    debug_assert!(format.is_depth_stencil_format());
+    device.create_texture(&render_target_descriptor(label, format, width, height, layers))
+        .create_view(&wgpu::TextureViewDescriptor {
+            ..Default::default()
+        })
+}
+
+/// #### 한국어 </br>
+/// 렌더 타겟으로 사용하고 쉐이더에서 읽을 수 있는 2차원 (배열) 텍스처의 설명자를 생성합니다. </br>
+/// 깊이 버퍼와 순서 독립적 투명도(OIT)의 렌더 타겟이 모두 이 설명자로 생성됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the descriptor of a 2D (array) texture that is used as a render target and can be read in shaders. </br>
+/// Both the depth buffers and the render targets of order-independent transparency (OIT) are created with this descriptor. </br>
+/// 
+pub fn render_target_descriptor(
+    label: &str, 
+    format: wgpu::TextureFormat, 
+    width: u32, 
+    height: u32, 
+    layers: u32
+) -> wgpu::TextureDescriptor<'_> {
+    wgpu::TextureDescriptor {
+        label: Some(label), 
+        size: wgpu::Extent3d {
+            width, 
+            height, 
+            depth_or_array_layers: layers, 
+        }, 
+        format, 
+        dimension: wgpu::TextureDimension::D2, 
+        mip_level_count: 1, 
+        sample_count: 1, 
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING, 
+        view_formats: &[], 
+    }
+}
+
 /// #### 한국어 </br>
 /// `wgpu` 렌더링 인스턴스를 생성합니다. </br>
 /// 
@@ -70,36 +241,181 @@ fn create_render_surface(instance: &wgpu::Instance, window: Arc<Window>) -> Arc<
 
 /// #### 한국어 </br>
 /// `wgpu` 렌더링 어뎁터를 생성합니다. </br>
+/// `selector`가 주어진 경우 표면을 지원하는 어뎁터들 중 조건에 맞는 첫 번째 어뎁터를 사용합니다. (표면이 없는 경우 모든 어뎁터) </br>
+/// 조건에 맞는 어뎁터가 없는 경우 경고와 함께 사용 가능한 어뎁터 목록을 출력하고 기본 어뎁터를 요청합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Creates a `wgpu` rendering adapter. </br>
+/// If `selector` is given, the first adapter matching it among the adapters that support the surface is used. (all adapters if there is no surface) </br>
+/// If no adapter matches, prints a warning along with the list of available adapters and requests the default adapter. </br>
 /// 
 #[inline]
-fn create_render_adapter(instance: &wgpu::Instance, surface: &wgpu::Surface) -> Arc<wgpu::Adapter> {
+fn create_render_adapter(instance: &wgpu::Instance, surface: Option<&wgpu::Surface>, selector: Option<&AdapterSelector>) -> Arc<wgpu::Adapter> {
+    let adapter = select_render_adapter(instance, surface, selector);
+    let info = adapter.get_info();
+    log::info!("Selected adapter: {} ({:?}, {:?})", info.name, info.backend, info.device_type);
+    adapter
+}
+
+#[inline]
+fn select_render_adapter(instance: &wgpu::Instance, surface: Option<&wgpu::Surface>, selector: Option<&AdapterSelector>) -> Arc<wgpu::Adapter> {
+    if let Some(selector) = selector {
+        let adapters = instance.enumerate_adapters(wgpu::Backends::all());
+        let selected = adapters.into_iter()
+            .enumerate()
+            .inspect(|(index, adapter)| {
+                let info = adapter.get_info();
+                log::info!("Adapter #{}: {} ({:?}, {:?})", index, info.name, info.backend, info.device_type);
+            })
+            .filter(|(_, adapter)| surface.map_or(true, |surface| adapter.is_surface_supported(surface)))
+            .find(|(index, adapter)| selector.matches(*index, &adapter.get_info()));
+
+        match selected {
+            Some((_, adapter)) => return Arc::new(adapter), 
+            None => log::warn!("No adapter matches {:?}. Fall back to the default adapter.", selector), 
+        }
+    }
+
     Arc::new(pollster::block_on(
         instance.request_adapter(&wgpu::RequestAdapterOptions {
-            compatible_surface: Some(surface), 
+            compatible_surface: surface, 
             force_fallback_adapter: false, 
             power_preference: wgpu::PowerPreference::default()
         }) 
     ).unwrap())
 }
 
+/// #### 한국어 </br>
+/// 렌더링 장치를 생성할 때 요청하는 기능과 제한 입니다. </br>
+/// 어뎁터가 지원하지 않는 기능과 제한은 장치를 요청하기 전에 경고와 함께 잘라내므로, 장치 요청이 실패하지 않습니다. </br>
+/// 
+/// 기본값은 어뎁터가 지원하는 경우 숨은 선 모드를 위한 `POLYGON_MODE_LINE` 기능, 깊이 클램핑을 위한 `DEPTH_CLIP_CONTROL` 기능, </br>
+/// `R16Unorm` 노출 값 렌더 타겟을 위한 `TEXTURE_FORMAT_16BIT_NORM` 기능, 스테레오 렌더링을 위한 `MULTIVIEW` 기능, </br>
+/// GPU 시간 측정을 위한 `TIMESTAMP_QUERY` 기능, 오브젝트별 데이터를 위한 `PUSH_CONSTANTS` 기능과 128 바이트의 푸시 상수를 요청합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The features and limits requested when creating the rendering device. </br>
+/// Features and limits the adapter does not support are trimmed with a warning before requesting the device, so the device request does not fail. </br>
+/// 
+/// By default, it requests the `POLYGON_MODE_LINE` feature for hidden-line mode, the `DEPTH_CLIP_CONTROL` feature for depth clamping, </br>
+/// the `TEXTURE_FORMAT_16BIT_NORM` feature for the `R16Unorm` revealage render target, the `MULTIVIEW` feature for stereo rendering, </br>
+/// the `TIMESTAMP_QUERY` feature for GPU timing, the `PUSH_CONSTANTS` feature for per-object data and 128 bytes of push constants if the adapter supports them. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceRequest {
+    pub features: wgpu::Features, 
+    pub limits: wgpu::Limits, 
+}
+
+impl Default for DeviceRequest {
+    fn default() -> Self {
+        Self {
+            features: wgpu::Features::POLYGON_MODE_LINE | 
+                wgpu::Features::DEPTH_CLIP_CONTROL | 
+                wgpu::Features::TEXTURE_FORMAT_16BIT_NORM | 
+                wgpu::Features::MULTIVIEW | 
+                wgpu::Features::TIMESTAMP_QUERY | 
+                wgpu::Features::PUSH_CONSTANTS, 
+            limits: wgpu::Limits {
+                // (한국어) 오브젝트별 데이터(`ColoredObjectUniformLayout`)를 푸시 상수로 전달할 수 있도록 128 바이트를 요청합니다.
+                // (English Translation) Requests 128 bytes so that per-object data (`ColoredObjectUniformLayout`) can be passed as push constants.
+                max_push_constant_size: 128, 
+                ..Default::default()
+            }, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl DeviceRequest {
+    #[inline]
+    pub fn new(features: wgpu::Features, limits: wgpu::Limits) -> Self {
+        Self { features, limits }
+    }
+
+    #[inline]
+    pub fn set_features(mut self, features: wgpu::Features) -> Self {
+        self.features = features;
+        self
+    }
+
+    #[inline]
+    pub fn set_limits(mut self, limits: wgpu::Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 어뎁터가 지원하는 범위로 잘라낸 기능과 제한을 반환합니다. </br>
+    /// 
+    /// 지원하지 않는 기능은 경고를 출력하고 빼며, 해상도 관련 제한은 어뎁터의 값을 사용합니다. </br>
+    /// 푸시 상수의 크기는 어뎁터의 최댓값으로 줄입니다. (`PUSH_CONSTANTS` 기능이 없는 경우 0) </br>
+    /// 그 외의 제한이 어뎁터의 제한을 넘는 경우 넘는 제한마다 경고를 출력하고 어뎁터의 제한을 그대로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the features and limits trimmed to what the adapter supports. </br>
+    /// 
+    /// Unsupported features are removed with a warning, and resolution related limits use the adapter's values. </br>
+    /// The push constant size is reduced to the adapter's maximum. (0 without the `PUSH_CONSTANTS` feature) </br>
+    /// If any other limit exceeds the adapter's limits, a warning is printed for each of them and the adapter's limits are used as is. </br>
+    /// 
+    #[inline]
+    pub fn supported_by(&self, adapter: &wgpu::Adapter) -> (wgpu::Features, wgpu::Limits) {
+        self.trimmed_to(adapter.features(), adapter.limits())
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 기능과 제한으로 잘라낸 기능과 제한을 반환합니다. (참고: `supported_by`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the features and limits trimmed to the given features and limits. (see also: `supported_by`) </br>
+    /// 
+    pub fn trimmed_to(&self, supported: wgpu::Features, allowed: wgpu::Limits) -> (wgpu::Features, wgpu::Limits) {
+        let unsupported = self.features - supported;
+        if !unsupported.is_empty() {
+            log::warn!("The adapter does not support the requested features {:?}. They are not enabled.", unsupported);
+        }
+        let features = self.features & supported;
+
+        let mut limits = wgpu::Limits {
+            max_push_constant_size: match features.contains(wgpu::Features::PUSH_CONSTANTS) {
+                true => self.limits.max_push_constant_size.min(allowed.max_push_constant_size), 
+                false => 0, 
+            }, 
+            ..self.limits.clone().using_resolution(allowed.clone())
+        };
+
+        let mut supported = true;
+        limits.check_limits_with_fail_fn(&allowed, false, |name, requested, allowed| {
+            log::warn!("The adapter does not support the requested limit {} = {}. (allowed: {})", name, requested, allowed);
+            supported = false;
+        });
+        if !supported {
+            limits = allowed;
+        }
+
+        (features, limits)
+    }
+}
+
 /// #### 한국어 </br>
 /// `wgpu` 렌더링 장치와 명령어 대기열을 생성합니다. </br>
+/// 요청한 기능과 제한은 어뎁터가 지원하는 범위로 잘라낸 후 요청합니다. (참고: `DeviceRequest::supported_by`) </br>
 /// 
 /// #### English (Translation) </br>
 /// Creates a `wgpu` rendering device and command queue. </br>
+/// The requested features and limits are trimmed to what the adapter supports before requesting. (see also: `DeviceRequest::supported_by`) </br>
 /// 
 #[inline]
-fn create_render_device_and_queue(adapter: &wgpu::Adapter) -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
+fn create_render_device_and_queue(adapter: &wgpu::Adapter, request: &DeviceRequest) -> (Arc<wgpu::Device>, Arc<wgpu::Queue>) {
+    let (required_features, required_limits) = request.supported_by(adapter);
+    log::info!("Enabled device features: {:?}", required_features);
     pollster::block_on(
         adapter.request_device(
             &wgpu::DeviceDescriptor {
                 label: Some("DeviceDescriptor"), 
-                required_features: wgpu::Features::empty(), 
-                required_limits: wgpu::Limits::default()
-                    .using_resolution(adapter.limits())
+                required_features, 
+                required_limits, 
             }, 
             None
         )
@@ -107,3 +423,135 @@ fn create_render_device_and_queue(adapter: &wgpu::Adapter) -> (Arc<wgpu::Device>
     .map(|(device, queue)| (Arc::new(device), Arc::new(queue)))
     .unwrap()
 }
+
+/// #### 한국어 </br>
+/// 순서 독립적 투명도(OIT)의 노출 값 렌더 타겟으로 사용할 형식을 선택합니다. </br>
+/// 선호하는 형식이 렌더 타겟, 텍스처 바인딩, 블렌딩을 모두 지원하는 경우 해당 형식을 반환하고, </br>
+/// 그렇지 않은 경우 경고를 출력하고 `R8Unorm`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Selects the format to use for the revealage render target of order-independent transparency (OIT). </br>
+/// Returns the preferred format if it supports render attachment, texture binding and blending, </br>
+/// otherwise prints a warning and returns `R8Unorm`. </br>
+/// 
+pub fn select_reveal_format(
+    adapter: &wgpu::Adapter, 
+    device: &wgpu::Device, 
+    preferred: wgpu::TextureFormat
+) -> wgpu::TextureFormat {
+    let features = adapter.get_texture_format_features(preferred);
+    let usages = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING;
+    let supported = features.allowed_usages.contains(usages)
+        && features.flags.contains(wgpu::TextureFormatFeatureFlags::BLENDABLE)
+        && device.features().contains(preferred.required_features());
+
+    if supported {
+        preferred
+    } else {
+        log::warn!("{:?} is not supported as a revealage render target. Fall back to R8Unorm.", preferred);
+        wgpu::TextureFormat::R8Unorm
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 화면(surface)에 사용할 색상 형식을 선택합니다. </br>
+/// 쉐이더는 선형 공간의 색상을 출력하므로, 지원되는 경우 sRGB 형식을 선택하여 쓰기 시 감마 보정이 자동으로 적용되도록 합니다. </br>
+/// sRGB 형식이 지원되지 않는 경우 경고를 출력하고 지원되는 첫 번째 형식을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Selects the color format to use for the surface. </br>
+/// Shaders output colors in linear space, so an sRGB format is chosen when supported so that gamma correction is applied automatically on write. </br>
+/// If no sRGB format is supported, prints a warning and returns the first supported format. </br>
+/// 
+pub fn select_surface_format(surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> wgpu::TextureFormat {
+    let capabilities = surface.get_capabilities(adapter);
+    match capabilities.formats.iter().copied().find(|format| format.is_srgb()) {
+        Some(format) => format, 
+        None => {
+            let format = capabilities.formats[0];
+            log::warn!("The surface does not support an sRGB format. Fall back to {:?}.", format);
+            format
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 화면(surface)이 지원하는 경우 선호하는 표시 모드를 반환합니다. </br>
+/// `AutoVsync`는 `FifoRelaxed` 또는 `Fifo`를, `AutoNoVsync`는 `Immediate` 또는 `Mailbox`를 지원하는 경우 지원되는 것으로 봅니다. </br>
+/// 지원되지 않는 경우 경고를 출력하고 항상 지원되는 `Fifo`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the preferred present mode if the surface supports it. </br>
+/// `AutoVsync` is considered supported if `FifoRelaxed` or `Fifo` is supported, and `AutoNoVsync` if `Immediate` or `Mailbox` is supported. </br>
+/// Otherwise, prints a warning and returns `Fifo`, which is always supported. </br>
+/// 
+pub fn select_present_mode(
+    surface: &wgpu::Surface, 
+    adapter: &wgpu::Adapter, 
+    preferred: wgpu::PresentMode
+) -> wgpu::PresentMode {
+    let present_modes = surface.get_capabilities(adapter).present_modes;
+    let supported = match preferred {
+        wgpu::PresentMode::AutoVsync => [wgpu::PresentMode::FifoRelaxed, wgpu::PresentMode::Fifo]
+            .iter()
+            .any(|mode| present_modes.contains(mode)), 
+        wgpu::PresentMode::AutoNoVsync => [wgpu::PresentMode::Immediate, wgpu::PresentMode::Mailbox]
+            .iter()
+            .any(|mode| present_modes.contains(mode)), 
+        mode => present_modes.contains(&mode), 
+    };
+
+    if supported {
+        preferred
+    } else {
+        log::warn!("The surface does not support the {:?} present mode. Fall back to Fifo.", preferred);
+        wgpu::PresentMode::Fifo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_target_descriptor_has_requested_size() {
+        let descriptor = render_target_descriptor("Accumulate", crate::pipeline::PipelineSettings::ACCUM_FORMAT, 1280, 720, 2);
+        assert_eq!(descriptor.size, wgpu::Extent3d { width: 1280, height: 720, depth_or_array_layers: 2 });
+        assert_eq!(descriptor.format, wgpu::TextureFormat::Rgba16Float);
+        assert!(descriptor.usage.contains(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING));
+        assert_eq!(descriptor.mip_level_count, 1);
+        assert_eq!(descriptor.sample_count, 1);
+    }
+
+    #[test]
+    fn empty_request_is_always_supported() {
+        let request = DeviceRequest::new(wgpu::Features::empty(), wgpu::Limits::downlevel_defaults());
+        let (features, limits) = request.trimmed_to(wgpu::Features::empty(), wgpu::Limits::downlevel_defaults());
+        assert_eq!(features, wgpu::Features::empty());
+        assert_eq!(limits.max_push_constant_size, 0);
+        assert!(limits.check_limits(&wgpu::Limits::downlevel_defaults()));
+    }
+
+    #[test]
+    fn unsupported_features_and_limits_are_trimmed() {
+        let supported = wgpu::Features::POLYGON_MODE_LINE | wgpu::Features::PUSH_CONSTANTS;
+        let allowed = wgpu::Limits { max_push_constant_size: 64, ..Default::default() };
+        let (features, limits) = DeviceRequest::default().trimmed_to(supported, allowed.clone());
+        assert_eq!(features, supported);
+        assert_eq!(limits.max_push_constant_size, 64);
+
+        // (한국어) 푸시 상수 기능이 없으면 푸시 상수 크기는 0이 됩니다.
+        // (English Translation) Without the push constant feature, the push constant size becomes 0.
+        let (features, limits) = DeviceRequest::default().trimmed_to(wgpu::Features::POLYGON_MODE_LINE, allowed.clone());
+        assert_eq!(features, wgpu::Features::POLYGON_MODE_LINE);
+        assert_eq!(limits.max_push_constant_size, 0);
+
+        // (한국어) 어뎁터의 제한을 넘는 제한을 요청하면 어뎁터의 제한을 그대로 사용합니다.
+        // (English Translation) Requesting a limit beyond the adapter's limits uses the adapter's limits as is.
+        let request = DeviceRequest::default().set_limits(wgpu::Limits { max_bind_groups: 64, ..Default::default() });
+        let (_, limits) = request.trimmed_to(supported, allowed.clone());
+        assert_eq!(limits, allowed);
+    }
+}